@@ -0,0 +1,103 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
+}
+pub const VAULT_ACCOUNT_DISCM: [u8; 8] = [211, 8, 232, 43, 2, 152, 117, 119];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct VaultAccount(pub Vault);
+impl VaultAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != VAULT_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                VAULT_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(Vault::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&VAULT_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const VAULT_ACCOUNT_LEN: usize = 108;
+impl VaultAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, VAULT_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `close_authority`
+    pub fn filter_close_authority(
+        close_authority: &COption<Pubkey>,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((8, borsh::to_vec(close_authority)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_shares`
+    pub fn filter_total_shares(total_shares: &U256) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((44, borsh::to_vec(total_shares)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pnl`
+    pub fn filter_pnl(pnl: &I256) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((76, borsh::to_vec(pnl)?))
+    }
+}
+#[cfg(feature = "client")]
+impl VaultAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_close_authority(
+        close_authority: &COption<Pubkey>,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_close_authority(close_authority).map(memcmp_filter)
+    }
+    pub fn rpc_filter_total_shares(total_shares: &U256) -> std::io::Result<RpcFilterType> {
+        Self::filter_total_shares(total_shares).map(memcmp_filter)
+    }
+    pub fn rpc_filter_pnl(pnl: &I256) -> std::io::Result<RpcFilterType> {
+        Self::filter_pnl(pnl).map(memcmp_filter)
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorCollectionsAccountData {
+    Vault(VaultAccount),
+}
+impl AnchorCollectionsAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&VAULT_ACCOUNT_DISCM) {
+            return Ok(Self::Vault(VaultAccount::deserialize(buf)?));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::Vault(_) => &VAULT_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Vault(_) => "Vault",
+        }
+    }
+}
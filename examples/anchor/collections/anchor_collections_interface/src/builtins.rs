@@ -0,0 +1,519 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use std::io::Read;
+/// Option with a u32 tag, laid out like `solana_program::program_option::COption`.
+///
+/// The value is always serialized, as `T::default()` if `None`,
+/// so that the serialized size is fixed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum COption<T> {
+    #[default]
+    None,
+    Some(T),
+}
+impl<T> COption<T> {
+    pub fn is_some(&self) -> bool {
+        matches!(self, Self::Some(_))
+    }
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+    pub fn as_option(&self) -> Option<&T> {
+        match self {
+            Self::None => None,
+            Self::Some(v) => Some(v),
+        }
+    }
+}
+impl<T> From<Option<T>> for COption<T> {
+    fn from(o: Option<T>) -> Self {
+        match o {
+            None => Self::None,
+            Some(v) => Self::Some(v),
+        }
+    }
+}
+impl<T> From<COption<T>> for Option<T> {
+    fn from(o: COption<T>) -> Self {
+        match o {
+            COption::None => None,
+            COption::Some(v) => Some(v),
+        }
+    }
+}
+impl<T: BorshSerialize + Default> BorshSerialize for COption<T> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::None => {
+                0u32.serialize(writer)?;
+                T::default().serialize(writer)
+            }
+            Self::Some(v) => {
+                1u32.serialize(writer)?;
+                v.serialize(writer)
+            }
+        }
+    }
+}
+impl<T: BorshDeserialize> BorshDeserialize for COption<T> {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let tag = u32::deserialize_reader(reader)?;
+        let v = T::deserialize_reader(reader)?;
+        match tag {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Some(v)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("invalid COption tag {}", tag),
+            )),
+        }
+    }
+}
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for COption<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_option().serialize(serializer)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for COption<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Into::into)
+    }
+}
+/// Unsigned 256-bit integer as 32 little-endian bytes
+#[repr(transparent)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    BorshDeserialize,
+    BorshSerialize,
+    Pod,
+    Zeroable,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct U256(pub [u8; 32]);
+impl U256 {
+    pub const ZERO: Self = Self([0u8; 32]);
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+    pub const fn to_le_bytes(self) -> [u8; 32] {
+        self.0
+    }
+}
+impl From<u128> for U256 {
+    fn from(n: u128) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&n.to_le_bytes());
+        Self(bytes)
+    }
+}
+impl From<u64> for U256 {
+    fn from(n: u64) -> Self {
+        u128::from(n).into()
+    }
+}
+impl TryFrom<U256> for u128 {
+    type Error = IntOverflowError;
+    fn try_from(n: U256) -> Result<Self, Self::Error> {
+        let (lo, hi) = n.0.split_at(16);
+        if hi.iter().any(|b| *b != 0) {
+            return Err(IntOverflowError);
+        }
+        Ok(u128::from_le_bytes(lo.try_into().unwrap()))
+    }
+}
+/// Signed 256-bit integer as 32 little-endian two's complement bytes
+#[repr(transparent)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    BorshDeserialize,
+    BorshSerialize,
+    Pod,
+    Zeroable,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct I256(pub [u8; 32]);
+impl I256 {
+    pub const ZERO: Self = Self([0u8; 32]);
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+    pub const fn to_le_bytes(self) -> [u8; 32] {
+        self.0
+    }
+    pub const fn is_negative(&self) -> bool {
+        self.0[31] & 0x80 != 0
+    }
+}
+impl From<i128> for I256 {
+    fn from(n: i128) -> Self {
+        let sign_ext = if n < 0 { 0xff } else { 0 };
+        let mut bytes = [sign_ext; 32];
+        bytes[..16].copy_from_slice(&n.to_le_bytes());
+        Self(bytes)
+    }
+}
+impl From<i64> for I256 {
+    fn from(n: i64) -> Self {
+        i128::from(n).into()
+    }
+}
+impl TryFrom<I256> for i128 {
+    type Error = IntOverflowError;
+    fn try_from(n: I256) -> Result<Self, Self::Error> {
+        let (lo, hi) = n.0.split_at(16);
+        let sign_ext = if lo[15] & 0x80 != 0 { 0xff } else { 0 };
+        if hi.iter().any(|b| *b != sign_ext) {
+            return Err(IntOverflowError);
+        }
+        Ok(i128::from_le_bytes(lo.try_into().unwrap()))
+    }
+}
+///`Vec` with a u64 length prefix
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct U64PrefixVec<T>(pub Vec<T>);
+impl<T> std::ops::Deref for U64PrefixVec<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> std::ops::DerefMut for U64PrefixVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T> From<Vec<T>> for U64PrefixVec<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self(v)
+    }
+}
+impl<T> From<U64PrefixVec<T>> for Vec<T> {
+    fn from(v: U64PrefixVec<T>) -> Self {
+        v.0
+    }
+}
+impl<T: BorshSerialize> BorshSerialize for U64PrefixVec<T> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_u64_len(self.0.len(), writer)?;
+        self.0.iter().try_for_each(|v| v.serialize(writer))
+    }
+}
+impl<T: BorshDeserialize> BorshDeserialize for U64PrefixVec<T> {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = read_u64_len(reader)?;
+        (0..len)
+            .map(|_| T::deserialize_reader(reader))
+            .collect::<std::io::Result<Vec<T>>>()
+            .map(Self)
+    }
+}
+///`Vec` with a compact-u16 (shortvec) length prefix
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ShortVec<T>(pub Vec<T>);
+impl<T> std::ops::Deref for ShortVec<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> std::ops::DerefMut for ShortVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T> From<Vec<T>> for ShortVec<T> {
+    fn from(v: Vec<T>) -> Self {
+        Self(v)
+    }
+}
+impl<T> From<ShortVec<T>> for Vec<T> {
+    fn from(v: ShortVec<T>) -> Self {
+        v.0
+    }
+}
+impl<T: BorshSerialize> BorshSerialize for ShortVec<T> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_short_u16_len(self.0.len(), writer)?;
+        self.0.iter().try_for_each(|v| v.serialize(writer))
+    }
+}
+impl<T: BorshDeserialize> BorshDeserialize for ShortVec<T> {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = read_short_u16_len(reader)?;
+        (0..len)
+            .map(|_| T::deserialize_reader(reader))
+            .collect::<std::io::Result<Vec<T>>>()
+            .map(Self)
+    }
+}
+///`String` with a u8 length prefix
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct U8PrefixString(pub String);
+impl std::ops::Deref for U8PrefixString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl std::ops::DerefMut for U8PrefixString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl From<String> for U8PrefixString {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+impl From<&str> for U8PrefixString {
+    fn from(s: &str) -> Self {
+        Self(s.to_owned())
+    }
+}
+impl From<U8PrefixString> for String {
+    fn from(s: U8PrefixString) -> Self {
+        s.0
+    }
+}
+impl BorshSerialize for U8PrefixString {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_u8_len(self.0.len(), writer)?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+impl BorshDeserialize for U8PrefixString {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = read_u8_len(reader)?;
+        let mut buf = Vec::new();
+        reader.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() != len {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(buf)
+            .map(Self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+///`String` with a u16 length prefix
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct U16PrefixString(pub String);
+impl std::ops::Deref for U16PrefixString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl std::ops::DerefMut for U16PrefixString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl From<String> for U16PrefixString {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+impl From<&str> for U16PrefixString {
+    fn from(s: &str) -> Self {
+        Self(s.to_owned())
+    }
+}
+impl From<U16PrefixString> for String {
+    fn from(s: U16PrefixString) -> Self {
+        s.0
+    }
+}
+impl BorshSerialize for U16PrefixString {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_u16_len(self.0.len(), writer)?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+impl BorshDeserialize for U16PrefixString {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = read_u16_len(reader)?;
+        let mut buf = Vec::new();
+        reader.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() != len {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(buf)
+            .map(Self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+///`String` with a compact-u16 (shortvec) length prefix
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ShortVecString(pub String);
+impl std::ops::Deref for ShortVecString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl std::ops::DerefMut for ShortVecString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl From<String> for ShortVecString {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+impl From<&str> for ShortVecString {
+    fn from(s: &str) -> Self {
+        Self(s.to_owned())
+    }
+}
+impl From<ShortVecString> for String {
+    fn from(s: ShortVecString) -> Self {
+        s.0
+    }
+}
+impl BorshSerialize for ShortVecString {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        write_short_u16_len(self.0.len(), writer)?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+impl BorshDeserialize for ShortVecString {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = read_short_u16_len(reader)?;
+        let mut buf = Vec::new();
+        reader.take(len as u64).read_to_end(&mut buf)?;
+        if buf.len() != len {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(buf)
+            .map(Self)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+/// A 256-bit integer did not fit in the target integer type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntOverflowError;
+impl std::fmt::Display for IntOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("integer overflow")
+    }
+}
+impl std::error::Error for IntOverflowError {}
+fn write_u8_len<W: std::io::Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+    u8::try_from(len)
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "length overflows u8 prefix",
+            )
+        })?
+        .serialize(writer)
+}
+fn read_u8_len<R: std::io::Read>(reader: &mut R) -> std::io::Result<usize> {
+    let len = u8::deserialize_reader(reader)?;
+    Ok(usize::from(len))
+}
+fn write_u16_len<W: std::io::Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+    u16::try_from(len)
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "length overflows u16 prefix",
+            )
+        })?
+        .serialize(writer)
+}
+fn read_u16_len<R: std::io::Read>(reader: &mut R) -> std::io::Result<usize> {
+    let len = u16::deserialize_reader(reader)?;
+    Ok(usize::from(len))
+}
+fn write_u64_len<W: std::io::Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+    u64::try_from(len)
+        .map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "length overflows u64 prefix",
+            )
+        })?
+        .serialize(writer)
+}
+fn read_u64_len<R: std::io::Read>(reader: &mut R) -> std::io::Result<usize> {
+    let len = u64::deserialize_reader(reader)?;
+    usize::try_from(len)
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "length overflows usize"))
+}
+fn write_short_u16_len<W: std::io::Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+    let mut rem = u16::try_from(len).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "length overflows shortvec prefix",
+        )
+    })?;
+    loop {
+        let mut byte = (rem & 0x7f) as u8;
+        rem >>= 7;
+        if rem != 0 {
+            byte |= 0x80;
+        }
+        byte.serialize(writer)?;
+        if rem == 0 {
+            return Ok(());
+        }
+    }
+}
+fn read_short_u16_len<R: std::io::Read>(reader: &mut R) -> std::io::Result<usize> {
+    let mut len = 0usize;
+    for i in 0..3 {
+        let byte = u8::deserialize_reader(reader)?;
+        len |= usize::from(byte & 0x7f) << (i * 7);
+        if byte & 0x80 == 0 {
+            if len > usize::from(u16::MAX) {
+                break;
+            }
+            return Ok(len);
+        }
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "invalid shortvec prefix",
+    ))
+}
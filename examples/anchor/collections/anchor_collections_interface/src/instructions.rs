@@ -0,0 +1,472 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorCollectionsProgramIx {
+    Configure(ConfigureIxArgs),
+    SetMetadata(SetMetadataIxArgs),
+}
+impl AnchorCollectionsProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            CONFIGURE_IX_DISCM => Ok(Self::Configure(ConfigureIxArgs::deserialize(&mut reader)?)),
+            SET_METADATA_IX_DISCM => Ok(Self::SetMetadata(SetMetadataIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::Configure(args) => {
+                writer.write_all(&CONFIGURE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetMetadata(args) => {
+                writer.write_all(&SET_METADATA_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const CONFIGURE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct ConfigureAccounts<'me, 'info> {
+    pub vault: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConfigureKeys {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<ConfigureAccounts<'_, '_>> for ConfigureKeys {
+    fn from(accounts: ConfigureAccounts) -> Self {
+        Self {
+            vault: *accounts.vault.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<ConfigureKeys> for [AccountMeta; CONFIGURE_IX_ACCOUNTS_LEN] {
+    fn from(keys: ConfigureKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<ConfigureKeys> for Vec<AccountMeta> {
+    fn from(keys: ConfigureKeys) -> Self {
+        let accounts: [AccountMeta; CONFIGURE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; CONFIGURE_IX_ACCOUNTS_LEN]> for ConfigureKeys {
+    fn from(pubkeys: [Pubkey; CONFIGURE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<ConfigureAccounts<'_, 'info>> for [AccountInfo<'info>; CONFIGURE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ConfigureAccounts<'_, 'info>) -> Self {
+        [accounts.vault.clone(), accounts.authority.clone()]
+    }
+}
+impl<'info> From<ConfigureAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ConfigureAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CONFIGURE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIGURE_IX_ACCOUNTS_LEN]>
+    for ConfigureAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CONFIGURE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const CONFIGURE_IX_DISCM: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+#[derive(Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfigureIxArgs {
+    pub weights: std::collections::HashMap<Pubkey, u64>,
+    pub tiers: std::collections::BTreeMap<u8, Vault>,
+    pub allowlist: std::collections::HashSet<Pubkey>,
+    pub fee_bps: std::collections::BTreeSet<u16>,
+    pub cap: COption<u64>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigureIxData(pub ConfigureIxArgs);
+impl From<ConfigureIxArgs> for ConfigureIxData {
+    fn from(args: ConfigureIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ConfigureIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CONFIGURE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CONFIGURE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(ConfigureIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CONFIGURE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn configure_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ConfigureKeys,
+    args: ConfigureIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CONFIGURE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ConfigureIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn configure_ix(keys: ConfigureKeys, args: ConfigureIxArgs) -> std::io::Result<Instruction> {
+    configure_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn configure_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ConfigureAccounts<'_, '_>,
+    args: ConfigureIxArgs,
+) -> ProgramResult {
+    let keys: ConfigureKeys = accounts.into();
+    let ix = configure_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn configure_invoke(
+    accounts: ConfigureAccounts<'_, '_>,
+    args: ConfigureIxArgs,
+) -> ProgramResult {
+    configure_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn configure_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ConfigureAccounts<'_, '_>,
+    args: ConfigureIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: ConfigureKeys = accounts.into();
+    let ix = configure_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn configure_invoke_signed(
+    accounts: ConfigureAccounts<'_, '_>,
+    args: ConfigureIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    configure_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn configure_verify_account_keys(
+    accounts: ConfigureAccounts<'_, '_>,
+    keys: ConfigureKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.vault.key, keys.vault),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn configure_verify_writable_privileges<'me, 'info>(
+    accounts: ConfigureAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vault] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn configure_verify_signer_privileges<'me, 'info>(
+    accounts: ConfigureAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn configure_verify_account_privileges<'me, 'info>(
+    accounts: ConfigureAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    configure_verify_writable_privileges(accounts)?;
+    configure_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_METADATA_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct SetMetadataAccounts<'me, 'info> {
+    pub vault: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetMetadataKeys {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<SetMetadataAccounts<'_, '_>> for SetMetadataKeys {
+    fn from(accounts: SetMetadataAccounts) -> Self {
+        Self {
+            vault: *accounts.vault.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<SetMetadataKeys> for [AccountMeta; SET_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<SetMetadataKeys> for Vec<AccountMeta> {
+    fn from(keys: SetMetadataKeys) -> Self {
+        let accounts: [AccountMeta; SET_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; SET_METADATA_IX_ACCOUNTS_LEN]> for SetMetadataKeys {
+    fn from(pubkeys: [Pubkey; SET_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<SetMetadataAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_METADATA_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetMetadataAccounts<'_, 'info>) -> Self {
+        [accounts.vault.clone(), accounts.authority.clone()]
+    }
+}
+impl<'info> From<SetMetadataAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetMetadataAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_METADATA_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_METADATA_IX_ACCOUNTS_LEN]>
+    for SetMetadataAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const SET_METADATA_IX_DISCM: [u8; 8] = [2, 3, 4, 5, 6, 7, 8, 9];
+#[derive(Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetMetadataIxArgs {
+    pub name: U8PrefixString,
+    pub uri: U16PrefixString,
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
+    pub data: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
+    pub extra: Option<Vec<u8>>,
+    pub blob: U64PrefixVec<u8>,
+    pub signers: ShortVec<Pubkey>,
+    pub label: ShortVecString,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetMetadataIxData(pub SetMetadataIxArgs);
+impl From<SetMetadataIxArgs> for SetMetadataIxData {
+    fn from(args: SetMetadataIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_METADATA_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_METADATA_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(SetMetadataIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_METADATA_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetMetadataKeys,
+    args: SetMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetMetadataIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_metadata_ix(
+    keys: SetMetadataKeys,
+    args: SetMetadataIxArgs,
+) -> std::io::Result<Instruction> {
+    set_metadata_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetMetadataAccounts<'_, '_>,
+    args: SetMetadataIxArgs,
+) -> ProgramResult {
+    let keys: SetMetadataKeys = accounts.into();
+    let ix = set_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_metadata_invoke(
+    accounts: SetMetadataAccounts<'_, '_>,
+    args: SetMetadataIxArgs,
+) -> ProgramResult {
+    set_metadata_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetMetadataAccounts<'_, '_>,
+    args: SetMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetMetadataKeys = accounts.into();
+    let ix = set_metadata_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_metadata_invoke_signed(
+    accounts: SetMetadataAccounts<'_, '_>,
+    args: SetMetadataIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_metadata_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_metadata_verify_account_keys(
+    accounts: SetMetadataAccounts<'_, '_>,
+    keys: SetMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.vault.key, keys.vault),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_metadata_verify_writable_privileges<'me, 'info>(
+    accounts: SetMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vault] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_metadata_verify_signer_privileges<'me, 'info>(
+    accounts: SetMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn set_metadata_verify_account_privileges<'me, 'info>(
+    accounts: SetMetadataAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_metadata_verify_writable_privileges(accounts)?;
+    set_metadata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -0,0 +1,9 @@
+solana_program::declare_id!("Co11ections11111111111111111111111111111111");
+pub mod accounts;
+pub use accounts::*;
+pub mod typedefs;
+pub use typedefs::*;
+pub mod builtins;
+pub use builtins::*;
+pub mod instructions;
+pub use instructions::*;
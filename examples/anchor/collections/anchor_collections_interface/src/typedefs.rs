@@ -0,0 +1,11 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vault {
+    pub close_authority: COption<Pubkey>,
+    pub total_shares: U256,
+    pub pnl: I256,
+}
+pub const VAULT_LEN: usize = 100;
@@ -0,0 +1,11 @@
+use solana_program::pubkey::Pubkey;
+pub const VAULT_SEED: &[u8] = &[118, 97, 117, 108, 116];
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const FEE_RATE_DENOMINATOR: u64 = 1000000;
+pub const MAX_REWARDS: usize = 3;
+pub const MIN_TICK: i32 = -443636;
+pub const PAUSED: bool = false;
+pub const ADMIN: Pubkey = solana_program::pubkey!("GThUX1Atko4tqhN2NaiTazWSeFWMuiUvfFnyJyUghFMJ");
+pub const MAGIC: [u8; 4] = [222, 173, 190, 239];
+pub const NAME: &str = "constants";
+pub const LOCK_DURATION: i64 = 60 * 60 * 24;
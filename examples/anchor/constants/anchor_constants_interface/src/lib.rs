@@ -0,0 +1,3 @@
+solana_program::declare_id!("TH1S1SNoTAVAL1DPUBKEYDoNoTUSE11111111111111");
+pub mod constants;
+pub use constants::*;
@@ -0,0 +1,56 @@
+use base64::Engine;
+use borsh::BorshSerialize;
+use drift_interface::{DriftEvent, NewUserRecord, NewUserRecordEvent, EVENT_IX_TAG};
+use solana_program::pubkey::Pubkey;
+
+fn new_user_record_event(ts: i64) -> NewUserRecordEvent {
+    NewUserRecordEvent(NewUserRecord {
+        ts,
+        user_authority: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        sub_account_id: 1,
+        name: [0u8; 32],
+        referrer: Pubkey::default(),
+    })
+}
+
+fn program_data_log(event: &NewUserRecordEvent) -> String {
+    let data = borsh::to_vec(event).unwrap();
+    format!(
+        "Program data: {}",
+        base64::engine::general_purpose::STANDARD.encode(data)
+    )
+}
+
+#[test]
+fn test_events_from_logs_only_drift_invocations() {
+    let drift_event = new_user_record_event(1);
+    let other_program_event = new_user_record_event(2);
+    let drift_id = drift_interface::ID.to_string();
+    let other_id = Pubkey::new_unique().to_string();
+    let logs = [
+        format!("Program {drift_id} invoke [1]"),
+        "Program log: Instruction: InitializeUser".to_owned(),
+        format!("Program {other_id} invoke [2]"),
+        program_data_log(&other_program_event),
+        format!("Program {other_id} success"),
+        program_data_log(&drift_event),
+        format!("Program {drift_id} consumed 1000 of 200000 compute units"),
+        format!("Program {drift_id} success"),
+        program_data_log(&other_program_event),
+    ];
+    let events = DriftEvent::from_logs(&logs).unwrap();
+    assert_eq!(events, vec![DriftEvent::NewUserRecord(drift_event)]);
+}
+
+#[test]
+fn test_event_from_cpi_ix_data() {
+    let event = new_user_record_event(1);
+    let mut data = EVENT_IX_TAG.to_vec();
+    event.serialize(&mut data).unwrap();
+    assert_eq!(
+        DriftEvent::from_cpi_ix_data(&data).unwrap(),
+        DriftEvent::NewUserRecord(event)
+    );
+    assert!(DriftEvent::from_cpi_ix_data(&data[8..]).is_err());
+}
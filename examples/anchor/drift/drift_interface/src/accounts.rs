@@ -1,22 +1,14 @@
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
-pub const PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] = [233, 45, 62, 40, 35, 129, 48, 72];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct PhoenixV1FulfillmentConfig {
-    pub pubkey: Pubkey,
-    pub phoenix_program_id: Pubkey,
-    pub phoenix_log_authority: Pubkey,
-    pub phoenix_market: Pubkey,
-    pub phoenix_base_vault: Pubkey,
-    pub phoenix_quote_vault: Pubkey,
-    pub market_index: u16,
-    pub fulfillment_type: SpotFulfillmentType,
-    pub status: SpotFulfillmentConfigStatus,
-    pub padding: [u8; 4],
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
 }
-#[derive(Clone, Debug, PartialEq)]
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] = [233, 45, 62, 40, 35, 129, 48, 72];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct PhoenixV1FulfillmentConfigAccount(pub PhoenixV1FulfillmentConfig);
 impl PhoenixV1FulfillmentConfigAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -25,13 +17,10 @@ impl PhoenixV1FulfillmentConfigAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PhoenixV1FulfillmentConfig::deserialize(&mut reader)?))
     }
@@ -45,28 +34,100 @@ impl PhoenixV1FulfillmentConfigAccount {
         Ok(data)
     }
 }
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_LEN: usize = 208;
+impl PhoenixV1FulfillmentConfigAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pubkey`
+    pub fn filter_pubkey(pubkey: &Pubkey) -> (usize, Vec<u8>) {
+        (8, pubkey.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `phoenix_program_id`
+    pub fn filter_phoenix_program_id(phoenix_program_id: &Pubkey) -> (usize, Vec<u8>) {
+        (40, phoenix_program_id.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `phoenix_log_authority`
+    pub fn filter_phoenix_log_authority(phoenix_log_authority: &Pubkey) -> (usize, Vec<u8>) {
+        (72, phoenix_log_authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `phoenix_market`
+    pub fn filter_phoenix_market(phoenix_market: &Pubkey) -> (usize, Vec<u8>) {
+        (104, phoenix_market.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `phoenix_base_vault`
+    pub fn filter_phoenix_base_vault(phoenix_base_vault: &Pubkey) -> (usize, Vec<u8>) {
+        (136, phoenix_base_vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `phoenix_quote_vault`
+    pub fn filter_phoenix_quote_vault(phoenix_quote_vault: &Pubkey) -> (usize, Vec<u8>) {
+        (168, phoenix_quote_vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `market_index`
+    pub fn filter_market_index(market_index: u16) -> (usize, Vec<u8>) {
+        (200, market_index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fulfillment_type`
+    pub fn filter_fulfillment_type(
+        fulfillment_type: &SpotFulfillmentType,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((202, borsh::to_vec(fulfillment_type)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `status`
+    pub fn filter_status(
+        status: &SpotFulfillmentConfigStatus,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((203, borsh::to_vec(status)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 4]) -> (usize, Vec<u8>) {
+        (204, padding.to_vec())
+    }
+}
+#[cfg(feature = "client")]
+impl PhoenixV1FulfillmentConfigAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_pubkey(pubkey: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_pubkey(pubkey))
+    }
+    pub fn rpc_filter_phoenix_program_id(phoenix_program_id: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_phoenix_program_id(phoenix_program_id))
+    }
+    pub fn rpc_filter_phoenix_log_authority(phoenix_log_authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_phoenix_log_authority(phoenix_log_authority))
+    }
+    pub fn rpc_filter_phoenix_market(phoenix_market: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_phoenix_market(phoenix_market))
+    }
+    pub fn rpc_filter_phoenix_base_vault(phoenix_base_vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_phoenix_base_vault(phoenix_base_vault))
+    }
+    pub fn rpc_filter_phoenix_quote_vault(phoenix_quote_vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_phoenix_quote_vault(phoenix_quote_vault))
+    }
+    pub fn rpc_filter_market_index(market_index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_market_index(market_index))
+    }
+    pub fn rpc_filter_fulfillment_type(
+        fulfillment_type: &SpotFulfillmentType,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_fulfillment_type(fulfillment_type).map(memcmp_filter)
+    }
+    pub fn rpc_filter_status(
+        status: &SpotFulfillmentConfigStatus,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_status(status).map(memcmp_filter)
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 4]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
 pub const SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] =
     [65, 160, 197, 112, 239, 168, 103, 185];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SerumV3FulfillmentConfig {
-    pub pubkey: Pubkey,
-    pub serum_program_id: Pubkey,
-    pub serum_market: Pubkey,
-    pub serum_request_queue: Pubkey,
-    pub serum_event_queue: Pubkey,
-    pub serum_bids: Pubkey,
-    pub serum_asks: Pubkey,
-    pub serum_base_vault: Pubkey,
-    pub serum_quote_vault: Pubkey,
-    pub serum_open_orders: Pubkey,
-    pub serum_signer_nonce: u64,
-    pub market_index: u16,
-    pub fulfillment_type: SpotFulfillmentType,
-    pub status: SpotFulfillmentConfigStatus,
-    pub padding: [u8; 4],
-}
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct SerumV3FulfillmentConfigAccount(pub SerumV3FulfillmentConfig);
 impl SerumV3FulfillmentConfigAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -75,13 +136,10 @@ impl SerumV3FulfillmentConfigAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SerumV3FulfillmentConfig::deserialize(&mut reader)?))
     }
@@ -95,22 +153,134 @@ impl SerumV3FulfillmentConfigAccount {
         Ok(data)
     }
 }
-pub const INSURANCE_FUND_STAKE_ACCOUNT_DISCM: [u8; 8] = [110, 202, 14, 42, 95, 73, 90, 95];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct InsuranceFundStake {
-    pub authority: Pubkey,
-    pub if_shares: u128,
-    pub last_withdraw_request_shares: u128,
-    pub if_base: u128,
-    pub last_valid_ts: i64,
-    pub last_withdraw_request_value: u64,
-    pub last_withdraw_request_ts: i64,
-    pub cost_basis: i64,
-    pub market_index: u16,
-    pub padding: [u8; 14],
+pub const SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_LEN: usize = 344;
+impl SerumV3FulfillmentConfigAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pubkey`
+    pub fn filter_pubkey(pubkey: &Pubkey) -> (usize, Vec<u8>) {
+        (8, pubkey.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_program_id`
+    pub fn filter_serum_program_id(serum_program_id: &Pubkey) -> (usize, Vec<u8>) {
+        (40, serum_program_id.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_market`
+    pub fn filter_serum_market(serum_market: &Pubkey) -> (usize, Vec<u8>) {
+        (72, serum_market.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_request_queue`
+    pub fn filter_serum_request_queue(serum_request_queue: &Pubkey) -> (usize, Vec<u8>) {
+        (104, serum_request_queue.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_event_queue`
+    pub fn filter_serum_event_queue(serum_event_queue: &Pubkey) -> (usize, Vec<u8>) {
+        (136, serum_event_queue.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_bids`
+    pub fn filter_serum_bids(serum_bids: &Pubkey) -> (usize, Vec<u8>) {
+        (168, serum_bids.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_asks`
+    pub fn filter_serum_asks(serum_asks: &Pubkey) -> (usize, Vec<u8>) {
+        (200, serum_asks.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_base_vault`
+    pub fn filter_serum_base_vault(serum_base_vault: &Pubkey) -> (usize, Vec<u8>) {
+        (232, serum_base_vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_quote_vault`
+    pub fn filter_serum_quote_vault(serum_quote_vault: &Pubkey) -> (usize, Vec<u8>) {
+        (264, serum_quote_vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_open_orders`
+    pub fn filter_serum_open_orders(serum_open_orders: &Pubkey) -> (usize, Vec<u8>) {
+        (296, serum_open_orders.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `serum_signer_nonce`
+    pub fn filter_serum_signer_nonce(serum_signer_nonce: u64) -> (usize, Vec<u8>) {
+        (328, serum_signer_nonce.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `market_index`
+    pub fn filter_market_index(market_index: u16) -> (usize, Vec<u8>) {
+        (336, market_index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fulfillment_type`
+    pub fn filter_fulfillment_type(
+        fulfillment_type: &SpotFulfillmentType,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((338, borsh::to_vec(fulfillment_type)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `status`
+    pub fn filter_status(
+        status: &SpotFulfillmentConfigStatus,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((339, borsh::to_vec(status)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 4]) -> (usize, Vec<u8>) {
+        (340, padding.to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl SerumV3FulfillmentConfigAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_pubkey(pubkey: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_pubkey(pubkey))
+    }
+    pub fn rpc_filter_serum_program_id(serum_program_id: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_program_id(serum_program_id))
+    }
+    pub fn rpc_filter_serum_market(serum_market: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_market(serum_market))
+    }
+    pub fn rpc_filter_serum_request_queue(serum_request_queue: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_request_queue(serum_request_queue))
+    }
+    pub fn rpc_filter_serum_event_queue(serum_event_queue: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_event_queue(serum_event_queue))
+    }
+    pub fn rpc_filter_serum_bids(serum_bids: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_bids(serum_bids))
+    }
+    pub fn rpc_filter_serum_asks(serum_asks: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_asks(serum_asks))
+    }
+    pub fn rpc_filter_serum_base_vault(serum_base_vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_base_vault(serum_base_vault))
+    }
+    pub fn rpc_filter_serum_quote_vault(serum_quote_vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_quote_vault(serum_quote_vault))
+    }
+    pub fn rpc_filter_serum_open_orders(serum_open_orders: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_open_orders(serum_open_orders))
+    }
+    pub fn rpc_filter_serum_signer_nonce(serum_signer_nonce: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_serum_signer_nonce(serum_signer_nonce))
+    }
+    pub fn rpc_filter_market_index(market_index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_market_index(market_index))
+    }
+    pub fn rpc_filter_fulfillment_type(
+        fulfillment_type: &SpotFulfillmentType,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_fulfillment_type(fulfillment_type).map(memcmp_filter)
+    }
+    pub fn rpc_filter_status(
+        status: &SpotFulfillmentConfigStatus,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_status(status).map(memcmp_filter)
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 4]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
+pub const INSURANCE_FUND_STAKE_ACCOUNT_DISCM: [u8; 8] = [110, 202, 14, 42, 95, 73, 90, 95];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct InsuranceFundStakeAccount(pub InsuranceFundStake);
 impl InsuranceFundStakeAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -119,13 +289,10 @@ impl InsuranceFundStakeAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INSURANCE_FUND_STAKE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INSURANCE_FUND_STAKE_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INSURANCE_FUND_STAKE_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InsuranceFundStake::deserialize(&mut reader)?))
     }
@@ -139,40 +306,105 @@ impl InsuranceFundStakeAccount {
         Ok(data)
     }
 }
-pub const PERP_MARKET_ACCOUNT_DISCM: [u8; 8] = [10, 223, 12, 44, 107, 245, 55, 247];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct PerpMarket {
-    pub pubkey: Pubkey,
-    pub amm: AMM,
-    pub pnl_pool: PoolBalance,
-    pub name: [u8; 32],
-    pub insurance_claim: InsuranceClaim,
-    pub unrealized_pnl_max_imbalance: u64,
-    pub expiry_ts: i64,
-    pub expiry_price: i64,
-    pub next_fill_record_id: u64,
-    pub next_funding_rate_record_id: u64,
-    pub next_curve_record_id: u64,
-    pub imf_factor: u32,
-    pub unrealized_pnl_imf_factor: u32,
-    pub liquidator_fee: u32,
-    pub if_liquidation_fee: u32,
-    pub margin_ratio_initial: u32,
-    pub margin_ratio_maintenance: u32,
-    pub unrealized_pnl_initial_asset_weight: u32,
-    pub unrealized_pnl_maintenance_asset_weight: u32,
-    pub number_of_users_with_base: u32,
-    pub number_of_users: u32,
-    pub market_index: u16,
-    pub status: MarketStatus,
-    pub contract_type: ContractType,
-    pub contract_tier: ContractTier,
-    pub padding1: bool,
-    pub quote_spot_market_index: u16,
-    pub padding: [u8; 48],
+pub const INSURANCE_FUND_STAKE_ACCOUNT_LEN: usize = 136;
+impl InsuranceFundStakeAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, INSURANCE_FUND_STAKE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `if_shares`
+    pub fn filter_if_shares(if_shares: u128) -> (usize, Vec<u8>) {
+        (40, if_shares.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_withdraw_request_shares`
+    pub fn filter_last_withdraw_request_shares(
+        last_withdraw_request_shares: u128,
+    ) -> (usize, Vec<u8>) {
+        (56, last_withdraw_request_shares.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `if_base`
+    pub fn filter_if_base(if_base: u128) -> (usize, Vec<u8>) {
+        (72, if_base.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_valid_ts`
+    pub fn filter_last_valid_ts(last_valid_ts: i64) -> (usize, Vec<u8>) {
+        (88, last_valid_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_withdraw_request_value`
+    pub fn filter_last_withdraw_request_value(
+        last_withdraw_request_value: u64,
+    ) -> (usize, Vec<u8>) {
+        (96, last_withdraw_request_value.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_withdraw_request_ts`
+    pub fn filter_last_withdraw_request_ts(last_withdraw_request_ts: i64) -> (usize, Vec<u8>) {
+        (104, last_withdraw_request_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `cost_basis`
+    pub fn filter_cost_basis(cost_basis: i64) -> (usize, Vec<u8>) {
+        (112, cost_basis.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `market_index`
+    pub fn filter_market_index(market_index: u16) -> (usize, Vec<u8>) {
+        (120, market_index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 14]) -> (usize, Vec<u8>) {
+        (122, padding.to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl InsuranceFundStakeAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_if_shares(if_shares: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_if_shares(if_shares))
+    }
+    pub fn rpc_filter_last_withdraw_request_shares(
+        last_withdraw_request_shares: u128,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_withdraw_request_shares(
+            last_withdraw_request_shares,
+        ))
+    }
+    pub fn rpc_filter_if_base(if_base: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_if_base(if_base))
+    }
+    pub fn rpc_filter_last_valid_ts(last_valid_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_valid_ts(last_valid_ts))
+    }
+    pub fn rpc_filter_last_withdraw_request_value(
+        last_withdraw_request_value: u64,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_withdraw_request_value(
+            last_withdraw_request_value,
+        ))
+    }
+    pub fn rpc_filter_last_withdraw_request_ts(last_withdraw_request_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_withdraw_request_ts(
+            last_withdraw_request_ts,
+        ))
+    }
+    pub fn rpc_filter_cost_basis(cost_basis: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_cost_basis(cost_basis))
+    }
+    pub fn rpc_filter_market_index(market_index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_market_index(market_index))
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 14]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
+pub const PERP_MARKET_ACCOUNT_DISCM: [u8; 8] = [10, 223, 12, 44, 107, 245, 55, 247];
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd)]
 pub struct PerpMarketAccount(pub PerpMarket);
 impl PerpMarketAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -181,13 +413,10 @@ impl PerpMarketAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PERP_MARKET_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PERP_MARKET_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PERP_MARKET_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PerpMarket::deserialize(&mut reader)?))
     }
@@ -201,64 +430,265 @@ impl PerpMarketAccount {
         Ok(data)
     }
 }
-pub const SPOT_MARKET_ACCOUNT_DISCM: [u8; 8] = [100, 177, 8, 107, 168, 65, 65, 39];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct SpotMarket {
-    pub pubkey: Pubkey,
-    pub oracle: Pubkey,
-    pub mint: Pubkey,
-    pub vault: Pubkey,
-    pub name: [u8; 32],
-    pub historical_oracle_data: HistoricalOracleData,
-    pub historical_index_data: HistoricalIndexData,
-    pub revenue_pool: PoolBalance,
-    pub spot_fee_pool: PoolBalance,
-    pub insurance_fund: InsuranceFund,
-    pub total_spot_fee: u128,
-    pub deposit_balance: u128,
-    pub borrow_balance: u128,
-    pub cumulative_deposit_interest: u128,
-    pub cumulative_borrow_interest: u128,
-    pub total_social_loss: u128,
-    pub total_quote_social_loss: u128,
-    pub withdraw_guard_threshold: u64,
-    pub max_token_deposits: u64,
-    pub deposit_token_twap: u64,
-    pub borrow_token_twap: u64,
-    pub utilization_twap: u64,
-    pub last_interest_ts: u64,
-    pub last_twap_ts: u64,
-    pub expiry_ts: i64,
-    pub order_step_size: u64,
-    pub order_tick_size: u64,
-    pub min_order_size: u64,
-    pub max_position_size: u64,
-    pub next_fill_record_id: u64,
-    pub next_deposit_record_id: u64,
-    pub initial_asset_weight: u32,
-    pub maintenance_asset_weight: u32,
-    pub initial_liability_weight: u32,
-    pub maintenance_liability_weight: u32,
-    pub imf_factor: u32,
-    pub liquidator_fee: u32,
-    pub if_liquidation_fee: u32,
-    pub optimal_utilization: u32,
-    pub optimal_borrow_rate: u32,
-    pub max_borrow_rate: u32,
-    pub decimals: u32,
-    pub market_index: u16,
-    pub orders_enabled: bool,
-    pub oracle_source: OracleSource,
-    pub status: MarketStatus,
-    pub asset_tier: AssetTier,
-    pub padding1: [u8; 6],
-    pub flash_loan_amount: u64,
-    pub flash_loan_initial_token_amount: u64,
-    pub total_swap_fee: u64,
-    pub padding: [u8; 56],
+pub const PERP_MARKET_ACCOUNT_LEN: usize = 1216;
+impl PerpMarketAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, PERP_MARKET_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pubkey`
+    pub fn filter_pubkey(pubkey: &Pubkey) -> (usize, Vec<u8>) {
+        (8, pubkey.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `amm`
+    pub fn filter_amm(amm: &AMM) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((40, borsh::to_vec(amm)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pnl_pool`
+    pub fn filter_pnl_pool(pnl_pool: &PoolBalance) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((976, borsh::to_vec(pnl_pool)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `name`
+    pub fn filter_name(name: &[u8; 32]) -> (usize, Vec<u8>) {
+        (1000, name.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `insurance_claim`
+    pub fn filter_insurance_claim(
+        insurance_claim: &InsuranceClaim,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((1032, borsh::to_vec(insurance_claim)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `unrealized_pnl_max_imbalance`
+    pub fn filter_unrealized_pnl_max_imbalance(
+        unrealized_pnl_max_imbalance: u64,
+    ) -> (usize, Vec<u8>) {
+        (1072, unrealized_pnl_max_imbalance.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `expiry_ts`
+    pub fn filter_expiry_ts(expiry_ts: i64) -> (usize, Vec<u8>) {
+        (1080, expiry_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `expiry_price`
+    pub fn filter_expiry_price(expiry_price: i64) -> (usize, Vec<u8>) {
+        (1088, expiry_price.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_fill_record_id`
+    pub fn filter_next_fill_record_id(next_fill_record_id: u64) -> (usize, Vec<u8>) {
+        (1096, next_fill_record_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_funding_rate_record_id`
+    pub fn filter_next_funding_rate_record_id(
+        next_funding_rate_record_id: u64,
+    ) -> (usize, Vec<u8>) {
+        (1104, next_funding_rate_record_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_curve_record_id`
+    pub fn filter_next_curve_record_id(next_curve_record_id: u64) -> (usize, Vec<u8>) {
+        (1112, next_curve_record_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `imf_factor`
+    pub fn filter_imf_factor(imf_factor: u32) -> (usize, Vec<u8>) {
+        (1120, imf_factor.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `unrealized_pnl_imf_factor`
+    pub fn filter_unrealized_pnl_imf_factor(unrealized_pnl_imf_factor: u32) -> (usize, Vec<u8>) {
+        (1124, unrealized_pnl_imf_factor.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `liquidator_fee`
+    pub fn filter_liquidator_fee(liquidator_fee: u32) -> (usize, Vec<u8>) {
+        (1128, liquidator_fee.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `if_liquidation_fee`
+    pub fn filter_if_liquidation_fee(if_liquidation_fee: u32) -> (usize, Vec<u8>) {
+        (1132, if_liquidation_fee.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `margin_ratio_initial`
+    pub fn filter_margin_ratio_initial(margin_ratio_initial: u32) -> (usize, Vec<u8>) {
+        (1136, margin_ratio_initial.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `margin_ratio_maintenance`
+    pub fn filter_margin_ratio_maintenance(margin_ratio_maintenance: u32) -> (usize, Vec<u8>) {
+        (1140, margin_ratio_maintenance.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `unrealized_pnl_initial_asset_weight`
+    pub fn filter_unrealized_pnl_initial_asset_weight(
+        unrealized_pnl_initial_asset_weight: u32,
+    ) -> (usize, Vec<u8>) {
+        (
+            1144,
+            unrealized_pnl_initial_asset_weight.to_le_bytes().to_vec(),
+        )
+    }
+    /// Memcmp filter `(offset, bytes)` matching `unrealized_pnl_maintenance_asset_weight`
+    pub fn filter_unrealized_pnl_maintenance_asset_weight(
+        unrealized_pnl_maintenance_asset_weight: u32,
+    ) -> (usize, Vec<u8>) {
+        (
+            1148,
+            unrealized_pnl_maintenance_asset_weight
+                .to_le_bytes()
+                .to_vec(),
+        )
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_users_with_base`
+    pub fn filter_number_of_users_with_base(number_of_users_with_base: u32) -> (usize, Vec<u8>) {
+        (1152, number_of_users_with_base.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_users`
+    pub fn filter_number_of_users(number_of_users: u32) -> (usize, Vec<u8>) {
+        (1156, number_of_users.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `market_index`
+    pub fn filter_market_index(market_index: u16) -> (usize, Vec<u8>) {
+        (1160, market_index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `status`
+    pub fn filter_status(status: &MarketStatus) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((1162, borsh::to_vec(status)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `contract_type`
+    pub fn filter_contract_type(contract_type: &ContractType) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((1163, borsh::to_vec(contract_type)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `contract_tier`
+    pub fn filter_contract_tier(contract_tier: &ContractTier) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((1164, borsh::to_vec(contract_tier)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding1`
+    pub fn filter_padding1(padding1: bool) -> (usize, Vec<u8>) {
+        (1165, vec![padding1 as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `quote_spot_market_index`
+    pub fn filter_quote_spot_market_index(quote_spot_market_index: u16) -> (usize, Vec<u8>) {
+        (1166, quote_spot_market_index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 48]) -> (usize, Vec<u8>) {
+        (1168, padding.to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl PerpMarketAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_pubkey(pubkey: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_pubkey(pubkey))
+    }
+    pub fn rpc_filter_amm(amm: &AMM) -> std::io::Result<RpcFilterType> {
+        Self::filter_amm(amm).map(memcmp_filter)
+    }
+    pub fn rpc_filter_pnl_pool(pnl_pool: &PoolBalance) -> std::io::Result<RpcFilterType> {
+        Self::filter_pnl_pool(pnl_pool).map(memcmp_filter)
+    }
+    pub fn rpc_filter_name(name: &[u8; 32]) -> RpcFilterType {
+        memcmp_filter(Self::filter_name(name))
+    }
+    pub fn rpc_filter_insurance_claim(
+        insurance_claim: &InsuranceClaim,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_insurance_claim(insurance_claim).map(memcmp_filter)
+    }
+    pub fn rpc_filter_unrealized_pnl_max_imbalance(
+        unrealized_pnl_max_imbalance: u64,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_unrealized_pnl_max_imbalance(
+            unrealized_pnl_max_imbalance,
+        ))
+    }
+    pub fn rpc_filter_expiry_ts(expiry_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_expiry_ts(expiry_ts))
+    }
+    pub fn rpc_filter_expiry_price(expiry_price: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_expiry_price(expiry_price))
+    }
+    pub fn rpc_filter_next_fill_record_id(next_fill_record_id: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_fill_record_id(next_fill_record_id))
+    }
+    pub fn rpc_filter_next_funding_rate_record_id(
+        next_funding_rate_record_id: u64,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_funding_rate_record_id(
+            next_funding_rate_record_id,
+        ))
+    }
+    pub fn rpc_filter_next_curve_record_id(next_curve_record_id: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_curve_record_id(next_curve_record_id))
+    }
+    pub fn rpc_filter_imf_factor(imf_factor: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_imf_factor(imf_factor))
+    }
+    pub fn rpc_filter_unrealized_pnl_imf_factor(unrealized_pnl_imf_factor: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_unrealized_pnl_imf_factor(
+            unrealized_pnl_imf_factor,
+        ))
+    }
+    pub fn rpc_filter_liquidator_fee(liquidator_fee: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_liquidator_fee(liquidator_fee))
+    }
+    pub fn rpc_filter_if_liquidation_fee(if_liquidation_fee: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_if_liquidation_fee(if_liquidation_fee))
+    }
+    pub fn rpc_filter_margin_ratio_initial(margin_ratio_initial: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_margin_ratio_initial(margin_ratio_initial))
+    }
+    pub fn rpc_filter_margin_ratio_maintenance(margin_ratio_maintenance: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_margin_ratio_maintenance(
+            margin_ratio_maintenance,
+        ))
+    }
+    pub fn rpc_filter_unrealized_pnl_initial_asset_weight(
+        unrealized_pnl_initial_asset_weight: u32,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_unrealized_pnl_initial_asset_weight(
+            unrealized_pnl_initial_asset_weight,
+        ))
+    }
+    pub fn rpc_filter_unrealized_pnl_maintenance_asset_weight(
+        unrealized_pnl_maintenance_asset_weight: u32,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_unrealized_pnl_maintenance_asset_weight(
+            unrealized_pnl_maintenance_asset_weight,
+        ))
+    }
+    pub fn rpc_filter_number_of_users_with_base(number_of_users_with_base: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_users_with_base(
+            number_of_users_with_base,
+        ))
+    }
+    pub fn rpc_filter_number_of_users(number_of_users: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_users(number_of_users))
+    }
+    pub fn rpc_filter_market_index(market_index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_market_index(market_index))
+    }
+    pub fn rpc_filter_status(status: &MarketStatus) -> std::io::Result<RpcFilterType> {
+        Self::filter_status(status).map(memcmp_filter)
+    }
+    pub fn rpc_filter_contract_type(
+        contract_type: &ContractType,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_contract_type(contract_type).map(memcmp_filter)
+    }
+    pub fn rpc_filter_contract_tier(
+        contract_tier: &ContractTier,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_contract_tier(contract_tier).map(memcmp_filter)
+    }
+    pub fn rpc_filter_padding1(padding1: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding1(padding1))
+    }
+    pub fn rpc_filter_quote_spot_market_index(quote_spot_market_index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_quote_spot_market_index(
+            quote_spot_market_index,
+        ))
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 48]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
+pub const SPOT_MARKET_ACCOUNT_DISCM: [u8; 8] = [100, 177, 8, 107, 168, 65, 65, 39];
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd)]
 pub struct SpotMarketAccount(pub SpotMarket);
 impl SpotMarketAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -267,13 +697,10 @@ impl SpotMarketAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SPOT_MARKET_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SPOT_MARKET_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SPOT_MARKET_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SpotMarket::deserialize(&mut reader)?))
     }
@@ -287,35 +714,429 @@ impl SpotMarketAccount {
         Ok(data)
     }
 }
-pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct State {
-    pub admin: Pubkey,
-    pub whitelist_mint: Pubkey,
-    pub discount_mint: Pubkey,
-    pub signer: Pubkey,
-    pub srm_vault: Pubkey,
-    pub perp_fee_structure: FeeStructure,
-    pub spot_fee_structure: FeeStructure,
-    pub oracle_guard_rails: OracleGuardRails,
-    pub number_of_authorities: u64,
-    pub number_of_sub_accounts: u64,
-    pub lp_cooldown_time: u64,
-    pub liquidation_margin_buffer_ratio: u32,
-    pub settlement_duration: u16,
-    pub number_of_markets: u16,
-    pub number_of_spot_markets: u16,
-    pub signer_nonce: u8,
-    pub min_perp_auction_duration: u8,
-    pub default_market_order_time_in_force: u8,
-    pub default_spot_auction_duration: u8,
-    pub exchange_status: u8,
-    pub liquidation_duration: u8,
-    pub initial_pct_to_liquidate: u16,
-    pub padding: [u8; 14],
+pub const SPOT_MARKET_ACCOUNT_LEN: usize = 776;
+impl SpotMarketAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, SPOT_MARKET_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pubkey`
+    pub fn filter_pubkey(pubkey: &Pubkey) -> (usize, Vec<u8>) {
+        (8, pubkey.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `oracle`
+    pub fn filter_oracle(oracle: &Pubkey) -> (usize, Vec<u8>) {
+        (40, oracle.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `mint`
+    pub fn filter_mint(mint: &Pubkey) -> (usize, Vec<u8>) {
+        (72, mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `vault`
+    pub fn filter_vault(vault: &Pubkey) -> (usize, Vec<u8>) {
+        (104, vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `name`
+    pub fn filter_name(name: &[u8; 32]) -> (usize, Vec<u8>) {
+        (136, name.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `historical_oracle_data`
+    pub fn filter_historical_oracle_data(
+        historical_oracle_data: &HistoricalOracleData,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((168, borsh::to_vec(historical_oracle_data)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `historical_index_data`
+    pub fn filter_historical_index_data(
+        historical_index_data: &HistoricalIndexData,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((216, borsh::to_vec(historical_index_data)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `revenue_pool`
+    pub fn filter_revenue_pool(revenue_pool: &PoolBalance) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((256, borsh::to_vec(revenue_pool)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `spot_fee_pool`
+    pub fn filter_spot_fee_pool(spot_fee_pool: &PoolBalance) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((280, borsh::to_vec(spot_fee_pool)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `insurance_fund`
+    pub fn filter_insurance_fund(
+        insurance_fund: &InsuranceFund,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((304, borsh::to_vec(insurance_fund)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_spot_fee`
+    pub fn filter_total_spot_fee(total_spot_fee: u128) -> (usize, Vec<u8>) {
+        (416, total_spot_fee.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `deposit_balance`
+    pub fn filter_deposit_balance(deposit_balance: u128) -> (usize, Vec<u8>) {
+        (432, deposit_balance.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `borrow_balance`
+    pub fn filter_borrow_balance(borrow_balance: u128) -> (usize, Vec<u8>) {
+        (448, borrow_balance.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `cumulative_deposit_interest`
+    pub fn filter_cumulative_deposit_interest(
+        cumulative_deposit_interest: u128,
+    ) -> (usize, Vec<u8>) {
+        (464, cumulative_deposit_interest.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `cumulative_borrow_interest`
+    pub fn filter_cumulative_borrow_interest(cumulative_borrow_interest: u128) -> (usize, Vec<u8>) {
+        (480, cumulative_borrow_interest.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_social_loss`
+    pub fn filter_total_social_loss(total_social_loss: u128) -> (usize, Vec<u8>) {
+        (496, total_social_loss.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_quote_social_loss`
+    pub fn filter_total_quote_social_loss(total_quote_social_loss: u128) -> (usize, Vec<u8>) {
+        (512, total_quote_social_loss.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `withdraw_guard_threshold`
+    pub fn filter_withdraw_guard_threshold(withdraw_guard_threshold: u64) -> (usize, Vec<u8>) {
+        (528, withdraw_guard_threshold.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `max_token_deposits`
+    pub fn filter_max_token_deposits(max_token_deposits: u64) -> (usize, Vec<u8>) {
+        (536, max_token_deposits.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `deposit_token_twap`
+    pub fn filter_deposit_token_twap(deposit_token_twap: u64) -> (usize, Vec<u8>) {
+        (544, deposit_token_twap.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `borrow_token_twap`
+    pub fn filter_borrow_token_twap(borrow_token_twap: u64) -> (usize, Vec<u8>) {
+        (552, borrow_token_twap.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `utilization_twap`
+    pub fn filter_utilization_twap(utilization_twap: u64) -> (usize, Vec<u8>) {
+        (560, utilization_twap.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_interest_ts`
+    pub fn filter_last_interest_ts(last_interest_ts: u64) -> (usize, Vec<u8>) {
+        (568, last_interest_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_twap_ts`
+    pub fn filter_last_twap_ts(last_twap_ts: u64) -> (usize, Vec<u8>) {
+        (576, last_twap_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `expiry_ts`
+    pub fn filter_expiry_ts(expiry_ts: i64) -> (usize, Vec<u8>) {
+        (584, expiry_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `order_step_size`
+    pub fn filter_order_step_size(order_step_size: u64) -> (usize, Vec<u8>) {
+        (592, order_step_size.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `order_tick_size`
+    pub fn filter_order_tick_size(order_tick_size: u64) -> (usize, Vec<u8>) {
+        (600, order_tick_size.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `min_order_size`
+    pub fn filter_min_order_size(min_order_size: u64) -> (usize, Vec<u8>) {
+        (608, min_order_size.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `max_position_size`
+    pub fn filter_max_position_size(max_position_size: u64) -> (usize, Vec<u8>) {
+        (616, max_position_size.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_fill_record_id`
+    pub fn filter_next_fill_record_id(next_fill_record_id: u64) -> (usize, Vec<u8>) {
+        (624, next_fill_record_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_deposit_record_id`
+    pub fn filter_next_deposit_record_id(next_deposit_record_id: u64) -> (usize, Vec<u8>) {
+        (632, next_deposit_record_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `initial_asset_weight`
+    pub fn filter_initial_asset_weight(initial_asset_weight: u32) -> (usize, Vec<u8>) {
+        (640, initial_asset_weight.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `maintenance_asset_weight`
+    pub fn filter_maintenance_asset_weight(maintenance_asset_weight: u32) -> (usize, Vec<u8>) {
+        (644, maintenance_asset_weight.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `initial_liability_weight`
+    pub fn filter_initial_liability_weight(initial_liability_weight: u32) -> (usize, Vec<u8>) {
+        (648, initial_liability_weight.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `maintenance_liability_weight`
+    pub fn filter_maintenance_liability_weight(
+        maintenance_liability_weight: u32,
+    ) -> (usize, Vec<u8>) {
+        (652, maintenance_liability_weight.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `imf_factor`
+    pub fn filter_imf_factor(imf_factor: u32) -> (usize, Vec<u8>) {
+        (656, imf_factor.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `liquidator_fee`
+    pub fn filter_liquidator_fee(liquidator_fee: u32) -> (usize, Vec<u8>) {
+        (660, liquidator_fee.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `if_liquidation_fee`
+    pub fn filter_if_liquidation_fee(if_liquidation_fee: u32) -> (usize, Vec<u8>) {
+        (664, if_liquidation_fee.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `optimal_utilization`
+    pub fn filter_optimal_utilization(optimal_utilization: u32) -> (usize, Vec<u8>) {
+        (668, optimal_utilization.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `optimal_borrow_rate`
+    pub fn filter_optimal_borrow_rate(optimal_borrow_rate: u32) -> (usize, Vec<u8>) {
+        (672, optimal_borrow_rate.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `max_borrow_rate`
+    pub fn filter_max_borrow_rate(max_borrow_rate: u32) -> (usize, Vec<u8>) {
+        (676, max_borrow_rate.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `decimals`
+    pub fn filter_decimals(decimals: u32) -> (usize, Vec<u8>) {
+        (680, decimals.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `market_index`
+    pub fn filter_market_index(market_index: u16) -> (usize, Vec<u8>) {
+        (684, market_index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `orders_enabled`
+    pub fn filter_orders_enabled(orders_enabled: bool) -> (usize, Vec<u8>) {
+        (686, vec![orders_enabled as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `oracle_source`
+    pub fn filter_oracle_source(oracle_source: &OracleSource) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((687, borsh::to_vec(oracle_source)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `status`
+    pub fn filter_status(status: &MarketStatus) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((688, borsh::to_vec(status)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `asset_tier`
+    pub fn filter_asset_tier(asset_tier: &AssetTier) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((689, borsh::to_vec(asset_tier)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding1`
+    pub fn filter_padding1(padding1: &[u8; 6]) -> (usize, Vec<u8>) {
+        (690, padding1.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `flash_loan_amount`
+    pub fn filter_flash_loan_amount(flash_loan_amount: u64) -> (usize, Vec<u8>) {
+        (696, flash_loan_amount.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `flash_loan_initial_token_amount`
+    pub fn filter_flash_loan_initial_token_amount(
+        flash_loan_initial_token_amount: u64,
+    ) -> (usize, Vec<u8>) {
+        (704, flash_loan_initial_token_amount.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_swap_fee`
+    pub fn filter_total_swap_fee(total_swap_fee: u64) -> (usize, Vec<u8>) {
+        (712, total_swap_fee.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 56]) -> (usize, Vec<u8>) {
+        (720, padding.to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl SpotMarketAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_pubkey(pubkey: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_pubkey(pubkey))
+    }
+    pub fn rpc_filter_oracle(oracle: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_oracle(oracle))
+    }
+    pub fn rpc_filter_mint(mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_mint(mint))
+    }
+    pub fn rpc_filter_vault(vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_vault(vault))
+    }
+    pub fn rpc_filter_name(name: &[u8; 32]) -> RpcFilterType {
+        memcmp_filter(Self::filter_name(name))
+    }
+    pub fn rpc_filter_historical_oracle_data(
+        historical_oracle_data: &HistoricalOracleData,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_historical_oracle_data(historical_oracle_data).map(memcmp_filter)
+    }
+    pub fn rpc_filter_historical_index_data(
+        historical_index_data: &HistoricalIndexData,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_historical_index_data(historical_index_data).map(memcmp_filter)
+    }
+    pub fn rpc_filter_revenue_pool(revenue_pool: &PoolBalance) -> std::io::Result<RpcFilterType> {
+        Self::filter_revenue_pool(revenue_pool).map(memcmp_filter)
+    }
+    pub fn rpc_filter_spot_fee_pool(spot_fee_pool: &PoolBalance) -> std::io::Result<RpcFilterType> {
+        Self::filter_spot_fee_pool(spot_fee_pool).map(memcmp_filter)
+    }
+    pub fn rpc_filter_insurance_fund(
+        insurance_fund: &InsuranceFund,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_insurance_fund(insurance_fund).map(memcmp_filter)
+    }
+    pub fn rpc_filter_total_spot_fee(total_spot_fee: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_spot_fee(total_spot_fee))
+    }
+    pub fn rpc_filter_deposit_balance(deposit_balance: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_deposit_balance(deposit_balance))
+    }
+    pub fn rpc_filter_borrow_balance(borrow_balance: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_borrow_balance(borrow_balance))
+    }
+    pub fn rpc_filter_cumulative_deposit_interest(
+        cumulative_deposit_interest: u128,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_cumulative_deposit_interest(
+            cumulative_deposit_interest,
+        ))
+    }
+    pub fn rpc_filter_cumulative_borrow_interest(
+        cumulative_borrow_interest: u128,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_cumulative_borrow_interest(
+            cumulative_borrow_interest,
+        ))
+    }
+    pub fn rpc_filter_total_social_loss(total_social_loss: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_social_loss(total_social_loss))
+    }
+    pub fn rpc_filter_total_quote_social_loss(total_quote_social_loss: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_quote_social_loss(
+            total_quote_social_loss,
+        ))
+    }
+    pub fn rpc_filter_withdraw_guard_threshold(withdraw_guard_threshold: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_withdraw_guard_threshold(
+            withdraw_guard_threshold,
+        ))
+    }
+    pub fn rpc_filter_max_token_deposits(max_token_deposits: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_max_token_deposits(max_token_deposits))
+    }
+    pub fn rpc_filter_deposit_token_twap(deposit_token_twap: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_deposit_token_twap(deposit_token_twap))
+    }
+    pub fn rpc_filter_borrow_token_twap(borrow_token_twap: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_borrow_token_twap(borrow_token_twap))
+    }
+    pub fn rpc_filter_utilization_twap(utilization_twap: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_utilization_twap(utilization_twap))
+    }
+    pub fn rpc_filter_last_interest_ts(last_interest_ts: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_interest_ts(last_interest_ts))
+    }
+    pub fn rpc_filter_last_twap_ts(last_twap_ts: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_twap_ts(last_twap_ts))
+    }
+    pub fn rpc_filter_expiry_ts(expiry_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_expiry_ts(expiry_ts))
+    }
+    pub fn rpc_filter_order_step_size(order_step_size: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_order_step_size(order_step_size))
+    }
+    pub fn rpc_filter_order_tick_size(order_tick_size: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_order_tick_size(order_tick_size))
+    }
+    pub fn rpc_filter_min_order_size(min_order_size: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_min_order_size(min_order_size))
+    }
+    pub fn rpc_filter_max_position_size(max_position_size: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_max_position_size(max_position_size))
+    }
+    pub fn rpc_filter_next_fill_record_id(next_fill_record_id: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_fill_record_id(next_fill_record_id))
+    }
+    pub fn rpc_filter_next_deposit_record_id(next_deposit_record_id: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_deposit_record_id(next_deposit_record_id))
+    }
+    pub fn rpc_filter_initial_asset_weight(initial_asset_weight: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_initial_asset_weight(initial_asset_weight))
+    }
+    pub fn rpc_filter_maintenance_asset_weight(maintenance_asset_weight: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_maintenance_asset_weight(
+            maintenance_asset_weight,
+        ))
+    }
+    pub fn rpc_filter_initial_liability_weight(initial_liability_weight: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_initial_liability_weight(
+            initial_liability_weight,
+        ))
+    }
+    pub fn rpc_filter_maintenance_liability_weight(
+        maintenance_liability_weight: u32,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_maintenance_liability_weight(
+            maintenance_liability_weight,
+        ))
+    }
+    pub fn rpc_filter_imf_factor(imf_factor: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_imf_factor(imf_factor))
+    }
+    pub fn rpc_filter_liquidator_fee(liquidator_fee: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_liquidator_fee(liquidator_fee))
+    }
+    pub fn rpc_filter_if_liquidation_fee(if_liquidation_fee: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_if_liquidation_fee(if_liquidation_fee))
+    }
+    pub fn rpc_filter_optimal_utilization(optimal_utilization: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_optimal_utilization(optimal_utilization))
+    }
+    pub fn rpc_filter_optimal_borrow_rate(optimal_borrow_rate: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_optimal_borrow_rate(optimal_borrow_rate))
+    }
+    pub fn rpc_filter_max_borrow_rate(max_borrow_rate: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_max_borrow_rate(max_borrow_rate))
+    }
+    pub fn rpc_filter_decimals(decimals: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_decimals(decimals))
+    }
+    pub fn rpc_filter_market_index(market_index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_market_index(market_index))
+    }
+    pub fn rpc_filter_orders_enabled(orders_enabled: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_orders_enabled(orders_enabled))
+    }
+    pub fn rpc_filter_oracle_source(
+        oracle_source: &OracleSource,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_oracle_source(oracle_source).map(memcmp_filter)
+    }
+    pub fn rpc_filter_status(status: &MarketStatus) -> std::io::Result<RpcFilterType> {
+        Self::filter_status(status).map(memcmp_filter)
+    }
+    pub fn rpc_filter_asset_tier(asset_tier: &AssetTier) -> std::io::Result<RpcFilterType> {
+        Self::filter_asset_tier(asset_tier).map(memcmp_filter)
+    }
+    pub fn rpc_filter_padding1(padding1: &[u8; 6]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding1(padding1))
+    }
+    pub fn rpc_filter_flash_loan_amount(flash_loan_amount: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_flash_loan_amount(flash_loan_amount))
+    }
+    pub fn rpc_filter_flash_loan_initial_token_amount(
+        flash_loan_initial_token_amount: u64,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_flash_loan_initial_token_amount(
+            flash_loan_initial_token_amount,
+        ))
+    }
+    pub fn rpc_filter_total_swap_fee(total_swap_fee: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_swap_fee(total_swap_fee))
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 56]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
+pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct StateAccount(pub State);
 impl StateAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -324,13 +1145,10 @@ impl StateAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != STATE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    STATE_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                STATE_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(State::deserialize(&mut reader)?))
     }
@@ -344,39 +1162,219 @@ impl StateAccount {
         Ok(data)
     }
 }
-pub const USER_ACCOUNT_DISCM: [u8; 8] = [159, 117, 95, 227, 239, 151, 58, 236];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct User {
-    pub authority: Pubkey,
-    pub delegate: Pubkey,
-    pub name: [u8; 32],
-    pub spot_positions: [SpotPosition; 8],
-    pub perp_positions: [PerpPosition; 8],
-    pub orders: [Order; 32],
-    pub last_add_perp_lp_shares_ts: i64,
-    pub total_deposits: u64,
-    pub total_withdraws: u64,
-    pub total_social_loss: u64,
-    pub settled_perp_pnl: i64,
-    pub cumulative_spot_fees: i64,
-    pub cumulative_perp_funding: i64,
-    pub liquidation_margin_freed: u64,
-    pub last_active_slot: u64,
-    pub next_order_id: u32,
-    pub max_margin_ratio: u32,
-    pub next_liquidation_id: u16,
-    pub sub_account_id: u16,
-    pub status: UserStatus,
-    pub is_margin_trading_enabled: bool,
-    pub idle: bool,
-    pub open_orders: u8,
-    pub has_open_order: bool,
-    pub open_auctions: u8,
-    pub has_open_auction: bool,
-    pub padding: [u8; 21],
+pub const STATE_ACCOUNT_LEN: usize = 992;
+impl StateAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, STATE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `admin`
+    pub fn filter_admin(admin: &Pubkey) -> (usize, Vec<u8>) {
+        (8, admin.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `whitelist_mint`
+    pub fn filter_whitelist_mint(whitelist_mint: &Pubkey) -> (usize, Vec<u8>) {
+        (40, whitelist_mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `discount_mint`
+    pub fn filter_discount_mint(discount_mint: &Pubkey) -> (usize, Vec<u8>) {
+        (72, discount_mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `signer`
+    pub fn filter_signer(signer: &Pubkey) -> (usize, Vec<u8>) {
+        (104, signer.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `srm_vault`
+    pub fn filter_srm_vault(srm_vault: &Pubkey) -> (usize, Vec<u8>) {
+        (136, srm_vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `perp_fee_structure`
+    pub fn filter_perp_fee_structure(
+        perp_fee_structure: &FeeStructure,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((168, borsh::to_vec(perp_fee_structure)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `spot_fee_structure`
+    pub fn filter_spot_fee_structure(
+        spot_fee_structure: &FeeStructure,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((528, borsh::to_vec(spot_fee_structure)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `oracle_guard_rails`
+    pub fn filter_oracle_guard_rails(
+        oracle_guard_rails: &OracleGuardRails,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((888, borsh::to_vec(oracle_guard_rails)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_authorities`
+    pub fn filter_number_of_authorities(number_of_authorities: u64) -> (usize, Vec<u8>) {
+        (936, number_of_authorities.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_sub_accounts`
+    pub fn filter_number_of_sub_accounts(number_of_sub_accounts: u64) -> (usize, Vec<u8>) {
+        (944, number_of_sub_accounts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lp_cooldown_time`
+    pub fn filter_lp_cooldown_time(lp_cooldown_time: u64) -> (usize, Vec<u8>) {
+        (952, lp_cooldown_time.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `liquidation_margin_buffer_ratio`
+    pub fn filter_liquidation_margin_buffer_ratio(
+        liquidation_margin_buffer_ratio: u32,
+    ) -> (usize, Vec<u8>) {
+        (960, liquidation_margin_buffer_ratio.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `settlement_duration`
+    pub fn filter_settlement_duration(settlement_duration: u16) -> (usize, Vec<u8>) {
+        (964, settlement_duration.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_markets`
+    pub fn filter_number_of_markets(number_of_markets: u16) -> (usize, Vec<u8>) {
+        (966, number_of_markets.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_spot_markets`
+    pub fn filter_number_of_spot_markets(number_of_spot_markets: u16) -> (usize, Vec<u8>) {
+        (968, number_of_spot_markets.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `signer_nonce`
+    pub fn filter_signer_nonce(signer_nonce: u8) -> (usize, Vec<u8>) {
+        (970, signer_nonce.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `min_perp_auction_duration`
+    pub fn filter_min_perp_auction_duration(min_perp_auction_duration: u8) -> (usize, Vec<u8>) {
+        (971, min_perp_auction_duration.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `default_market_order_time_in_force`
+    pub fn filter_default_market_order_time_in_force(
+        default_market_order_time_in_force: u8,
+    ) -> (usize, Vec<u8>) {
+        (
+            972,
+            default_market_order_time_in_force.to_le_bytes().to_vec(),
+        )
+    }
+    /// Memcmp filter `(offset, bytes)` matching `default_spot_auction_duration`
+    pub fn filter_default_spot_auction_duration(
+        default_spot_auction_duration: u8,
+    ) -> (usize, Vec<u8>) {
+        (973, default_spot_auction_duration.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `exchange_status`
+    pub fn filter_exchange_status(exchange_status: u8) -> (usize, Vec<u8>) {
+        (974, exchange_status.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `liquidation_duration`
+    pub fn filter_liquidation_duration(liquidation_duration: u8) -> (usize, Vec<u8>) {
+        (975, liquidation_duration.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `initial_pct_to_liquidate`
+    pub fn filter_initial_pct_to_liquidate(initial_pct_to_liquidate: u16) -> (usize, Vec<u8>) {
+        (976, initial_pct_to_liquidate.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 14]) -> (usize, Vec<u8>) {
+        (978, padding.to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl StateAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_admin(admin: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_admin(admin))
+    }
+    pub fn rpc_filter_whitelist_mint(whitelist_mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_whitelist_mint(whitelist_mint))
+    }
+    pub fn rpc_filter_discount_mint(discount_mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_discount_mint(discount_mint))
+    }
+    pub fn rpc_filter_signer(signer: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_signer(signer))
+    }
+    pub fn rpc_filter_srm_vault(srm_vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_srm_vault(srm_vault))
+    }
+    pub fn rpc_filter_perp_fee_structure(
+        perp_fee_structure: &FeeStructure,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_perp_fee_structure(perp_fee_structure).map(memcmp_filter)
+    }
+    pub fn rpc_filter_spot_fee_structure(
+        spot_fee_structure: &FeeStructure,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_spot_fee_structure(spot_fee_structure).map(memcmp_filter)
+    }
+    pub fn rpc_filter_oracle_guard_rails(
+        oracle_guard_rails: &OracleGuardRails,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_oracle_guard_rails(oracle_guard_rails).map(memcmp_filter)
+    }
+    pub fn rpc_filter_number_of_authorities(number_of_authorities: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_authorities(number_of_authorities))
+    }
+    pub fn rpc_filter_number_of_sub_accounts(number_of_sub_accounts: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_sub_accounts(number_of_sub_accounts))
+    }
+    pub fn rpc_filter_lp_cooldown_time(lp_cooldown_time: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_lp_cooldown_time(lp_cooldown_time))
+    }
+    pub fn rpc_filter_liquidation_margin_buffer_ratio(
+        liquidation_margin_buffer_ratio: u32,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_liquidation_margin_buffer_ratio(
+            liquidation_margin_buffer_ratio,
+        ))
+    }
+    pub fn rpc_filter_settlement_duration(settlement_duration: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_settlement_duration(settlement_duration))
+    }
+    pub fn rpc_filter_number_of_markets(number_of_markets: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_markets(number_of_markets))
+    }
+    pub fn rpc_filter_number_of_spot_markets(number_of_spot_markets: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_spot_markets(number_of_spot_markets))
+    }
+    pub fn rpc_filter_signer_nonce(signer_nonce: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_signer_nonce(signer_nonce))
+    }
+    pub fn rpc_filter_min_perp_auction_duration(min_perp_auction_duration: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_min_perp_auction_duration(
+            min_perp_auction_duration,
+        ))
+    }
+    pub fn rpc_filter_default_market_order_time_in_force(
+        default_market_order_time_in_force: u8,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_default_market_order_time_in_force(
+            default_market_order_time_in_force,
+        ))
+    }
+    pub fn rpc_filter_default_spot_auction_duration(
+        default_spot_auction_duration: u8,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_default_spot_auction_duration(
+            default_spot_auction_duration,
+        ))
+    }
+    pub fn rpc_filter_exchange_status(exchange_status: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_exchange_status(exchange_status))
+    }
+    pub fn rpc_filter_liquidation_duration(liquidation_duration: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_liquidation_duration(liquidation_duration))
+    }
+    pub fn rpc_filter_initial_pct_to_liquidate(initial_pct_to_liquidate: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_initial_pct_to_liquidate(
+            initial_pct_to_liquidate,
+        ))
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 14]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
+pub const USER_ACCOUNT_DISCM: [u8; 8] = [159, 117, 95, 227, 239, 151, 58, 236];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct UserAccount(pub User);
 impl UserAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -385,13 +1383,10 @@ impl UserAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != USER_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    USER_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                USER_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(User::deserialize(&mut reader)?))
     }
@@ -405,27 +1400,226 @@ impl UserAccount {
         Ok(data)
     }
 }
-pub const USER_STATS_ACCOUNT_DISCM: [u8; 8] = [176, 223, 136, 27, 122, 79, 32, 227];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UserStats {
-    pub authority: Pubkey,
-    pub referrer: Pubkey,
-    pub fees: UserFees,
-    pub next_epoch_ts: i64,
-    pub maker_volume30d: u64,
-    pub taker_volume30d: u64,
-    pub filler_volume30d: u64,
-    pub last_maker_volume30d_ts: i64,
-    pub last_taker_volume30d_ts: i64,
-    pub last_filler_volume30d_ts: i64,
-    pub if_staked_quote_asset_amount: u64,
-    pub number_of_sub_accounts: u16,
-    pub number_of_sub_accounts_created: u16,
-    pub is_referrer: bool,
-    pub padding: [u8; 51],
+pub const USER_ACCOUNT_LEN: usize = 4376;
+impl UserAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, USER_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `delegate`
+    pub fn filter_delegate(delegate: &Pubkey) -> (usize, Vec<u8>) {
+        (40, delegate.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `name`
+    pub fn filter_name(name: &[u8; 32]) -> (usize, Vec<u8>) {
+        (72, name.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `spot_positions`
+    pub fn filter_spot_positions(
+        spot_positions: &[SpotPosition; 8],
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((104, borsh::to_vec(spot_positions)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `perp_positions`
+    pub fn filter_perp_positions(
+        perp_positions: &[PerpPosition; 8],
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((424, borsh::to_vec(perp_positions)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `orders`
+    pub fn filter_orders(orders: &[Order; 32]) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((1192, borsh::to_vec(orders)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_add_perp_lp_shares_ts`
+    pub fn filter_last_add_perp_lp_shares_ts(last_add_perp_lp_shares_ts: i64) -> (usize, Vec<u8>) {
+        (4264, last_add_perp_lp_shares_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_deposits`
+    pub fn filter_total_deposits(total_deposits: u64) -> (usize, Vec<u8>) {
+        (4272, total_deposits.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_withdraws`
+    pub fn filter_total_withdraws(total_withdraws: u64) -> (usize, Vec<u8>) {
+        (4280, total_withdraws.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_social_loss`
+    pub fn filter_total_social_loss(total_social_loss: u64) -> (usize, Vec<u8>) {
+        (4288, total_social_loss.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `settled_perp_pnl`
+    pub fn filter_settled_perp_pnl(settled_perp_pnl: i64) -> (usize, Vec<u8>) {
+        (4296, settled_perp_pnl.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `cumulative_spot_fees`
+    pub fn filter_cumulative_spot_fees(cumulative_spot_fees: i64) -> (usize, Vec<u8>) {
+        (4304, cumulative_spot_fees.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `cumulative_perp_funding`
+    pub fn filter_cumulative_perp_funding(cumulative_perp_funding: i64) -> (usize, Vec<u8>) {
+        (4312, cumulative_perp_funding.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `liquidation_margin_freed`
+    pub fn filter_liquidation_margin_freed(liquidation_margin_freed: u64) -> (usize, Vec<u8>) {
+        (4320, liquidation_margin_freed.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_active_slot`
+    pub fn filter_last_active_slot(last_active_slot: u64) -> (usize, Vec<u8>) {
+        (4328, last_active_slot.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_order_id`
+    pub fn filter_next_order_id(next_order_id: u32) -> (usize, Vec<u8>) {
+        (4336, next_order_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `max_margin_ratio`
+    pub fn filter_max_margin_ratio(max_margin_ratio: u32) -> (usize, Vec<u8>) {
+        (4340, max_margin_ratio.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_liquidation_id`
+    pub fn filter_next_liquidation_id(next_liquidation_id: u16) -> (usize, Vec<u8>) {
+        (4344, next_liquidation_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `sub_account_id`
+    pub fn filter_sub_account_id(sub_account_id: u16) -> (usize, Vec<u8>) {
+        (4346, sub_account_id.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `status`
+    pub fn filter_status(status: &UserStatus) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((4348, borsh::to_vec(status)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `is_margin_trading_enabled`
+    pub fn filter_is_margin_trading_enabled(is_margin_trading_enabled: bool) -> (usize, Vec<u8>) {
+        (4349, vec![is_margin_trading_enabled as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `idle`
+    pub fn filter_idle(idle: bool) -> (usize, Vec<u8>) {
+        (4350, vec![idle as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `open_orders`
+    pub fn filter_open_orders(open_orders: u8) -> (usize, Vec<u8>) {
+        (4351, open_orders.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `has_open_order`
+    pub fn filter_has_open_order(has_open_order: bool) -> (usize, Vec<u8>) {
+        (4352, vec![has_open_order as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `open_auctions`
+    pub fn filter_open_auctions(open_auctions: u8) -> (usize, Vec<u8>) {
+        (4353, open_auctions.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `has_open_auction`
+    pub fn filter_has_open_auction(has_open_auction: bool) -> (usize, Vec<u8>) {
+        (4354, vec![has_open_auction as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 21]) -> (usize, Vec<u8>) {
+        (4355, padding.to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl UserAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_delegate(delegate: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_delegate(delegate))
+    }
+    pub fn rpc_filter_name(name: &[u8; 32]) -> RpcFilterType {
+        memcmp_filter(Self::filter_name(name))
+    }
+    pub fn rpc_filter_spot_positions(
+        spot_positions: &[SpotPosition; 8],
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_spot_positions(spot_positions).map(memcmp_filter)
+    }
+    pub fn rpc_filter_perp_positions(
+        perp_positions: &[PerpPosition; 8],
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_perp_positions(perp_positions).map(memcmp_filter)
+    }
+    pub fn rpc_filter_orders(orders: &[Order; 32]) -> std::io::Result<RpcFilterType> {
+        Self::filter_orders(orders).map(memcmp_filter)
+    }
+    pub fn rpc_filter_last_add_perp_lp_shares_ts(last_add_perp_lp_shares_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_add_perp_lp_shares_ts(
+            last_add_perp_lp_shares_ts,
+        ))
+    }
+    pub fn rpc_filter_total_deposits(total_deposits: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_deposits(total_deposits))
+    }
+    pub fn rpc_filter_total_withdraws(total_withdraws: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_withdraws(total_withdraws))
+    }
+    pub fn rpc_filter_total_social_loss(total_social_loss: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_social_loss(total_social_loss))
+    }
+    pub fn rpc_filter_settled_perp_pnl(settled_perp_pnl: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_settled_perp_pnl(settled_perp_pnl))
+    }
+    pub fn rpc_filter_cumulative_spot_fees(cumulative_spot_fees: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_cumulative_spot_fees(cumulative_spot_fees))
+    }
+    pub fn rpc_filter_cumulative_perp_funding(cumulative_perp_funding: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_cumulative_perp_funding(
+            cumulative_perp_funding,
+        ))
+    }
+    pub fn rpc_filter_liquidation_margin_freed(liquidation_margin_freed: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_liquidation_margin_freed(
+            liquidation_margin_freed,
+        ))
+    }
+    pub fn rpc_filter_last_active_slot(last_active_slot: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_active_slot(last_active_slot))
+    }
+    pub fn rpc_filter_next_order_id(next_order_id: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_order_id(next_order_id))
+    }
+    pub fn rpc_filter_max_margin_ratio(max_margin_ratio: u32) -> RpcFilterType {
+        memcmp_filter(Self::filter_max_margin_ratio(max_margin_ratio))
+    }
+    pub fn rpc_filter_next_liquidation_id(next_liquidation_id: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_liquidation_id(next_liquidation_id))
+    }
+    pub fn rpc_filter_sub_account_id(sub_account_id: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_sub_account_id(sub_account_id))
+    }
+    pub fn rpc_filter_status(status: &UserStatus) -> std::io::Result<RpcFilterType> {
+        Self::filter_status(status).map(memcmp_filter)
+    }
+    pub fn rpc_filter_is_margin_trading_enabled(is_margin_trading_enabled: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_is_margin_trading_enabled(
+            is_margin_trading_enabled,
+        ))
+    }
+    pub fn rpc_filter_idle(idle: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_idle(idle))
+    }
+    pub fn rpc_filter_open_orders(open_orders: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_open_orders(open_orders))
+    }
+    pub fn rpc_filter_has_open_order(has_open_order: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_has_open_order(has_open_order))
+    }
+    pub fn rpc_filter_open_auctions(open_auctions: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_open_auctions(open_auctions))
+    }
+    pub fn rpc_filter_has_open_auction(has_open_auction: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_has_open_auction(has_open_auction))
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 21]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
+pub const USER_STATS_ACCOUNT_DISCM: [u8; 8] = [176, 223, 136, 27, 122, 79, 32, 227];
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd)]
 pub struct UserStatsAccount(pub UserStats);
 impl UserStatsAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -434,13 +1628,10 @@ impl UserStatsAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != USER_STATS_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    USER_STATS_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                USER_STATS_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UserStats::deserialize(&mut reader)?))
     }
@@ -454,16 +1645,144 @@ impl UserStatsAccount {
         Ok(data)
     }
 }
-pub const REFERRER_NAME_ACCOUNT_DISCM: [u8; 8] = [105, 133, 170, 110, 52, 42, 28, 182];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ReferrerName {
-    pub authority: Pubkey,
-    pub user: Pubkey,
-    pub user_stats: Pubkey,
-    pub name: [u8; 32],
+pub const USER_STATS_ACCOUNT_LEN: usize = 240;
+impl UserStatsAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, USER_STATS_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `referrer`
+    pub fn filter_referrer(referrer: &Pubkey) -> (usize, Vec<u8>) {
+        (40, referrer.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fees`
+    pub fn filter_fees(fees: &UserFees) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((72, borsh::to_vec(fees)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `next_epoch_ts`
+    pub fn filter_next_epoch_ts(next_epoch_ts: i64) -> (usize, Vec<u8>) {
+        (120, next_epoch_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `maker_volume30d`
+    pub fn filter_maker_volume30d(maker_volume30d: u64) -> (usize, Vec<u8>) {
+        (128, maker_volume30d.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `taker_volume30d`
+    pub fn filter_taker_volume30d(taker_volume30d: u64) -> (usize, Vec<u8>) {
+        (136, taker_volume30d.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `filler_volume30d`
+    pub fn filter_filler_volume30d(filler_volume30d: u64) -> (usize, Vec<u8>) {
+        (144, filler_volume30d.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_maker_volume30d_ts`
+    pub fn filter_last_maker_volume30d_ts(last_maker_volume30d_ts: i64) -> (usize, Vec<u8>) {
+        (152, last_maker_volume30d_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_taker_volume30d_ts`
+    pub fn filter_last_taker_volume30d_ts(last_taker_volume30d_ts: i64) -> (usize, Vec<u8>) {
+        (160, last_taker_volume30d_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_filler_volume30d_ts`
+    pub fn filter_last_filler_volume30d_ts(last_filler_volume30d_ts: i64) -> (usize, Vec<u8>) {
+        (168, last_filler_volume30d_ts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `if_staked_quote_asset_amount`
+    pub fn filter_if_staked_quote_asset_amount(
+        if_staked_quote_asset_amount: u64,
+    ) -> (usize, Vec<u8>) {
+        (176, if_staked_quote_asset_amount.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_sub_accounts`
+    pub fn filter_number_of_sub_accounts(number_of_sub_accounts: u16) -> (usize, Vec<u8>) {
+        (184, number_of_sub_accounts.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `number_of_sub_accounts_created`
+    pub fn filter_number_of_sub_accounts_created(
+        number_of_sub_accounts_created: u16,
+    ) -> (usize, Vec<u8>) {
+        (186, number_of_sub_accounts_created.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `is_referrer`
+    pub fn filter_is_referrer(is_referrer: bool) -> (usize, Vec<u8>) {
+        (188, vec![is_referrer as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u8; 51]) -> (usize, Vec<u8>) {
+        (189, padding.to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl UserStatsAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_referrer(referrer: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_referrer(referrer))
+    }
+    pub fn rpc_filter_fees(fees: &UserFees) -> std::io::Result<RpcFilterType> {
+        Self::filter_fees(fees).map(memcmp_filter)
+    }
+    pub fn rpc_filter_next_epoch_ts(next_epoch_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_next_epoch_ts(next_epoch_ts))
+    }
+    pub fn rpc_filter_maker_volume30d(maker_volume30d: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_maker_volume30d(maker_volume30d))
+    }
+    pub fn rpc_filter_taker_volume30d(taker_volume30d: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_taker_volume30d(taker_volume30d))
+    }
+    pub fn rpc_filter_filler_volume30d(filler_volume30d: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_filler_volume30d(filler_volume30d))
+    }
+    pub fn rpc_filter_last_maker_volume30d_ts(last_maker_volume30d_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_maker_volume30d_ts(
+            last_maker_volume30d_ts,
+        ))
+    }
+    pub fn rpc_filter_last_taker_volume30d_ts(last_taker_volume30d_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_taker_volume30d_ts(
+            last_taker_volume30d_ts,
+        ))
+    }
+    pub fn rpc_filter_last_filler_volume30d_ts(last_filler_volume30d_ts: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_filler_volume30d_ts(
+            last_filler_volume30d_ts,
+        ))
+    }
+    pub fn rpc_filter_if_staked_quote_asset_amount(
+        if_staked_quote_asset_amount: u64,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_if_staked_quote_asset_amount(
+            if_staked_quote_asset_amount,
+        ))
+    }
+    pub fn rpc_filter_number_of_sub_accounts(number_of_sub_accounts: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_sub_accounts(number_of_sub_accounts))
+    }
+    pub fn rpc_filter_number_of_sub_accounts_created(
+        number_of_sub_accounts_created: u16,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_number_of_sub_accounts_created(
+            number_of_sub_accounts_created,
+        ))
+    }
+    pub fn rpc_filter_is_referrer(is_referrer: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_is_referrer(is_referrer))
+    }
+    pub fn rpc_filter_padding(padding: &[u8; 51]) -> RpcFilterType {
+        memcmp_filter(Self::filter_padding(padding))
+    }
+}
+pub const REFERRER_NAME_ACCOUNT_DISCM: [u8; 8] = [105, 133, 170, 110, 52, 42, 28, 182];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct ReferrerNameAccount(pub ReferrerName);
 impl ReferrerNameAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -472,13 +1791,10 @@ impl ReferrerNameAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REFERRER_NAME_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REFERRER_NAME_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REFERRER_NAME_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ReferrerName::deserialize(&mut reader)?))
     }
@@ -492,3 +1808,127 @@ impl ReferrerNameAccount {
         Ok(data)
     }
 }
+pub const REFERRER_NAME_ACCOUNT_LEN: usize = 136;
+impl ReferrerNameAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, REFERRER_NAME_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `user`
+    pub fn filter_user(user: &Pubkey) -> (usize, Vec<u8>) {
+        (40, user.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `user_stats`
+    pub fn filter_user_stats(user_stats: &Pubkey) -> (usize, Vec<u8>) {
+        (72, user_stats.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `name`
+    pub fn filter_name(name: &[u8; 32]) -> (usize, Vec<u8>) {
+        (104, name.to_vec())
+    }
+}
+#[cfg(feature = "client")]
+impl ReferrerNameAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_user(user: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_user(user))
+    }
+    pub fn rpc_filter_user_stats(user_stats: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_user_stats(user_stats))
+    }
+    pub fn rpc_filter_name(name: &[u8; 32]) -> RpcFilterType {
+        memcmp_filter(Self::filter_name(name))
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DriftAccountData {
+    PhoenixV1FulfillmentConfig(PhoenixV1FulfillmentConfigAccount),
+    SerumV3FulfillmentConfig(SerumV3FulfillmentConfigAccount),
+    InsuranceFundStake(InsuranceFundStakeAccount),
+    PerpMarket(PerpMarketAccount),
+    SpotMarket(SpotMarketAccount),
+    State(StateAccount),
+    User(UserAccount),
+    UserStats(UserStatsAccount),
+    ReferrerName(ReferrerNameAccount),
+}
+impl DriftAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM) {
+            return Ok(Self::PhoenixV1FulfillmentConfig(
+                PhoenixV1FulfillmentConfigAccount::deserialize(buf)?,
+            ));
+        }
+        if buf.starts_with(&SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM) {
+            return Ok(Self::SerumV3FulfillmentConfig(
+                SerumV3FulfillmentConfigAccount::deserialize(buf)?,
+            ));
+        }
+        if buf.starts_with(&INSURANCE_FUND_STAKE_ACCOUNT_DISCM) {
+            return Ok(Self::InsuranceFundStake(
+                InsuranceFundStakeAccount::deserialize(buf)?,
+            ));
+        }
+        if buf.starts_with(&PERP_MARKET_ACCOUNT_DISCM) {
+            return Ok(Self::PerpMarket(PerpMarketAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&SPOT_MARKET_ACCOUNT_DISCM) {
+            return Ok(Self::SpotMarket(SpotMarketAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&STATE_ACCOUNT_DISCM) {
+            return Ok(Self::State(StateAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&USER_ACCOUNT_DISCM) {
+            return Ok(Self::User(UserAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&USER_STATS_ACCOUNT_DISCM) {
+            return Ok(Self::UserStats(UserStatsAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&REFERRER_NAME_ACCOUNT_DISCM) {
+            return Ok(Self::ReferrerName(ReferrerNameAccount::deserialize(buf)?));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::PhoenixV1FulfillmentConfig(_) => &PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM,
+            Self::SerumV3FulfillmentConfig(_) => &SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM,
+            Self::InsuranceFundStake(_) => &INSURANCE_FUND_STAKE_ACCOUNT_DISCM,
+            Self::PerpMarket(_) => &PERP_MARKET_ACCOUNT_DISCM,
+            Self::SpotMarket(_) => &SPOT_MARKET_ACCOUNT_DISCM,
+            Self::State(_) => &STATE_ACCOUNT_DISCM,
+            Self::User(_) => &USER_ACCOUNT_DISCM,
+            Self::UserStats(_) => &USER_STATS_ACCOUNT_DISCM,
+            Self::ReferrerName(_) => &REFERRER_NAME_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PhoenixV1FulfillmentConfig(_) => "PhoenixV1FulfillmentConfig",
+            Self::SerumV3FulfillmentConfig(_) => "SerumV3FulfillmentConfig",
+            Self::InsuranceFundStake(_) => "InsuranceFundStake",
+            Self::PerpMarket(_) => "PerpMarket",
+            Self::SpotMarket(_) => "SpotMarket",
+            Self::State(_) => "State",
+            Self::User(_) => "User",
+            Self::UserStats(_) => "UserStats",
+            Self::ReferrerName(_) => "ReferrerName",
+        }
+    }
+}
@@ -1,8 +1,4 @@
-use solana_program::{
-    decode_error::DecodeError,
-    msg,
-    program_error::{PrintProgramError, ProgramError},
-};
+use solana_program::program_error::ProgramError;
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
 pub enum DriftError {
@@ -516,20 +512,3 @@ impl From<DriftError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
-impl<T> DecodeError<T> for DriftError {
-    fn type_of() -> &'static str {
-        "DriftError"
-    }
-}
-impl PrintProgramError for DriftError {
-    fn print<E>(&self)
-    where
-        E: 'static
-            + std::error::Error
-            + DecodeError<E>
-            + PrintProgramError
-            + num_traits::FromPrimitive,
-    {
-        msg!(&self.to_string());
-    }
-}
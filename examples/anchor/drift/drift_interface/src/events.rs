@@ -1,16 +1,6 @@
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
 pub const NEW_USER_RECORD_EVENT_DISCM: [u8; 8] = [236, 186, 113, 219, 42, 51, 149, 249];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct NewUserRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    user: Pubkey,
-    sub_account_id: u16,
-    name: [u8; 32],
-    referrer: Pubkey,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct NewUserRecordEvent(pub NewUserRecord);
 impl BorshSerialize for NewUserRecordEvent {
@@ -23,37 +13,15 @@ impl NewUserRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != NEW_USER_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NEW_USER_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                NEW_USER_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(NewUserRecord::deserialize(buf)?))
     }
 }
 pub const DEPOSIT_RECORD_EVENT_DISCM: [u8; 8] = [180, 241, 218, 207, 102, 135, 44, 134];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct DepositRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    user: Pubkey,
-    direction: DepositDirection,
-    deposit_record_id: u64,
-    amount: u64,
-    market_index: u16,
-    oracle_price: i64,
-    market_deposit_balance: u128,
-    market_withdraw_balance: u128,
-    market_cumulative_deposit_interest: u128,
-    market_cumulative_borrow_interest: u128,
-    total_deposits_after: u64,
-    total_withdraws_after: u64,
-    explanation: DepositExplanation,
-    transfer_user: Option<Pubkey>,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct DepositRecordEvent(pub DepositRecord);
 impl BorshSerialize for DepositRecordEvent {
@@ -66,30 +34,15 @@ impl DepositRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != DEPOSIT_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DepositRecord::deserialize(buf)?))
     }
 }
 pub const SPOT_INTEREST_RECORD_EVENT_DISCM: [u8; 8] = [183, 186, 203, 186, 225, 187, 95, 130];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct SpotInterestRecord {
-    ts: i64,
-    market_index: u16,
-    deposit_balance: u128,
-    cumulative_deposit_interest: u128,
-    borrow_balance: u128,
-    cumulative_borrow_interest: u128,
-    optimal_utilization: u32,
-    optimal_borrow_rate: u32,
-    max_borrow_rate: u32,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct SpotInterestRecordEvent(pub SpotInterestRecord);
 impl BorshSerialize for SpotInterestRecordEvent {
@@ -102,30 +55,15 @@ impl SpotInterestRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != SPOT_INTEREST_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SPOT_INTEREST_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SPOT_INTEREST_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SpotInterestRecord::deserialize(buf)?))
     }
 }
 pub const FUNDING_PAYMENT_RECORD_EVENT_DISCM: [u8; 8] = [8, 59, 96, 20, 137, 201, 56, 95];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct FundingPaymentRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    user: Pubkey,
-    market_index: u16,
-    funding_payment: i64,
-    base_asset_amount: i64,
-    user_last_cumulative_funding: i64,
-    amm_cumulative_funding_long: i128,
-    amm_cumulative_funding_short: i128,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct FundingPaymentRecordEvent(pub FundingPaymentRecord);
 impl BorshSerialize for FundingPaymentRecordEvent {
@@ -138,34 +76,15 @@ impl FundingPaymentRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != FUNDING_PAYMENT_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FUNDING_PAYMENT_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                FUNDING_PAYMENT_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(FundingPaymentRecord::deserialize(buf)?))
     }
 }
 pub const FUNDING_RATE_RECORD_EVENT_DISCM: [u8; 8] = [68, 3, 255, 26, 133, 91, 147, 254];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct FundingRateRecord {
-    ts: i64,
-    record_id: u64,
-    market_index: u16,
-    funding_rate: i64,
-    funding_rate_long: i128,
-    funding_rate_short: i128,
-    cumulative_funding_rate_long: i128,
-    cumulative_funding_rate_short: i128,
-    oracle_price_twap: i64,
-    mark_price_twap: u64,
-    period_revenue: i64,
-    base_asset_amount_with_amm: i128,
-    base_asset_amount_with_unsettled_lp: i128,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct FundingRateRecordEvent(pub FundingRateRecord);
 impl BorshSerialize for FundingRateRecordEvent {
@@ -178,41 +97,15 @@ impl FundingRateRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != FUNDING_RATE_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FUNDING_RATE_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                FUNDING_RATE_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(FundingRateRecord::deserialize(buf)?))
     }
 }
 pub const CURVE_RECORD_EVENT_DISCM: [u8; 8] = [101, 238, 40, 228, 70, 46, 61, 117];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct CurveRecord {
-    ts: i64,
-    record_id: u64,
-    peg_multiplier_before: u128,
-    base_asset_reserve_before: u128,
-    quote_asset_reserve_before: u128,
-    sqrt_k_before: u128,
-    peg_multiplier_after: u128,
-    base_asset_reserve_after: u128,
-    quote_asset_reserve_after: u128,
-    sqrt_k_after: u128,
-    base_asset_amount_long: u128,
-    base_asset_amount_short: u128,
-    base_asset_amount_with_amm: i128,
-    total_fee: i128,
-    total_fee_minus_distributions: i128,
-    adjustment_cost: i128,
-    oracle_price: i64,
-    fill_record: u128,
-    number_of_users: u32,
-    market_index: u16,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct CurveRecordEvent(pub CurveRecord);
 impl BorshSerialize for CurveRecordEvent {
@@ -225,24 +118,15 @@ impl CurveRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != CURVE_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CURVE_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CURVE_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CurveRecord::deserialize(buf)?))
     }
 }
 pub const ORDER_RECORD_EVENT_DISCM: [u8; 8] = [104, 19, 64, 56, 89, 21, 2, 90];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct OrderRecord {
-    ts: i64,
-    user: Pubkey,
-    order: Order,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct OrderRecordEvent(pub OrderRecord);
 impl BorshSerialize for OrderRecordEvent {
@@ -255,49 +139,15 @@ impl OrderRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != ORDER_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ORDER_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ORDER_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(OrderRecord::deserialize(buf)?))
     }
 }
 pub const ORDER_ACTION_RECORD_EVENT_DISCM: [u8; 8] = [224, 52, 67, 71, 194, 237, 109, 1];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct OrderActionRecord {
-    ts: i64,
-    action: OrderAction,
-    action_explanation: OrderActionExplanation,
-    market_index: u16,
-    market_type: MarketType,
-    filler: Option<Pubkey>,
-    filler_reward: Option<u64>,
-    fill_record_id: Option<u64>,
-    base_asset_amount_filled: Option<u64>,
-    quote_asset_amount_filled: Option<u64>,
-    taker_fee: Option<u64>,
-    maker_fee: Option<i64>,
-    referrer_reward: Option<u32>,
-    quote_asset_amount_surplus: Option<i64>,
-    spot_fulfillment_method_fee: Option<u64>,
-    taker: Option<Pubkey>,
-    taker_order_id: Option<u32>,
-    taker_order_direction: Option<PositionDirection>,
-    taker_order_base_asset_amount: Option<u64>,
-    taker_order_cumulative_base_asset_amount_filled: Option<u64>,
-    taker_order_cumulative_quote_asset_amount_filled: Option<u64>,
-    maker: Option<Pubkey>,
-    maker_order_id: Option<u32>,
-    maker_order_direction: Option<PositionDirection>,
-    maker_order_base_asset_amount: Option<u64>,
-    maker_order_cumulative_base_asset_amount_filled: Option<u64>,
-    maker_order_cumulative_quote_asset_amount_filled: Option<u64>,
-    oracle_price: i64,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct OrderActionRecordEvent(pub OrderActionRecord);
 impl BorshSerialize for OrderActionRecordEvent {
@@ -310,72 +160,36 @@ impl OrderActionRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != ORDER_ACTION_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ORDER_ACTION_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ORDER_ACTION_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(OrderActionRecord::deserialize(buf)?))
     }
 }
 pub const LP_RECORD_EVENT_DISCM: [u8; 8] = [101, 22, 54, 38, 178, 13, 142, 111];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct LpRecord {
-    ts: i64,
-    user: Pubkey,
-    action: LPAction,
-    n_shares: u64,
-    market_index: u16,
-    delta_base_asset_amount: i64,
-    delta_quote_asset_amount: i64,
-    pnl: i64,
-}
 #[derive(Clone, Debug, PartialEq)]
-pub struct LpRecordEvent(pub LpRecord);
-impl BorshSerialize for LpRecordEvent {
+pub struct LPRecordEvent(pub LPRecord);
+impl BorshSerialize for LPRecordEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         LP_RECORD_EVENT_DISCM.serialize(writer)?;
         self.0.serialize(writer)
     }
 }
-impl LpRecordEvent {
+impl LPRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != LP_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LP_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LP_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
-        Ok(Self(LpRecord::deserialize(buf)?))
+        Ok(Self(LPRecord::deserialize(buf)?))
     }
 }
 pub const LIQUIDATION_RECORD_EVENT_DISCM: [u8; 8] = [127, 17, 0, 108, 182, 13, 231, 53];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct LiquidationRecord {
-    ts: i64,
-    liquidation_type: LiquidationType,
-    user: Pubkey,
-    liquidator: Pubkey,
-    margin_requirement: u128,
-    total_collateral: i128,
-    margin_freed: u64,
-    liquidation_id: u16,
-    bankrupt: bool,
-    canceled_order_ids: Vec<u32>,
-    liquidate_perp: LiquidatePerpRecord,
-    liquidate_spot: LiquidateSpotRecord,
-    liquidate_borrow_for_perp_pnl: LiquidateBorrowForPerpPnlRecord,
-    liquidate_perp_pnl_for_deposit: LiquidatePerpPnlForDepositRecord,
-    perp_bankruptcy: PerpBankruptcyRecord,
-    spot_bankruptcy: SpotBankruptcyRecord,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct LiquidationRecordEvent(pub LiquidationRecord);
 impl BorshSerialize for LiquidationRecordEvent {
@@ -388,30 +202,15 @@ impl LiquidationRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != LIQUIDATION_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LIQUIDATION_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LIQUIDATION_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(LiquidationRecord::deserialize(buf)?))
     }
 }
 pub const SETTLE_PNL_RECORD_EVENT_DISCM: [u8; 8] = [57, 68, 105, 26, 119, 198, 213, 89];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct SettlePnlRecord {
-    ts: i64,
-    user: Pubkey,
-    market_index: u16,
-    pnl: i128,
-    base_asset_amount: i64,
-    quote_asset_amount_after: i64,
-    quote_entry_amount: i64,
-    settle_price: i64,
-    explanation: SettlePnlExplanation,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct SettlePnlRecordEvent(pub SettlePnlRecord);
 impl BorshSerialize for SettlePnlRecordEvent {
@@ -424,31 +223,15 @@ impl SettlePnlRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != SETTLE_PNL_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SETTLE_PNL_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SETTLE_PNL_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SettlePnlRecord::deserialize(buf)?))
     }
 }
 pub const INSURANCE_FUND_RECORD_EVENT_DISCM: [u8; 8] = [56, 222, 215, 235, 78, 197, 99, 146];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct InsuranceFundRecord {
-    ts: i64,
-    spot_market_index: u16,
-    perp_market_index: u16,
-    user_if_factor: u32,
-    total_if_factor: u32,
-    vault_amount_before: u64,
-    insurance_vault_amount_before: u64,
-    total_if_shares_before: u128,
-    total_if_shares_after: u128,
-    amount: i64,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct InsuranceFundRecordEvent(pub InsuranceFundRecord);
 impl BorshSerialize for InsuranceFundRecordEvent {
@@ -461,33 +244,15 @@ impl InsuranceFundRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != INSURANCE_FUND_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INSURANCE_FUND_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INSURANCE_FUND_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InsuranceFundRecord::deserialize(buf)?))
     }
 }
 pub const INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM: [u8; 8] = [68, 66, 156, 7, 216, 148, 250, 114];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct InsuranceFundStakeRecord {
-    ts: i64,
-    user_authority: Pubkey,
-    action: StakeAction,
-    amount: u64,
-    market_index: u16,
-    insurance_vault_amount_before: u64,
-    if_shares_before: u128,
-    user_if_shares_before: u128,
-    total_if_shares_before: u128,
-    if_shares_after: u128,
-    user_if_shares_after: u128,
-    total_if_shares_after: u128,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct InsuranceFundStakeRecordEvent(pub InsuranceFundStakeRecord);
 impl BorshSerialize for InsuranceFundStakeRecordEvent {
@@ -500,30 +265,15 @@ impl InsuranceFundStakeRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InsuranceFundStakeRecord::deserialize(buf)?))
     }
 }
 pub const SWAP_RECORD_EVENT_DISCM: [u8; 8] = [162, 187, 123, 194, 138, 56, 250, 241];
-#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-pub struct SwapRecord {
-    ts: i64,
-    user: Pubkey,
-    amount_out: u64,
-    amount_in: u64,
-    out_market_index: u16,
-    in_market_index: u16,
-    out_oracle_price: i64,
-    in_oracle_price: i64,
-    fee: u64,
-}
 #[derive(Clone, Debug, PartialEq)]
 pub struct SwapRecordEvent(pub SwapRecord);
 impl BorshSerialize for SwapRecordEvent {
@@ -536,14 +286,195 @@ impl SwapRecordEvent {
     pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
         let maybe_discm = <[u8; 8]>::deserialize(buf)?;
         if maybe_discm != SWAP_RECORD_EVENT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SWAP_RECORD_EVENT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SWAP_RECORD_EVENT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SwapRecord::deserialize(buf)?))
     }
 }
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DriftEvent {
+    NewUserRecord(NewUserRecordEvent),
+    DepositRecord(DepositRecordEvent),
+    SpotInterestRecord(SpotInterestRecordEvent),
+    FundingPaymentRecord(FundingPaymentRecordEvent),
+    FundingRateRecord(FundingRateRecordEvent),
+    CurveRecord(CurveRecordEvent),
+    OrderRecord(OrderRecordEvent),
+    OrderActionRecord(OrderActionRecordEvent),
+    LPRecord(LPRecordEvent),
+    LiquidationRecord(LiquidationRecordEvent),
+    SettlePnlRecord(SettlePnlRecordEvent),
+    InsuranceFundRecord(InsuranceFundRecordEvent),
+    InsuranceFundStakeRecord(InsuranceFundStakeRecordEvent),
+    SwapRecord(SwapRecordEvent),
+}
+impl DriftEvent {
+    /// Decodes any of the program's events from its discriminator-prefixed bytes
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        if buf.starts_with(&NEW_USER_RECORD_EVENT_DISCM) {
+            return Ok(Self::NewUserRecord(NewUserRecordEvent::deserialize(
+                &mut reader,
+            )?));
+        }
+        if buf.starts_with(&DEPOSIT_RECORD_EVENT_DISCM) {
+            return Ok(Self::DepositRecord(DepositRecordEvent::deserialize(
+                &mut reader,
+            )?));
+        }
+        if buf.starts_with(&SPOT_INTEREST_RECORD_EVENT_DISCM) {
+            return Ok(Self::SpotInterestRecord(
+                SpotInterestRecordEvent::deserialize(&mut reader)?,
+            ));
+        }
+        if buf.starts_with(&FUNDING_PAYMENT_RECORD_EVENT_DISCM) {
+            return Ok(Self::FundingPaymentRecord(
+                FundingPaymentRecordEvent::deserialize(&mut reader)?,
+            ));
+        }
+        if buf.starts_with(&FUNDING_RATE_RECORD_EVENT_DISCM) {
+            return Ok(Self::FundingRateRecord(
+                FundingRateRecordEvent::deserialize(&mut reader)?,
+            ));
+        }
+        if buf.starts_with(&CURVE_RECORD_EVENT_DISCM) {
+            return Ok(Self::CurveRecord(CurveRecordEvent::deserialize(
+                &mut reader,
+            )?));
+        }
+        if buf.starts_with(&ORDER_RECORD_EVENT_DISCM) {
+            return Ok(Self::OrderRecord(OrderRecordEvent::deserialize(
+                &mut reader,
+            )?));
+        }
+        if buf.starts_with(&ORDER_ACTION_RECORD_EVENT_DISCM) {
+            return Ok(Self::OrderActionRecord(
+                OrderActionRecordEvent::deserialize(&mut reader)?,
+            ));
+        }
+        if buf.starts_with(&LP_RECORD_EVENT_DISCM) {
+            return Ok(Self::LPRecord(LPRecordEvent::deserialize(&mut reader)?));
+        }
+        if buf.starts_with(&LIQUIDATION_RECORD_EVENT_DISCM) {
+            return Ok(Self::LiquidationRecord(
+                LiquidationRecordEvent::deserialize(&mut reader)?,
+            ));
+        }
+        if buf.starts_with(&SETTLE_PNL_RECORD_EVENT_DISCM) {
+            return Ok(Self::SettlePnlRecord(SettlePnlRecordEvent::deserialize(
+                &mut reader,
+            )?));
+        }
+        if buf.starts_with(&INSURANCE_FUND_RECORD_EVENT_DISCM) {
+            return Ok(Self::InsuranceFundRecord(
+                InsuranceFundRecordEvent::deserialize(&mut reader)?,
+            ));
+        }
+        if buf.starts_with(&INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM) {
+            return Ok(Self::InsuranceFundStakeRecord(
+                InsuranceFundStakeRecordEvent::deserialize(&mut reader)?,
+            ));
+        }
+        if buf.starts_with(&SWAP_RECORD_EVENT_DISCM) {
+            return Ok(Self::SwapRecord(SwapRecordEvent::deserialize(&mut reader)?));
+        }
+        Err(std::io::Error::other(format!(
+            "event discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+}
+/// Prefix of the self-CPI instruction data anchor's `emit_cpi!` emits events with
+pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+impl DriftEvent {
+    fn has_known_discm(buf: &[u8]) -> bool {
+        buf.starts_with(&NEW_USER_RECORD_EVENT_DISCM)
+            || buf.starts_with(&DEPOSIT_RECORD_EVENT_DISCM)
+            || buf.starts_with(&SPOT_INTEREST_RECORD_EVENT_DISCM)
+            || buf.starts_with(&FUNDING_PAYMENT_RECORD_EVENT_DISCM)
+            || buf.starts_with(&FUNDING_RATE_RECORD_EVENT_DISCM)
+            || buf.starts_with(&CURVE_RECORD_EVENT_DISCM)
+            || buf.starts_with(&ORDER_RECORD_EVENT_DISCM)
+            || buf.starts_with(&ORDER_ACTION_RECORD_EVENT_DISCM)
+            || buf.starts_with(&LP_RECORD_EVENT_DISCM)
+            || buf.starts_with(&LIQUIDATION_RECORD_EVENT_DISCM)
+            || buf.starts_with(&SETTLE_PNL_RECORD_EVENT_DISCM)
+            || buf.starts_with(&INSURANCE_FUND_RECORD_EVENT_DISCM)
+            || buf.starts_with(&INSURANCE_FUND_STAKE_RECORD_EVENT_DISCM)
+            || buf.starts_with(&SWAP_RECORD_EVENT_DISCM)
+    }
+    /// Decodes the events emitted by this program with `emit!` from a transaction's log messages.
+    ///
+    /// Only `Program data:` lines logged while this program is the innermost invoked program are considered.
+    /// Data that does not start with any of the program's event discms is skipped.
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> std::io::Result<Vec<Self>> {
+        use base64::Engine;
+        let program_id = crate::ID.to_string();
+        let mut invoke_stack: Vec<&str> = Vec::new();
+        let mut res = Vec::new();
+        for log in logs {
+            let log = log.as_ref();
+            if let Some(data) = log.strip_prefix("Program data: ") {
+                if invoke_stack.last() != Some(&program_id.as_str()) {
+                    continue;
+                }
+                let data = data.split(' ').next().unwrap_or_default();
+                let buf = base64::engine::general_purpose::STANDARD
+                    .decode(data)
+                    .map_err(std::io::Error::other)?;
+                if Self::has_known_discm(&buf) {
+                    res.push(Self::deserialize(&buf)?);
+                }
+            } else if let Some(rest) = log.strip_prefix("Program ") {
+                let mut words = rest.split(' ');
+                let (Some(id), Some(status)) = (words.next(), words.next()) else {
+                    continue;
+                };
+                if status == "invoke" {
+                    invoke_stack.push(id);
+                } else if status == "success" || status.starts_with("failed") {
+                    invoke_stack.pop();
+                }
+            }
+        }
+        Ok(res)
+    }
+    /// Decodes an event emitted by this program with `emit_cpi!` from the data of
+    /// the self-CPI inner instruction, which is [`EVENT_IX_TAG`] followed by the event's bytes.
+    ///
+    /// Callers should check that the inner instruction's program ID is this program's.
+    pub fn from_cpi_ix_data(data: &[u8]) -> std::io::Result<Self> {
+        let buf = data.strip_prefix(&EVENT_IX_TAG).ok_or_else(|| {
+            std::io::Error::other(format!(
+                "event ix tag does not match. Expected: {:?}. Received: {:?}",
+                EVENT_IX_TAG,
+                &data[..data.len().min(EVENT_IX_TAG.len())]
+            ))
+        })?;
+        Self::deserialize(buf)
+    }
+}
+impl BorshSerialize for DriftEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::NewUserRecord(e) => e.serialize(writer),
+            Self::DepositRecord(e) => e.serialize(writer),
+            Self::SpotInterestRecord(e) => e.serialize(writer),
+            Self::FundingPaymentRecord(e) => e.serialize(writer),
+            Self::FundingRateRecord(e) => e.serialize(writer),
+            Self::CurveRecord(e) => e.serialize(writer),
+            Self::OrderRecord(e) => e.serialize(writer),
+            Self::OrderActionRecord(e) => e.serialize(writer),
+            Self::LPRecord(e) => e.serialize(writer),
+            Self::LiquidationRecord(e) => e.serialize(writer),
+            Self::SettlePnlRecord(e) => e.serialize(writer),
+            Self::InsuranceFundRecord(e) => e.serialize(writer),
+            Self::InsuranceFundStakeRecord(e) => e.serialize(writer),
+            Self::SwapRecord(e) => e.serialize(writer),
+        }
+    }
+}
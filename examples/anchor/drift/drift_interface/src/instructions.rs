@@ -512,10 +512,10 @@ impl DriftProgramIx {
             ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM => Ok(Self::AdminRemoveInsuranceFundStake(
                 AdminRemoveInsuranceFundStakeIxArgs::deserialize(&mut reader)?,
             )),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -1076,6 +1076,12 @@ impl From<InitializeUserKeys> for [AccountMeta; INITIALIZE_USER_IX_ACCOUNTS_LEN]
         ]
     }
 }
+impl From<InitializeUserKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeUserKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_USER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_USER_IX_ACCOUNTS_LEN]> for InitializeUserKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_USER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1104,6 +1110,12 @@ impl<'info> From<InitializeUserAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeUserAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeUserAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_USER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_USER_IX_ACCOUNTS_LEN]>
     for InitializeUserAccounts<'me, 'info>
 {
@@ -1120,7 +1132,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_USER_IX_ACCOUNTS_LEN]
     }
 }
 pub const INITIALIZE_USER_IX_DISCM: [u8; 8] = [111, 17, 185, 250, 60, 122, 38, 254];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeUserIxArgs {
     pub sub_account_id: u16,
@@ -1139,13 +1153,10 @@ impl InitializeUserIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_USER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_USER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_USER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializeUserIxArgs::deserialize(&mut reader)?))
     }
@@ -1328,6 +1339,12 @@ impl From<InitializeUserStatsKeys> for [AccountMeta; INITIALIZE_USER_STATS_IX_AC
         ]
     }
 }
+impl From<InitializeUserStatsKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeUserStatsKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN]> for InitializeUserStatsKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1354,6 +1371,13 @@ impl<'info> From<InitializeUserStatsAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeUserStatsAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeUserStatsAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_USER_STATS_IX_ACCOUNTS_LEN]>
     for InitializeUserStatsAccounts<'me, 'info>
 {
@@ -1377,13 +1401,10 @@ impl InitializeUserStatsIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_USER_STATS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_USER_STATS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_USER_STATS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -1558,6 +1579,12 @@ impl From<InitializeReferrerNameKeys> for [AccountMeta; INITIALIZE_REFERRER_NAME
         ]
     }
 }
+impl From<InitializeReferrerNameKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeReferrerNameKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN]> for InitializeReferrerNameKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1586,6 +1613,13 @@ impl<'info> From<InitializeReferrerNameAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeReferrerNameAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeReferrerNameAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_REFERRER_NAME_IX_ACCOUNTS_LEN]>
     for InitializeReferrerNameAccounts<'me, 'info>
 {
@@ -1602,7 +1636,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_REFERRER_NAME_IX_ACCO
     }
 }
 pub const INITIALIZE_REFERRER_NAME_IX_DISCM: [u8; 8] = [235, 126, 231, 10, 42, 164, 26, 61];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeReferrerNameIxArgs {
     pub name: [u8; 32],
@@ -1620,13 +1656,10 @@ impl InitializeReferrerNameIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_REFERRER_NAME_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_REFERRER_NAME_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_REFERRER_NAME_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializeReferrerNameIxArgs::deserialize(
             &mut reader,
@@ -1819,6 +1852,12 @@ impl From<DepositKeys> for [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<DepositKeys> for Vec<AccountMeta> {
+    fn from(keys: DepositKeys) -> Self {
+        let accounts: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]> for DepositKeys {
     fn from(pubkeys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1845,6 +1884,12 @@ impl<'info> From<DepositAccounts<'_, 'info>> for [AccountInfo<'info>; DEPOSIT_IX
         ]
     }
 }
+impl<'info> From<DepositAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
     for DepositAccounts<'me, 'info>
 {
@@ -1861,7 +1906,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
     }
 }
 pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositIxArgs {
     pub market_index: u16,
@@ -1881,13 +1928,10 @@ impl DepositIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEPOSIT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DepositIxArgs::deserialize(&mut reader)?))
     }
@@ -2080,6 +2124,12 @@ impl From<WithdrawKeys> for [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<WithdrawKeys> for Vec<AccountMeta> {
+    fn from(keys: WithdrawKeys) -> Self {
+        let accounts: [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]> for WithdrawKeys {
     fn from(pubkeys: [Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2108,6 +2158,12 @@ impl<'info> From<WithdrawAccounts<'_, 'info>> for [AccountInfo<'info>; WITHDRAW_
         ]
     }
 }
+impl<'info> From<WithdrawAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: WithdrawAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
     for WithdrawAccounts<'me, 'info>
 {
@@ -2125,7 +2181,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
     }
 }
 pub const WITHDRAW_IX_DISCM: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawIxArgs {
     pub market_index: u16,
@@ -2145,13 +2203,10 @@ impl WithdrawIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != WITHDRAW_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    WITHDRAW_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                WITHDRAW_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(WithdrawIxArgs::deserialize(&mut reader)?))
     }
@@ -2329,6 +2384,12 @@ impl From<TransferDepositKeys> for [AccountMeta; TRANSFER_DEPOSIT_IX_ACCOUNTS_LE
         ]
     }
 }
+impl From<TransferDepositKeys> for Vec<AccountMeta> {
+    fn from(keys: TransferDepositKeys) -> Self {
+        let accounts: [AccountMeta; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN]> for TransferDepositKeys {
     fn from(pubkeys: [Pubkey; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2355,6 +2416,13 @@ impl<'info> From<TransferDepositAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<TransferDepositAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: TransferDepositAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN]>
     for TransferDepositAccounts<'me, 'info>
 {
@@ -2370,7 +2438,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_DEPOSIT_IX_ACCOUNTS_LEN
     }
 }
 pub const TRANSFER_DEPOSIT_IX_DISCM: [u8; 8] = [20, 20, 147, 223, 41, 63, 204, 111];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransferDepositIxArgs {
     pub market_index: u16,
@@ -2389,13 +2459,10 @@ impl TransferDepositIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != TRANSFER_DEPOSIT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    TRANSFER_DEPOSIT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                TRANSFER_DEPOSIT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(TransferDepositIxArgs::deserialize(&mut reader)?))
     }
@@ -2548,6 +2615,12 @@ impl From<PlacePerpOrderKeys> for [AccountMeta; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN
         ]
     }
 }
+impl From<PlacePerpOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: PlacePerpOrderKeys) -> Self {
+        let accounts: [AccountMeta; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN]> for PlacePerpOrderKeys {
     fn from(pubkeys: [Pubkey; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2568,6 +2641,13 @@ impl<'info> From<PlacePerpOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<PlacePerpOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: PlacePerpOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN]>
     for PlacePerpOrderAccounts<'me, 'info>
 {
@@ -2580,7 +2660,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_PERP_ORDER_IX_ACCOUNTS_LEN
     }
 }
 pub const PLACE_PERP_ORDER_IX_DISCM: [u8; 8] = [69, 161, 93, 202, 120, 126, 76, 185];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlacePerpOrderIxArgs {
     pub params: OrderParams,
@@ -2598,13 +2680,10 @@ impl PlacePerpOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PLACE_PERP_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_PERP_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PLACE_PERP_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PlacePerpOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -2754,6 +2833,12 @@ impl From<CancelOrderKeys> for [AccountMeta; CANCEL_ORDER_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<CancelOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: CancelOrderKeys) -> Self {
+        let accounts: [AccountMeta; CANCEL_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CANCEL_ORDER_IX_ACCOUNTS_LEN]> for CancelOrderKeys {
     fn from(pubkeys: [Pubkey; CANCEL_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2774,6 +2859,12 @@ impl<'info> From<CancelOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<CancelOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: CancelOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CANCEL_ORDER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDER_IX_ACCOUNTS_LEN]>
     for CancelOrderAccounts<'me, 'info>
 {
@@ -2786,7 +2877,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDER_IX_ACCOUNTS_LEN]>
     }
 }
 pub const CANCEL_ORDER_IX_DISCM: [u8; 8] = [95, 129, 237, 240, 8, 49, 223, 132];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CancelOrderIxArgs {
     pub order_id: Option<u32>,
@@ -2804,13 +2897,10 @@ impl CancelOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CANCEL_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CANCEL_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CancelOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -2960,6 +3050,12 @@ impl From<CancelOrderByUserIdKeys> for [AccountMeta; CANCEL_ORDER_BY_USER_ID_IX_
         ]
     }
 }
+impl From<CancelOrderByUserIdKeys> for Vec<AccountMeta> {
+    fn from(keys: CancelOrderByUserIdKeys) -> Self {
+        let accounts: [AccountMeta; CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN]> for CancelOrderByUserIdKeys {
     fn from(pubkeys: [Pubkey; CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2980,6 +3076,13 @@ impl<'info> From<CancelOrderByUserIdAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<CancelOrderByUserIdAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: CancelOrderByUserIdAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN]>
     for CancelOrderByUserIdAccounts<'me, 'info>
 {
@@ -2992,7 +3095,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDER_BY_USER_ID_IX_ACCOU
     }
 }
 pub const CANCEL_ORDER_BY_USER_ID_IX_DISCM: [u8; 8] = [107, 211, 250, 133, 18, 37, 57, 100];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CancelOrderByUserIdIxArgs {
     pub user_order_id: u8,
@@ -3010,13 +3115,10 @@ impl CancelOrderByUserIdIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CANCEL_ORDER_BY_USER_ID_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_ORDER_BY_USER_ID_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CANCEL_ORDER_BY_USER_ID_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CancelOrderByUserIdIxArgs::deserialize(&mut reader)?))
     }
@@ -3166,6 +3268,12 @@ impl From<CancelOrdersKeys> for [AccountMeta; CANCEL_ORDERS_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<CancelOrdersKeys> for Vec<AccountMeta> {
+    fn from(keys: CancelOrdersKeys) -> Self {
+        let accounts: [AccountMeta; CANCEL_ORDERS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CANCEL_ORDERS_IX_ACCOUNTS_LEN]> for CancelOrdersKeys {
     fn from(pubkeys: [Pubkey; CANCEL_ORDERS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3186,6 +3294,12 @@ impl<'info> From<CancelOrdersAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<CancelOrdersAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: CancelOrdersAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CANCEL_ORDERS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDERS_IX_ACCOUNTS_LEN]>
     for CancelOrdersAccounts<'me, 'info>
 {
@@ -3198,7 +3312,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ORDERS_IX_ACCOUNTS_LEN]>
     }
 }
 pub const CANCEL_ORDERS_IX_DISCM: [u8; 8] = [238, 225, 95, 158, 227, 103, 8, 194];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CancelOrdersIxArgs {
     pub market_type: Option<MarketType>,
@@ -3218,13 +3334,10 @@ impl CancelOrdersIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CANCEL_ORDERS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_ORDERS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CANCEL_ORDERS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CancelOrdersIxArgs::deserialize(&mut reader)?))
     }
@@ -3374,6 +3487,12 @@ impl From<ModifyOrderKeys> for [AccountMeta; MODIFY_ORDER_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<ModifyOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: ModifyOrderKeys) -> Self {
+        let accounts: [AccountMeta; MODIFY_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; MODIFY_ORDER_IX_ACCOUNTS_LEN]> for ModifyOrderKeys {
     fn from(pubkeys: [Pubkey; MODIFY_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3394,6 +3513,12 @@ impl<'info> From<ModifyOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ModifyOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ModifyOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; MODIFY_ORDER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_ORDER_IX_ACCOUNTS_LEN]>
     for ModifyOrderAccounts<'me, 'info>
 {
@@ -3406,7 +3531,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_ORDER_IX_ACCOUNTS_LEN]>
     }
 }
 pub const MODIFY_ORDER_IX_DISCM: [u8; 8] = [47, 124, 117, 255, 201, 197, 130, 94];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModifyOrderIxArgs {
     pub order_id: Option<u32>,
@@ -3425,13 +3552,10 @@ impl ModifyOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != MODIFY_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    MODIFY_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                MODIFY_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ModifyOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -3581,6 +3705,12 @@ impl From<ModifyOrderByUserIdKeys> for [AccountMeta; MODIFY_ORDER_BY_USER_ID_IX_
         ]
     }
 }
+impl From<ModifyOrderByUserIdKeys> for Vec<AccountMeta> {
+    fn from(keys: ModifyOrderByUserIdKeys) -> Self {
+        let accounts: [AccountMeta; MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN]> for ModifyOrderByUserIdKeys {
     fn from(pubkeys: [Pubkey; MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3601,6 +3731,13 @@ impl<'info> From<ModifyOrderByUserIdAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ModifyOrderByUserIdAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ModifyOrderByUserIdAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_ORDER_BY_USER_ID_IX_ACCOUNTS_LEN]>
     for ModifyOrderByUserIdAccounts<'me, 'info>
 {
@@ -3613,7 +3750,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MODIFY_ORDER_BY_USER_ID_IX_ACCOU
     }
 }
 pub const MODIFY_ORDER_BY_USER_ID_IX_DISCM: [u8; 8] = [158, 77, 4, 253, 252, 194, 161, 179];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModifyOrderByUserIdIxArgs {
     pub user_order_id: u8,
@@ -3632,13 +3771,10 @@ impl ModifyOrderByUserIdIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != MODIFY_ORDER_BY_USER_ID_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    MODIFY_ORDER_BY_USER_ID_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                MODIFY_ORDER_BY_USER_ID_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ModifyOrderByUserIdIxArgs::deserialize(&mut reader)?))
     }
@@ -3796,6 +3932,12 @@ impl From<PlaceAndTakePerpOrderKeys> for [AccountMeta; PLACE_AND_TAKE_PERP_ORDER
         ]
     }
 }
+impl From<PlaceAndTakePerpOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: PlaceAndTakePerpOrderKeys) -> Self {
+        let accounts: [AccountMeta; PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN]> for PlaceAndTakePerpOrderKeys {
     fn from(pubkeys: [Pubkey; PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3818,6 +3960,13 @@ impl<'info> From<PlaceAndTakePerpOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<PlaceAndTakePerpOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: PlaceAndTakePerpOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_TAKE_PERP_ORDER_IX_ACCOUNTS_LEN]>
     for PlaceAndTakePerpOrderAccounts<'me, 'info>
 {
@@ -3831,7 +3980,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_TAKE_PERP_ORDER_IX_ACC
     }
 }
 pub const PLACE_AND_TAKE_PERP_ORDER_IX_DISCM: [u8; 8] = [213, 51, 1, 187, 108, 220, 230, 224];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlaceAndTakePerpOrderIxArgs {
     pub params: OrderParams,
@@ -3850,13 +4001,10 @@ impl PlaceAndTakePerpOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PLACE_AND_TAKE_PERP_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_AND_TAKE_PERP_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PLACE_AND_TAKE_PERP_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PlaceAndTakePerpOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -4031,6 +4179,12 @@ impl From<PlaceAndMakePerpOrderKeys> for [AccountMeta; PLACE_AND_MAKE_PERP_ORDER
         ]
     }
 }
+impl From<PlaceAndMakePerpOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: PlaceAndMakePerpOrderKeys) -> Self {
+        let accounts: [AccountMeta; PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN]> for PlaceAndMakePerpOrderKeys {
     fn from(pubkeys: [Pubkey; PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -4057,6 +4211,13 @@ impl<'info> From<PlaceAndMakePerpOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<PlaceAndMakePerpOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: PlaceAndMakePerpOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_MAKE_PERP_ORDER_IX_ACCOUNTS_LEN]>
     for PlaceAndMakePerpOrderAccounts<'me, 'info>
 {
@@ -4072,7 +4233,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_MAKE_PERP_ORDER_IX_ACC
     }
 }
 pub const PLACE_AND_MAKE_PERP_ORDER_IX_DISCM: [u8; 8] = [149, 117, 11, 237, 47, 95, 89, 237];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlaceAndMakePerpOrderIxArgs {
     pub params: OrderParams,
@@ -4091,13 +4254,10 @@ impl PlaceAndMakePerpOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PLACE_AND_MAKE_PERP_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_AND_MAKE_PERP_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PLACE_AND_MAKE_PERP_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PlaceAndMakePerpOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -4255,6 +4415,12 @@ impl From<PlaceSpotOrderKeys> for [AccountMeta; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN
         ]
     }
 }
+impl From<PlaceSpotOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: PlaceSpotOrderKeys) -> Self {
+        let accounts: [AccountMeta; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN]> for PlaceSpotOrderKeys {
     fn from(pubkeys: [Pubkey; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -4275,6 +4441,13 @@ impl<'info> From<PlaceSpotOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<PlaceSpotOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: PlaceSpotOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN]>
     for PlaceSpotOrderAccounts<'me, 'info>
 {
@@ -4287,7 +4460,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_SPOT_ORDER_IX_ACCOUNTS_LEN
     }
 }
 pub const PLACE_SPOT_ORDER_IX_DISCM: [u8; 8] = [45, 79, 81, 160, 248, 90, 91, 220];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlaceSpotOrderIxArgs {
     pub params: OrderParams,
@@ -4305,13 +4480,10 @@ impl PlaceSpotOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PLACE_SPOT_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_SPOT_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PLACE_SPOT_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PlaceSpotOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -4469,6 +4641,12 @@ impl From<PlaceAndTakeSpotOrderKeys> for [AccountMeta; PLACE_AND_TAKE_SPOT_ORDER
         ]
     }
 }
+impl From<PlaceAndTakeSpotOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: PlaceAndTakeSpotOrderKeys) -> Self {
+        let accounts: [AccountMeta; PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN]> for PlaceAndTakeSpotOrderKeys {
     fn from(pubkeys: [Pubkey; PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -4491,6 +4669,13 @@ impl<'info> From<PlaceAndTakeSpotOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<PlaceAndTakeSpotOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: PlaceAndTakeSpotOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_TAKE_SPOT_ORDER_IX_ACCOUNTS_LEN]>
     for PlaceAndTakeSpotOrderAccounts<'me, 'info>
 {
@@ -4504,7 +4689,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_TAKE_SPOT_ORDER_IX_ACC
     }
 }
 pub const PLACE_AND_TAKE_SPOT_ORDER_IX_DISCM: [u8; 8] = [191, 3, 138, 71, 114, 198, 202, 100];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlaceAndTakeSpotOrderIxArgs {
     pub params: OrderParams,
@@ -4524,13 +4711,10 @@ impl PlaceAndTakeSpotOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PLACE_AND_TAKE_SPOT_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_AND_TAKE_SPOT_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PLACE_AND_TAKE_SPOT_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PlaceAndTakeSpotOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -4705,6 +4889,12 @@ impl From<PlaceAndMakeSpotOrderKeys> for [AccountMeta; PLACE_AND_MAKE_SPOT_ORDER
         ]
     }
 }
+impl From<PlaceAndMakeSpotOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: PlaceAndMakeSpotOrderKeys) -> Self {
+        let accounts: [AccountMeta; PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN]> for PlaceAndMakeSpotOrderKeys {
     fn from(pubkeys: [Pubkey; PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -4731,6 +4921,13 @@ impl<'info> From<PlaceAndMakeSpotOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<PlaceAndMakeSpotOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: PlaceAndMakeSpotOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_MAKE_SPOT_ORDER_IX_ACCOUNTS_LEN]>
     for PlaceAndMakeSpotOrderAccounts<'me, 'info>
 {
@@ -4746,7 +4943,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_AND_MAKE_SPOT_ORDER_IX_ACC
     }
 }
 pub const PLACE_AND_MAKE_SPOT_ORDER_IX_DISCM: [u8; 8] = [149, 158, 85, 66, 239, 9, 243, 98];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlaceAndMakeSpotOrderIxArgs {
     pub params: OrderParams,
@@ -4766,13 +4965,10 @@ impl PlaceAndMakeSpotOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PLACE_AND_MAKE_SPOT_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_AND_MAKE_SPOT_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PLACE_AND_MAKE_SPOT_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PlaceAndMakeSpotOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -4900,6 +5096,7 @@ pub struct BeginSwapAccounts<'me, 'info> {
     pub in_token_account: &'me AccountInfo<'info>,
     pub token_program: &'me AccountInfo<'info>,
     pub drift_signer: &'me AccountInfo<'info>,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -4914,6 +5111,7 @@ pub struct BeginSwapKeys {
     pub in_token_account: Pubkey,
     pub token_program: Pubkey,
     pub drift_signer: Pubkey,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: Pubkey,
 }
 impl From<BeginSwapAccounts<'_, '_>> for BeginSwapKeys {
@@ -4994,6 +5192,12 @@ impl From<BeginSwapKeys> for [AccountMeta; BEGIN_SWAP_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<BeginSwapKeys> for Vec<AccountMeta> {
+    fn from(keys: BeginSwapKeys) -> Self {
+        let accounts: [AccountMeta; BEGIN_SWAP_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; BEGIN_SWAP_IX_ACCOUNTS_LEN]> for BeginSwapKeys {
     fn from(pubkeys: [Pubkey; BEGIN_SWAP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -5030,6 +5234,12 @@ impl<'info> From<BeginSwapAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<BeginSwapAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: BeginSwapAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; BEGIN_SWAP_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; BEGIN_SWAP_IX_ACCOUNTS_LEN]>
     for BeginSwapAccounts<'me, 'info>
 {
@@ -5050,7 +5260,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; BEGIN_SWAP_IX_ACCOUNTS_LEN]>
     }
 }
 pub const BEGIN_SWAP_IX_DISCM: [u8; 8] = [174, 109, 228, 1, 242, 105, 232, 105];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BeginSwapIxArgs {
     pub in_market_index: u16,
@@ -5070,13 +5282,10 @@ impl BeginSwapIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != BEGIN_SWAP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    BEGIN_SWAP_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                BEGIN_SWAP_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(BeginSwapIxArgs::deserialize(&mut reader)?))
     }
@@ -5214,6 +5423,7 @@ pub struct EndSwapAccounts<'me, 'info> {
     pub in_token_account: &'me AccountInfo<'info>,
     pub token_program: &'me AccountInfo<'info>,
     pub drift_signer: &'me AccountInfo<'info>,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -5228,6 +5438,7 @@ pub struct EndSwapKeys {
     pub in_token_account: Pubkey,
     pub token_program: Pubkey,
     pub drift_signer: Pubkey,
+    /// Instructions Sysvar for instruction introspection
     pub instructions: Pubkey,
 }
 impl From<EndSwapAccounts<'_, '_>> for EndSwapKeys {
@@ -5308,6 +5519,12 @@ impl From<EndSwapKeys> for [AccountMeta; END_SWAP_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<EndSwapKeys> for Vec<AccountMeta> {
+    fn from(keys: EndSwapKeys) -> Self {
+        let accounts: [AccountMeta; END_SWAP_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; END_SWAP_IX_ACCOUNTS_LEN]> for EndSwapKeys {
     fn from(pubkeys: [Pubkey; END_SWAP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -5342,6 +5559,12 @@ impl<'info> From<EndSwapAccounts<'_, 'info>> for [AccountInfo<'info>; END_SWAP_I
         ]
     }
 }
+impl<'info> From<EndSwapAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: EndSwapAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; END_SWAP_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; END_SWAP_IX_ACCOUNTS_LEN]>
     for EndSwapAccounts<'me, 'info>
 {
@@ -5362,7 +5585,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; END_SWAP_IX_ACCOUNTS_LEN]>
     }
 }
 pub const END_SWAP_IX_DISCM: [u8; 8] = [177, 184, 27, 193, 34, 13, 210, 145];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EndSwapIxArgs {
     pub in_market_index: u16,
@@ -5383,13 +5608,10 @@ impl EndSwapIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != END_SWAP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    END_SWAP_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                END_SWAP_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(EndSwapIxArgs::deserialize(&mut reader)?))
     }
@@ -5554,6 +5776,12 @@ impl From<AddPerpLpSharesKeys> for [AccountMeta; ADD_PERP_LP_SHARES_IX_ACCOUNTS_
         ]
     }
 }
+impl From<AddPerpLpSharesKeys> for Vec<AccountMeta> {
+    fn from(keys: AddPerpLpSharesKeys) -> Self {
+        let accounts: [AccountMeta; ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN]> for AddPerpLpSharesKeys {
     fn from(pubkeys: [Pubkey; ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -5574,6 +5802,13 @@ impl<'info> From<AddPerpLpSharesAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<AddPerpLpSharesAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: AddPerpLpSharesAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_PERP_LP_SHARES_IX_ACCOUNTS_LEN]>
     for AddPerpLpSharesAccounts<'me, 'info>
 {
@@ -5586,7 +5821,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_PERP_LP_SHARES_IX_ACCOUNTS_L
     }
 }
 pub const ADD_PERP_LP_SHARES_IX_DISCM: [u8; 8] = [56, 209, 56, 197, 119, 254, 188, 117];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddPerpLpSharesIxArgs {
     pub n_shares: u64,
@@ -5605,13 +5842,10 @@ impl AddPerpLpSharesIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_PERP_LP_SHARES_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_PERP_LP_SHARES_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ADD_PERP_LP_SHARES_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(AddPerpLpSharesIxArgs::deserialize(&mut reader)?))
     }
@@ -5761,6 +5995,12 @@ impl From<RemovePerpLpSharesKeys> for [AccountMeta; REMOVE_PERP_LP_SHARES_IX_ACC
         ]
     }
 }
+impl From<RemovePerpLpSharesKeys> for Vec<AccountMeta> {
+    fn from(keys: RemovePerpLpSharesKeys) -> Self {
+        let accounts: [AccountMeta; REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN]> for RemovePerpLpSharesKeys {
     fn from(pubkeys: [Pubkey; REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -5781,6 +6021,13 @@ impl<'info> From<RemovePerpLpSharesAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RemovePerpLpSharesAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RemovePerpLpSharesAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_PERP_LP_SHARES_IX_ACCOUNTS_LEN]>
     for RemovePerpLpSharesAccounts<'me, 'info>
 {
@@ -5793,7 +6040,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_PERP_LP_SHARES_IX_ACCOUNT
     }
 }
 pub const REMOVE_PERP_LP_SHARES_IX_DISCM: [u8; 8] = [213, 89, 217, 18, 160, 55, 53, 141];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemovePerpLpSharesIxArgs {
     pub shares_to_burn: u64,
@@ -5812,13 +6061,10 @@ impl RemovePerpLpSharesIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_PERP_LP_SHARES_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_PERP_LP_SHARES_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REMOVE_PERP_LP_SHARES_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RemovePerpLpSharesIxArgs::deserialize(&mut reader)?))
     }
@@ -5964,6 +6210,13 @@ impl From<RemovePerpLpSharesInExpiringMarketKeys>
         ]
     }
 }
+impl From<RemovePerpLpSharesInExpiringMarketKeys> for Vec<AccountMeta> {
+    fn from(keys: RemovePerpLpSharesInExpiringMarketKeys) -> Self {
+        let accounts: [AccountMeta; REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN]>
     for RemovePerpLpSharesInExpiringMarketKeys
 {
@@ -5981,6 +6234,15 @@ impl<'info> From<RemovePerpLpSharesInExpiringMarketAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.user.clone()]
     }
 }
+impl<'info> From<RemovePerpLpSharesInExpiringMarketAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: RemovePerpLpSharesInExpiringMarketAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_ACCOUNTS_LEN]>
     for RemovePerpLpSharesInExpiringMarketAccounts<'me, 'info>
@@ -5996,7 +6258,9 @@ impl<'me, 'info>
 }
 pub const REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_DISCM: [u8; 8] =
     [83, 254, 253, 137, 59, 122, 68, 156];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemovePerpLpSharesInExpiringMarketIxArgs {
     pub shares_to_burn: u64,
@@ -6015,13 +6279,10 @@ impl RemovePerpLpSharesInExpiringMarketIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REMOVE_PERP_LP_SHARES_IN_EXPIRING_MARKET_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RemovePerpLpSharesInExpiringMarketIxArgs::deserialize(
             &mut reader,
@@ -6159,6 +6420,12 @@ impl From<UpdateUserNameKeys> for [AccountMeta; UPDATE_USER_NAME_IX_ACCOUNTS_LEN
         ]
     }
 }
+impl From<UpdateUserNameKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateUserNameKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_USER_NAME_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_USER_NAME_IX_ACCOUNTS_LEN]> for UpdateUserNameKeys {
     fn from(pubkeys: [Pubkey; UPDATE_USER_NAME_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -6174,6 +6441,13 @@ impl<'info> From<UpdateUserNameAccounts<'_, 'info>>
         [accounts.user.clone(), accounts.authority.clone()]
     }
 }
+impl<'info> From<UpdateUserNameAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateUserNameAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_USER_NAME_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_NAME_IX_ACCOUNTS_LEN]>
     for UpdateUserNameAccounts<'me, 'info>
 {
@@ -6185,7 +6459,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_NAME_IX_ACCOUNTS_LEN
     }
 }
 pub const UPDATE_USER_NAME_IX_DISCM: [u8; 8] = [135, 25, 185, 56, 165, 53, 34, 136];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateUserNameIxArgs {
     pub sub_account_id: u16,
@@ -6204,13 +6480,10 @@ impl UpdateUserNameIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_USER_NAME_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_USER_NAME_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_USER_NAME_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateUserNameIxArgs::deserialize(&mut reader)?))
     }
@@ -6353,6 +6626,12 @@ impl From<UpdateUserCustomMarginRatioKeys>
         ]
     }
 }
+impl From<UpdateUserCustomMarginRatioKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateUserCustomMarginRatioKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN]>
     for UpdateUserCustomMarginRatioKeys
 {
@@ -6370,6 +6649,13 @@ impl<'info> From<UpdateUserCustomMarginRatioAccounts<'_, 'info>>
         [accounts.user.clone(), accounts.authority.clone()]
     }
 }
+impl<'info> From<UpdateUserCustomMarginRatioAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateUserCustomMarginRatioAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_ACCOUNTS_LEN]>
     for UpdateUserCustomMarginRatioAccounts<'me, 'info>
 {
@@ -6383,7 +6669,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_CUSTOM_MARGIN_RATIO_
     }
 }
 pub const UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_DISCM: [u8; 8] = [21, 221, 140, 187, 32, 129, 11, 123];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateUserCustomMarginRatioIxArgs {
     pub sub_account_id: u16,
@@ -6402,13 +6690,10 @@ impl UpdateUserCustomMarginRatioIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_USER_CUSTOM_MARGIN_RATIO_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateUserCustomMarginRatioIxArgs::deserialize(
             &mut reader,
@@ -6553,6 +6838,13 @@ impl From<UpdateUserMarginTradingEnabledKeys>
         ]
     }
 }
+impl From<UpdateUserMarginTradingEnabledKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateUserMarginTradingEnabledKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN]>
     for UpdateUserMarginTradingEnabledKeys
 {
@@ -6570,6 +6862,13 @@ impl<'info> From<UpdateUserMarginTradingEnabledAccounts<'_, 'info>>
         [accounts.user.clone(), accounts.authority.clone()]
     }
 }
+impl<'info> From<UpdateUserMarginTradingEnabledAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateUserMarginTradingEnabledAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_MARGIN_TRADING_ENABLED_IX_ACCOUNTS_LEN]>
     for UpdateUserMarginTradingEnabledAccounts<'me, 'info>
 {
@@ -6584,7 +6883,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_MARGIN_TRADING_ENABL
 }
 pub const UPDATE_USER_MARGIN_TRADING_ENABLED_IX_DISCM: [u8; 8] =
     [194, 92, 204, 223, 246, 188, 31, 203];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateUserMarginTradingEnabledIxArgs {
     pub sub_account_id: u16,
@@ -6603,13 +6904,10 @@ impl UpdateUserMarginTradingEnabledIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_USER_MARGIN_TRADING_ENABLED_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_USER_MARGIN_TRADING_ENABLED_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_USER_MARGIN_TRADING_ENABLED_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateUserMarginTradingEnabledIxArgs::deserialize(
             &mut reader,
@@ -6757,6 +7055,12 @@ impl From<UpdateUserDelegateKeys> for [AccountMeta; UPDATE_USER_DELEGATE_IX_ACCO
         ]
     }
 }
+impl From<UpdateUserDelegateKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateUserDelegateKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN]> for UpdateUserDelegateKeys {
     fn from(pubkeys: [Pubkey; UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -6772,6 +7076,13 @@ impl<'info> From<UpdateUserDelegateAccounts<'_, 'info>>
         [accounts.user.clone(), accounts.authority.clone()]
     }
 }
+impl<'info> From<UpdateUserDelegateAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateUserDelegateAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_DELEGATE_IX_ACCOUNTS_LEN]>
     for UpdateUserDelegateAccounts<'me, 'info>
 {
@@ -6783,7 +7094,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_DELEGATE_IX_ACCOUNTS
     }
 }
 pub const UPDATE_USER_DELEGATE_IX_DISCM: [u8; 8] = [139, 205, 141, 141, 113, 36, 94, 187];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateUserDelegateIxArgs {
     pub sub_account_id: u16,
@@ -6802,13 +7115,10 @@ impl UpdateUserDelegateIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_USER_DELEGATE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_USER_DELEGATE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_USER_DELEGATE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateUserDelegateIxArgs::deserialize(&mut reader)?))
     }
@@ -6965,6 +7275,12 @@ impl From<DeleteUserKeys> for [AccountMeta; DELETE_USER_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<DeleteUserKeys> for Vec<AccountMeta> {
+    fn from(keys: DeleteUserKeys) -> Self {
+        let accounts: [AccountMeta; DELETE_USER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DELETE_USER_IX_ACCOUNTS_LEN]> for DeleteUserKeys {
     fn from(pubkeys: [Pubkey; DELETE_USER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -6987,6 +7303,12 @@ impl<'info> From<DeleteUserAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<DeleteUserAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DeleteUserAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DELETE_USER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DELETE_USER_IX_ACCOUNTS_LEN]>
     for DeleteUserAccounts<'me, 'info>
 {
@@ -7008,13 +7330,10 @@ impl DeleteUserIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DELETE_USER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DELETE_USER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DELETE_USER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -7177,6 +7496,12 @@ impl From<FillPerpOrderKeys> for [AccountMeta; FILL_PERP_ORDER_IX_ACCOUNTS_LEN]
         ]
     }
 }
+impl From<FillPerpOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: FillPerpOrderKeys) -> Self {
+        let accounts: [AccountMeta; FILL_PERP_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; FILL_PERP_ORDER_IX_ACCOUNTS_LEN]> for FillPerpOrderKeys {
     fn from(pubkeys: [Pubkey; FILL_PERP_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -7203,6 +7528,12 @@ impl<'info> From<FillPerpOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<FillPerpOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: FillPerpOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; FILL_PERP_ORDER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; FILL_PERP_ORDER_IX_ACCOUNTS_LEN]>
     for FillPerpOrderAccounts<'me, 'info>
 {
@@ -7218,7 +7549,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; FILL_PERP_ORDER_IX_ACCOUNTS_LEN]
     }
 }
 pub const FILL_PERP_ORDER_IX_DISCM: [u8; 8] = [13, 188, 248, 103, 134, 217, 106, 240];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FillPerpOrderIxArgs {
     pub order_id: Option<u32>,
@@ -7237,13 +7570,10 @@ impl FillPerpOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != FILL_PERP_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FILL_PERP_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                FILL_PERP_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(FillPerpOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -7409,6 +7739,12 @@ impl From<RevertFillKeys> for [AccountMeta; REVERT_FILL_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<RevertFillKeys> for Vec<AccountMeta> {
+    fn from(keys: RevertFillKeys) -> Self {
+        let accounts: [AccountMeta; REVERT_FILL_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REVERT_FILL_IX_ACCOUNTS_LEN]> for RevertFillKeys {
     fn from(pubkeys: [Pubkey; REVERT_FILL_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -7431,6 +7767,12 @@ impl<'info> From<RevertFillAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RevertFillAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RevertFillAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REVERT_FILL_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REVERT_FILL_IX_ACCOUNTS_LEN]>
     for RevertFillAccounts<'me, 'info>
 {
@@ -7452,13 +7794,10 @@ impl RevertFillIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REVERT_FILL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REVERT_FILL_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REVERT_FILL_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -7621,6 +7960,12 @@ impl From<FillSpotOrderKeys> for [AccountMeta; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN]
         ]
     }
 }
+impl From<FillSpotOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: FillSpotOrderKeys) -> Self {
+        let accounts: [AccountMeta; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN]> for FillSpotOrderKeys {
     fn from(pubkeys: [Pubkey; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -7647,6 +7992,12 @@ impl<'info> From<FillSpotOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<FillSpotOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: FillSpotOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN]>
     for FillSpotOrderAccounts<'me, 'info>
 {
@@ -7662,7 +8013,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; FILL_SPOT_ORDER_IX_ACCOUNTS_LEN]
     }
 }
 pub const FILL_SPOT_ORDER_IX_DISCM: [u8; 8] = [212, 206, 130, 173, 21, 34, 199, 40];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FillSpotOrderIxArgs {
     pub order_id: Option<u32>,
@@ -7682,13 +8035,10 @@ impl FillSpotOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != FILL_SPOT_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FILL_SPOT_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                FILL_SPOT_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(FillSpotOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -7854,6 +8204,12 @@ impl From<TriggerOrderKeys> for [AccountMeta; TRIGGER_ORDER_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<TriggerOrderKeys> for Vec<AccountMeta> {
+    fn from(keys: TriggerOrderKeys) -> Self {
+        let accounts: [AccountMeta; TRIGGER_ORDER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; TRIGGER_ORDER_IX_ACCOUNTS_LEN]> for TriggerOrderKeys {
     fn from(pubkeys: [Pubkey; TRIGGER_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -7876,6 +8232,12 @@ impl<'info> From<TriggerOrderAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<TriggerOrderAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: TriggerOrderAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; TRIGGER_ORDER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; TRIGGER_ORDER_IX_ACCOUNTS_LEN]>
     for TriggerOrderAccounts<'me, 'info>
 {
@@ -7889,7 +8251,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; TRIGGER_ORDER_IX_ACCOUNTS_LEN]>
     }
 }
 pub const TRIGGER_ORDER_IX_DISCM: [u8; 8] = [63, 112, 51, 233, 232, 47, 240, 199];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TriggerOrderIxArgs {
     pub order_id: u32,
@@ -7907,13 +8271,10 @@ impl TriggerOrderIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != TRIGGER_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    TRIGGER_ORDER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                TRIGGER_ORDER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(TriggerOrderIxArgs::deserialize(&mut reader)?))
     }
@@ -8072,6 +8433,12 @@ impl From<ForceCancelOrdersKeys> for [AccountMeta; FORCE_CANCEL_ORDERS_IX_ACCOUN
         ]
     }
 }
+impl From<ForceCancelOrdersKeys> for Vec<AccountMeta> {
+    fn from(keys: ForceCancelOrdersKeys) -> Self {
+        let accounts: [AccountMeta; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN]> for ForceCancelOrdersKeys {
     fn from(pubkeys: [Pubkey; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -8094,6 +8461,13 @@ impl<'info> From<ForceCancelOrdersAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ForceCancelOrdersAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ForceCancelOrdersAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN]>
     for ForceCancelOrdersAccounts<'me, 'info>
 {
@@ -8115,13 +8489,10 @@ impl ForceCancelOrdersIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != FORCE_CANCEL_ORDERS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FORCE_CANCEL_ORDERS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                FORCE_CANCEL_ORDERS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -8268,6 +8639,12 @@ impl From<UpdateUserIdleKeys> for [AccountMeta; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN
         ]
     }
 }
+impl From<UpdateUserIdleKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateUserIdleKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN]> for UpdateUserIdleKeys {
     fn from(pubkeys: [Pubkey; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -8290,6 +8667,13 @@ impl<'info> From<UpdateUserIdleAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateUserIdleAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateUserIdleAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_IDLE_IX_ACCOUNTS_LEN]>
     for UpdateUserIdleAccounts<'me, 'info>
 {
@@ -8311,13 +8695,10 @@ impl UpdateUserIdleIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_USER_IDLE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_USER_IDLE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_USER_IDLE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -8466,6 +8847,12 @@ impl From<UpdateUserOpenOrdersCountKeys>
         ]
     }
 }
+impl From<UpdateUserOpenOrdersCountKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateUserOpenOrdersCountKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN]>
     for UpdateUserOpenOrdersCountKeys
 {
@@ -8490,6 +8877,13 @@ impl<'info> From<UpdateUserOpenOrdersCountAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateUserOpenOrdersCountAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateUserOpenOrdersCountAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_USER_OPEN_ORDERS_COUNT_IX_ACCOUNTS_LEN]>
     for UpdateUserOpenOrdersCountAccounts<'me, 'info>
 {
@@ -8511,13 +8905,10 @@ impl UpdateUserOpenOrdersCountIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_USER_OPEN_ORDERS_COUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_USER_OPEN_ORDERS_COUNT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_USER_OPEN_ORDERS_COUNT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -8668,6 +9059,12 @@ impl From<SettlePnlKeys> for [AccountMeta; SETTLE_PNL_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<SettlePnlKeys> for Vec<AccountMeta> {
+    fn from(keys: SettlePnlKeys) -> Self {
+        let accounts: [AccountMeta; SETTLE_PNL_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SETTLE_PNL_IX_ACCOUNTS_LEN]> for SettlePnlKeys {
     fn from(pubkeys: [Pubkey; SETTLE_PNL_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -8690,6 +9087,12 @@ impl<'info> From<SettlePnlAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<SettlePnlAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SettlePnlAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SETTLE_PNL_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_PNL_IX_ACCOUNTS_LEN]>
     for SettlePnlAccounts<'me, 'info>
 {
@@ -8703,7 +9106,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_PNL_IX_ACCOUNTS_LEN]>
     }
 }
 pub const SETTLE_PNL_IX_DISCM: [u8; 8] = [43, 61, 234, 45, 15, 95, 152, 153];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SettlePnlIxArgs {
     pub market_index: u16,
@@ -8721,13 +9126,10 @@ impl SettlePnlIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SETTLE_PNL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SETTLE_PNL_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SETTLE_PNL_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SettlePnlIxArgs::deserialize(&mut reader)?))
     }
@@ -8867,6 +9269,12 @@ impl From<SettleFundingPaymentKeys> for [AccountMeta; SETTLE_FUNDING_PAYMENT_IX_
         ]
     }
 }
+impl From<SettleFundingPaymentKeys> for Vec<AccountMeta> {
+    fn from(keys: SettleFundingPaymentKeys) -> Self {
+        let accounts: [AccountMeta; SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN]> for SettleFundingPaymentKeys {
     fn from(pubkeys: [Pubkey; SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -8882,6 +9290,13 @@ impl<'info> From<SettleFundingPaymentAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.user.clone()]
     }
 }
+impl<'info> From<SettleFundingPaymentAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SettleFundingPaymentAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_FUNDING_PAYMENT_IX_ACCOUNTS_LEN]>
     for SettleFundingPaymentAccounts<'me, 'info>
 {
@@ -8901,13 +9316,10 @@ impl SettleFundingPaymentIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SETTLE_FUNDING_PAYMENT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SETTLE_FUNDING_PAYMENT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SETTLE_FUNDING_PAYMENT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -9027,6 +9439,12 @@ impl From<SettleLpKeys> for [AccountMeta; SETTLE_LP_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<SettleLpKeys> for Vec<AccountMeta> {
+    fn from(keys: SettleLpKeys) -> Self {
+        let accounts: [AccountMeta; SETTLE_LP_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SETTLE_LP_IX_ACCOUNTS_LEN]> for SettleLpKeys {
     fn from(pubkeys: [Pubkey; SETTLE_LP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -9040,6 +9458,12 @@ impl<'info> From<SettleLpAccounts<'_, 'info>> for [AccountInfo<'info>; SETTLE_LP
         [accounts.state.clone(), accounts.user.clone()]
     }
 }
+impl<'info> From<SettleLpAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SettleLpAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SETTLE_LP_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_LP_IX_ACCOUNTS_LEN]>
     for SettleLpAccounts<'me, 'info>
 {
@@ -9051,7 +9475,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_LP_IX_ACCOUNTS_LEN]>
     }
 }
 pub const SETTLE_LP_IX_DISCM: [u8; 8] = [155, 231, 116, 113, 97, 229, 139, 141];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SettleLpIxArgs {
     pub market_index: u16,
@@ -9069,13 +9495,10 @@ impl SettleLpIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SETTLE_LP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SETTLE_LP_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SETTLE_LP_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SettleLpIxArgs::deserialize(&mut reader)?))
     }
@@ -9199,6 +9622,12 @@ impl From<SettleExpiredMarketKeys> for [AccountMeta; SETTLE_EXPIRED_MARKET_IX_AC
         ]
     }
 }
+impl From<SettleExpiredMarketKeys> for Vec<AccountMeta> {
+    fn from(keys: SettleExpiredMarketKeys) -> Self {
+        let accounts: [AccountMeta; SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN]> for SettleExpiredMarketKeys {
     fn from(pubkeys: [Pubkey; SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -9214,6 +9643,13 @@ impl<'info> From<SettleExpiredMarketAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.authority.clone()]
     }
 }
+impl<'info> From<SettleExpiredMarketAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SettleExpiredMarketAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_EXPIRED_MARKET_IX_ACCOUNTS_LEN]>
     for SettleExpiredMarketAccounts<'me, 'info>
 {
@@ -9225,7 +9661,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_EXPIRED_MARKET_IX_ACCOUNT
     }
 }
 pub const SETTLE_EXPIRED_MARKET_IX_DISCM: [u8; 8] = [120, 89, 11, 25, 122, 77, 72, 193];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SettleExpiredMarketIxArgs {
     pub market_index: u16,
@@ -9243,13 +9681,10 @@ impl SettleExpiredMarketIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SETTLE_EXPIRED_MARKET_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SETTLE_EXPIRED_MARKET_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SETTLE_EXPIRED_MARKET_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SettleExpiredMarketIxArgs::deserialize(&mut reader)?))
     }
@@ -9411,6 +9846,12 @@ impl From<LiquidatePerpKeys> for [AccountMeta; LIQUIDATE_PERP_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<LiquidatePerpKeys> for Vec<AccountMeta> {
+    fn from(keys: LiquidatePerpKeys) -> Self {
+        let accounts: [AccountMeta; LIQUIDATE_PERP_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; LIQUIDATE_PERP_IX_ACCOUNTS_LEN]> for LiquidatePerpKeys {
     fn from(pubkeys: [Pubkey; LIQUIDATE_PERP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -9437,6 +9878,12 @@ impl<'info> From<LiquidatePerpAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<LiquidatePerpAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: LiquidatePerpAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; LIQUIDATE_PERP_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_PERP_IX_ACCOUNTS_LEN]>
     for LiquidatePerpAccounts<'me, 'info>
 {
@@ -9452,7 +9899,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_PERP_IX_ACCOUNTS_LEN]>
     }
 }
 pub const LIQUIDATE_PERP_IX_DISCM: [u8; 8] = [75, 35, 119, 247, 191, 18, 139, 2];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidatePerpIxArgs {
     pub market_index: u16,
@@ -9472,13 +9921,10 @@ impl LiquidatePerpIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != LIQUIDATE_PERP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LIQUIDATE_PERP_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LIQUIDATE_PERP_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(LiquidatePerpIxArgs::deserialize(&mut reader)?))
     }
@@ -9660,6 +10106,12 @@ impl From<LiquidateSpotKeys> for [AccountMeta; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<LiquidateSpotKeys> for Vec<AccountMeta> {
+    fn from(keys: LiquidateSpotKeys) -> Self {
+        let accounts: [AccountMeta; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN]> for LiquidateSpotKeys {
     fn from(pubkeys: [Pubkey; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -9686,6 +10138,12 @@ impl<'info> From<LiquidateSpotAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<LiquidateSpotAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: LiquidateSpotAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN]>
     for LiquidateSpotAccounts<'me, 'info>
 {
@@ -9701,7 +10159,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_SPOT_IX_ACCOUNTS_LEN]>
     }
 }
 pub const LIQUIDATE_SPOT_IX_DISCM: [u8; 8] = [107, 0, 128, 41, 35, 229, 251, 18];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateSpotIxArgs {
     pub asset_market_index: u16,
@@ -9722,13 +10182,10 @@ impl LiquidateSpotIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != LIQUIDATE_SPOT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LIQUIDATE_SPOT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LIQUIDATE_SPOT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(LiquidateSpotIxArgs::deserialize(&mut reader)?))
     }
@@ -9912,6 +10369,12 @@ impl From<LiquidateBorrowForPerpPnlKeys>
         ]
     }
 }
+impl From<LiquidateBorrowForPerpPnlKeys> for Vec<AccountMeta> {
+    fn from(keys: LiquidateBorrowForPerpPnlKeys) -> Self {
+        let accounts: [AccountMeta; LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN]>
     for LiquidateBorrowForPerpPnlKeys
 {
@@ -9940,6 +10403,13 @@ impl<'info> From<LiquidateBorrowForPerpPnlAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<LiquidateBorrowForPerpPnlAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: LiquidateBorrowForPerpPnlAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_BORROW_FOR_PERP_PNL_IX_ACCOUNTS_LEN]>
     for LiquidateBorrowForPerpPnlAccounts<'me, 'info>
 {
@@ -9955,7 +10425,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_BORROW_FOR_PERP_PNL_IX
     }
 }
 pub const LIQUIDATE_BORROW_FOR_PERP_PNL_IX_DISCM: [u8; 8] = [169, 17, 32, 90, 207, 148, 209, 27];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateBorrowForPerpPnlIxArgs {
     pub perp_market_index: u16,
@@ -9976,13 +10448,10 @@ impl LiquidateBorrowForPerpPnlIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != LIQUIDATE_BORROW_FOR_PERP_PNL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LIQUIDATE_BORROW_FOR_PERP_PNL_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LIQUIDATE_BORROW_FOR_PERP_PNL_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(LiquidateBorrowForPerpPnlIxArgs::deserialize(
             &mut reader,
@@ -10168,6 +10637,12 @@ impl From<LiquidatePerpPnlForDepositKeys>
         ]
     }
 }
+impl From<LiquidatePerpPnlForDepositKeys> for Vec<AccountMeta> {
+    fn from(keys: LiquidatePerpPnlForDepositKeys) -> Self {
+        let accounts: [AccountMeta; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN]>
     for LiquidatePerpPnlForDepositKeys
 {
@@ -10196,6 +10671,13 @@ impl<'info> From<LiquidatePerpPnlForDepositAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<LiquidatePerpPnlForDepositAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: LiquidatePerpPnlForDepositAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_ACCOUNTS_LEN]>
     for LiquidatePerpPnlForDepositAccounts<'me, 'info>
 {
@@ -10213,7 +10695,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUIDATE_PERP_PNL_FOR_DEPOSIT_I
     }
 }
 pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_DISCM: [u8; 8] = [237, 75, 198, 235, 233, 186, 75, 35];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidatePerpPnlForDepositIxArgs {
     pub perp_market_index: u16,
@@ -10234,13 +10718,10 @@ impl LiquidatePerpPnlForDepositIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LIQUIDATE_PERP_PNL_FOR_DEPOSIT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(LiquidatePerpPnlForDepositIxArgs::deserialize(
             &mut reader,
@@ -10424,7 +10905,13 @@ impl From<ResolvePerpPnlDeficitKeys> for [AccountMeta; RESOLVE_PERP_PNL_DEFICIT_
         ]
     }
 }
-impl From<[Pubkey; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN]> for ResolvePerpPnlDeficitKeys {
+impl From<ResolvePerpPnlDeficitKeys> for Vec<AccountMeta> {
+    fn from(keys: ResolvePerpPnlDeficitKeys) -> Self {
+        let accounts: [AccountMeta; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN]> for ResolvePerpPnlDeficitKeys {
     fn from(pubkeys: [Pubkey; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: pubkeys[0],
@@ -10450,6 +10937,13 @@ impl<'info> From<ResolvePerpPnlDeficitAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ResolvePerpPnlDeficitAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ResolvePerpPnlDeficitAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_PERP_PNL_DEFICIT_IX_ACCOUNTS_LEN]>
     for ResolvePerpPnlDeficitAccounts<'me, 'info>
 {
@@ -10465,7 +10959,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_PERP_PNL_DEFICIT_IX_ACCO
     }
 }
 pub const RESOLVE_PERP_PNL_DEFICIT_IX_DISCM: [u8; 8] = [168, 204, 68, 150, 159, 126, 95, 148];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResolvePerpPnlDeficitIxArgs {
     pub spot_market_index: u16,
@@ -10484,13 +10980,10 @@ impl ResolvePerpPnlDeficitIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != RESOLVE_PERP_PNL_DEFICIT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    RESOLVE_PERP_PNL_DEFICIT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                RESOLVE_PERP_PNL_DEFICIT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ResolvePerpPnlDeficitIxArgs::deserialize(&mut reader)?))
     }
@@ -10702,6 +11195,12 @@ impl From<ResolvePerpBankruptcyKeys> for [AccountMeta; RESOLVE_PERP_BANKRUPTCY_I
         ]
     }
 }
+impl From<ResolvePerpBankruptcyKeys> for Vec<AccountMeta> {
+    fn from(keys: ResolvePerpBankruptcyKeys) -> Self {
+        let accounts: [AccountMeta; RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN]> for ResolvePerpBankruptcyKeys {
     fn from(pubkeys: [Pubkey; RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -10736,6 +11235,13 @@ impl<'info> From<ResolvePerpBankruptcyAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ResolvePerpBankruptcyAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ResolvePerpBankruptcyAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_PERP_BANKRUPTCY_IX_ACCOUNTS_LEN]>
     for ResolvePerpBankruptcyAccounts<'me, 'info>
 {
@@ -10755,7 +11261,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_PERP_BANKRUPTCY_IX_ACCOU
     }
 }
 pub const RESOLVE_PERP_BANKRUPTCY_IX_DISCM: [u8; 8] = [224, 16, 176, 214, 162, 213, 183, 222];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResolvePerpBankruptcyIxArgs {
     pub quote_spot_market_index: u16,
@@ -10774,13 +11282,10 @@ impl ResolvePerpBankruptcyIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != RESOLVE_PERP_BANKRUPTCY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    RESOLVE_PERP_BANKRUPTCY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                RESOLVE_PERP_BANKRUPTCY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ResolvePerpBankruptcyIxArgs::deserialize(&mut reader)?))
     }
@@ -11003,6 +11508,12 @@ impl From<ResolveSpotBankruptcyKeys> for [AccountMeta; RESOLVE_SPOT_BANKRUPTCY_I
         ]
     }
 }
+impl From<ResolveSpotBankruptcyKeys> for Vec<AccountMeta> {
+    fn from(keys: ResolveSpotBankruptcyKeys) -> Self {
+        let accounts: [AccountMeta; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN]> for ResolveSpotBankruptcyKeys {
     fn from(pubkeys: [Pubkey; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -11037,6 +11548,13 @@ impl<'info> From<ResolveSpotBankruptcyAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ResolveSpotBankruptcyAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ResolveSpotBankruptcyAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOUNTS_LEN]>
     for ResolveSpotBankruptcyAccounts<'me, 'info>
 {
@@ -11056,7 +11574,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESOLVE_SPOT_BANKRUPTCY_IX_ACCOU
     }
 }
 pub const RESOLVE_SPOT_BANKRUPTCY_IX_DISCM: [u8; 8] = [124, 194, 240, 254, 198, 213, 52, 122];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResolveSpotBankruptcyIxArgs {
     pub market_index: u16,
@@ -11074,13 +11594,10 @@ impl ResolveSpotBankruptcyIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != RESOLVE_SPOT_BANKRUPTCY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    RESOLVE_SPOT_BANKRUPTCY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                RESOLVE_SPOT_BANKRUPTCY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ResolveSpotBankruptcyIxArgs::deserialize(&mut reader)?))
     }
@@ -11273,6 +11790,12 @@ impl From<SettleRevenueToInsuranceFundKeys>
         ]
     }
 }
+impl From<SettleRevenueToInsuranceFundKeys> for Vec<AccountMeta> {
+    fn from(keys: SettleRevenueToInsuranceFundKeys) -> Self {
+        let accounts: [AccountMeta; SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN]>
     for SettleRevenueToInsuranceFundKeys
 {
@@ -11301,6 +11824,13 @@ impl<'info> From<SettleRevenueToInsuranceFundAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<SettleRevenueToInsuranceFundAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SettleRevenueToInsuranceFundAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_REVENUE_TO_INSURANCE_FUND_IX_ACCOUNTS_LEN]>
     for SettleRevenueToInsuranceFundAccounts<'me, 'info>
 {
@@ -11319,7 +11849,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SETTLE_REVENUE_TO_INSURANCE_FUND
 }
 pub const SETTLE_REVENUE_TO_INSURANCE_FUND_IX_DISCM: [u8; 8] =
     [200, 120, 93, 136, 69, 38, 199, 159];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SettleRevenueToInsuranceFundIxArgs {
     pub spot_market_index: u16,
@@ -11337,13 +11869,10 @@ impl SettleRevenueToInsuranceFundIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SETTLE_REVENUE_TO_INSURANCE_FUND_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SETTLE_REVENUE_TO_INSURANCE_FUND_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SETTLE_REVENUE_TO_INSURANCE_FUND_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SettleRevenueToInsuranceFundIxArgs::deserialize(
             &mut reader,
@@ -11494,6 +12023,12 @@ impl From<UpdateFundingRateKeys> for [AccountMeta; UPDATE_FUNDING_RATE_IX_ACCOUN
         ]
     }
 }
+impl From<UpdateFundingRateKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateFundingRateKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN]> for UpdateFundingRateKeys {
     fn from(pubkeys: [Pubkey; UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -11514,6 +12049,13 @@ impl<'info> From<UpdateFundingRateAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateFundingRateAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateFundingRateAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_FUNDING_RATE_IX_ACCOUNTS_LEN]>
     for UpdateFundingRateAccounts<'me, 'info>
 {
@@ -11526,7 +12068,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_FUNDING_RATE_IX_ACCOUNTS_
     }
 }
 pub const UPDATE_FUNDING_RATE_IX_DISCM: [u8; 8] = [201, 178, 116, 212, 166, 144, 72, 238];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateFundingRateIxArgs {
     pub market_index: u16,
@@ -11544,13 +12088,10 @@ impl UpdateFundingRateIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_FUNDING_RATE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_FUNDING_RATE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_FUNDING_RATE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateFundingRateIxArgs::deserialize(&mut reader)?))
     }
@@ -11693,6 +12234,13 @@ impl From<UpdateSpotMarketCumulativeInterestKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketCumulativeInterestKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketCumulativeInterestKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketCumulativeInterestKeys
 {
@@ -11715,6 +12263,15 @@ impl<'info> From<UpdateSpotMarketCumulativeInterestAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketCumulativeInterestAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdateSpotMarketCumulativeInterestAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketCumulativeInterestAccounts<'me, 'info>
@@ -11739,13 +12296,10 @@ impl UpdateSpotMarketCumulativeInterestIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_CUMULATIVE_INTEREST_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -11868,6 +12422,12 @@ impl From<UpdateAmmsKeys> for [AccountMeta; UPDATE_AMMS_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<UpdateAmmsKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateAmmsKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_AMMS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_AMMS_IX_ACCOUNTS_LEN]> for UpdateAmmsKeys {
     fn from(pubkeys: [Pubkey; UPDATE_AMMS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -11883,6 +12443,12 @@ impl<'info> From<UpdateAmmsAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.authority.clone()]
     }
 }
+impl<'info> From<UpdateAmmsAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateAmmsAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_AMMS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_AMMS_IX_ACCOUNTS_LEN]>
     for UpdateAmmsAccounts<'me, 'info>
 {
@@ -11894,7 +12460,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_AMMS_IX_ACCOUNTS_LEN]>
     }
 }
 pub const UPDATE_AMMS_IX_DISCM: [u8; 8] = [201, 106, 217, 253, 4, 175, 228, 97];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateAmmsIxArgs {
     pub market_indexes: [u16; 5],
@@ -11912,13 +12480,10 @@ impl UpdateAmmsIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_AMMS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_AMMS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_AMMS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateAmmsIxArgs::deserialize(&mut reader)?))
     }
@@ -12056,6 +12621,12 @@ impl From<UpdateSpotMarketExpiryKeys> for [AccountMeta; UPDATE_SPOT_MARKET_EXPIR
         ]
     }
 }
+impl From<UpdateSpotMarketExpiryKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketExpiryKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN]> for UpdateSpotMarketExpiryKeys {
     fn from(pubkeys: [Pubkey; UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -12076,6 +12647,13 @@ impl<'info> From<UpdateSpotMarketExpiryAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketExpiryAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketExpiryAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_EXPIRY_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketExpiryAccounts<'me, 'info>
 {
@@ -12088,7 +12666,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_EXPIRY_IX_ACC
     }
 }
 pub const UPDATE_SPOT_MARKET_EXPIRY_IX_DISCM: [u8; 8] = [208, 11, 211, 159, 226, 24, 11, 247];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketExpiryIxArgs {
     pub expiry_ts: i64,
@@ -12106,13 +12686,10 @@ impl UpdateSpotMarketExpiryIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_EXPIRY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_EXPIRY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_EXPIRY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketExpiryIxArgs::deserialize(
             &mut reader,
@@ -12292,6 +12869,13 @@ impl From<UpdateUserQuoteAssetInsuranceStakeKeys>
         ]
     }
 }
+impl From<UpdateUserQuoteAssetInsuranceStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateUserQuoteAssetInsuranceStakeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN]>
     for UpdateUserQuoteAssetInsuranceStakeKeys
 {
@@ -12320,6 +12904,15 @@ impl<'info> From<UpdateUserQuoteAssetInsuranceStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateUserQuoteAssetInsuranceStakeAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdateUserQuoteAssetInsuranceStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_ACCOUNTS_LEN]>
     for UpdateUserQuoteAssetInsuranceStakeAccounts<'me, 'info>
@@ -12347,13 +12940,10 @@ impl UpdateUserQuoteAssetInsuranceStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_USER_QUOTE_ASSET_INSURANCE_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -12554,6 +13144,12 @@ impl From<InitializeInsuranceFundStakeKeys>
         ]
     }
 }
+impl From<InitializeInsuranceFundStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeInsuranceFundStakeKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for InitializeInsuranceFundStakeKeys
 {
@@ -12586,6 +13182,13 @@ impl<'info> From<InitializeInsuranceFundStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeInsuranceFundStakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeInsuranceFundStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for InitializeInsuranceFundStakeAccounts<'me, 'info>
 {
@@ -12605,7 +13208,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_INSURANCE_FUND_STAKE_
     }
 }
 pub const INITIALIZE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] = [187, 179, 243, 70, 248, 90, 92, 147];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeInsuranceFundStakeIxArgs {
     pub market_index: u16,
@@ -12623,13 +13228,10 @@ impl InitializeInsuranceFundStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_INSURANCE_FUND_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializeInsuranceFundStakeIxArgs::deserialize(
             &mut reader,
@@ -12849,6 +13451,12 @@ impl From<AddInsuranceFundStakeKeys> for [AccountMeta; ADD_INSURANCE_FUND_STAKE_
         ]
     }
 }
+impl From<AddInsuranceFundStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: AddInsuranceFundStakeKeys) -> Self {
+        let accounts: [AccountMeta; ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]> for AddInsuranceFundStakeKeys {
     fn from(pubkeys: [Pubkey; ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -12883,6 +13491,13 @@ impl<'info> From<AddInsuranceFundStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<AddInsuranceFundStakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: AddInsuranceFundStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for AddInsuranceFundStakeAccounts<'me, 'info>
 {
@@ -12902,7 +13517,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_INSURANCE_FUND_STAKE_IX_ACCO
     }
 }
 pub const ADD_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] = [251, 144, 115, 11, 222, 47, 62, 236];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddInsuranceFundStakeIxArgs {
     pub market_index: u16,
@@ -12921,13 +13538,10 @@ impl AddInsuranceFundStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_INSURANCE_FUND_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ADD_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(AddInsuranceFundStakeIxArgs::deserialize(&mut reader)?))
     }
@@ -13114,6 +13728,13 @@ impl From<RequestRemoveInsuranceFundStakeKeys>
         ]
     }
 }
+impl From<RequestRemoveInsuranceFundStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: RequestRemoveInsuranceFundStakeKeys) -> Self {
+        let accounts: [AccountMeta; REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for RequestRemoveInsuranceFundStakeKeys
 {
@@ -13140,6 +13761,13 @@ impl<'info> From<RequestRemoveInsuranceFundStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RequestRemoveInsuranceFundStakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RequestRemoveInsuranceFundStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for RequestRemoveInsuranceFundStakeAccounts<'me, 'info>
@@ -13158,7 +13786,9 @@ impl<'me, 'info>
 }
 pub const REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] =
     [142, 70, 204, 92, 73, 106, 180, 52];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RequestRemoveInsuranceFundStakeIxArgs {
     pub market_index: u16,
@@ -13177,13 +13807,10 @@ impl RequestRemoveInsuranceFundStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RequestRemoveInsuranceFundStakeIxArgs::deserialize(
             &mut reader,
@@ -13372,6 +13999,13 @@ impl From<CancelRequestRemoveInsuranceFundStakeKeys>
         ]
     }
 }
+impl From<CancelRequestRemoveInsuranceFundStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: CancelRequestRemoveInsuranceFundStakeKeys) -> Self {
+        let accounts: [AccountMeta; CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for CancelRequestRemoveInsuranceFundStakeKeys
 {
@@ -13398,6 +14032,15 @@ impl<'info> From<CancelRequestRemoveInsuranceFundStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<CancelRequestRemoveInsuranceFundStakeAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: CancelRequestRemoveInsuranceFundStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for CancelRequestRemoveInsuranceFundStakeAccounts<'me, 'info>
@@ -13416,7 +14059,9 @@ impl<'me, 'info>
 }
 pub const CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] =
     [97, 235, 78, 62, 212, 42, 241, 127];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CancelRequestRemoveInsuranceFundStakeIxArgs {
     pub market_index: u16,
@@ -13438,13 +14083,10 @@ impl CancelRequestRemoveInsuranceFundStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CANCEL_REQUEST_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             CancelRequestRemoveInsuranceFundStakeIxArgs::deserialize(&mut reader)?,
@@ -13664,6 +14306,12 @@ impl From<RemoveInsuranceFundStakeKeys>
         ]
     }
 }
+impl From<RemoveInsuranceFundStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: RemoveInsuranceFundStakeKeys) -> Self {
+        let accounts: [AccountMeta; REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]> for RemoveInsuranceFundStakeKeys {
     fn from(pubkeys: [Pubkey; REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -13696,6 +14344,13 @@ impl<'info> From<RemoveInsuranceFundStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RemoveInsuranceFundStakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RemoveInsuranceFundStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for RemoveInsuranceFundStakeAccounts<'me, 'info>
 {
@@ -13714,7 +14369,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_INSURANCE_FUND_STAKE_IX_A
     }
 }
 pub const REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] = [128, 166, 142, 9, 254, 187, 143, 174];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveInsuranceFundStakeIxArgs {
     pub market_index: u16,
@@ -13732,13 +14389,10 @@ impl RemoveInsuranceFundStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_INSURANCE_FUND_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RemoveInsuranceFundStakeIxArgs::deserialize(
             &mut reader,
@@ -13939,6 +14593,12 @@ impl From<InitializeKeys> for [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<InitializeKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -13967,6 +14627,12 @@ impl<'info> From<InitializeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
     for InitializeAccounts<'me, 'info>
 {
@@ -13991,13 +14657,10 @@ impl InitializeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -14203,6 +14866,12 @@ impl From<InitializeSpotMarketKeys> for [AccountMeta; INITIALIZE_SPOT_MARKET_IX_
         ]
     }
 }
+impl From<InitializeSpotMarketKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeSpotMarketKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN]> for InitializeSpotMarketKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -14239,6 +14908,13 @@ impl<'info> From<InitializeSpotMarketAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeSpotMarketAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeSpotMarketAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_SPOT_MARKET_IX_ACCOUNTS_LEN]>
     for InitializeSpotMarketAccounts<'me, 'info>
 {
@@ -14259,7 +14935,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_SPOT_MARKET_IX_ACCOUN
     }
 }
 pub const INITIALIZE_SPOT_MARKET_IX_DISCM: [u8; 8] = [234, 196, 128, 44, 94, 15, 48, 201];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeSpotMarketIxArgs {
     pub optimal_utilization: u32,
@@ -14288,13 +14966,10 @@ impl InitializeSpotMarketIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_SPOT_MARKET_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_SPOT_MARKET_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_SPOT_MARKET_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializeSpotMarketIxArgs::deserialize(&mut reader)?))
     }
@@ -14529,6 +15204,13 @@ impl From<InitializeSerumFulfillmentConfigKeys>
         ]
     }
 }
+impl From<InitializeSerumFulfillmentConfigKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeSerumFulfillmentConfigKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN]>
     for InitializeSerumFulfillmentConfigKeys
 {
@@ -14567,6 +15249,13 @@ impl<'info> From<InitializeSerumFulfillmentConfigAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeSerumFulfillmentConfigAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeSerumFulfillmentConfigAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN]>
     for InitializeSerumFulfillmentConfigAccounts<'me, 'info>
@@ -14591,7 +15280,9 @@ impl<'me, 'info>
 }
 pub const INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_DISCM: [u8; 8] =
     [193, 211, 132, 172, 70, 171, 7, 94];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeSerumFulfillmentConfigIxArgs {
     pub market_index: u16,
@@ -14609,13 +15300,10 @@ impl InitializeSerumFulfillmentConfigIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_SERUM_FULFILLMENT_CONFIG_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializeSerumFulfillmentConfigIxArgs::deserialize(
             &mut reader,
@@ -14792,6 +15480,13 @@ impl From<UpdateSerumFulfillmentConfigStatusKeys>
         ]
     }
 }
+impl From<UpdateSerumFulfillmentConfigStatusKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSerumFulfillmentConfigStatusKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN]>
     for UpdateSerumFulfillmentConfigStatusKeys
 {
@@ -14814,6 +15509,15 @@ impl<'info> From<UpdateSerumFulfillmentConfigStatusAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSerumFulfillmentConfigStatusAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdateSerumFulfillmentConfigStatusAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN]>
     for UpdateSerumFulfillmentConfigStatusAccounts<'me, 'info>
@@ -14830,7 +15534,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_DISCM: [u8; 8] =
     [171, 109, 240, 251, 95, 1, 149, 89];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSerumFulfillmentConfigStatusIxArgs {
     pub status: SpotFulfillmentConfigStatus,
@@ -14848,13 +15554,10 @@ impl UpdateSerumFulfillmentConfigStatusIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SERUM_FULFILLMENT_CONFIG_STATUS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSerumFulfillmentConfigStatusIxArgs::deserialize(
             &mut reader,
@@ -15074,6 +15777,13 @@ impl From<InitializePhoenixFulfillmentConfigKeys>
         ]
     }
 }
+impl From<InitializePhoenixFulfillmentConfigKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializePhoenixFulfillmentConfigKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN]>
     for InitializePhoenixFulfillmentConfigKeys
 {
@@ -15110,6 +15820,15 @@ impl<'info> From<InitializePhoenixFulfillmentConfigAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializePhoenixFulfillmentConfigAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: InitializePhoenixFulfillmentConfigAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_ACCOUNTS_LEN]>
     for InitializePhoenixFulfillmentConfigAccounts<'me, 'info>
@@ -15133,7 +15852,9 @@ impl<'me, 'info>
 }
 pub const INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_DISCM: [u8; 8] =
     [135, 132, 110, 107, 185, 160, 169, 154];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializePhoenixFulfillmentConfigIxArgs {
     pub market_index: u16,
@@ -15151,13 +15872,10 @@ impl InitializePhoenixFulfillmentConfigIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_PHOENIX_FULFILLMENT_CONFIG_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializePhoenixFulfillmentConfigIxArgs::deserialize(
             &mut reader,
@@ -15330,6 +16048,13 @@ impl From<PhoenixFulfillmentConfigStatusKeys>
         ]
     }
 }
+impl From<PhoenixFulfillmentConfigStatusKeys> for Vec<AccountMeta> {
+    fn from(keys: PhoenixFulfillmentConfigStatusKeys) -> Self {
+        let accounts: [AccountMeta; PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN]>
     for PhoenixFulfillmentConfigStatusKeys
 {
@@ -15352,6 +16077,13 @@ impl<'info> From<PhoenixFulfillmentConfigStatusAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<PhoenixFulfillmentConfigStatusAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: PhoenixFulfillmentConfigStatusAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_ACCOUNTS_LEN]>
     for PhoenixFulfillmentConfigStatusAccounts<'me, 'info>
 {
@@ -15366,7 +16098,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PHOENIX_FULFILLMENT_CONFIG_STATU
     }
 }
 pub const PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_DISCM: [u8; 8] = [96, 31, 113, 32, 12, 203, 7, 154];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PhoenixFulfillmentConfigStatusIxArgs {
     pub status: SpotFulfillmentConfigStatus,
@@ -15384,13 +16118,10 @@ impl PhoenixFulfillmentConfigStatusIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PHOENIX_FULFILLMENT_CONFIG_STATUS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(PhoenixFulfillmentConfigStatusIxArgs::deserialize(
             &mut reader,
@@ -15550,6 +16281,12 @@ impl From<UpdateSerumVaultKeys> for [AccountMeta; UPDATE_SERUM_VAULT_IX_ACCOUNTS
         ]
     }
 }
+impl From<UpdateSerumVaultKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSerumVaultKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN]> for UpdateSerumVaultKeys {
     fn from(pubkeys: [Pubkey; UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -15570,6 +16307,13 @@ impl<'info> From<UpdateSerumVaultAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSerumVaultAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSerumVaultAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SERUM_VAULT_IX_ACCOUNTS_LEN]>
     for UpdateSerumVaultAccounts<'me, 'info>
 {
@@ -15590,13 +16334,10 @@ impl UpdateSerumVaultIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SERUM_VAULT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SERUM_VAULT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SERUM_VAULT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -15758,6 +16499,12 @@ impl From<InitializePerpMarketKeys> for [AccountMeta; INITIALIZE_PERP_MARKET_IX_
         ]
     }
 }
+impl From<InitializePerpMarketKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializePerpMarketKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN]> for InitializePerpMarketKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -15784,6 +16531,13 @@ impl<'info> From<InitializePerpMarketAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializePerpMarketAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializePerpMarketAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_PERP_MARKET_IX_ACCOUNTS_LEN]>
     for InitializePerpMarketAccounts<'me, 'info>
 {
@@ -15799,7 +16553,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_PERP_MARKET_IX_ACCOUN
     }
 }
 pub const INITIALIZE_PERP_MARKET_IX_DISCM: [u8; 8] = [132, 9, 229, 118, 117, 118, 117, 62];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializePerpMarketIxArgs {
     pub market_index: u16,
@@ -15827,13 +16583,10 @@ impl InitializePerpMarketIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_PERP_MARKET_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_PERP_MARKET_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_PERP_MARKET_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializePerpMarketIxArgs::deserialize(&mut reader)?))
     }
@@ -15988,6 +16741,12 @@ impl From<DeleteInitializedPerpMarketKeys>
         ]
     }
 }
+impl From<DeleteInitializedPerpMarketKeys> for Vec<AccountMeta> {
+    fn from(keys: DeleteInitializedPerpMarketKeys) -> Self {
+        let accounts: [AccountMeta; DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN]>
     for DeleteInitializedPerpMarketKeys
 {
@@ -16010,6 +16769,13 @@ impl<'info> From<DeleteInitializedPerpMarketAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<DeleteInitializedPerpMarketAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DeleteInitializedPerpMarketAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DELETE_INITIALIZED_PERP_MARKET_IX_ACCOUNTS_LEN]>
     for DeleteInitializedPerpMarketAccounts<'me, 'info>
 {
@@ -16024,7 +16790,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DELETE_INITIALIZED_PERP_MARKET_I
     }
 }
 pub const DELETE_INITIALIZED_PERP_MARKET_IX_DISCM: [u8; 8] = [91, 154, 24, 87, 106, 59, 190, 66];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteInitializedPerpMarketIxArgs {
     pub market_index: u16,
@@ -16042,13 +16810,10 @@ impl DeleteInitializedPerpMarketIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DELETE_INITIALIZED_PERP_MARKET_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DELETE_INITIALIZED_PERP_MARKET_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DELETE_INITIALIZED_PERP_MARKET_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DeleteInitializedPerpMarketIxArgs::deserialize(
             &mut reader,
@@ -16200,6 +16965,12 @@ impl From<MoveAmmPriceKeys> for [AccountMeta; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<MoveAmmPriceKeys> for Vec<AccountMeta> {
+    fn from(keys: MoveAmmPriceKeys) -> Self {
+        let accounts: [AccountMeta; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN]> for MoveAmmPriceKeys {
     fn from(pubkeys: [Pubkey; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -16220,6 +16991,12 @@ impl<'info> From<MoveAmmPriceAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<MoveAmmPriceAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: MoveAmmPriceAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN]>
     for MoveAmmPriceAccounts<'me, 'info>
 {
@@ -16232,7 +17009,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MOVE_AMM_PRICE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const MOVE_AMM_PRICE_IX_DISCM: [u8; 8] = [235, 109, 2, 82, 219, 118, 6, 159];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MoveAmmPriceIxArgs {
     pub base_asset_reserve: u128,
@@ -16252,13 +17031,10 @@ impl MoveAmmPriceIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != MOVE_AMM_PRICE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    MOVE_AMM_PRICE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                MOVE_AMM_PRICE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(MoveAmmPriceIxArgs::deserialize(&mut reader)?))
     }
@@ -16408,6 +17184,12 @@ impl From<UpdatePerpMarketExpiryKeys> for [AccountMeta; UPDATE_PERP_MARKET_EXPIR
         ]
     }
 }
+impl From<UpdatePerpMarketExpiryKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketExpiryKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN]> for UpdatePerpMarketExpiryKeys {
     fn from(pubkeys: [Pubkey; UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -16428,6 +17210,13 @@ impl<'info> From<UpdatePerpMarketExpiryAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketExpiryAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketExpiryAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_EXPIRY_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketExpiryAccounts<'me, 'info>
 {
@@ -16440,7 +17229,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_EXPIRY_IX_ACC
     }
 }
 pub const UPDATE_PERP_MARKET_EXPIRY_IX_DISCM: [u8; 8] = [44, 221, 227, 151, 131, 140, 22, 110];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketExpiryIxArgs {
     pub expiry_ts: i64,
@@ -16458,13 +17249,10 @@ impl UpdatePerpMarketExpiryIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_EXPIRY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_EXPIRY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_EXPIRY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketExpiryIxArgs::deserialize(
             &mut reader,
@@ -16628,6 +17416,13 @@ impl From<SettleExpiredMarketPoolsToRevenuePoolKeys>
         ]
     }
 }
+impl From<SettleExpiredMarketPoolsToRevenuePoolKeys> for Vec<AccountMeta> {
+    fn from(keys: SettleExpiredMarketPoolsToRevenuePoolKeys) -> Self {
+        let accounts: [AccountMeta; SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN]>
     for SettleExpiredMarketPoolsToRevenuePoolKeys
 {
@@ -16654,6 +17449,15 @@ impl<'info> From<SettleExpiredMarketPoolsToRevenuePoolAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<SettleExpiredMarketPoolsToRevenuePoolAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: SettleExpiredMarketPoolsToRevenuePoolAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_ACCOUNTS_LEN]>
     for SettleExpiredMarketPoolsToRevenuePoolAccounts<'me, 'info>
@@ -16679,13 +17483,10 @@ impl SettleExpiredMarketPoolsToRevenuePoolIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SETTLE_EXPIRED_MARKET_POOLS_TO_REVENUE_POOL_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -16875,6 +17676,13 @@ impl From<DepositIntoPerpMarketFeePoolKeys>
         ]
     }
 }
+impl From<DepositIntoPerpMarketFeePoolKeys> for Vec<AccountMeta> {
+    fn from(keys: DepositIntoPerpMarketFeePoolKeys) -> Self {
+        let accounts: [AccountMeta; DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN]>
     for DepositIntoPerpMarketFeePoolKeys
 {
@@ -16907,6 +17715,13 @@ impl<'info> From<DepositIntoPerpMarketFeePoolAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<DepositIntoPerpMarketFeePoolAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DepositIntoPerpMarketFeePoolAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_ACCOUNTS_LEN]>
     for DepositIntoPerpMarketFeePoolAccounts<'me, 'info>
 {
@@ -16926,7 +17741,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_INTO_PERP_MARKET_FEE_POO
     }
 }
 pub const DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_DISCM: [u8; 8] = [34, 58, 57, 68, 97, 80, 244, 6];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositIntoPerpMarketFeePoolIxArgs {
     pub amount: u64,
@@ -16944,13 +17761,10 @@ impl DepositIntoPerpMarketFeePoolIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_INTO_PERP_MARKET_FEE_POOL_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DepositIntoPerpMarketFeePoolIxArgs::deserialize(
             &mut reader,
@@ -17126,6 +17940,12 @@ impl From<RepegAmmCurveKeys> for [AccountMeta; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN]
         ]
     }
 }
+impl From<RepegAmmCurveKeys> for Vec<AccountMeta> {
+    fn from(keys: RepegAmmCurveKeys) -> Self {
+        let accounts: [AccountMeta; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN]> for RepegAmmCurveKeys {
     fn from(pubkeys: [Pubkey; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -17148,6 +17968,12 @@ impl<'info> From<RepegAmmCurveAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RepegAmmCurveAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RepegAmmCurveAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN]>
     for RepegAmmCurveAccounts<'me, 'info>
 {
@@ -17161,7 +17987,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REPEG_AMM_CURVE_IX_ACCOUNTS_LEN]
     }
 }
 pub const REPEG_AMM_CURVE_IX_DISCM: [u8; 8] = [3, 36, 102, 89, 180, 128, 120, 213];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RepegAmmCurveIxArgs {
     pub new_peg_candidate: u128,
@@ -17179,13 +18007,10 @@ impl RepegAmmCurveIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REPEG_AMM_CURVE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REPEG_AMM_CURVE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REPEG_AMM_CURVE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RepegAmmCurveIxArgs::deserialize(&mut reader)?))
     }
@@ -17346,6 +18171,13 @@ impl From<UpdatePerpMarketAmmOracleTwapKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketAmmOracleTwapKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketAmmOracleTwapKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketAmmOracleTwapKeys
 {
@@ -17370,6 +18202,13 @@ impl<'info> From<UpdatePerpMarketAmmOracleTwapAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketAmmOracleTwapAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketAmmOracleTwapAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketAmmOracleTwapAccounts<'me, 'info>
 {
@@ -17394,13 +18233,10 @@ impl UpdatePerpMarketAmmOracleTwapIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -17553,6 +18389,13 @@ impl From<ResetPerpMarketAmmOracleTwapKeys>
         ]
     }
 }
+impl From<ResetPerpMarketAmmOracleTwapKeys> for Vec<AccountMeta> {
+    fn from(keys: ResetPerpMarketAmmOracleTwapKeys) -> Self {
+        let accounts: [AccountMeta; RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN]>
     for ResetPerpMarketAmmOracleTwapKeys
 {
@@ -17577,6 +18420,13 @@ impl<'info> From<ResetPerpMarketAmmOracleTwapAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ResetPerpMarketAmmOracleTwapAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ResetPerpMarketAmmOracleTwapAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_ACCOUNTS_LEN]>
     for ResetPerpMarketAmmOracleTwapAccounts<'me, 'info>
 {
@@ -17601,13 +18451,10 @@ impl ResetPerpMarketAmmOracleTwapIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                RESET_PERP_MARKET_AMM_ORACLE_TWAP_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -17758,6 +18605,12 @@ impl From<UpdateKKeys> for [AccountMeta; UPDATE_K_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<UpdateKKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateKKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_K_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_K_IX_ACCOUNTS_LEN]> for UpdateKKeys {
     fn from(pubkeys: [Pubkey; UPDATE_K_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -17778,6 +18631,12 @@ impl<'info> From<UpdateKAccounts<'_, 'info>> for [AccountInfo<'info>; UPDATE_K_I
         ]
     }
 }
+impl<'info> From<UpdateKAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateKAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_K_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_K_IX_ACCOUNTS_LEN]>
     for UpdateKAccounts<'me, 'info>
 {
@@ -17791,7 +18650,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_K_IX_ACCOUNTS_LEN]>
     }
 }
 pub const UPDATE_K_IX_DISCM: [u8; 8] = [72, 98, 9, 139, 129, 229, 172, 56];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateKIxArgs {
     pub sqrt_k: u128,
@@ -17809,13 +18670,10 @@ impl UpdateKIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_K_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_K_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_K_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateKIxArgs::deserialize(&mut reader)?))
     }
@@ -17962,6 +18820,12 @@ impl From<UpdatePerpMarketMarginRatioKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketMarginRatioKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketMarginRatioKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMarginRatioKeys
 {
@@ -17984,6 +18848,13 @@ impl<'info> From<UpdatePerpMarketMarginRatioAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketMarginRatioAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketMarginRatioAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MARGIN_RATIO_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMarginRatioAccounts<'me, 'info>
 {
@@ -17999,7 +18870,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MARGIN_RATIO_
 }
 pub const UPDATE_PERP_MARKET_MARGIN_RATIO_IX_DISCM: [u8; 8] =
     [130, 173, 107, 45, 119, 105, 26, 113];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketMarginRatioIxArgs {
     pub margin_ratio_initial: u32,
@@ -18018,13 +18891,10 @@ impl UpdatePerpMarketMarginRatioIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_MARGIN_RATIO_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_MARGIN_RATIO_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_MARGIN_RATIO_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketMarginRatioIxArgs::deserialize(
             &mut reader,
@@ -18178,6 +19048,13 @@ impl From<UpdatePerpMarketMaxImbalancesKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketMaxImbalancesKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketMaxImbalancesKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxImbalancesKeys
 {
@@ -18200,6 +19077,13 @@ impl<'info> From<UpdatePerpMarketMaxImbalancesAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketMaxImbalancesAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketMaxImbalancesAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxImbalancesAccounts<'me, 'info>
 {
@@ -18214,7 +19098,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_IMBALANCE
     }
 }
 pub const UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_DISCM: [u8; 8] = [15, 206, 73, 133, 60, 8, 86, 89];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketMaxImbalancesIxArgs {
     pub unrealized_max_imbalance: u64,
@@ -18234,13 +19120,10 @@ impl UpdatePerpMarketMaxImbalancesIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_MAX_IMBALANCES_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketMaxImbalancesIxArgs::deserialize(
             &mut reader,
@@ -18399,6 +19282,13 @@ impl From<UpdatePerpMarketLiquidationFeeKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketLiquidationFeeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketLiquidationFeeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketLiquidationFeeKeys
 {
@@ -18421,6 +19311,13 @@ impl<'info> From<UpdatePerpMarketLiquidationFeeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketLiquidationFeeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketLiquidationFeeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketLiquidationFeeAccounts<'me, 'info>
 {
@@ -18435,7 +19332,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_LIQUIDATION_F
     }
 }
 pub const UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_DISCM: [u8; 8] = [90, 137, 9, 145, 41, 8, 148, 117];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketLiquidationFeeIxArgs {
     pub liquidator_fee: u32,
@@ -18454,13 +19353,10 @@ impl UpdatePerpMarketLiquidationFeeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_LIQUIDATION_FEE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketLiquidationFeeIxArgs::deserialize(
             &mut reader,
@@ -18621,6 +19517,13 @@ impl From<UpdateInsuranceFundUnstakingPeriodKeys>
         ]
     }
 }
+impl From<UpdateInsuranceFundUnstakingPeriodKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateInsuranceFundUnstakingPeriodKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN]>
     for UpdateInsuranceFundUnstakingPeriodKeys
 {
@@ -18643,6 +19546,15 @@ impl<'info> From<UpdateInsuranceFundUnstakingPeriodAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateInsuranceFundUnstakingPeriodAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdateInsuranceFundUnstakingPeriodAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_ACCOUNTS_LEN]>
     for UpdateInsuranceFundUnstakingPeriodAccounts<'me, 'info>
@@ -18659,7 +19571,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_DISCM: [u8; 8] =
     [44, 69, 43, 226, 204, 223, 202, 52];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateInsuranceFundUnstakingPeriodIxArgs {
     pub insurance_fund_unstaking_period: i64,
@@ -18677,13 +19591,10 @@ impl UpdateInsuranceFundUnstakingPeriodIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_INSURANCE_FUND_UNSTAKING_PERIOD_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateInsuranceFundUnstakingPeriodIxArgs::deserialize(
             &mut reader,
@@ -18842,6 +19753,13 @@ impl From<UpdateSpotMarketLiquidationFeeKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketLiquidationFeeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketLiquidationFeeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketLiquidationFeeKeys
 {
@@ -18864,6 +19782,13 @@ impl<'info> From<UpdateSpotMarketLiquidationFeeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketLiquidationFeeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketLiquidationFeeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketLiquidationFeeAccounts<'me, 'info>
 {
@@ -18879,7 +19804,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_LIQUIDATION_F
 }
 pub const UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_DISCM: [u8; 8] =
     [11, 13, 255, 53, 56, 136, 104, 177];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketLiquidationFeeIxArgs {
     pub liquidator_fee: u32,
@@ -18898,13 +19825,10 @@ impl UpdateSpotMarketLiquidationFeeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_LIQUIDATION_FEE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketLiquidationFeeIxArgs::deserialize(
             &mut reader,
@@ -19063,6 +19987,12 @@ impl From<UpdateWithdrawGuardThresholdKeys>
         ]
     }
 }
+impl From<UpdateWithdrawGuardThresholdKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateWithdrawGuardThresholdKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN]>
     for UpdateWithdrawGuardThresholdKeys
 {
@@ -19085,6 +20015,13 @@ impl<'info> From<UpdateWithdrawGuardThresholdAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateWithdrawGuardThresholdAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateWithdrawGuardThresholdAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_ACCOUNTS_LEN]>
     for UpdateWithdrawGuardThresholdAccounts<'me, 'info>
 {
@@ -19099,7 +20036,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_WITHDRAW_GUARD_THRESHOLD_
     }
 }
 pub const UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_DISCM: [u8; 8] = [56, 18, 39, 61, 155, 211, 44, 133];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateWithdrawGuardThresholdIxArgs {
     pub withdraw_guard_threshold: u64,
@@ -19117,13 +20056,10 @@ impl UpdateWithdrawGuardThresholdIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_WITHDRAW_GUARD_THRESHOLD_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateWithdrawGuardThresholdIxArgs::deserialize(
             &mut reader,
@@ -19277,6 +20213,12 @@ impl From<UpdateSpotMarketIfFactorKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketIfFactorKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketIfFactorKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN]> for UpdateSpotMarketIfFactorKeys {
     fn from(pubkeys: [Pubkey; UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -19297,6 +20239,13 @@ impl<'info> From<UpdateSpotMarketIfFactorAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketIfFactorAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketIfFactorAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_IF_FACTOR_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketIfFactorAccounts<'me, 'info>
 {
@@ -19309,7 +20258,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_IF_FACTOR_IX_
     }
 }
 pub const UPDATE_SPOT_MARKET_IF_FACTOR_IX_DISCM: [u8; 8] = [147, 30, 224, 34, 18, 230, 105, 4];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketIfFactorIxArgs {
     pub spot_market_index: u16,
@@ -19329,13 +20280,10 @@ impl UpdateSpotMarketIfFactorIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_IF_FACTOR_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_IF_FACTOR_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_IF_FACTOR_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketIfFactorIxArgs::deserialize(
             &mut reader,
@@ -19491,6 +20439,13 @@ impl From<UpdateSpotMarketRevenueSettlePeriodKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketRevenueSettlePeriodKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketRevenueSettlePeriodKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketRevenueSettlePeriodKeys
 {
@@ -19513,6 +20468,15 @@ impl<'info> From<UpdateSpotMarketRevenueSettlePeriodAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketRevenueSettlePeriodAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdateSpotMarketRevenueSettlePeriodAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketRevenueSettlePeriodAccounts<'me, 'info>
@@ -19529,7 +20493,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_DISCM: [u8; 8] =
     [81, 92, 126, 41, 250, 225, 156, 219];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketRevenueSettlePeriodIxArgs {
     pub revenue_settle_period: i64,
@@ -19547,13 +20513,10 @@ impl UpdateSpotMarketRevenueSettlePeriodIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_REVENUE_SETTLE_PERIOD_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             UpdateSpotMarketRevenueSettlePeriodIxArgs::deserialize(&mut reader)?,
@@ -19711,6 +20674,12 @@ impl From<UpdateSpotMarketStatusKeys> for [AccountMeta; UPDATE_SPOT_MARKET_STATU
         ]
     }
 }
+impl From<UpdateSpotMarketStatusKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketStatusKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN]> for UpdateSpotMarketStatusKeys {
     fn from(pubkeys: [Pubkey; UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -19731,6 +20700,13 @@ impl<'info> From<UpdateSpotMarketStatusAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketStatusAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketStatusAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_STATUS_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketStatusAccounts<'me, 'info>
 {
@@ -19743,7 +20719,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_STATUS_IX_ACC
     }
 }
 pub const UPDATE_SPOT_MARKET_STATUS_IX_DISCM: [u8; 8] = [78, 94, 16, 188, 193, 110, 231, 31];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketStatusIxArgs {
     pub status: MarketStatus,
@@ -19761,13 +20739,10 @@ impl UpdateSpotMarketStatusIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_STATUS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_STATUS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_STATUS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketStatusIxArgs::deserialize(
             &mut reader,
@@ -19921,6 +20896,12 @@ impl From<UpdateSpotMarketAssetTierKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketAssetTierKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketAssetTierKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketAssetTierKeys
 {
@@ -19943,6 +20924,13 @@ impl<'info> From<UpdateSpotMarketAssetTierAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketAssetTierAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketAssetTierAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ASSET_TIER_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketAssetTierAccounts<'me, 'info>
 {
@@ -19955,7 +20943,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ASSET_TIER_IX
     }
 }
 pub const UPDATE_SPOT_MARKET_ASSET_TIER_IX_DISCM: [u8; 8] = [253, 209, 231, 14, 242, 208, 243, 130];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketAssetTierIxArgs {
     pub asset_tier: AssetTier,
@@ -19973,13 +20963,10 @@ impl UpdateSpotMarketAssetTierIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_ASSET_TIER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_ASSET_TIER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_ASSET_TIER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketAssetTierIxArgs::deserialize(
             &mut reader,
@@ -20133,6 +21120,13 @@ impl From<UpdateSpotMarketMarginWeightsKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketMarginWeightsKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketMarginWeightsKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketMarginWeightsKeys
 {
@@ -20155,6 +21149,13 @@ impl<'info> From<UpdateSpotMarketMarginWeightsAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketMarginWeightsAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketMarginWeightsAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketMarginWeightsAccounts<'me, 'info>
 {
@@ -20169,7 +21170,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MARGIN_WEIGHT
     }
 }
 pub const UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_DISCM: [u8; 8] = [109, 33, 87, 195, 255, 36, 6, 81];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketMarginWeightsIxArgs {
     pub initial_asset_weight: u32,
@@ -20191,13 +21194,10 @@ impl UpdateSpotMarketMarginWeightsIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_MARGIN_WEIGHTS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketMarginWeightsIxArgs::deserialize(
             &mut reader,
@@ -20356,6 +21356,12 @@ impl From<UpdateSpotMarketBorrowRateKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketBorrowRateKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketBorrowRateKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketBorrowRateKeys
 {
@@ -20378,6 +21384,13 @@ impl<'info> From<UpdateSpotMarketBorrowRateAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketBorrowRateAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketBorrowRateAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_BORROW_RATE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketBorrowRateAccounts<'me, 'info>
 {
@@ -20392,7 +21405,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_BORROW_RATE_I
     }
 }
 pub const UPDATE_SPOT_MARKET_BORROW_RATE_IX_DISCM: [u8; 8] = [71, 239, 236, 153, 210, 62, 254, 76];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketBorrowRateIxArgs {
     pub optimal_utilization: u32,
@@ -20412,13 +21427,10 @@ impl UpdateSpotMarketBorrowRateIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_BORROW_RATE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_BORROW_RATE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_BORROW_RATE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketBorrowRateIxArgs::deserialize(
             &mut reader,
@@ -20574,6 +21586,13 @@ impl From<UpdateSpotMarketMaxTokenDepositsKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketMaxTokenDepositsKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketMaxTokenDepositsKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketMaxTokenDepositsKeys
 {
@@ -20596,6 +21615,13 @@ impl<'info> From<UpdateSpotMarketMaxTokenDepositsAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketMaxTokenDepositsAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketMaxTokenDepositsAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketMaxTokenDepositsAccounts<'me, 'info>
@@ -20612,7 +21638,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_DISCM: [u8; 8] =
     [56, 191, 79, 18, 26, 121, 80, 208];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketMaxTokenDepositsIxArgs {
     pub max_token_deposits: u64,
@@ -20630,13 +21658,10 @@ impl UpdateSpotMarketMaxTokenDepositsIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_MAX_TOKEN_DEPOSITS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketMaxTokenDepositsIxArgs::deserialize(
             &mut reader,
@@ -20801,6 +21826,12 @@ impl From<UpdateSpotMarketOracleKeys> for [AccountMeta; UPDATE_SPOT_MARKET_ORACL
         ]
     }
 }
+impl From<UpdateSpotMarketOracleKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketOracleKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN]> for UpdateSpotMarketOracleKeys {
     fn from(pubkeys: [Pubkey; UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -20823,6 +21854,13 @@ impl<'info> From<UpdateSpotMarketOracleAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketOracleAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketOracleAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORACLE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketOracleAccounts<'me, 'info>
 {
@@ -20836,7 +21874,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORACLE_IX_ACC
     }
 }
 pub const UPDATE_SPOT_MARKET_ORACLE_IX_DISCM: [u8; 8] = [114, 184, 102, 37, 246, 186, 180, 99];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketOracleIxArgs {
     pub oracle: Pubkey,
@@ -20855,13 +21895,10 @@ impl UpdateSpotMarketOracleIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_ORACLE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_ORACLE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_ORACLE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketOracleIxArgs::deserialize(
             &mut reader,
@@ -21018,6 +22055,13 @@ impl From<UpdateSpotMarketStepSizeAndTickSizeKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketStepSizeAndTickSizeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketStepSizeAndTickSizeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketStepSizeAndTickSizeKeys
 {
@@ -21040,6 +22084,15 @@ impl<'info> From<UpdateSpotMarketStepSizeAndTickSizeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketStepSizeAndTickSizeAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdateSpotMarketStepSizeAndTickSizeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketStepSizeAndTickSizeAccounts<'me, 'info>
@@ -21056,7 +22109,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM: [u8; 8] =
     [238, 153, 137, 80, 206, 59, 250, 61];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketStepSizeAndTickSizeIxArgs {
     pub step_size: u64,
@@ -21075,13 +22130,10 @@ impl UpdateSpotMarketStepSizeAndTickSizeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             UpdateSpotMarketStepSizeAndTickSizeIxArgs::deserialize(&mut reader)?,
@@ -21241,6 +22293,13 @@ impl From<UpdateSpotMarketMinOrderSizeKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketMinOrderSizeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketMinOrderSizeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketMinOrderSizeKeys
 {
@@ -21263,6 +22322,13 @@ impl<'info> From<UpdateSpotMarketMinOrderSizeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketMinOrderSizeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketMinOrderSizeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketMinOrderSizeAccounts<'me, 'info>
 {
@@ -21277,7 +22343,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_MIN_ORDER_SIZ
     }
 }
 pub const UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_DISCM: [u8; 8] = [93, 128, 11, 119, 26, 20, 181, 50];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketMinOrderSizeIxArgs {
     pub order_size: u64,
@@ -21295,13 +22363,10 @@ impl UpdateSpotMarketMinOrderSizeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_MIN_ORDER_SIZE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketMinOrderSizeIxArgs::deserialize(
             &mut reader,
@@ -21460,6 +22525,13 @@ impl From<UpdateSpotMarketOrdersEnabledKeys>
         ]
     }
 }
+impl From<UpdateSpotMarketOrdersEnabledKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketOrdersEnabledKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketOrdersEnabledKeys
 {
@@ -21482,6 +22554,13 @@ impl<'info> From<UpdateSpotMarketOrdersEnabledAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketOrdersEnabledAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketOrdersEnabledAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketOrdersEnabledAccounts<'me, 'info>
 {
@@ -21497,7 +22576,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_ORDERS_ENABLE
 }
 pub const UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_DISCM: [u8; 8] =
     [190, 79, 206, 15, 26, 229, 229, 43];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketOrdersEnabledIxArgs {
     pub orders_enabled: bool,
@@ -21515,13 +22596,10 @@ impl UpdateSpotMarketOrdersEnabledIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_ORDERS_ENABLED_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketOrdersEnabledIxArgs::deserialize(
             &mut reader,
@@ -21678,6 +22756,12 @@ impl From<UpdateSpotMarketNameKeys> for [AccountMeta; UPDATE_SPOT_MARKET_NAME_IX
         ]
     }
 }
+impl From<UpdateSpotMarketNameKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotMarketNameKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN]> for UpdateSpotMarketNameKeys {
     fn from(pubkeys: [Pubkey; UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -21698,6 +22782,13 @@ impl<'info> From<UpdateSpotMarketNameAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateSpotMarketNameAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotMarketNameAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_NAME_IX_ACCOUNTS_LEN]>
     for UpdateSpotMarketNameAccounts<'me, 'info>
 {
@@ -21710,7 +22801,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_MARKET_NAME_IX_ACCOU
     }
 }
 pub const UPDATE_SPOT_MARKET_NAME_IX_DISCM: [u8; 8] = [17, 208, 1, 1, 162, 211, 188, 224];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotMarketNameIxArgs {
     pub name: [u8; 32],
@@ -21728,13 +22821,10 @@ impl UpdateSpotMarketNameIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_MARKET_NAME_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_MARKET_NAME_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_MARKET_NAME_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotMarketNameIxArgs::deserialize(&mut reader)?))
     }
@@ -21884,6 +22974,12 @@ impl From<UpdatePerpMarketStatusKeys> for [AccountMeta; UPDATE_PERP_MARKET_STATU
         ]
     }
 }
+impl From<UpdatePerpMarketStatusKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketStatusKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN]> for UpdatePerpMarketStatusKeys {
     fn from(pubkeys: [Pubkey; UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -21904,6 +23000,13 @@ impl<'info> From<UpdatePerpMarketStatusAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketStatusAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketStatusAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_STATUS_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketStatusAccounts<'me, 'info>
 {
@@ -21916,7 +23019,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_STATUS_IX_ACC
     }
 }
 pub const UPDATE_PERP_MARKET_STATUS_IX_DISCM: [u8; 8] = [71, 201, 175, 122, 255, 207, 196, 207];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketStatusIxArgs {
     pub status: MarketStatus,
@@ -21934,13 +23039,10 @@ impl UpdatePerpMarketStatusIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_STATUS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_STATUS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_STATUS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketStatusIxArgs::deserialize(
             &mut reader,
@@ -22094,6 +23196,12 @@ impl From<UpdatePerpMarketContractTierKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketContractTierKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketContractTierKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketContractTierKeys
 {
@@ -22116,6 +23224,13 @@ impl<'info> From<UpdatePerpMarketContractTierAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketContractTierAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketContractTierAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_CONTRACT_TIER_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketContractTierAccounts<'me, 'info>
 {
@@ -22131,7 +23246,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_CONTRACT_TIER
 }
 pub const UPDATE_PERP_MARKET_CONTRACT_TIER_IX_DISCM: [u8; 8] =
     [236, 128, 15, 95, 203, 214, 68, 117];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketContractTierIxArgs {
     pub contract_tier: ContractTier,
@@ -22149,13 +23266,10 @@ impl UpdatePerpMarketContractTierIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_CONTRACT_TIER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_CONTRACT_TIER_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_CONTRACT_TIER_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketContractTierIxArgs::deserialize(
             &mut reader,
@@ -22309,6 +23423,12 @@ impl From<UpdatePerpMarketImfFactorKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketImfFactorKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketImfFactorKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketImfFactorKeys
 {
@@ -22331,6 +23451,13 @@ impl<'info> From<UpdatePerpMarketImfFactorAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketImfFactorAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketImfFactorAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_IMF_FACTOR_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketImfFactorAccounts<'me, 'info>
 {
@@ -22343,7 +23470,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_IMF_FACTOR_IX
     }
 }
 pub const UPDATE_PERP_MARKET_IMF_FACTOR_IX_DISCM: [u8; 8] = [207, 194, 56, 132, 35, 67, 71, 244];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketImfFactorIxArgs {
     pub imf_factor: u32,
@@ -22362,13 +23491,10 @@ impl UpdatePerpMarketImfFactorIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_IMF_FACTOR_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_IMF_FACTOR_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_IMF_FACTOR_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketImfFactorIxArgs::deserialize(
             &mut reader,
@@ -22524,6 +23650,13 @@ impl From<UpdatePerpMarketUnrealizedAssetWeightKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketUnrealizedAssetWeightKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketUnrealizedAssetWeightKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketUnrealizedAssetWeightKeys
 {
@@ -22546,6 +23679,15 @@ impl<'info> From<UpdatePerpMarketUnrealizedAssetWeightAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketUnrealizedAssetWeightAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdatePerpMarketUnrealizedAssetWeightAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketUnrealizedAssetWeightAccounts<'me, 'info>
@@ -22562,7 +23704,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_DISCM: [u8; 8] =
     [135, 132, 205, 165, 109, 150, 166, 106];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketUnrealizedAssetWeightIxArgs {
     pub unrealized_initial_asset_weight: u32,
@@ -22585,13 +23729,10 @@ impl UpdatePerpMarketUnrealizedAssetWeightIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_UNREALIZED_ASSET_WEIGHT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             UpdatePerpMarketUnrealizedAssetWeightIxArgs::deserialize(&mut reader)?,
@@ -22753,6 +23894,13 @@ impl From<UpdatePerpMarketConcentrationCoefKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketConcentrationCoefKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketConcentrationCoefKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketConcentrationCoefKeys
 {
@@ -22775,6 +23923,13 @@ impl<'info> From<UpdatePerpMarketConcentrationCoefAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketConcentrationCoefAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketConcentrationCoefAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketConcentrationCoefAccounts<'me, 'info>
@@ -22791,7 +23946,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_DISCM: [u8; 8] =
     [24, 78, 232, 126, 169, 176, 230, 16];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketConcentrationCoefIxArgs {
     pub concentration_scale: u128,
@@ -22809,13 +23966,10 @@ impl UpdatePerpMarketConcentrationCoefIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_CONCENTRATION_COEF_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketConcentrationCoefIxArgs::deserialize(
             &mut reader,
@@ -22976,6 +24130,13 @@ impl From<UpdatePerpMarketCurveUpdateIntensityKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketCurveUpdateIntensityKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketCurveUpdateIntensityKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketCurveUpdateIntensityKeys
 {
@@ -22998,6 +24159,15 @@ impl<'info> From<UpdatePerpMarketCurveUpdateIntensityAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketCurveUpdateIntensityAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdatePerpMarketCurveUpdateIntensityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketCurveUpdateIntensityAccounts<'me, 'info>
@@ -23014,7 +24184,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_DISCM: [u8; 8] =
     [50, 131, 6, 156, 226, 231, 189, 72];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketCurveUpdateIntensityIxArgs {
     pub curve_update_intensity: u8,
@@ -23036,13 +24208,10 @@ impl UpdatePerpMarketCurveUpdateIntensityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_CURVE_UPDATE_INTENSITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             UpdatePerpMarketCurveUpdateIntensityIxArgs::deserialize(&mut reader)?,
@@ -23204,6 +24373,13 @@ impl From<UpdatePerpMarketTargetBaseAssetAmountPerLpKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketTargetBaseAssetAmountPerLpKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketTargetBaseAssetAmountPerLpKeys) -> Self {
+        let accounts: [AccountMeta;
+            UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketTargetBaseAssetAmountPerLpKeys
 {
@@ -23228,6 +24404,15 @@ impl<'info> From<UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdatePerpMarketTargetBaseAssetAmountPerLpAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<
         &'me [AccountInfo<'info>;
@@ -23247,7 +24432,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_DISCM: [u8; 8] =
     [62, 87, 68, 115, 29, 150, 150, 165];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketTargetBaseAssetAmountPerLpIxArgs {
     pub target_base_asset_amount_per_lp: i32,
@@ -23269,13 +24456,10 @@ impl UpdatePerpMarketTargetBaseAssetAmountPerLpIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_TARGET_BASE_ASSET_AMOUNT_PER_LP_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             UpdatePerpMarketTargetBaseAssetAmountPerLpIxArgs::deserialize(&mut reader)?,
@@ -23433,6 +24617,12 @@ impl From<UpdateLpCooldownTimeKeys> for [AccountMeta; UPDATE_LP_COOLDOWN_TIME_IX
         ]
     }
 }
+impl From<UpdateLpCooldownTimeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateLpCooldownTimeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN]> for UpdateLpCooldownTimeKeys {
     fn from(pubkeys: [Pubkey; UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -23448,6 +24638,13 @@ impl<'info> From<UpdateLpCooldownTimeAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateLpCooldownTimeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateLpCooldownTimeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_LP_COOLDOWN_TIME_IX_ACCOUNTS_LEN]>
     for UpdateLpCooldownTimeAccounts<'me, 'info>
 {
@@ -23459,7 +24656,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_LP_COOLDOWN_TIME_IX_ACCOU
     }
 }
 pub const UPDATE_LP_COOLDOWN_TIME_IX_DISCM: [u8; 8] = [198, 133, 88, 41, 241, 119, 61, 14];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateLpCooldownTimeIxArgs {
     pub lp_cooldown_time: u64,
@@ -23477,13 +24676,10 @@ impl UpdateLpCooldownTimeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_LP_COOLDOWN_TIME_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_LP_COOLDOWN_TIME_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_LP_COOLDOWN_TIME_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateLpCooldownTimeIxArgs::deserialize(&mut reader)?))
     }
@@ -23624,6 +24820,12 @@ impl From<UpdatePerpFeeStructureKeys> for [AccountMeta; UPDATE_PERP_FEE_STRUCTUR
         ]
     }
 }
+impl From<UpdatePerpFeeStructureKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpFeeStructureKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN]> for UpdatePerpFeeStructureKeys {
     fn from(pubkeys: [Pubkey; UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -23639,6 +24841,13 @@ impl<'info> From<UpdatePerpFeeStructureAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdatePerpFeeStructureAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpFeeStructureAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_FEE_STRUCTURE_IX_ACCOUNTS_LEN]>
     for UpdatePerpFeeStructureAccounts<'me, 'info>
 {
@@ -23650,7 +24859,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_FEE_STRUCTURE_IX_ACC
     }
 }
 pub const UPDATE_PERP_FEE_STRUCTURE_IX_DISCM: [u8; 8] = [23, 178, 111, 203, 73, 22, 140, 75];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpFeeStructureIxArgs {
     pub fee_structure: FeeStructure,
@@ -23668,13 +24879,10 @@ impl UpdatePerpFeeStructureIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_FEE_STRUCTURE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_FEE_STRUCTURE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_FEE_STRUCTURE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpFeeStructureIxArgs::deserialize(
             &mut reader,
@@ -23817,6 +25025,12 @@ impl From<UpdateSpotFeeStructureKeys> for [AccountMeta; UPDATE_SPOT_FEE_STRUCTUR
         ]
     }
 }
+impl From<UpdateSpotFeeStructureKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotFeeStructureKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN]> for UpdateSpotFeeStructureKeys {
     fn from(pubkeys: [Pubkey; UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -23832,6 +25046,13 @@ impl<'info> From<UpdateSpotFeeStructureAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateSpotFeeStructureAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotFeeStructureAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_FEE_STRUCTURE_IX_ACCOUNTS_LEN]>
     for UpdateSpotFeeStructureAccounts<'me, 'info>
 {
@@ -23843,7 +25064,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_FEE_STRUCTURE_IX_ACC
     }
 }
 pub const UPDATE_SPOT_FEE_STRUCTURE_IX_DISCM: [u8; 8] = [97, 216, 105, 131, 113, 246, 142, 141];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotFeeStructureIxArgs {
     pub fee_structure: FeeStructure,
@@ -23861,13 +25084,10 @@ impl UpdateSpotFeeStructureIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_FEE_STRUCTURE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_FEE_STRUCTURE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_FEE_STRUCTURE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotFeeStructureIxArgs::deserialize(
             &mut reader,
@@ -24012,6 +25232,12 @@ impl From<UpdateInitialPctToLiquidateKeys>
         ]
     }
 }
+impl From<UpdateInitialPctToLiquidateKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateInitialPctToLiquidateKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN]>
     for UpdateInitialPctToLiquidateKeys
 {
@@ -24029,6 +25255,13 @@ impl<'info> From<UpdateInitialPctToLiquidateAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateInitialPctToLiquidateAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateInitialPctToLiquidateAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_ACCOUNTS_LEN]>
     for UpdateInitialPctToLiquidateAccounts<'me, 'info>
 {
@@ -24043,7 +25276,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_INITIAL_PCT_TO_LIQUIDATE_
 }
 pub const UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_DISCM: [u8; 8] =
     [210, 133, 225, 128, 194, 50, 13, 109];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateInitialPctToLiquidateIxArgs {
     pub initial_pct_to_liquidate: u16,
@@ -24061,13 +25296,10 @@ impl UpdateInitialPctToLiquidateIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_INITIAL_PCT_TO_LIQUIDATE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateInitialPctToLiquidateIxArgs::deserialize(
             &mut reader,
@@ -24212,6 +25444,12 @@ impl From<UpdateLiquidationDurationKeys>
         ]
     }
 }
+impl From<UpdateLiquidationDurationKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateLiquidationDurationKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN]> for UpdateLiquidationDurationKeys {
     fn from(pubkeys: [Pubkey; UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -24227,6 +25465,13 @@ impl<'info> From<UpdateLiquidationDurationAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateLiquidationDurationAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateLiquidationDurationAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_LIQUIDATION_DURATION_IX_ACCOUNTS_LEN]>
     for UpdateLiquidationDurationAccounts<'me, 'info>
 {
@@ -24238,7 +25483,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_LIQUIDATION_DURATION_IX_A
     }
 }
 pub const UPDATE_LIQUIDATION_DURATION_IX_DISCM: [u8; 8] = [28, 154, 20, 249, 102, 192, 73, 71];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateLiquidationDurationIxArgs {
     pub liquidation_duration: u8,
@@ -24256,13 +25503,10 @@ impl UpdateLiquidationDurationIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_LIQUIDATION_DURATION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_LIQUIDATION_DURATION_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_LIQUIDATION_DURATION_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateLiquidationDurationIxArgs::deserialize(
             &mut reader,
@@ -24405,6 +25649,12 @@ impl From<UpdateOracleGuardRailsKeys> for [AccountMeta; UPDATE_ORACLE_GUARD_RAIL
         ]
     }
 }
+impl From<UpdateOracleGuardRailsKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateOracleGuardRailsKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN]> for UpdateOracleGuardRailsKeys {
     fn from(pubkeys: [Pubkey; UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -24420,6 +25670,13 @@ impl<'info> From<UpdateOracleGuardRailsAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateOracleGuardRailsAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateOracleGuardRailsAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ORACLE_GUARD_RAILS_IX_ACCOUNTS_LEN]>
     for UpdateOracleGuardRailsAccounts<'me, 'info>
 {
@@ -24431,7 +25688,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ORACLE_GUARD_RAILS_IX_ACC
     }
 }
 pub const UPDATE_ORACLE_GUARD_RAILS_IX_DISCM: [u8; 8] = [131, 112, 10, 59, 32, 54, 40, 164];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateOracleGuardRailsIxArgs {
     pub oracle_guard_rails: OracleGuardRails,
@@ -24449,13 +25708,10 @@ impl UpdateOracleGuardRailsIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_ORACLE_GUARD_RAILS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_ORACLE_GUARD_RAILS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_ORACLE_GUARD_RAILS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateOracleGuardRailsIxArgs::deserialize(
             &mut reader,
@@ -24600,6 +25856,12 @@ impl From<UpdateStateSettlementDurationKeys>
         ]
     }
 }
+impl From<UpdateStateSettlementDurationKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateStateSettlementDurationKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN]>
     for UpdateStateSettlementDurationKeys
 {
@@ -24617,6 +25879,13 @@ impl<'info> From<UpdateStateSettlementDurationAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateStateSettlementDurationAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateStateSettlementDurationAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_STATE_SETTLEMENT_DURATION_IX_ACCOUNTS_LEN]>
     for UpdateStateSettlementDurationAccounts<'me, 'info>
 {
@@ -24630,7 +25899,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_STATE_SETTLEMENT_DURATION
     }
 }
 pub const UPDATE_STATE_SETTLEMENT_DURATION_IX_DISCM: [u8; 8] = [97, 68, 199, 235, 131, 80, 61, 173];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateStateSettlementDurationIxArgs {
     pub settlement_duration: u16,
@@ -24648,13 +25919,10 @@ impl UpdateStateSettlementDurationIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_STATE_SETTLEMENT_DURATION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_STATE_SETTLEMENT_DURATION_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_STATE_SETTLEMENT_DURATION_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateStateSettlementDurationIxArgs::deserialize(
             &mut reader,
@@ -24813,6 +26081,12 @@ impl From<UpdatePerpMarketOracleKeys> for [AccountMeta; UPDATE_PERP_MARKET_ORACL
         ]
     }
 }
+impl From<UpdatePerpMarketOracleKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketOracleKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN]> for UpdatePerpMarketOracleKeys {
     fn from(pubkeys: [Pubkey; UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -24835,6 +26109,13 @@ impl<'info> From<UpdatePerpMarketOracleAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketOracleAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketOracleAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_ORACLE_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketOracleAccounts<'me, 'info>
 {
@@ -24848,7 +26129,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_ORACLE_IX_ACC
     }
 }
 pub const UPDATE_PERP_MARKET_ORACLE_IX_DISCM: [u8; 8] = [182, 113, 111, 160, 67, 174, 89, 191];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketOracleIxArgs {
     pub oracle: Pubkey,
@@ -24867,13 +26150,10 @@ impl UpdatePerpMarketOracleIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_ORACLE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_ORACLE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_ORACLE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketOracleIxArgs::deserialize(
             &mut reader,
@@ -25028,6 +26308,12 @@ impl From<UpdatePerpMarketBaseSpreadKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketBaseSpreadKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketBaseSpreadKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketBaseSpreadKeys
 {
@@ -25050,6 +26336,13 @@ impl<'info> From<UpdatePerpMarketBaseSpreadAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketBaseSpreadAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketBaseSpreadAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_BASE_SPREAD_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketBaseSpreadAccounts<'me, 'info>
 {
@@ -25064,7 +26357,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_BASE_SPREAD_I
     }
 }
 pub const UPDATE_PERP_MARKET_BASE_SPREAD_IX_DISCM: [u8; 8] = [71, 95, 84, 168, 9, 157, 198, 65];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketBaseSpreadIxArgs {
     pub base_spread: u32,
@@ -25082,13 +26377,10 @@ impl UpdatePerpMarketBaseSpreadIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_BASE_SPREAD_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_BASE_SPREAD_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_BASE_SPREAD_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketBaseSpreadIxArgs::deserialize(
             &mut reader,
@@ -25240,6 +26532,12 @@ impl From<UpdateAmmJitIntensityKeys> for [AccountMeta; UPDATE_AMM_JIT_INTENSITY_
         ]
     }
 }
+impl From<UpdateAmmJitIntensityKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateAmmJitIntensityKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN]> for UpdateAmmJitIntensityKeys {
     fn from(pubkeys: [Pubkey; UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -25260,6 +26558,13 @@ impl<'info> From<UpdateAmmJitIntensityAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateAmmJitIntensityAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateAmmJitIntensityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_AMM_JIT_INTENSITY_IX_ACCOUNTS_LEN]>
     for UpdateAmmJitIntensityAccounts<'me, 'info>
 {
@@ -25272,7 +26577,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_AMM_JIT_INTENSITY_IX_ACCO
     }
 }
 pub const UPDATE_AMM_JIT_INTENSITY_IX_DISCM: [u8; 8] = [181, 191, 53, 109, 166, 249, 55, 142];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateAmmJitIntensityIxArgs {
     pub amm_jit_intensity: u8,
@@ -25290,13 +26597,10 @@ impl UpdateAmmJitIntensityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_AMM_JIT_INTENSITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_AMM_JIT_INTENSITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_AMM_JIT_INTENSITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateAmmJitIntensityIxArgs::deserialize(&mut reader)?))
     }
@@ -25448,6 +26752,12 @@ impl From<UpdatePerpMarketMaxSpreadKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketMaxSpreadKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketMaxSpreadKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxSpreadKeys
 {
@@ -25470,6 +26780,13 @@ impl<'info> From<UpdatePerpMarketMaxSpreadAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketMaxSpreadAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketMaxSpreadAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_SPREAD_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxSpreadAccounts<'me, 'info>
 {
@@ -25482,7 +26799,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_SPREAD_IX
     }
 }
 pub const UPDATE_PERP_MARKET_MAX_SPREAD_IX_DISCM: [u8; 8] = [80, 252, 122, 62, 40, 218, 91, 100];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketMaxSpreadIxArgs {
     pub max_spread: u32,
@@ -25500,13 +26819,10 @@ impl UpdatePerpMarketMaxSpreadIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_MAX_SPREAD_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_MAX_SPREAD_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_MAX_SPREAD_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketMaxSpreadIxArgs::deserialize(
             &mut reader,
@@ -25662,6 +26978,13 @@ impl From<UpdatePerpMarketStepSizeAndTickSizeKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketStepSizeAndTickSizeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketStepSizeAndTickSizeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketStepSizeAndTickSizeKeys
 {
@@ -25684,6 +27007,15 @@ impl<'info> From<UpdatePerpMarketStepSizeAndTickSizeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketStepSizeAndTickSizeAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdatePerpMarketStepSizeAndTickSizeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketStepSizeAndTickSizeAccounts<'me, 'info>
@@ -25700,7 +27032,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM: [u8; 8] =
     [231, 255, 97, 25, 146, 139, 174, 4];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketStepSizeAndTickSizeIxArgs {
     pub step_size: u64,
@@ -25719,13 +27053,10 @@ impl UpdatePerpMarketStepSizeAndTickSizeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_STEP_SIZE_AND_TICK_SIZE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             UpdatePerpMarketStepSizeAndTickSizeIxArgs::deserialize(&mut reader)?,
@@ -25883,6 +27214,12 @@ impl From<UpdatePerpMarketNameKeys> for [AccountMeta; UPDATE_PERP_MARKET_NAME_IX
         ]
     }
 }
+impl From<UpdatePerpMarketNameKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketNameKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN]> for UpdatePerpMarketNameKeys {
     fn from(pubkeys: [Pubkey; UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -25903,6 +27240,13 @@ impl<'info> From<UpdatePerpMarketNameAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketNameAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketNameAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_NAME_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketNameAccounts<'me, 'info>
 {
@@ -25915,7 +27259,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_NAME_IX_ACCOU
     }
 }
 pub const UPDATE_PERP_MARKET_NAME_IX_DISCM: [u8; 8] = [211, 31, 21, 210, 64, 108, 66, 201];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketNameIxArgs {
     pub name: [u8; 32],
@@ -25933,13 +27279,10 @@ impl UpdatePerpMarketNameIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_NAME_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_NAME_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_NAME_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketNameIxArgs::deserialize(&mut reader)?))
     }
@@ -26091,6 +27434,13 @@ impl From<UpdatePerpMarketMinOrderSizeKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketMinOrderSizeKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketMinOrderSizeKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMinOrderSizeKeys
 {
@@ -26113,6 +27463,13 @@ impl<'info> From<UpdatePerpMarketMinOrderSizeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketMinOrderSizeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketMinOrderSizeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMinOrderSizeAccounts<'me, 'info>
 {
@@ -26127,7 +27484,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MIN_ORDER_SIZ
     }
 }
 pub const UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_DISCM: [u8; 8] = [226, 74, 5, 89, 108, 223, 46, 141];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketMinOrderSizeIxArgs {
     pub order_size: u64,
@@ -26145,13 +27504,10 @@ impl UpdatePerpMarketMinOrderSizeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_MIN_ORDER_SIZE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketMinOrderSizeIxArgs::deserialize(
             &mut reader,
@@ -26312,6 +27668,13 @@ impl From<UpdatePerpMarketMaxSlippageRatioKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketMaxSlippageRatioKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketMaxSlippageRatioKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxSlippageRatioKeys
 {
@@ -26334,6 +27697,13 @@ impl<'info> From<UpdatePerpMarketMaxSlippageRatioAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketMaxSlippageRatioAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketMaxSlippageRatioAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxSlippageRatioAccounts<'me, 'info>
@@ -26350,7 +27720,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_DISCM: [u8; 8] =
     [235, 37, 40, 196, 70, 146, 54, 201];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketMaxSlippageRatioIxArgs {
     pub max_slippage_ratio: u16,
@@ -26368,13 +27740,10 @@ impl UpdatePerpMarketMaxSlippageRatioIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_MAX_SLIPPAGE_RATIO_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketMaxSlippageRatioIxArgs::deserialize(
             &mut reader,
@@ -26535,6 +27904,13 @@ impl From<UpdatePerpMarketMaxFillReserveFractionKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketMaxFillReserveFractionKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketMaxFillReserveFractionKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxFillReserveFractionKeys
 {
@@ -26559,6 +27935,15 @@ impl<'info> From<UpdatePerpMarketMaxFillReserveFractionAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketMaxFillReserveFractionAccounts<'_, 'info>>
+    for Vec<AccountInfo<'info>>
+{
+    fn from(accounts: UpdatePerpMarketMaxFillReserveFractionAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxFillReserveFractionAccounts<'me, 'info>
@@ -26576,7 +27961,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_DISCM: [u8; 8] =
     [19, 172, 114, 154, 42, 135, 161, 133];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketMaxFillReserveFractionIxArgs {
     pub max_fill_reserve_fraction: u16,
@@ -26598,13 +27985,10 @@ impl UpdatePerpMarketMaxFillReserveFractionIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_MAX_FILL_RESERVE_FRACTION_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(
             UpdatePerpMarketMaxFillReserveFractionIxArgs::deserialize(&mut reader)?,
@@ -26766,6 +28150,13 @@ impl From<UpdatePerpMarketMaxOpenInterestKeys>
         ]
     }
 }
+impl From<UpdatePerpMarketMaxOpenInterestKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpMarketMaxOpenInterestKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxOpenInterestKeys
 {
@@ -26788,6 +28179,13 @@ impl<'info> From<UpdatePerpMarketMaxOpenInterestAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdatePerpMarketMaxOpenInterestAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpMarketMaxOpenInterestAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info>
     From<&'me [AccountInfo<'info>; UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_ACCOUNTS_LEN]>
     for UpdatePerpMarketMaxOpenInterestAccounts<'me, 'info>
@@ -26804,7 +28202,9 @@ impl<'me, 'info>
 }
 pub const UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_DISCM: [u8; 8] =
     [194, 79, 149, 224, 246, 102, 186, 140];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpMarketMaxOpenInterestIxArgs {
     pub max_open_interest: u128,
@@ -26822,13 +28222,10 @@ impl UpdatePerpMarketMaxOpenInterestIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_MARKET_MAX_OPEN_INTEREST_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpMarketMaxOpenInterestIxArgs::deserialize(
             &mut reader,
@@ -26977,6 +28374,12 @@ impl From<UpdateAdminKeys> for [AccountMeta; UPDATE_ADMIN_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<UpdateAdminKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateAdminKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_ADMIN_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_ADMIN_IX_ACCOUNTS_LEN]> for UpdateAdminKeys {
     fn from(pubkeys: [Pubkey; UPDATE_ADMIN_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -26992,6 +28395,12 @@ impl<'info> From<UpdateAdminAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateAdminAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateAdminAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_ADMIN_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ADMIN_IX_ACCOUNTS_LEN]>
     for UpdateAdminAccounts<'me, 'info>
 {
@@ -27003,7 +28412,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ADMIN_IX_ACCOUNTS_LEN]>
     }
 }
 pub const UPDATE_ADMIN_IX_DISCM: [u8; 8] = [161, 176, 40, 213, 60, 184, 179, 228];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateAdminIxArgs {
     pub admin: Pubkey,
@@ -27021,13 +28432,10 @@ impl UpdateAdminIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_ADMIN_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_ADMIN_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_ADMIN_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateAdminIxArgs::deserialize(&mut reader)?))
     }
@@ -27168,6 +28576,12 @@ impl From<UpdateWhitelistMintKeys> for [AccountMeta; UPDATE_WHITELIST_MINT_IX_AC
         ]
     }
 }
+impl From<UpdateWhitelistMintKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateWhitelistMintKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN]> for UpdateWhitelistMintKeys {
     fn from(pubkeys: [Pubkey; UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -27183,6 +28597,13 @@ impl<'info> From<UpdateWhitelistMintAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateWhitelistMintAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateWhitelistMintAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_WHITELIST_MINT_IX_ACCOUNTS_LEN]>
     for UpdateWhitelistMintAccounts<'me, 'info>
 {
@@ -27194,7 +28615,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_WHITELIST_MINT_IX_ACCOUNT
     }
 }
 pub const UPDATE_WHITELIST_MINT_IX_DISCM: [u8; 8] = [161, 15, 162, 19, 148, 120, 144, 151];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateWhitelistMintIxArgs {
     pub whitelist_mint: Pubkey,
@@ -27212,13 +28635,10 @@ impl UpdateWhitelistMintIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_WHITELIST_MINT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_WHITELIST_MINT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_WHITELIST_MINT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateWhitelistMintIxArgs::deserialize(&mut reader)?))
     }
@@ -27359,6 +28779,12 @@ impl From<UpdateDiscountMintKeys> for [AccountMeta; UPDATE_DISCOUNT_MINT_IX_ACCO
         ]
     }
 }
+impl From<UpdateDiscountMintKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateDiscountMintKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN]> for UpdateDiscountMintKeys {
     fn from(pubkeys: [Pubkey; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -27374,6 +28800,13 @@ impl<'info> From<UpdateDiscountMintAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateDiscountMintAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateDiscountMintAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS_LEN]>
     for UpdateDiscountMintAccounts<'me, 'info>
 {
@@ -27385,7 +28818,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DISCOUNT_MINT_IX_ACCOUNTS
     }
 }
 pub const UPDATE_DISCOUNT_MINT_IX_DISCM: [u8; 8] = [32, 252, 122, 211, 66, 31, 47, 241];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateDiscountMintIxArgs {
     pub discount_mint: Pubkey,
@@ -27403,13 +28838,10 @@ impl UpdateDiscountMintIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_DISCOUNT_MINT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_DISCOUNT_MINT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_DISCOUNT_MINT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateDiscountMintIxArgs::deserialize(&mut reader)?))
     }
@@ -27550,6 +28982,12 @@ impl From<UpdateExchangeStatusKeys> for [AccountMeta; UPDATE_EXCHANGE_STATUS_IX_
         ]
     }
 }
+impl From<UpdateExchangeStatusKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateExchangeStatusKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN]> for UpdateExchangeStatusKeys {
     fn from(pubkeys: [Pubkey; UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -27565,6 +29003,13 @@ impl<'info> From<UpdateExchangeStatusAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateExchangeStatusAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateExchangeStatusAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_EXCHANGE_STATUS_IX_ACCOUNTS_LEN]>
     for UpdateExchangeStatusAccounts<'me, 'info>
 {
@@ -27576,7 +29021,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_EXCHANGE_STATUS_IX_ACCOUN
     }
 }
 pub const UPDATE_EXCHANGE_STATUS_IX_DISCM: [u8; 8] = [83, 160, 252, 250, 129, 116, 49, 223];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateExchangeStatusIxArgs {
     pub exchange_status: u8,
@@ -27594,13 +29041,10 @@ impl UpdateExchangeStatusIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_EXCHANGE_STATUS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_EXCHANGE_STATUS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_EXCHANGE_STATUS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateExchangeStatusIxArgs::deserialize(&mut reader)?))
     }
@@ -27743,6 +29187,12 @@ impl From<UpdatePerpAuctionDurationKeys>
         ]
     }
 }
+impl From<UpdatePerpAuctionDurationKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdatePerpAuctionDurationKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN]>
     for UpdatePerpAuctionDurationKeys
 {
@@ -27760,6 +29210,13 @@ impl<'info> From<UpdatePerpAuctionDurationAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdatePerpAuctionDurationAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdatePerpAuctionDurationAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_AUCTION_DURATION_IX_ACCOUNTS_LEN]>
     for UpdatePerpAuctionDurationAccounts<'me, 'info>
 {
@@ -27771,7 +29228,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_PERP_AUCTION_DURATION_IX_
     }
 }
 pub const UPDATE_PERP_AUCTION_DURATION_IX_DISCM: [u8; 8] = [126, 110, 52, 174, 30, 206, 215, 90];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePerpAuctionDurationIxArgs {
     pub min_perp_auction_duration: u8,
@@ -27789,13 +29248,10 @@ impl UpdatePerpAuctionDurationIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_PERP_AUCTION_DURATION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PERP_AUCTION_DURATION_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_PERP_AUCTION_DURATION_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePerpAuctionDurationIxArgs::deserialize(
             &mut reader,
@@ -27940,6 +29396,12 @@ impl From<UpdateSpotAuctionDurationKeys>
         ]
     }
 }
+impl From<UpdateSpotAuctionDurationKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateSpotAuctionDurationKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN]>
     for UpdateSpotAuctionDurationKeys
 {
@@ -27957,6 +29419,13 @@ impl<'info> From<UpdateSpotAuctionDurationAccounts<'_, 'info>>
         [accounts.admin.clone(), accounts.state.clone()]
     }
 }
+impl<'info> From<UpdateSpotAuctionDurationAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateSpotAuctionDurationAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_AUCTION_DURATION_IX_ACCOUNTS_LEN]>
     for UpdateSpotAuctionDurationAccounts<'me, 'info>
 {
@@ -27968,7 +29437,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_SPOT_AUCTION_DURATION_IX_
     }
 }
 pub const UPDATE_SPOT_AUCTION_DURATION_IX_DISCM: [u8; 8] = [182, 178, 203, 72, 187, 143, 157, 107];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateSpotAuctionDurationIxArgs {
     pub default_spot_auction_duration: u8,
@@ -27986,13 +29457,10 @@ impl UpdateSpotAuctionDurationIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_SPOT_AUCTION_DURATION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_SPOT_AUCTION_DURATION_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_SPOT_AUCTION_DURATION_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateSpotAuctionDurationIxArgs::deserialize(
             &mut reader,
@@ -28177,6 +29645,13 @@ impl From<AdminRemoveInsuranceFundStakeKeys>
         ]
     }
 }
+impl From<AdminRemoveInsuranceFundStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: AdminRemoveInsuranceFundStakeKeys) -> Self {
+        let accounts: [AccountMeta; ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] =
+            keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for AdminRemoveInsuranceFundStakeKeys
 {
@@ -28207,6 +29682,13 @@ impl<'info> From<AdminRemoveInsuranceFundStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<AdminRemoveInsuranceFundStakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: AdminRemoveInsuranceFundStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>;
+            ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_ACCOUNTS_LEN]>
     for AdminRemoveInsuranceFundStakeAccounts<'me, 'info>
 {
@@ -28226,7 +29708,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADMIN_REMOVE_INSURANCE_FUND_STAK
 }
 pub const ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM: [u8; 8] =
     [35, 13, 111, 220, 103, 217, 174, 115];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdminRemoveInsuranceFundStakeIxArgs {
     pub market_index: u16,
@@ -28245,13 +29729,10 @@ impl AdminRemoveInsuranceFundStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ADMIN_REMOVE_INSURANCE_FUND_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(AdminRemoveInsuranceFundStakeIxArgs::deserialize(
             &mut reader,
@@ -1,6 +1,805 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhoenixV1FulfillmentConfig {
+    pub pubkey: Pubkey,
+    pub phoenix_program_id: Pubkey,
+    pub phoenix_log_authority: Pubkey,
+    pub phoenix_market: Pubkey,
+    pub phoenix_base_vault: Pubkey,
+    pub phoenix_quote_vault: Pubkey,
+    pub market_index: u16,
+    pub fulfillment_type: SpotFulfillmentType,
+    pub status: SpotFulfillmentConfigStatus,
+    pub padding: [u8; 4],
+}
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_LEN: usize = 200;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SerumV3FulfillmentConfig {
+    pub pubkey: Pubkey,
+    pub serum_program_id: Pubkey,
+    pub serum_market: Pubkey,
+    pub serum_request_queue: Pubkey,
+    pub serum_event_queue: Pubkey,
+    pub serum_bids: Pubkey,
+    pub serum_asks: Pubkey,
+    pub serum_base_vault: Pubkey,
+    pub serum_quote_vault: Pubkey,
+    pub serum_open_orders: Pubkey,
+    pub serum_signer_nonce: u64,
+    pub market_index: u16,
+    pub fulfillment_type: SpotFulfillmentType,
+    pub status: SpotFulfillmentConfigStatus,
+    pub padding: [u8; 4],
+}
+pub const SERUM_V3_FULFILLMENT_CONFIG_LEN: usize = 336;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundStake {
+    pub authority: Pubkey,
+    pub if_shares: u128,
+    pub last_withdraw_request_shares: u128,
+    pub if_base: u128,
+    pub last_valid_ts: i64,
+    pub last_withdraw_request_value: u64,
+    pub last_withdraw_request_ts: i64,
+    pub cost_basis: i64,
+    pub market_index: u16,
+    pub padding: [u8; 14],
+}
+pub const INSURANCE_FUND_STAKE_LEN: usize = 128;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PerpMarket {
+    /// The perp market's address. It is a pda of the market index
+    pub pubkey: Pubkey,
+    /// The automated market maker
+    pub amm: AMM,
+    /// The market's pnl pool. When users settle negative pnl, the balance increases.
+    /// When users settle positive pnl, the balance decreases. Can not go negative.
+    pub pnl_pool: PoolBalance,
+    /// Encoded display name for the perp market e.g. SOL-PERP
+    pub name: [u8; 32],
+    /// The perp market's claim on the insurance fund
+    pub insurance_claim: InsuranceClaim,
+    /// The max pnl imbalance before positive pnl asset weight is discounted
+    /// pnl imbalance is the difference between long and short pnl. When it's greater than 0,
+    /// the amm has negative pnl and the initial asset weight for positive pnl is discounted
+    /// precision = QUOTE_PRECISION
+    pub unrealized_pnl_max_imbalance: u64,
+    /// The ts when the market will be expired. Only set if market is in reduce only mode
+    pub expiry_ts: i64,
+    /// The price at which positions will be settled. Only set if market is expired
+    /// precision = PRICE_PRECISION
+    pub expiry_price: i64,
+    /// Every trade has a fill record id. This is the next id to be used
+    pub next_fill_record_id: u64,
+    /// Every funding rate update has a record id. This is the next id to be used
+    pub next_funding_rate_record_id: u64,
+    /// Every amm k updated has a record id. This is the next id to be used
+    pub next_curve_record_id: u64,
+    /// The initial margin fraction factor. Used to increase margin ratio for large positions
+    /// precision: MARGIN_PRECISION
+    pub imf_factor: u32,
+    /// The imf factor for unrealized pnl. Used to discount asset weight for large positive pnl
+    /// precision: MARGIN_PRECISION
+    pub unrealized_pnl_imf_factor: u32,
+    /// The fee the liquidator is paid for taking over perp position
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub liquidator_fee: u32,
+    /// The fee the insurance fund receives from liquidation
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub if_liquidation_fee: u32,
+    /// The margin ratio which determines how much collateral is required to open a position
+    /// e.g. margin ratio of .1 means a user must have $100 of total collateral to open a $1000 position
+    /// precision: MARGIN_PRECISION
+    pub margin_ratio_initial: u32,
+    /// The margin ratio which determines when a user will be liquidated
+    /// e.g. margin ratio of .05 means a user must have $50 of total collateral to maintain a $1000 position
+    /// else they will be liquidated
+    /// precision: MARGIN_PRECISION
+    pub margin_ratio_maintenance: u32,
+    /// The initial asset weight for positive pnl. Negative pnl always has an asset weight of 1
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub unrealized_pnl_initial_asset_weight: u32,
+    /// The maintenance asset weight for positive pnl. Negative pnl always has an asset weight of 1
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub unrealized_pnl_maintenance_asset_weight: u32,
+    /// number of users in a position (base)
+    pub number_of_users_with_base: u32,
+    /// number of users in a position (pnl) or pnl (quote)
+    pub number_of_users: u32,
+    pub market_index: u16,
+    /// Whether a market is active, reduce only, expired, etc
+    /// Affects whether users can open/close positions
+    pub status: MarketStatus,
+    /// Currently only Perpetual markets are supported
+    pub contract_type: ContractType,
+    /// The contract tier determines how much insurance a market can receive, with more speculative markets receiving less insurance
+    /// It also influences the order perp markets can be liquidated, with less speculative markets being liquidated first
+    pub contract_tier: ContractTier,
+    pub padding1: bool,
+    /// The spot market that pnl is settled in
+    pub quote_spot_market_index: u16,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub padding: [u8; 48],
+}
+pub const PERP_MARKET_LEN: usize = 1208;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpotMarket {
+    /// The address of the spot market. It is a pda of the market index
+    pub pubkey: Pubkey,
+    /// The oracle used to price the markets deposits/borrows
+    pub oracle: Pubkey,
+    /// The token mint of the market
+    pub mint: Pubkey,
+    /// The vault used to store the market's deposits
+    /// The amount in the vault should be equal to or greater than deposits - borrows
+    pub vault: Pubkey,
+    /// The encoded display name fo the market e.g. SOL
+    pub name: [u8; 32],
+    pub historical_oracle_data: HistoricalOracleData,
+    pub historical_index_data: HistoricalIndexData,
+    /// Revenue the protocol has collected in this markets token
+    /// e.g. for SOL-PERP, funds can be settled in usdc and will flow into the USDC revenue pool
+    pub revenue_pool: PoolBalance,
+    /// The fees collected from swaps between this market and the quote market
+    /// Is settled to the quote markets revenue pool
+    pub spot_fee_pool: PoolBalance,
+    /// Details on the insurance fund covering bankruptcies in this markets token
+    /// Covers bankruptcies for borrows with this markets token and perps settling in this markets token
+    pub insurance_fund: InsuranceFund,
+    /// The total spot fees collected for this market
+    /// precision: QUOTE_PRECISION
+    pub total_spot_fee: u128,
+    /// The sum of the scaled balances for deposits across users and pool balances
+    /// To convert to the deposit token amount, multiply by the cumulative deposit interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub deposit_balance: u128,
+    /// The sum of the scaled balances for borrows across users and pool balances
+    /// To convert to the borrow token amount, multiply by the cumulative borrow interest
+    /// precision: SPOT_BALANCE_PRECISION
+    pub borrow_balance: u128,
+    /// The cumulative interest earned by depositors
+    /// Used to calculate the deposit token amount from the deposit balance
+    /// precision: SPOT_CUMULATIVE_INTEREST_PRECISION
+    pub cumulative_deposit_interest: u128,
+    /// The cumulative interest earned by borrowers
+    /// Used to calculate the borrow token amount from the borrow balance
+    /// precision: SPOT_CUMULATIVE_INTEREST_PRECISION
+    pub cumulative_borrow_interest: u128,
+    /// The total socialized loss from borrows, in the mint's token
+    /// precision: token mint precision
+    pub total_social_loss: u128,
+    /// The total socialized loss from borrows, in the quote market's token
+    /// preicision: QUOTE_PRECISION
+    pub total_quote_social_loss: u128,
+    /// no withdraw limits/guards when deposits below this threshold
+    /// precision: token mint precision
+    pub withdraw_guard_threshold: u64,
+    /// The max amount of token deposits in this market
+    /// 0 if there is no limit
+    /// precision: token mint precision
+    pub max_token_deposits: u64,
+    /// 24hr average of deposit token amount
+    /// precision: token mint precision
+    pub deposit_token_twap: u64,
+    /// 24hr average of borrow token amount
+    /// precision: token mint precision
+    pub borrow_token_twap: u64,
+    /// 24hr average of utilization
+    /// which is borrow amount over token amount
+    /// precision: SPOT_UTILIZATION_PRECISION
+    pub utilization_twap: u64,
+    /// Last time the cumulative deposit and borrow interest was updated
+    pub last_interest_ts: u64,
+    /// Last time the deposit/borrow/utilization averages were updated
+    pub last_twap_ts: u64,
+    /// The time the market is set to expire. Only set if market is in reduce only mode
+    pub expiry_ts: i64,
+    /// Spot orders must be a multiple of the step size
+    /// precision: token mint precision
+    pub order_step_size: u64,
+    /// Spot orders must be a multiple of the tick size
+    /// precision: PRICE_PRECISION
+    pub order_tick_size: u64,
+    /// The minimum order size
+    /// precision: token mint precision
+    pub min_order_size: u64,
+    /// The maximum spot position size
+    /// if the limit is 0, there is no limit
+    /// precision: token mint precision
+    pub max_position_size: u64,
+    /// Every spot trade has a fill record id. This is the next id to use
+    pub next_fill_record_id: u64,
+    /// Every deposit has a deposit record id. This is the next id to use
+    pub next_deposit_record_id: u64,
+    /// The initial asset weight used to calculate a deposits contribution to a users initial total collateral
+    /// e.g. if the asset weight is .8, $100 of deposits contributes $80 to the users initial total collateral
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub initial_asset_weight: u32,
+    /// The maintenance asset weight used to calculate a deposits contribution to a users maintenance total collateral
+    /// e.g. if the asset weight is .9, $100 of deposits contributes $90 to the users maintenance total collateral
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub maintenance_asset_weight: u32,
+    /// The initial liability weight used to calculate a borrows contribution to a users initial margin requirement
+    /// e.g. if the liability weight is .9, $100 of borrows contributes $90 to the users initial margin requirement
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub initial_liability_weight: u32,
+    /// The maintenance liability weight used to calculate a borrows contribution to a users maintenance margin requirement
+    /// e.g. if the liability weight is .8, $100 of borrows contributes $80 to the users maintenance margin requirement
+    /// precision: SPOT_WEIGHT_PRECISION
+    pub maintenance_liability_weight: u32,
+    /// The initial margin fraction factor. Used to increase liability weight/decrease asset weight for large positions
+    /// precision: MARGIN_PRECISION
+    pub imf_factor: u32,
+    /// The fee the liquidator is paid for taking over borrow/deposit
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub liquidator_fee: u32,
+    /// The fee the insurance fund receives from liquidation
+    /// precision: LIQUIDATOR_FEE_PRECISION
+    pub if_liquidation_fee: u32,
+    /// The optimal utilization rate for this market.
+    /// Used to determine the markets borrow rate
+    /// precision: SPOT_UTILIZATION_PRECISION
+    pub optimal_utilization: u32,
+    /// The borrow rate for this market when the market has optimal utilization
+    /// precision: SPOT_RATE_PRECISION
+    pub optimal_borrow_rate: u32,
+    /// The borrow rate for this market when the market has 1000 utilization
+    /// precision: SPOT_RATE_PRECISION
+    pub max_borrow_rate: u32,
+    /// The market's token mint's decimals. To from decimals to a precision, 10^decimals
+    pub decimals: u32,
+    pub market_index: u16,
+    /// Whether or not spot trading is enabled
+    pub orders_enabled: bool,
+    pub oracle_source: OracleSource,
+    pub status: MarketStatus,
+    /// The asset tier affects how a deposit can be used as collateral and the priority for a borrow being liquidated
+    pub asset_tier: AssetTier,
+    pub padding1: [u8; 6],
+    /// For swaps, the amount of token loaned out in the begin_swap ix
+    /// precision: token mint precision
+    pub flash_loan_amount: u64,
+    /// For swaps, the amount in the users token account in the begin_swap ix
+    /// Used to calculate how much of the token left the system in end_swap ix
+    /// precision: token mint precision
+    pub flash_loan_initial_token_amount: u64,
+    /// The total fees received from swaps
+    /// precision: token mint precision
+    pub total_swap_fee: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub padding: [u8; 56],
+}
+pub const SPOT_MARKET_LEN: usize = 768;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub admin: Pubkey,
+    pub whitelist_mint: Pubkey,
+    pub discount_mint: Pubkey,
+    pub signer: Pubkey,
+    pub srm_vault: Pubkey,
+    pub perp_fee_structure: FeeStructure,
+    pub spot_fee_structure: FeeStructure,
+    pub oracle_guard_rails: OracleGuardRails,
+    pub number_of_authorities: u64,
+    pub number_of_sub_accounts: u64,
+    pub lp_cooldown_time: u64,
+    pub liquidation_margin_buffer_ratio: u32,
+    pub settlement_duration: u16,
+    pub number_of_markets: u16,
+    pub number_of_spot_markets: u16,
+    pub signer_nonce: u8,
+    pub min_perp_auction_duration: u8,
+    pub default_market_order_time_in_force: u8,
+    pub default_spot_auction_duration: u8,
+    pub exchange_status: u8,
+    pub liquidation_duration: u8,
+    pub initial_pct_to_liquidate: u16,
+    pub padding: [u8; 14],
+}
+pub const STATE_LEN: usize = 984;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct User {
+    /// The owner/authority of the account
+    pub authority: Pubkey,
+    /// An addresses that can control the account on the authority's behalf. Has limited power, cant withdraw
+    pub delegate: Pubkey,
+    /// Encoded display name e.g. "toly"
+    pub name: [u8; 32],
+    /// The user's spot positions
+    pub spot_positions: [SpotPosition; 8],
+    /// The user's perp positions
+    pub perp_positions: [PerpPosition; 8],
+    /// The user's orders
+    pub orders: [Order; 32],
+    /// The last time the user added perp lp positions
+    pub last_add_perp_lp_shares_ts: i64,
+    /// The total values of deposits the user has made
+    /// precision: QUOTE_PRECISION
+    pub total_deposits: u64,
+    /// The total values of withdrawals the user has made
+    /// precision: QUOTE_PRECISION
+    pub total_withdraws: u64,
+    /// The total socialized loss the users has incurred upon the protocol
+    /// precision: QUOTE_PRECISION
+    pub total_social_loss: u64,
+    /// Fees (taker fees, maker rebate, referrer reward, filler reward) and pnl for perps
+    /// precision: QUOTE_PRECISION
+    pub settled_perp_pnl: i64,
+    /// Fees (taker fees, maker rebate, filler reward) for spot
+    /// precision: QUOTE_PRECISION
+    pub cumulative_spot_fees: i64,
+    /// Cumulative funding paid/received for perps
+    /// precision: QUOTE_PRECISION
+    pub cumulative_perp_funding: i64,
+    /// The amount of margin freed during liquidation. Used to force the liquidation to occur over a period of time
+    /// Defaults to zero when not being liquidated
+    /// precision: QUOTE_PRECISION
+    pub liquidation_margin_freed: u64,
+    /// The last slot a user was active. Used to determine if a user is idle
+    pub last_active_slot: u64,
+    /// Every user order has an order id. This is the next order id to be used
+    pub next_order_id: u32,
+    /// Custom max initial margin ratio for the user
+    pub max_margin_ratio: u32,
+    /// The next liquidation id to be used for user
+    pub next_liquidation_id: u16,
+    /// The sub account id for this user
+    pub sub_account_id: u16,
+    /// Whether the user is active, being liquidated or bankrupt
+    pub status: UserStatus,
+    /// Whether the user has enabled margin trading
+    pub is_margin_trading_enabled: bool,
+    /// User is idle if they haven't interacted with the protocol in 1 week and they have no orders, perp positions or borrows
+    /// Off-chain keeper bots can ignore users that are idle
+    pub idle: bool,
+    /// number of open orders
+    pub open_orders: u8,
+    /// Whether or not user has open order
+    pub has_open_order: bool,
+    /// number of open orders with auction
+    pub open_auctions: u8,
+    /// Whether or not user has open order with auction
+    pub has_open_auction: bool,
+    pub padding: [u8; 21],
+}
+pub const USER_LEN: usize = 4368;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UserStats {
+    /// The authority for all of a users sub accounts
+    pub authority: Pubkey,
+    /// The address that referred this user
+    pub referrer: Pubkey,
+    /// Stats on the fees paid by the user
+    pub fees: UserFees,
+    /// The timestamp of the next epoch
+    /// Epoch is used to limit referrer rewards earned in single epoch
+    pub next_epoch_ts: i64,
+    /// Rolling 30day maker volume for user
+    /// precision: QUOTE_PRECISION
+    pub maker_volume30d: u64,
+    /// Rolling 30day taker volume for user
+    /// precision: QUOTE_PRECISION
+    pub taker_volume30d: u64,
+    /// Rolling 30day filler volume for user
+    /// precision: QUOTE_PRECISION
+    pub filler_volume30d: u64,
+    /// last time the maker volume was updated
+    pub last_maker_volume30d_ts: i64,
+    /// last time the taker volume was updated
+    pub last_taker_volume30d_ts: i64,
+    /// last time the filler volume was updated
+    pub last_filler_volume30d_ts: i64,
+    /// The amount of tokens staked in the quote spot markets if
+    pub if_staked_quote_asset_amount: u64,
+    /// The current number of sub accounts
+    pub number_of_sub_accounts: u16,
+    /// The number of sub accounts created. Can be greater than the number of sub accounts if user
+    /// has deleted sub accounts
+    pub number_of_sub_accounts_created: u16,
+    /// Whether the user is a referrer. Sub account 0 can not be deleted if user is a referrer
+    pub is_referrer: bool,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub padding: [u8; 51],
+}
+pub const USER_STATS_LEN: usize = 232;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferrerName {
+    pub authority: Pubkey,
+    pub user: Pubkey,
+    pub user_stats: Pubkey,
+    pub name: [u8; 32],
+}
+pub const REFERRER_NAME_LEN: usize = 128;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NewUserRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub sub_account_id: u16,
+    pub name: [u8; 32],
+    pub referrer: Pubkey,
+}
+pub const NEW_USER_RECORD_LEN: usize = 138;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub direction: DepositDirection,
+    pub deposit_record_id: u64,
+    pub amount: u64,
+    pub market_index: u16,
+    pub oracle_price: i64,
+    pub market_deposit_balance: u128,
+    pub market_withdraw_balance: u128,
+    pub market_cumulative_deposit_interest: u128,
+    pub market_cumulative_borrow_interest: u128,
+    pub total_deposits_after: u64,
+    pub total_withdraws_after: u64,
+    pub explanation: DepositExplanation,
+    pub transfer_user: Option<Pubkey>,
+}
+impl DepositRecord {
+    pub const MIN_LEN: usize = 181;
+    /// Borsh-serialized length of this [`DepositRecord`]
+    pub fn serialized_len(&self) -> usize {
+        181 + if self.transfer_user.is_some() { 32 } else { 0 }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpotInterestRecord {
+    pub ts: i64,
+    pub market_index: u16,
+    pub deposit_balance: u128,
+    pub cumulative_deposit_interest: u128,
+    pub borrow_balance: u128,
+    pub cumulative_borrow_interest: u128,
+    pub optimal_utilization: u32,
+    pub optimal_borrow_rate: u32,
+    pub max_borrow_rate: u32,
+}
+pub const SPOT_INTEREST_RECORD_LEN: usize = 86;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FundingPaymentRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub funding_payment: i64,
+    pub base_asset_amount: i64,
+    pub user_last_cumulative_funding: i64,
+    pub amm_cumulative_funding_long: i128,
+    pub amm_cumulative_funding_short: i128,
+}
+pub const FUNDING_PAYMENT_RECORD_LEN: usize = 130;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FundingRateRecord {
+    pub ts: i64,
+    pub record_id: u64,
+    pub market_index: u16,
+    pub funding_rate: i64,
+    pub funding_rate_long: i128,
+    pub funding_rate_short: i128,
+    pub cumulative_funding_rate_long: i128,
+    pub cumulative_funding_rate_short: i128,
+    pub oracle_price_twap: i64,
+    pub mark_price_twap: u64,
+    pub period_revenue: i64,
+    pub base_asset_amount_with_amm: i128,
+    pub base_asset_amount_with_unsettled_lp: i128,
+}
+pub const FUNDING_RATE_RECORD_LEN: usize = 146;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CurveRecord {
+    pub ts: i64,
+    pub record_id: u64,
+    pub peg_multiplier_before: u128,
+    pub base_asset_reserve_before: u128,
+    pub quote_asset_reserve_before: u128,
+    pub sqrt_k_before: u128,
+    pub peg_multiplier_after: u128,
+    pub base_asset_reserve_after: u128,
+    pub quote_asset_reserve_after: u128,
+    pub sqrt_k_after: u128,
+    pub base_asset_amount_long: u128,
+    pub base_asset_amount_short: u128,
+    pub base_asset_amount_with_amm: i128,
+    pub total_fee: i128,
+    pub total_fee_minus_distributions: i128,
+    pub adjustment_cost: i128,
+    pub oracle_price: i64,
+    pub fill_record: u128,
+    pub number_of_users: u32,
+    pub market_index: u16,
+}
+pub const CURVE_RECORD_LEN: usize = 270;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub order: Order,
+}
+pub const ORDER_RECORD_LEN: usize = 136;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderActionRecord {
+    pub ts: i64,
+    pub action: OrderAction,
+    pub action_explanation: OrderActionExplanation,
+    pub market_index: u16,
+    pub market_type: MarketType,
+    pub filler: Option<Pubkey>,
+    pub filler_reward: Option<u64>,
+    pub fill_record_id: Option<u64>,
+    pub base_asset_amount_filled: Option<u64>,
+    pub quote_asset_amount_filled: Option<u64>,
+    pub taker_fee: Option<u64>,
+    pub maker_fee: Option<i64>,
+    pub referrer_reward: Option<u32>,
+    pub quote_asset_amount_surplus: Option<i64>,
+    pub spot_fulfillment_method_fee: Option<u64>,
+    pub taker: Option<Pubkey>,
+    pub taker_order_id: Option<u32>,
+    pub taker_order_direction: Option<PositionDirection>,
+    pub taker_order_base_asset_amount: Option<u64>,
+    pub taker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub taker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub maker: Option<Pubkey>,
+    pub maker_order_id: Option<u32>,
+    pub maker_order_direction: Option<PositionDirection>,
+    pub maker_order_base_asset_amount: Option<u64>,
+    pub maker_order_cumulative_base_asset_amount_filled: Option<u64>,
+    pub maker_order_cumulative_quote_asset_amount_filled: Option<u64>,
+    pub oracle_price: i64,
+}
+impl OrderActionRecord {
+    pub const MIN_LEN: usize = 43;
+    /// Borsh-serialized length of this [`OrderActionRecord`]
+    pub fn serialized_len(&self) -> usize {
+        43 + if self.filler.is_some() { 32 } else { 0 }
+            + if self.filler_reward.is_some() { 8 } else { 0 }
+            + if self.fill_record_id.is_some() { 8 } else { 0 }
+            + if self.base_asset_amount_filled.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.quote_asset_amount_filled.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.taker_fee.is_some() { 8 } else { 0 }
+            + if self.maker_fee.is_some() { 8 } else { 0 }
+            + if self.referrer_reward.is_some() { 4 } else { 0 }
+            + if self.quote_asset_amount_surplus.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.spot_fulfillment_method_fee.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.taker.is_some() { 32 } else { 0 }
+            + if self.taker_order_id.is_some() { 4 } else { 0 }
+            + if self.taker_order_direction.is_some() {
+                1
+            } else {
+                0
+            }
+            + if self.taker_order_base_asset_amount.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self
+                .taker_order_cumulative_base_asset_amount_filled
+                .is_some()
+            {
+                8
+            } else {
+                0
+            }
+            + if self
+                .taker_order_cumulative_quote_asset_amount_filled
+                .is_some()
+            {
+                8
+            } else {
+                0
+            }
+            + if self.maker.is_some() { 32 } else { 0 }
+            + if self.maker_order_id.is_some() { 4 } else { 0 }
+            + if self.maker_order_direction.is_some() {
+                1
+            } else {
+                0
+            }
+            + if self.maker_order_base_asset_amount.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self
+                .maker_order_cumulative_base_asset_amount_filled
+                .is_some()
+            {
+                8
+            } else {
+                0
+            }
+            + if self
+                .maker_order_cumulative_quote_asset_amount_filled
+                .is_some()
+            {
+                8
+            } else {
+                0
+            }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LPRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub action: LPAction,
+    pub n_shares: u64,
+    pub market_index: u16,
+    pub delta_base_asset_amount: i64,
+    pub delta_quote_asset_amount: i64,
+    pub pnl: i64,
+}
+pub const LP_RECORD_LEN: usize = 75;
+#[derive(
+    Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidationRecord {
+    pub ts: i64,
+    pub liquidation_type: LiquidationType,
+    pub user: Pubkey,
+    pub liquidator: Pubkey,
+    pub margin_requirement: u128,
+    pub total_collateral: i128,
+    pub margin_freed: u64,
+    pub liquidation_id: u16,
+    pub bankrupt: bool,
+    pub canceled_order_ids: Vec<u32>,
+    pub liquidate_perp: LiquidatePerpRecord,
+    pub liquidate_spot: LiquidateSpotRecord,
+    pub liquidate_borrow_for_perp_pnl: LiquidateBorrowForPerpPnlRecord,
+    pub liquidate_perp_pnl_for_deposit: LiquidatePerpPnlForDepositRecord,
+    pub perp_bankruptcy: PerpBankruptcyRecord,
+    pub spot_bankruptcy: SpotBankruptcyRecord,
+}
+impl LiquidationRecord {
+    pub const MIN_LEN: usize = 452;
+    /// Borsh-serialized length of this [`LiquidationRecord`]
+    pub fn serialized_len(&self) -> usize {
+        400 + self.canceled_order_ids.len() * 4 + self.perp_bankruptcy.serialized_len()
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SettlePnlRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub market_index: u16,
+    pub pnl: i128,
+    pub base_asset_amount: i64,
+    pub quote_asset_amount_after: i64,
+    pub quote_entry_amount: i64,
+    pub settle_price: i64,
+    pub explanation: SettlePnlExplanation,
+}
+pub const SETTLE_PNL_RECORD_LEN: usize = 91;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundRecord {
+    pub ts: i64,
+    pub spot_market_index: u16,
+    pub perp_market_index: u16,
+    pub user_if_factor: u32,
+    pub total_if_factor: u32,
+    pub vault_amount_before: u64,
+    pub insurance_vault_amount_before: u64,
+    pub total_if_shares_before: u128,
+    pub total_if_shares_after: u128,
+    pub amount: i64,
+}
+pub const INSURANCE_FUND_RECORD_LEN: usize = 76;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsuranceFundStakeRecord {
+    pub ts: i64,
+    pub user_authority: Pubkey,
+    pub action: StakeAction,
+    pub amount: u64,
+    pub market_index: u16,
+    pub insurance_vault_amount_before: u64,
+    pub if_shares_before: u128,
+    pub user_if_shares_before: u128,
+    pub total_if_shares_before: u128,
+    pub if_shares_after: u128,
+    pub user_if_shares_after: u128,
+    pub total_if_shares_after: u128,
+}
+pub const INSURANCE_FUND_STAKE_RECORD_LEN: usize = 155;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwapRecord {
+    pub ts: i64,
+    pub user: Pubkey,
+    pub amount_out: u64,
+    pub amount_in: u64,
+    pub out_market_index: u16,
+    pub in_market_index: u16,
+    pub out_oracle_price: i64,
+    pub in_oracle_price: i64,
+    pub fee: u64,
+}
+pub const SWAP_RECORD_LEN: usize = 84;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderParams {
     pub order_type: OrderType,
@@ -21,7 +820,37 @@ pub struct OrderParams {
     pub auction_start_price: Option<i64>,
     pub auction_end_price: Option<i64>,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl OrderParams {
+    pub const MIN_LEN: usize = 32;
+    /// Borsh-serialized length of this [`OrderParams`]
+    pub fn serialized_len(&self) -> usize {
+        32 + if self.max_ts.is_some() { 8 } else { 0 }
+            + if self.trigger_price.is_some() { 8 } else { 0 }
+            + if self.oracle_price_offset.is_some() {
+                4
+            } else {
+                0
+            }
+            + if self.auction_duration.is_some() {
+                1
+            } else {
+                0
+            }
+            + if self.auction_start_price.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.auction_end_price.is_some() {
+                8
+            } else {
+                0
+            }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModifyOrderParams {
     pub direction: Option<PositionDirection>,
@@ -39,7 +868,57 @@ pub struct ModifyOrderParams {
     pub auction_end_price: Option<i64>,
     pub policy: Option<ModifyOrderPolicy>,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl ModifyOrderParams {
+    pub const MIN_LEN: usize = 14;
+    /// Borsh-serialized length of this [`ModifyOrderParams`]
+    pub fn serialized_len(&self) -> usize {
+        14 + if self.direction.is_some() { 1 } else { 0 }
+            + if self.base_asset_amount.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.price.is_some() { 8 } else { 0 }
+            + if self.reduce_only.is_some() { 1 } else { 0 }
+            + if self.post_only.is_some() { 1 } else { 0 }
+            + if self.immediate_or_cancel.is_some() {
+                1
+            } else {
+                0
+            }
+            + if self.max_ts.is_some() { 8 } else { 0 }
+            + if self.trigger_price.is_some() { 8 } else { 0 }
+            + if self.trigger_condition.is_some() {
+                1
+            } else {
+                0
+            }
+            + if self.oracle_price_offset.is_some() {
+                4
+            } else {
+                0
+            }
+            + if self.auction_duration.is_some() {
+                1
+            } else {
+                0
+            }
+            + if self.auction_start_price.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.auction_end_price.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.policy.is_some() { 1 } else { 0 }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidatePerpRecord {
     pub market_index: u16,
@@ -53,7 +932,10 @@ pub struct LiquidatePerpRecord {
     pub liquidator_fee: u64,
     pub if_fee: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQUIDATE_PERP_RECORD_LEN: usize = 66;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateSpotRecord {
     pub asset_market_index: u16,
@@ -64,7 +946,10 @@ pub struct LiquidateSpotRecord {
     pub liability_transfer: u128,
     pub if_fee: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQUIDATE_SPOT_RECORD_LEN: usize = 60;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidateBorrowForPerpPnlRecord {
     pub perp_market_index: u16,
@@ -74,7 +959,10 @@ pub struct LiquidateBorrowForPerpPnlRecord {
     pub liability_price: i64,
     pub liability_transfer: u128,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQUIDATE_BORROW_FOR_PERP_PNL_RECORD_LEN: usize = 52;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidatePerpPnlForDepositRecord {
     pub perp_market_index: u16,
@@ -84,7 +972,10 @@ pub struct LiquidatePerpPnlForDepositRecord {
     pub asset_price: i64,
     pub asset_transfer: u128,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQUIDATE_PERP_PNL_FOR_DEPOSIT_RECORD_LEN: usize = 52;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerpBankruptcyRecord {
     pub market_index: u16,
@@ -94,7 +985,21 @@ pub struct PerpBankruptcyRecord {
     pub clawback_user_payment: Option<u128>,
     pub cumulative_funding_rate_delta: i128,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl PerpBankruptcyRecord {
+    pub const MIN_LEN: usize = 52;
+    /// Borsh-serialized length of this [`PerpBankruptcyRecord`]
+    pub fn serialized_len(&self) -> usize {
+        52 + if self.clawback_user.is_some() { 32 } else { 0 }
+            + if self.clawback_user_payment.is_some() {
+                16
+            } else {
+                0
+            }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpotBankruptcyRecord {
     pub market_index: u16,
@@ -102,123 +1007,255 @@ pub struct SpotBankruptcyRecord {
     pub if_payment: u128,
     pub cumulative_deposit_interest_delta: u128,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SPOT_BANKRUPTCY_RECORD_LEN: usize = 50;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HistoricalOracleData {
+    /// precision: PRICE_PRECISION
     pub last_oracle_price: i64,
+    /// precision: PRICE_PRECISION
     pub last_oracle_conf: u64,
     pub last_oracle_delay: i64,
+    /// precision: PRICE_PRECISION
     pub last_oracle_price_twap: i64,
+    /// precision: PRICE_PRECISION
     pub last_oracle_price_twap5min: i64,
     pub last_oracle_price_twap_ts: i64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const HISTORICAL_ORACLE_DATA_LEN: usize = 48;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HistoricalIndexData {
+    /// precision: PRICE_PRECISION
     pub last_index_bid_price: u64,
+    /// precision: PRICE_PRECISION
     pub last_index_ask_price: u64,
+    /// precision: PRICE_PRECISION
     pub last_index_price_twap: u64,
+    /// precision: PRICE_PRECISION
     pub last_index_price_twap5min: u64,
     pub last_index_price_twap_ts: i64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const HISTORICAL_INDEX_DATA_LEN: usize = 40;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsuranceClaim {
+    /// The amount of revenue last settled
+    /// Positive if funds left the perp market,
+    /// negative if funds were pulled into the perp market
+    /// precision: QUOTE_PRECISION
     pub revenue_withdraw_since_last_settle: i64,
+    /// The max amount of revenue that can be withdrawn per period
+    /// precision: QUOTE_PRECISION
     pub max_revenue_withdraw_per_period: u64,
+    /// The max amount of insurance that perp market can use to resolve bankruptcy and pnl deficits
+    /// precision: QUOTE_PRECISION
     pub quote_max_insurance: u64,
+    /// The amount of insurance that has been used to resolve bankruptcy and pnl deficits
+    /// precision: QUOTE_PRECISION
     pub quote_settled_insurance: u64,
+    /// The last time revenue was settled in/out of market
     pub last_revenue_withdraw_ts: i64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const INSURANCE_CLAIM_LEN: usize = 40;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PoolBalance {
+    /// To get the pool's token amount, you must multiply the scaled balance by the market's cumulative
+    /// deposit interest
+    /// precision: SPOT_BALANCE_PRECISION
     pub scaled_balance: u128,
+    /// The spot market the pool is for
     pub market_index: u16,
     pub padding: [u8; 6],
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const POOL_BALANCE_LEN: usize = 24;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AMM {
+    /// oracle price data public key
     pub oracle: Pubkey,
+    /// stores historically witnessed oracle data
     pub historical_oracle_data: HistoricalOracleData,
+    /// accumulated base asset amount since inception per lp share
     pub base_asset_amount_per_lp: i128,
+    /// accumulated quote asset amount since inception per lp share
     pub quote_asset_amount_per_lp: i128,
+    /// partition of fees from perp market trading moved from pnl settlements
     pub fee_pool: PoolBalance,
+    /// `x` reserves for constant product mm formula (x * y = k)
     pub base_asset_reserve: u128,
+    /// `y` reserves for constant product mm formula (x * y = k)
     pub quote_asset_reserve: u128,
+    /// determines how close the min/max base asset reserve sit vs base reserves
+    /// allow for decreasing slippage without increasing liquidity and v.v.
     pub concentration_coef: u128,
+    /// minimum base_asset_reserve allowed before AMM is unavailable
     pub min_base_asset_reserve: u128,
+    /// maximum base_asset_reserve allowed before AMM is unavailable
     pub max_base_asset_reserve: u128,
+    /// `sqrt(k)` in constant product mm formula (x * y = k). stored to avoid drift caused by integer math issues
     pub sqrt_k: u128,
+    /// normalizing numerical factor for y, its use offers lowest slippage in cp-curve when market is balanced
     pub peg_multiplier: u128,
+    /// y when market is balanced. stored to save computation
     pub terminal_quote_asset_reserve: u128,
+    /// tracks number of total longs in market (regardless of counterparty)
     pub base_asset_amount_long: i128,
+    /// tracks number of total shorts in market (regardless of counterparty)
     pub base_asset_amount_short: i128,
+    /// tracks net position (longs-shorts) in market with AMM as counterparty
     pub base_asset_amount_with_amm: i128,
+    /// tracks net position (longs-shorts) in market with LPs as counterparty
     pub base_asset_amount_with_unsettled_lp: i128,
+    /// max allowed open interest, blocks trades that breach this value
     pub max_open_interest: u128,
+    /// sum of all user's perp quote_asset_amount in market
     pub quote_asset_amount: i128,
+    /// sum of all long user's quote_entry_amount in market
     pub quote_entry_amount_long: i128,
+    /// sum of all short user's quote_entry_amount in market
     pub quote_entry_amount_short: i128,
+    /// sum of all long user's quote_break_even_amount in market
     pub quote_break_even_amount_long: i128,
+    /// sum of all short user's quote_break_even_amount in market
     pub quote_break_even_amount_short: i128,
+    /// total user lp shares of sqrt_k (protocol owned liquidity = sqrt_k - last_funding_rate)
     pub user_lp_shares: u128,
+    /// last funding rate in this perp market (unit is quote per base)
     pub last_funding_rate: i64,
+    /// last funding rate for longs in this perp market (unit is quote per base)
     pub last_funding_rate_long: i64,
+    /// last funding rate for shorts in this perp market (unit is quote per base)
     pub last_funding_rate_short: i64,
+    /// estimate of last 24h of funding rate perp market (unit is quote per base)
     pub last24h_avg_funding_rate: i64,
+    /// total fees collected by this perp market
     pub total_fee: i128,
+    /// total fees collected by the vAMM's bid/ask spread
     pub total_mm_fee: i128,
+    /// total fees collected by exchange fee schedule
     pub total_exchange_fee: u128,
+    /// total fees minus any recognized upnl and pool withdraws
     pub total_fee_minus_distributions: i128,
+    /// sum of all fees from fee pool withdrawn to revenue pool
     pub total_fee_withdrawn: u128,
+    /// all fees collected by market for liquidations
     pub total_liquidation_fee: u128,
+    /// accumulated funding rate for longs since inception in market
     pub cumulative_funding_rate_long: i128,
+    /// accumulated funding rate for shorts since inception in market
     pub cumulative_funding_rate_short: i128,
+    /// accumulated social loss paid by users since inception in market
     pub total_social_loss: u128,
+    /// transformed base_asset_reserve for users going long
     pub ask_base_asset_reserve: u128,
+    /// transformed quote_asset_reserve for users going long
     pub ask_quote_asset_reserve: u128,
+    /// transformed base_asset_reserve for users going short
     pub bid_base_asset_reserve: u128,
+    /// transformed quote_asset_reserve for users going short
     pub bid_quote_asset_reserve: u128,
+    /// the last seen oracle price partially shrunk toward the amm reserve price
+    /// precision: PRICE_PRECISION
     pub last_oracle_normalised_price: i64,
+    /// the gap between the oracle price and the reserve price = y * peg_multiplier / x
     pub last_oracle_reserve_price_spread_pct: i64,
+    /// average estimate of bid price over funding_period
+    /// precision: PRICE_PRECISION
     pub last_bid_price_twap: u64,
+    /// average estimate of ask price over funding_period
+    /// precision: PRICE_PRECISION
     pub last_ask_price_twap: u64,
+    /// average estimate of (bid+ask)/2 price over funding_period
+    /// precision: PRICE_PRECISION
     pub last_mark_price_twap: u64,
+    /// average estimate of (bid+ask)/2 price over FIVE_MINUTES
     pub last_mark_price_twap5min: u64,
+    /// the last blockchain slot the amm was updated
     pub last_update_slot: u64,
+    /// the pct size of the oracle confidence interval
+    /// precision: PERCENTAGE_PRECISION
     pub last_oracle_conf_pct: u64,
+    /// the total_fee_minus_distribution change since the last funding update
+    /// precision: QUOTE_PRECISION
     pub net_revenue_since_last_funding: i64,
+    /// the last funding rate update unix_timestamp
     pub last_funding_rate_ts: i64,
+    /// the peridocity of the funding rate updates
     pub funding_period: i64,
+    /// the base step size (increment) of orders
+    /// precision: BASE_PRECISION
     pub order_step_size: u64,
+    /// the price tick size of orders
+    /// precision: PRICE_PRECISION
     pub order_tick_size: u64,
+    /// the minimum base size of an order
+    /// precision: BASE_PRECISION
     pub min_order_size: u64,
+    /// the max base size a single user can have
+    /// precision: BASE_PRECISION
     pub max_position_size: u64,
+    /// estimated total of volume in market
+    /// QUOTE_PRECISION
     pub volume24h: u64,
+    /// the volume intensity of long fills against AMM
     pub long_intensity_volume: u64,
+    /// the volume intensity of short fills against AMM
     pub short_intensity_volume: u64,
+    /// the blockchain unix timestamp at the time of the last trade
     pub last_trade_ts: i64,
+    /// estimate of standard deviation of the fill (mark) prices
+    /// precision: PRICE_PRECISION
     pub mark_std: u64,
+    /// estimate of standard deviation of the oracle price at each update
+    /// precision: PRICE_PRECISION
     pub oracle_std: u64,
+    /// the last unix_timestamp the mark twap was updated
     pub last_mark_price_twap_ts: i64,
+    /// the minimum spread the AMM can quote. also used as step size for some spread logic increases.
     pub base_spread: u32,
+    /// the maximum spread the AMM can quote
     pub max_spread: u32,
+    /// the spread for asks vs the reserve price
     pub long_spread: u32,
+    /// the spread for bids vs the reserve price
     pub short_spread: u32,
+    /// the count intensity of long fills against AMM
     pub long_intensity_count: u32,
+    /// the count intensity of short fills against AMM
     pub short_intensity_count: u32,
+    /// the fraction of total available liquidity a single fill on the AMM can consume
     pub max_fill_reserve_fraction: u16,
+    /// the maximum slippage a single fill on the AMM can push
     pub max_slippage_ratio: u16,
+    /// the update intensity of AMM formulaic updates (adjusting k). 0-100
     pub curve_update_intensity: u8,
+    /// the jit intensity of AMM. larger intensity means larger participation in jit. 0 means no jit participation.
+    /// (0, 100] is intensity for protocol-owned AMM. (100, 200] is intensity for user LP-owned AMM.
     pub amm_jit_intensity: u8,
+    /// the oracle provider information. used to decode/scale the oracle public key
     pub oracle_source: OracleSource,
+    /// tracks whether the oracle was considered valid at the last AMM update
     pub last_oracle_valid: bool,
+    /// the target value for `base_asset_amount_per_lp`, used during AMM JIT with LP split
+    /// precision: BASE_PRECISION
     pub target_base_asset_amount_per_lp: i32,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub padding: [u8; 44],
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const AMM_LEN: usize = 936;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsuranceFund {
     pub vault: Pubkey,
@@ -231,19 +1268,28 @@ pub struct InsuranceFund {
     pub total_factor: u32,
     pub user_factor: u32,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const INSURANCE_FUND_LEN: usize = 112;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OracleGuardRails {
     pub price_divergence: PriceDivergenceGuardRails,
     pub validity: ValidityGuardRails,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORACLE_GUARD_RAILS_LEN: usize = 48;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PriceDivergenceGuardRails {
     pub mark_oracle_divergence_numerator: u64,
     pub mark_oracle_divergence_denominator: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const PRICE_DIVERGENCE_GUARD_RAILS_LEN: usize = 16;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidityGuardRails {
     pub slots_before_stale_for_amm: i64,
@@ -251,7 +1297,10 @@ pub struct ValidityGuardRails {
     pub confidence_interval_max_size: u64,
     pub too_volatile_ratio: i64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const VALIDITY_GUARD_RAILS_LEN: usize = 32;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeeStructure {
     pub fee_tiers: [FeeTier; 10],
@@ -259,7 +1308,10 @@ pub struct FeeStructure {
     pub referrer_reward_epoch_upper_bound: u64,
     pub flat_filler_fee: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const FEE_STRUCTURE_LEN: usize = 360;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeeTier {
     pub fee_numerator: u32,
@@ -271,147 +1323,303 @@ pub struct FeeTier {
     pub referee_fee_numerator: u32,
     pub referee_fee_denominator: u32,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const FEE_TIER_LEN: usize = 32;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderFillerRewardStructure {
     pub reward_numerator: u32,
     pub reward_denominator: u32,
     pub time_based_reward_lower_bound: u128,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORDER_FILLER_REWARD_STRUCTURE_LEN: usize = 24;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserFees {
+    /// Total taker fee paid
+    /// precision: QUOTE_PRECISION
     pub total_fee_paid: u64,
+    /// Total maker fee rebate
+    /// precision: QUOTE_PRECISION
     pub total_fee_rebate: u64,
+    /// Total discount from holding token
+    /// precision: QUOTE_PRECISION
     pub total_token_discount: u64,
+    /// Total discount from being referred
+    /// precision: QUOTE_PRECISION
     pub total_referee_discount: u64,
+    /// Total reward to referrer
+    /// precision: QUOTE_PRECISION
     pub total_referrer_reward: u64,
+    /// Total reward to referrer this epoch
+    /// precision: QUOTE_PRECISION
     pub current_epoch_referrer_reward: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const USER_FEES_LEN: usize = 48;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpotPosition {
+    /// The scaled balance of the position. To get the token amount, multiply by the cumulative deposit/borrow
+    /// interest of corresponding market.
+    /// precision: SPOT_BALANCE_PRECISION
     pub scaled_balance: u64,
+    /// How many spot bids the user has open
+    /// precision: token mint precision
     pub open_bids: i64,
+    /// How many spot asks the user has open
+    /// precision: token mint precision
     pub open_asks: i64,
+    /// The cumulative deposits/borrows a user has made into a market
+    /// precision: token mint precision
     pub cumulative_deposits: i64,
+    /// The market index of the corresponding spot market
     pub market_index: u16,
+    /// Whether the position is deposit or borrow
     pub balance_type: SpotBalanceType,
+    /// Number of open orders
     pub open_orders: u8,
     pub padding: [u8; 4],
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SPOT_POSITION_LEN: usize = 40;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerpPosition {
+    /// The perp market's last cumulative funding rate. Used to calculate the funding payment owed to user
+    /// precision: FUNDING_RATE_PRECISION
     pub last_cumulative_funding_rate: i64,
+    /// the size of the users perp position
+    /// precision: BASE_PRECISION
     pub base_asset_amount: i64,
+    /// Used to calculate the users pnl. Upon entry, is equal to base_asset_amount * avg entry price - fees
+    /// Updated when the user open/closes position or settles pnl. Includes fees/funding
+    /// precision: QUOTE_PRECISION
     pub quote_asset_amount: i64,
+    /// The amount of quote the user would need to exit their position at to break even
+    /// Updated when the user open/closes position or settles pnl. Includes fees/funding
+    /// precision: QUOTE_PRECISION
     pub quote_break_even_amount: i64,
+    /// The amount quote the user entered the position with. Equal to base asset amount * avg entry price
+    /// Updated when the user open/closes position. Excludes fees/funding
+    /// precision: QUOTE_PRECISION
     pub quote_entry_amount: i64,
+    /// The amount of open bids the user has in this perp market
+    /// precision: BASE_PRECISION
     pub open_bids: i64,
+    /// The amount of open asks the user has in this perp market
+    /// precision: BASE_PRECISION
     pub open_asks: i64,
+    /// The amount of pnl settled in this market since opening the position
+    /// precision: QUOTE_PRECISION
     pub settled_pnl: i64,
+    /// The number of lp (liquidity provider) shares the user has in this perp market
+    /// LP shares allow users to provide liquidity via the AMM
+    /// precision: BASE_PRECISION
     pub lp_shares: u64,
+    /// The last base asset amount per lp the amm had
+    /// Used to settle the users lp position
+    /// precision: BASE_PRECISION
     pub last_base_asset_amount_per_lp: i64,
+    /// The last quote asset amount per lp the amm had
+    /// Used to settle the users lp position
+    /// precision: QUOTE_PRECISION
     pub last_quote_asset_amount_per_lp: i64,
+    /// Settling LP position can lead to a small amount of base asset being left over smaller than step size
+    /// This records that remainder so it can be settled later on
+    /// precision: BASE_PRECISION
     pub remainder_base_asset_amount: i32,
+    /// The market index for the perp market
     pub market_index: u16,
+    /// The number of open orders
     pub open_orders: u8,
     pub padding: [u8; 1],
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const PERP_POSITION_LEN: usize = 96;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Order {
+    /// The slot the order was placed
     pub slot: u64,
+    /// The limit price for the order (can be 0 for market orders)
+    /// For orders with an auction, this price isn't used until the auction is complete
+    /// precision: PRICE_PRECISION
     pub price: u64,
+    /// The size of the order
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
     pub base_asset_amount: u64,
+    /// The amount of the order filled
+    /// precision for perps: BASE_PRECISION
+    /// precision for spot: token mint precision
     pub base_asset_amount_filled: u64,
+    /// The amount of quote filled for the order
+    /// precision: QUOTE_PRECISION
     pub quote_asset_amount_filled: u64,
+    /// At what price the order will be triggered. Only relevant for trigger orders
+    /// precision: PRICE_PRECISION
     pub trigger_price: u64,
+    /// The start price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
     pub auction_start_price: i64,
+    /// The end price for the auction. Only relevant for market/oracle orders
+    /// precision: PRICE_PRECISION
     pub auction_end_price: i64,
+    /// The time when the order will expire
     pub max_ts: i64,
+    /// If set, the order limit price is the oracle price + this offset
+    /// precision: PRICE_PRECISION
     pub oracle_price_offset: i32,
+    /// The id for the order. Each users has their own order id space
     pub order_id: u32,
+    /// The perp/spot market index
     pub market_index: u16,
+    /// Whether the order is open or unused
     pub status: OrderStatus,
+    /// The type of order
     pub order_type: OrderType,
+    /// Whether market is spot or perp
     pub market_type: MarketType,
+    /// User generated order id. Can make it easier to place/cancel orders
     pub user_order_id: u8,
+    /// What the users position was when the order was placed
     pub existing_position_direction: PositionDirection,
+    /// Whether the user is going long or short. LONG = bid, SHORT = ask
     pub direction: PositionDirection,
+    /// Whether the order is allowed to only reduce position size
     pub reduce_only: bool,
+    /// Whether the order must be a maker
     pub post_only: bool,
+    /// Whether the order must be canceled the same slot it is placed
     pub immediate_or_cancel: bool,
+    /// Whether the order is triggered above or below the trigger price. Only relevant for trigger orders
     pub trigger_condition: OrderTriggerCondition,
+    /// How many slots the auction lasts
     pub auction_duration: u8,
     pub padding: [u8; 3],
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORDER_LEN: usize = 96;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SwapDirection {
+    #[default]
     Add,
     Remove,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SWAP_DIRECTION_LEN: usize = 1;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ModifyOrderId {
     UserOrderId(u8),
     OrderId(u32),
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl ModifyOrderId {
+    pub const MIN_LEN: usize = 2;
+    /// Borsh-serialized length of this [`ModifyOrderId`]
+    pub fn serialized_len(&self) -> usize {
+        match self {
+            Self::UserOrderId(_) => 2,
+            Self::OrderId(_) => 5,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionDirection {
+    #[default]
     Long,
     Short,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const POSITION_DIRECTION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpotFulfillmentType {
+    #[default]
     SerumV3,
     Match,
     PhoenixV1,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SPOT_FULFILLMENT_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PostOnlyParam {
+    #[default]
     None,
     MustPostOnly,
     TryPostOnly,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const POST_ONLY_PARAM_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ModifyOrderPolicy {
+    #[default]
     TryModify,
     MustModify,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const MODIFY_ORDER_POLICY_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SwapReduceOnly {
+    #[default]
     In,
     Out,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SWAP_REDUCE_ONLY_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TwapPeriod {
+    #[default]
     FundingPeriod,
     FiveMin,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const TWAP_PERIOD_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LiquidationMultiplierType {
+    #[default]
     Discount,
     Premium,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQUIDATION_MULTIPLIER_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MarginRequirementType {
+    #[default]
     Initial,
     Maintenance,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const MARGIN_REQUIREMENT_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OracleValidity {
+    #[default]
     Invalid,
     TooVolatile,
     TooUncertain,
@@ -420,9 +1628,13 @@ pub enum OracleValidity {
     StaleForAmm,
     Valid,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORACLE_VALIDITY_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DriftAction {
+    #[default]
     UpdateFunding,
     SettlePnl,
     TriggerOrder,
@@ -433,39 +1645,59 @@ pub enum DriftAction {
     UpdateTwap,
     UpdateAmmCurve,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const DRIFT_ACTION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionUpdateType {
+    #[default]
     Open,
     Increase,
     Reduce,
     Close,
     Flip,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const POSITION_UPDATE_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DepositExplanation {
+    #[default]
     None,
     Transfer,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const DEPOSIT_EXPLANATION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DepositDirection {
+    #[default]
     Deposit,
     Withdraw,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const DEPOSIT_DIRECTION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderAction {
+    #[default]
     Place,
     Cancel,
     Fill,
     Trigger,
     Expire,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORDER_ACTION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderActionExplanation {
+    #[default]
     None,
     InsufficientFreeCollateral,
     OraclePriceBreachedLimitPrice,
@@ -485,16 +1717,24 @@ pub enum OrderActionExplanation {
     OrderFilledWithAmmJitLpSplit,
     OrderFilledWithLpJit,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORDER_ACTION_EXPLANATION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LPAction {
+    #[default]
     AddLiquidity,
     RemoveLiquidity,
     SettleLiquidity,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LP_ACTION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LiquidationType {
+    #[default]
     LiquidatePerp,
     LiquidateSpot,
     LiquidateBorrowForPerpPnl,
@@ -502,35 +1742,61 @@ pub enum LiquidationType {
     PerpBankruptcy,
     SpotBankruptcy,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQUIDATION_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SettlePnlExplanation {
+    #[default]
     None,
     ExpiredPosition,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SETTLE_PNL_EXPLANATION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StakeAction {
+    #[default]
     Stake,
     UnstakeRequest,
     UnstakeCancelRequest,
     Unstake,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const STAKE_ACTION_LEN: usize = 1;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PerpFulfillmentMethod {
     Amm(Option<u64>),
     Match(Pubkey, u16),
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl PerpFulfillmentMethod {
+    pub const MIN_LEN: usize = 2;
+    /// Borsh-serialized length of this [`PerpFulfillmentMethod`]
+    pub fn serialized_len(&self) -> usize {
+        match self {
+            Self::Amm(f0) => 2 + if f0.is_some() { 8 } else { 0 },
+            Self::Match(_, _) => 35,
+        }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpotFulfillmentMethod {
+    #[default]
     ExternalMarket,
     Match,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SPOT_FULFILLMENT_METHOD_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OracleSource {
+    #[default]
     Pyth,
     Switchboard,
     QuoteAsset,
@@ -538,9 +1804,13 @@ pub enum OracleSource {
     Pyth1M,
     PythStableCoin,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORACLE_SOURCE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MarketStatus {
+    #[default]
     Initialized,
     Active,
     FundingPaused,
@@ -551,52 +1821,80 @@ pub enum MarketStatus {
     Settlement,
     Delisted,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const MARKET_STATUS_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContractType {
+    #[default]
     Perpetual,
     Future,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const CONTRACT_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContractTier {
+    #[default]
     A,
     B,
     C,
     Speculative,
     Isolated,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const CONTRACT_TIER_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AMMLiquiditySplit {
+    #[default]
     ProtocolOwned,
     LpOwned,
     Shared,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const AMM_LIQUIDITY_SPLIT_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpotBalanceType {
+    #[default]
     Deposit,
     Borrow,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SPOT_BALANCE_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpotFulfillmentConfigStatus {
+    #[default]
     Enabled,
     Disabled,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const SPOT_FULFILLMENT_CONFIG_STATUS_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AssetTier {
+    #[default]
     Collateral,
     Protected,
     Cross,
     Isolated,
     Unlisted,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ASSET_TIER_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExchangeStatus {
+    #[default]
     DepositPaused,
     WithdrawPaused,
     AmmPaused,
@@ -605,47 +1903,72 @@ pub enum ExchangeStatus {
     FundingPaused,
     SettlePnlPaused,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const EXCHANGE_STATUS_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UserStatus {
+    #[default]
     Active,
     BeingLiquidated,
     Bankrupt,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const USER_STATUS_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AssetType {
+    #[default]
     Base,
     Quote,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ASSET_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderStatus {
+    #[default]
     Init,
     Open,
     Filled,
     Canceled,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORDER_STATUS_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderType {
+    #[default]
     Market,
     Limit,
     TriggerMarket,
     TriggerLimit,
     Oracle,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORDER_TYPE_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderTriggerCondition {
+    #[default]
     Above,
     Below,
     TriggeredAbove,
     TriggeredBelow,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const ORDER_TRIGGER_CONDITION_LEN: usize = 1;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MarketType {
+    #[default]
     Spot,
     Perp,
 }
+pub const MARKET_TYPE_LEN: usize = 1;
@@ -0,0 +1,85 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
+}
+pub const GENERIC_ACCOUNT_ACCOUNT_DISCM: [u8; 8] = [10, 71, 68, 49, 51, 72, 147, 245];
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd)]
+pub struct GenericAccountAccount(pub GenericAccount);
+impl GenericAccountAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GENERIC_ACCOUNT_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                GENERIC_ACCOUNT_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(GenericAccount::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GENERIC_ACCOUNT_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+impl GenericAccountAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, GENERIC_ACCOUNT_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `data`
+    pub fn filter_data(data: &GenericType<u32, u64, 10>) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((8, borsh::to_vec(data)?))
+    }
+}
+#[cfg(feature = "client")]
+impl GenericAccountAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_data(data: &GenericType<u32, u64, 10>) -> std::io::Result<RpcFilterType> {
+        Self::filter_data(data).map(memcmp_filter)
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorGenericsAccountData {
+    GenericAccount(GenericAccountAccount),
+}
+impl AnchorGenericsAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&GENERIC_ACCOUNT_ACCOUNT_DISCM) {
+            return Ok(Self::GenericAccount(GenericAccountAccount::deserialize(
+                buf,
+            )?));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::GenericAccount(_) => &GENERIC_ACCOUNT_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GenericAccount(_) => "GenericAccount",
+        }
+    }
+}
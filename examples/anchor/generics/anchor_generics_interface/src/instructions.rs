@@ -0,0 +1,306 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorGenericsProgramIx {
+    Generic(GenericIxArgs),
+}
+impl AnchorGenericsProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            GENERIC_IX_DISCM => Ok(Self::Generic(GenericIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::Generic(args) => {
+                writer.write_all(&GENERIC_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const GENERIC_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct GenericAccounts<'me, 'info> {
+    pub generic_acc: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GenericKeys {
+    pub generic_acc: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<GenericAccounts<'_, '_>> for GenericKeys {
+    fn from(accounts: GenericAccounts) -> Self {
+        Self {
+            generic_acc: *accounts.generic_acc.key,
+            payer: *accounts.payer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<GenericKeys> for [AccountMeta; GENERIC_IX_ACCOUNTS_LEN] {
+    fn from(keys: GenericKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.generic_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<GenericKeys> for Vec<AccountMeta> {
+    fn from(keys: GenericKeys) -> Self {
+        let accounts: [AccountMeta; GENERIC_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; GENERIC_IX_ACCOUNTS_LEN]> for GenericKeys {
+    fn from(pubkeys: [Pubkey; GENERIC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            generic_acc: pubkeys[0],
+            payer: pubkeys[1],
+            system_program: pubkeys[2],
+        }
+    }
+}
+impl<'info> From<GenericAccounts<'_, 'info>> for [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN] {
+    fn from(accounts: GenericAccounts<'_, 'info>) -> Self {
+        [
+            accounts.generic_acc.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'info> From<GenericAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: GenericAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN]>
+    for GenericAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; GENERIC_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            generic_acc: &arr[0],
+            payer: &arr[1],
+            system_program: &arr[2],
+        }
+    }
+}
+pub const GENERIC_IX_DISCM: [u8; 8] = [63, 235, 150, 148, 7, 255, 185, 159];
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericIxArgs {
+    pub generic_field: GenericType<u32, u64, 10>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericIxData(pub GenericIxArgs);
+impl From<GenericIxArgs> for GenericIxData {
+    fn from(args: GenericIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl GenericIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != GENERIC_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                GENERIC_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(GenericIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&GENERIC_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn generic_ix_with_program_id(
+    program_id: Pubkey,
+    keys: GenericKeys,
+    args: GenericIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; GENERIC_IX_ACCOUNTS_LEN] = keys.into();
+    let data: GenericIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn generic_ix(keys: GenericKeys, args: GenericIxArgs) -> std::io::Result<Instruction> {
+    generic_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn generic_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: GenericAccounts<'_, '_>,
+    args: GenericIxArgs,
+) -> ProgramResult {
+    let keys: GenericKeys = accounts.into();
+    let ix = generic_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn generic_invoke(accounts: GenericAccounts<'_, '_>, args: GenericIxArgs) -> ProgramResult {
+    generic_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn generic_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: GenericAccounts<'_, '_>,
+    args: GenericIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: GenericKeys = accounts.into();
+    let ix = generic_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn generic_invoke_signed(
+    accounts: GenericAccounts<'_, '_>,
+    args: GenericIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    generic_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn generic_verify_account_keys(
+    accounts: GenericAccounts<'_, '_>,
+    keys: GenericKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.generic_acc.key, keys.generic_acc),
+        (*accounts.payer.key, keys.payer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn generic_verify_writable_privileges<'me, 'info>(
+    accounts: GenericAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.generic_acc, accounts.payer] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn generic_verify_signer_privileges<'me, 'info>(
+    accounts: GenericAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn generic_verify_account_privileges<'me, 'info>(
+    accounts: GenericAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    generic_verify_writable_privileges(accounts)?;
+    generic_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+/// Builds [`GenericKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GenericKeysBuilder {
+    pub generic_acc: Option<Pubkey>,
+    pub payer: Option<Pubkey>,
+    pub system_program: Option<Pubkey>,
+}
+impl GenericKeysBuilder {
+    pub fn generic_acc(mut self, generic_acc: Pubkey) -> Self {
+        self.generic_acc = Some(generic_acc);
+        self
+    }
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn build(self) -> Result<GenericKeys, MissingAccountError> {
+        let generic_acc = self.generic_acc.ok_or(MissingAccountError("generic_acc"))?;
+        let payer = self.payer.ok_or(MissingAccountError("payer"))?;
+        let system_program = self
+            .system_program
+            .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111"));
+        Ok(GenericKeys {
+            generic_acc,
+            payer,
+            system_program,
+        })
+    }
+}
+/// An account that a `*KeysBuilder` could not resolve and was not set
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MissingAccountError(pub &'static str);
+impl std::fmt::Display for MissingAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing account {}", self.0)
+    }
+}
+impl std::error::Error for MissingAccountError {}
@@ -0,0 +1,7 @@
+solana_program::declare_id!("Generics111111111111111111111111111111111111");
+pub mod accounts;
+pub use accounts::*;
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
@@ -0,0 +1,49 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericAccount {
+    pub data: GenericType<u32, u64, 10>,
+}
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GenericEnum<T, U, const N: usize> {
+    Unnamed(T, U),
+    Named { gen1: T, gen2: U },
+    Struct(GenericNested<T, U>),
+    Arr(#[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))] [T; N]),
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericNested<V, Z> {
+    pub gen1: V,
+    pub gen2: Z,
+}
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericType<T, U, const N: usize> {
+    pub gen1: T,
+    pub gen2: U,
+    pub gen3: GenericNested<u32, U>,
+    pub gen4: GenericNested<T, MyStruct>,
+    pub gen5: GenericNested<T, U>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub arr: [u8; N],
+    pub warr: WrappedU8Array<10>,
+    pub warr2: WrappedU8Array<N>,
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MyStruct {
+    pub some_field: u8,
+}
+pub const MY_STRUCT_LEN: usize = 1;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WrappedU8Array<const N: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
+    pub data: [u8; N],
+}
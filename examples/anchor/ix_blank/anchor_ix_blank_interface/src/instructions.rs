@@ -16,10 +16,10 @@ impl AnchorIxBlankProgramIx {
         reader.read_exact(&mut maybe_discm)?;
         match maybe_discm {
             BLANK_IX_IX_DISCM => Ok(Self::BlankIx),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -42,13 +42,10 @@ impl BlankIxIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != BLANK_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    BLANK_IX_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                BLANK_IX_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
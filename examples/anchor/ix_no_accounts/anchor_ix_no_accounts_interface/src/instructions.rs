@@ -19,10 +19,10 @@ impl AnchorIxNoAccountsProgramIx {
             NO_ACCOUNTS_IX_IX_DISCM => Ok(Self::NoAccountsIx(NoAccountsIxIxArgs::deserialize(
                 &mut reader,
             )?)),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -40,7 +40,9 @@ impl AnchorIxNoAccountsProgramIx {
     }
 }
 pub const NO_ACCOUNTS_IX_IX_DISCM: [u8; 8] = [195, 226, 242, 196, 225, 147, 32, 41];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoAccountsIxIxArgs {
     pub arg: u8,
@@ -58,13 +60,10 @@ impl NoAccountsIxIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != NO_ACCOUNTS_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_ACCOUNTS_IX_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                NO_ACCOUNTS_IX_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(NoAccountsIxIxArgs::deserialize(&mut reader)?))
     }
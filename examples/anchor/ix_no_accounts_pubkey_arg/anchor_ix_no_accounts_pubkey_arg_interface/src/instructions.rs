@@ -19,10 +19,10 @@ impl AnchorIxNoAccountsPubkeyArgProgramIx {
             NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM => Ok(Self::NoAccountsPubkeyArgIx(
                 NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?,
             )),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -40,7 +40,9 @@ impl AnchorIxNoAccountsPubkeyArgProgramIx {
     }
 }
 pub const NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM: [u8; 8] = [184, 145, 219, 5, 131, 41, 20, 197];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoAccountsPubkeyArgIxIxArgs {
     pub arg: Pubkey,
@@ -58,13 +60,10 @@ impl NoAccountsPubkeyArgIxIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?))
     }
@@ -18,10 +18,10 @@ impl AnchorIxNoArgsProgramIx {
         reader.read_exact(&mut maybe_discm)?;
         match maybe_discm {
             NO_ARGS_IX_IX_DISCM => Ok(Self::NoArgsIx),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -73,6 +73,12 @@ impl From<NoArgsIxKeys> for [AccountMeta; NO_ARGS_IX_IX_ACCOUNTS_LEN] {
         }]
     }
 }
+impl From<NoArgsIxKeys> for Vec<AccountMeta> {
+    fn from(keys: NoArgsIxKeys) -> Self {
+        let accounts: [AccountMeta; NO_ARGS_IX_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; NO_ARGS_IX_IX_ACCOUNTS_LEN]> for NoArgsIxKeys {
     fn from(pubkeys: [Pubkey; NO_ARGS_IX_IX_ACCOUNTS_LEN]) -> Self {
         Self { b: pubkeys[0] }
@@ -83,6 +89,12 @@ impl<'info> From<NoArgsIxAccounts<'_, 'info>> for [AccountInfo<'info>; NO_ARGS_I
         [accounts.b.clone()]
     }
 }
+impl<'info> From<NoArgsIxAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: NoArgsIxAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN]>
     for NoArgsIxAccounts<'me, 'info>
 {
@@ -99,13 +111,10 @@ impl NoArgsIxIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != NO_ARGS_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_ARGS_IX_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                NO_ARGS_IX_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -20,10 +20,10 @@ impl AnchorIxNoPrivilegeProgramIx {
             NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM => Ok(Self::NoPrivilegedAccountIx(
                 NoPrivilegedAccountIxIxArgs::deserialize(&mut reader)?,
             )),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -78,6 +78,12 @@ impl From<NoPrivilegedAccountIxKeys> for [AccountMeta; NO_PRIVILEGED_ACCOUNT_IX_
         }]
     }
 }
+impl From<NoPrivilegedAccountIxKeys> for Vec<AccountMeta> {
+    fn from(keys: NoPrivilegedAccountIxKeys) -> Self {
+        let accounts: [AccountMeta; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]> for NoPrivilegedAccountIxKeys {
     fn from(pubkeys: [Pubkey; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]) -> Self {
         Self { a: pubkeys[0] }
@@ -90,6 +96,13 @@ impl<'info> From<NoPrivilegedAccountIxAccounts<'_, 'info>>
         [accounts.a.clone()]
     }
 }
+impl<'info> From<NoPrivilegedAccountIxAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: NoPrivilegedAccountIxAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]>
     for NoPrivilegedAccountIxAccounts<'me, 'info>
 {
@@ -98,7 +111,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCO
     }
 }
 pub const NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM: [u8; 8] = [137, 46, 202, 239, 43, 151, 145, 176];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoPrivilegedAccountIxIxArgs {
     pub arg: u8,
@@ -116,13 +131,10 @@ impl NoPrivilegedAccountIxIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(NoPrivilegedAccountIxIxArgs::deserialize(&mut reader)?))
     }
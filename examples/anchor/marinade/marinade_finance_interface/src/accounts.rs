@@ -1,33 +1,14 @@
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
-pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct State {
-    pub msol_mint: Pubkey,
-    pub admin_authority: Pubkey,
-    pub operational_sol_account: Pubkey,
-    pub treasury_msol_account: Pubkey,
-    pub reserve_bump_seed: u8,
-    pub msol_mint_authority_bump_seed: u8,
-    pub rent_exempt_for_token_acc: u64,
-    pub reward_fee: Fee,
-    pub stake_system: StakeSystem,
-    pub validator_system: ValidatorSystem,
-    pub liq_pool: LiqPool,
-    pub available_reserve_balance: u64,
-    pub msol_supply: u64,
-    pub msol_price: u64,
-    pub circulating_ticket_count: u64,
-    pub circulating_ticket_balance: u64,
-    pub lent_from_reserve: u64,
-    pub min_deposit: u64,
-    pub min_withdraw: u64,
-    pub staking_sol_cap: u64,
-    pub emergency_cooling_down: u64,
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
 }
-#[derive(Clone, Debug, PartialEq)]
+pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct StateAccount(pub State);
 impl StateAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -36,13 +17,10 @@ impl StateAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != STATE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    STATE_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                STATE_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(State::deserialize(&mut reader)?))
     }
@@ -56,16 +34,188 @@ impl StateAccount {
         Ok(data)
     }
 }
-pub const TICKET_ACCOUNT_DATA_ACCOUNT_DISCM: [u8; 8] = [133, 77, 18, 98, 211, 1, 231, 3];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct TicketAccountData {
-    pub state_address: Pubkey,
-    pub beneficiary: Pubkey,
-    pub lamports_amount: u64,
-    pub created_epoch: u64,
+pub const STATE_ACCOUNT_LEN: usize = 576;
+impl StateAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, STATE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `msol_mint`
+    pub fn filter_msol_mint(msol_mint: &Pubkey) -> (usize, Vec<u8>) {
+        (8, msol_mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `admin_authority`
+    pub fn filter_admin_authority(admin_authority: &Pubkey) -> (usize, Vec<u8>) {
+        (40, admin_authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `operational_sol_account`
+    pub fn filter_operational_sol_account(operational_sol_account: &Pubkey) -> (usize, Vec<u8>) {
+        (72, operational_sol_account.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `treasury_msol_account`
+    pub fn filter_treasury_msol_account(treasury_msol_account: &Pubkey) -> (usize, Vec<u8>) {
+        (104, treasury_msol_account.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `reserve_bump_seed`
+    pub fn filter_reserve_bump_seed(reserve_bump_seed: u8) -> (usize, Vec<u8>) {
+        (136, reserve_bump_seed.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `msol_mint_authority_bump_seed`
+    pub fn filter_msol_mint_authority_bump_seed(
+        msol_mint_authority_bump_seed: u8,
+    ) -> (usize, Vec<u8>) {
+        (137, msol_mint_authority_bump_seed.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `rent_exempt_for_token_acc`
+    pub fn filter_rent_exempt_for_token_acc(rent_exempt_for_token_acc: u64) -> (usize, Vec<u8>) {
+        (138, rent_exempt_for_token_acc.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `reward_fee`
+    pub fn filter_reward_fee(reward_fee: &Fee) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((146, borsh::to_vec(reward_fee)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `stake_system`
+    pub fn filter_stake_system(stake_system: &StakeSystem) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((150, borsh::to_vec(stake_system)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `validator_system`
+    pub fn filter_validator_system(
+        validator_system: &ValidatorSystem,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((264, borsh::to_vec(validator_system)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `liq_pool`
+    pub fn filter_liq_pool(liq_pool: &LiqPool) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((385, borsh::to_vec(liq_pool)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `available_reserve_balance`
+    pub fn filter_available_reserve_balance(available_reserve_balance: u64) -> (usize, Vec<u8>) {
+        (496, available_reserve_balance.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `msol_supply`
+    pub fn filter_msol_supply(msol_supply: u64) -> (usize, Vec<u8>) {
+        (504, msol_supply.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `msol_price`
+    pub fn filter_msol_price(msol_price: u64) -> (usize, Vec<u8>) {
+        (512, msol_price.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `circulating_ticket_count`
+    pub fn filter_circulating_ticket_count(circulating_ticket_count: u64) -> (usize, Vec<u8>) {
+        (520, circulating_ticket_count.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `circulating_ticket_balance`
+    pub fn filter_circulating_ticket_balance(circulating_ticket_balance: u64) -> (usize, Vec<u8>) {
+        (528, circulating_ticket_balance.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lent_from_reserve`
+    pub fn filter_lent_from_reserve(lent_from_reserve: u64) -> (usize, Vec<u8>) {
+        (536, lent_from_reserve.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `min_deposit`
+    pub fn filter_min_deposit(min_deposit: u64) -> (usize, Vec<u8>) {
+        (544, min_deposit.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `min_withdraw`
+    pub fn filter_min_withdraw(min_withdraw: u64) -> (usize, Vec<u8>) {
+        (552, min_withdraw.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `staking_sol_cap`
+    pub fn filter_staking_sol_cap(staking_sol_cap: u64) -> (usize, Vec<u8>) {
+        (560, staking_sol_cap.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `emergency_cooling_down`
+    pub fn filter_emergency_cooling_down(emergency_cooling_down: u64) -> (usize, Vec<u8>) {
+        (568, emergency_cooling_down.to_le_bytes().to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl StateAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_msol_mint(msol_mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_msol_mint(msol_mint))
+    }
+    pub fn rpc_filter_admin_authority(admin_authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_admin_authority(admin_authority))
+    }
+    pub fn rpc_filter_operational_sol_account(operational_sol_account: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_operational_sol_account(
+            operational_sol_account,
+        ))
+    }
+    pub fn rpc_filter_treasury_msol_account(treasury_msol_account: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_treasury_msol_account(treasury_msol_account))
+    }
+    pub fn rpc_filter_reserve_bump_seed(reserve_bump_seed: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_reserve_bump_seed(reserve_bump_seed))
+    }
+    pub fn rpc_filter_msol_mint_authority_bump_seed(
+        msol_mint_authority_bump_seed: u8,
+    ) -> RpcFilterType {
+        memcmp_filter(Self::filter_msol_mint_authority_bump_seed(
+            msol_mint_authority_bump_seed,
+        ))
+    }
+    pub fn rpc_filter_rent_exempt_for_token_acc(rent_exempt_for_token_acc: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_rent_exempt_for_token_acc(
+            rent_exempt_for_token_acc,
+        ))
+    }
+    pub fn rpc_filter_reward_fee(reward_fee: &Fee) -> std::io::Result<RpcFilterType> {
+        Self::filter_reward_fee(reward_fee).map(memcmp_filter)
+    }
+    pub fn rpc_filter_stake_system(stake_system: &StakeSystem) -> std::io::Result<RpcFilterType> {
+        Self::filter_stake_system(stake_system).map(memcmp_filter)
+    }
+    pub fn rpc_filter_validator_system(
+        validator_system: &ValidatorSystem,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_validator_system(validator_system).map(memcmp_filter)
+    }
+    pub fn rpc_filter_liq_pool(liq_pool: &LiqPool) -> std::io::Result<RpcFilterType> {
+        Self::filter_liq_pool(liq_pool).map(memcmp_filter)
+    }
+    pub fn rpc_filter_available_reserve_balance(available_reserve_balance: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_available_reserve_balance(
+            available_reserve_balance,
+        ))
+    }
+    pub fn rpc_filter_msol_supply(msol_supply: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_msol_supply(msol_supply))
+    }
+    pub fn rpc_filter_msol_price(msol_price: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_msol_price(msol_price))
+    }
+    pub fn rpc_filter_circulating_ticket_count(circulating_ticket_count: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_circulating_ticket_count(
+            circulating_ticket_count,
+        ))
+    }
+    pub fn rpc_filter_circulating_ticket_balance(circulating_ticket_balance: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_circulating_ticket_balance(
+            circulating_ticket_balance,
+        ))
+    }
+    pub fn rpc_filter_lent_from_reserve(lent_from_reserve: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_lent_from_reserve(lent_from_reserve))
+    }
+    pub fn rpc_filter_min_deposit(min_deposit: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_min_deposit(min_deposit))
+    }
+    pub fn rpc_filter_min_withdraw(min_withdraw: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_min_withdraw(min_withdraw))
+    }
+    pub fn rpc_filter_staking_sol_cap(staking_sol_cap: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_staking_sol_cap(staking_sol_cap))
+    }
+    pub fn rpc_filter_emergency_cooling_down(emergency_cooling_down: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_emergency_cooling_down(emergency_cooling_down))
+    }
+}
+pub const TICKET_ACCOUNT_DATA_ACCOUNT_DISCM: [u8; 8] = [133, 77, 18, 98, 211, 1, 231, 3];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct TicketAccountDataAccount(pub TicketAccountData);
 impl TicketAccountDataAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -74,13 +224,10 @@ impl TicketAccountDataAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != TICKET_ACCOUNT_DATA_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    TICKET_ACCOUNT_DATA_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                TICKET_ACCOUNT_DATA_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(TicketAccountData::deserialize(&mut reader)?))
     }
@@ -94,3 +241,81 @@ impl TicketAccountDataAccount {
         Ok(data)
     }
 }
+pub const TICKET_ACCOUNT_DATA_ACCOUNT_LEN: usize = 88;
+impl TicketAccountDataAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, TICKET_ACCOUNT_DATA_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `state_address`
+    pub fn filter_state_address(state_address: &Pubkey) -> (usize, Vec<u8>) {
+        (8, state_address.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `beneficiary`
+    pub fn filter_beneficiary(beneficiary: &Pubkey) -> (usize, Vec<u8>) {
+        (40, beneficiary.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lamports_amount`
+    pub fn filter_lamports_amount(lamports_amount: u64) -> (usize, Vec<u8>) {
+        (72, lamports_amount.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `created_epoch`
+    pub fn filter_created_epoch(created_epoch: u64) -> (usize, Vec<u8>) {
+        (80, created_epoch.to_le_bytes().to_vec())
+    }
+}
+#[cfg(feature = "client")]
+impl TicketAccountDataAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_state_address(state_address: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_state_address(state_address))
+    }
+    pub fn rpc_filter_beneficiary(beneficiary: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_beneficiary(beneficiary))
+    }
+    pub fn rpc_filter_lamports_amount(lamports_amount: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_lamports_amount(lamports_amount))
+    }
+    pub fn rpc_filter_created_epoch(created_epoch: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_created_epoch(created_epoch))
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarinadeFinanceAccountData {
+    State(StateAccount),
+    TicketAccountData(TicketAccountDataAccount),
+}
+impl MarinadeFinanceAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&STATE_ACCOUNT_DISCM) {
+            return Ok(Self::State(StateAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&TICKET_ACCOUNT_DATA_ACCOUNT_DISCM) {
+            return Ok(Self::TicketAccountData(
+                TicketAccountDataAccount::deserialize(buf)?,
+            ));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::State(_) => &STATE_ACCOUNT_DISCM,
+            Self::TicketAccountData(_) => &TICKET_ACCOUNT_DATA_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::State(_) => "State",
+            Self::TicketAccountData(_) => "TicketAccountData",
+        }
+    }
+}
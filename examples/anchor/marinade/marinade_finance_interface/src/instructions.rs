@@ -98,10 +98,10 @@ impl MarinadeFinanceProgramIx {
             MERGE_STAKES_IX_DISCM => Ok(Self::MergeStakes(MergeStakesIxArgs::deserialize(
                 &mut reader,
             )?)),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -333,6 +333,12 @@ impl From<InitializeKeys> for [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<InitializeKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -373,6 +379,12 @@ impl<'info> From<InitializeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitializeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
     for InitializeAccounts<'me, 'info>
 {
@@ -395,7 +407,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const INITIALIZE_IX_DISCM: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeIxArgs {
     pub data: InitializeData,
@@ -413,13 +427,10 @@ impl InitializeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializeIxArgs::deserialize(&mut reader)?))
     }
@@ -577,6 +588,12 @@ impl From<ChangeAuthorityKeys> for [AccountMeta; CHANGE_AUTHORITY_IX_ACCOUNTS_LE
         ]
     }
 }
+impl From<ChangeAuthorityKeys> for Vec<AccountMeta> {
+    fn from(keys: ChangeAuthorityKeys) -> Self {
+        let accounts: [AccountMeta; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN]> for ChangeAuthorityKeys {
     fn from(pubkeys: [Pubkey; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -592,6 +609,13 @@ impl<'info> From<ChangeAuthorityAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.admin_authority.clone()]
     }
 }
+impl<'info> From<ChangeAuthorityAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ChangeAuthorityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN]>
     for ChangeAuthorityAccounts<'me, 'info>
 {
@@ -603,7 +627,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN
     }
 }
 pub const CHANGE_AUTHORITY_IX_DISCM: [u8; 8] = [50, 106, 66, 104, 99, 118, 145, 88];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeAuthorityIxArgs {
     pub data: ChangeAuthorityData,
@@ -621,13 +647,10 @@ impl ChangeAuthorityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CHANGE_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CHANGE_AUTHORITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CHANGE_AUTHORITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ChangeAuthorityIxArgs::deserialize(&mut reader)?))
     }
@@ -824,6 +847,12 @@ impl From<AddValidatorKeys> for [AccountMeta; ADD_VALIDATOR_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<AddValidatorKeys> for Vec<AccountMeta> {
+    fn from(keys: AddValidatorKeys) -> Self {
+        let accounts: [AccountMeta; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; ADD_VALIDATOR_IX_ACCOUNTS_LEN]> for AddValidatorKeys {
     fn from(pubkeys: [Pubkey; ADD_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -856,6 +885,12 @@ impl<'info> From<AddValidatorAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<AddValidatorAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: AddValidatorAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN]>
     for AddValidatorAccounts<'me, 'info>
 {
@@ -874,7 +909,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN]>
     }
 }
 pub const ADD_VALIDATOR_IX_DISCM: [u8; 8] = [250, 113, 53, 54, 141, 117, 215, 185];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddValidatorIxArgs {
     pub score: u32,
@@ -892,13 +929,10 @@ impl AddValidatorIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_VALIDATOR_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_VALIDATOR_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ADD_VALIDATOR_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(AddValidatorIxArgs::deserialize(&mut reader)?))
     }
@@ -1075,6 +1109,12 @@ impl From<RemoveValidatorKeys> for [AccountMeta; REMOVE_VALIDATOR_IX_ACCOUNTS_LE
         ]
     }
 }
+impl From<RemoveValidatorKeys> for Vec<AccountMeta> {
+    fn from(keys: RemoveValidatorKeys) -> Self {
+        let accounts: [AccountMeta; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN]> for RemoveValidatorKeys {
     fn from(pubkeys: [Pubkey; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1099,6 +1139,13 @@ impl<'info> From<RemoveValidatorAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RemoveValidatorAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RemoveValidatorAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN]>
     for RemoveValidatorAccounts<'me, 'info>
 {
@@ -1113,7 +1160,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN
     }
 }
 pub const REMOVE_VALIDATOR_IX_DISCM: [u8; 8] = [25, 96, 211, 155, 161, 14, 168, 188];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveValidatorIxArgs {
     pub index: u32,
@@ -1132,13 +1181,10 @@ impl RemoveValidatorIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_VALIDATOR_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_VALIDATOR_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REMOVE_VALIDATOR_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RemoveValidatorIxArgs::deserialize(&mut reader)?))
     }
@@ -1298,6 +1344,12 @@ impl From<SetValidatorScoreKeys> for [AccountMeta; SET_VALIDATOR_SCORE_IX_ACCOUN
         ]
     }
 }
+impl From<SetValidatorScoreKeys> for Vec<AccountMeta> {
+    fn from(keys: SetValidatorScoreKeys) -> Self {
+        let accounts: [AccountMeta; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN]> for SetValidatorScoreKeys {
     fn from(pubkeys: [Pubkey; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1318,6 +1370,13 @@ impl<'info> From<SetValidatorScoreAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<SetValidatorScoreAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetValidatorScoreAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN]>
     for SetValidatorScoreAccounts<'me, 'info>
 {
@@ -1330,7 +1389,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_
     }
 }
 pub const SET_VALIDATOR_SCORE_IX_DISCM: [u8; 8] = [101, 41, 206, 33, 216, 111, 25, 78];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetValidatorScoreIxArgs {
     pub index: u32,
@@ -1350,13 +1411,10 @@ impl SetValidatorScoreIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_VALIDATOR_SCORE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_VALIDATOR_SCORE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_VALIDATOR_SCORE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SetValidatorScoreIxArgs::deserialize(&mut reader)?))
     }
@@ -1498,6 +1556,12 @@ impl From<ConfigValidatorSystemKeys> for [AccountMeta; CONFIG_VALIDATOR_SYSTEM_I
         ]
     }
 }
+impl From<ConfigValidatorSystemKeys> for Vec<AccountMeta> {
+    fn from(keys: ConfigValidatorSystemKeys) -> Self {
+        let accounts: [AccountMeta; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]> for ConfigValidatorSystemKeys {
     fn from(pubkeys: [Pubkey; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1513,6 +1577,13 @@ impl<'info> From<ConfigValidatorSystemAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.manager_authority.clone()]
     }
 }
+impl<'info> From<ConfigValidatorSystemAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ConfigValidatorSystemAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]>
     for ConfigValidatorSystemAccounts<'me, 'info>
 {
@@ -1524,7 +1595,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOU
     }
 }
 pub const CONFIG_VALIDATOR_SYSTEM_IX_DISCM: [u8; 8] = [27, 90, 97, 209, 17, 115, 7, 40];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigValidatorSystemIxArgs {
     pub extra_runs: u32,
@@ -1542,13 +1615,10 @@ impl ConfigValidatorSystemIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CONFIG_VALIDATOR_SYSTEM_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CONFIG_VALIDATOR_SYSTEM_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CONFIG_VALIDATOR_SYSTEM_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ConfigValidatorSystemIxArgs::deserialize(&mut reader)?))
     }
@@ -1761,6 +1831,12 @@ impl From<DepositKeys> for [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<DepositKeys> for Vec<AccountMeta> {
+    fn from(keys: DepositKeys) -> Self {
+        let accounts: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]> for DepositKeys {
     fn from(pubkeys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1795,6 +1871,12 @@ impl<'info> From<DepositAccounts<'_, 'info>> for [AccountInfo<'info>; DEPOSIT_IX
         ]
     }
 }
+impl<'info> From<DepositAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
     for DepositAccounts<'me, 'info>
 {
@@ -1815,7 +1897,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
     }
 }
 pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositIxArgs {
     pub lamports: u64,
@@ -1833,13 +1917,10 @@ impl DepositIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEPOSIT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DepositIxArgs::deserialize(&mut reader)?))
     }
@@ -2101,6 +2182,12 @@ impl From<DepositStakeAccountKeys> for [AccountMeta; DEPOSIT_STAKE_ACCOUNT_IX_AC
         ]
     }
 }
+impl From<DepositStakeAccountKeys> for Vec<AccountMeta> {
+    fn from(keys: DepositStakeAccountKeys) -> Self {
+        let accounts: [AccountMeta; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for DepositStakeAccountKeys {
     fn from(pubkeys: [Pubkey; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2145,6 +2232,13 @@ impl<'info> From<DepositStakeAccountAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<DepositStakeAccountAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DepositStakeAccountAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
     for DepositStakeAccountAccounts<'me, 'info>
 {
@@ -2169,7 +2263,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNT
     }
 }
 pub const DEPOSIT_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [110, 130, 115, 41, 164, 102, 2, 59];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositStakeAccountIxArgs {
     pub validator_index: u32,
@@ -2187,13 +2283,10 @@ impl DepositStakeAccountIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEPOSIT_STAKE_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_STAKE_ACCOUNT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DepositStakeAccountIxArgs::deserialize(&mut reader)?))
     }
@@ -2420,6 +2513,12 @@ impl From<LiquidUnstakeKeys> for [AccountMeta; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<LiquidUnstakeKeys> for Vec<AccountMeta> {
+    fn from(keys: LiquidUnstakeKeys) -> Self {
+        let accounts: [AccountMeta; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]> for LiquidUnstakeKeys {
     fn from(pubkeys: [Pubkey; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2454,6 +2553,12 @@ impl<'info> From<LiquidUnstakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<LiquidUnstakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: LiquidUnstakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]>
     for LiquidUnstakeAccounts<'me, 'info>
 {
@@ -2473,7 +2578,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const LIQUID_UNSTAKE_IX_DISCM: [u8; 8] = [30, 30, 119, 240, 191, 227, 12, 16];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidUnstakeIxArgs {
     pub msol_amount: u64,
@@ -2491,13 +2598,10 @@ impl LiquidUnstakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != LIQUID_UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LIQUID_UNSTAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LIQUID_UNSTAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(LiquidUnstakeIxArgs::deserialize(&mut reader)?))
     }
@@ -2719,6 +2823,12 @@ impl From<AddLiquidityKeys> for [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<AddLiquidityKeys> for Vec<AccountMeta> {
+    fn from(keys: AddLiquidityKeys) -> Self {
+        let accounts: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]> for AddLiquidityKeys {
     fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2751,6 +2861,12 @@ impl<'info> From<AddLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<AddLiquidityAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: AddLiquidityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
     for AddLiquidityAccounts<'me, 'info>
 {
@@ -2769,7 +2885,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
     }
 }
 pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddLiquidityIxArgs {
     pub lamports: u64,
@@ -2787,13 +2905,10 @@ impl AddLiquidityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_LIQUIDITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ADD_LIQUIDITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(AddLiquidityIxArgs::deserialize(&mut reader)?))
     }
@@ -3022,6 +3137,12 @@ impl From<RemoveLiquidityKeys> for [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LE
         ]
     }
 }
+impl From<RemoveLiquidityKeys> for Vec<AccountMeta> {
+    fn from(keys: RemoveLiquidityKeys) -> Self {
+        let accounts: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]> for RemoveLiquidityKeys {
     fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3058,6 +3179,13 @@ impl<'info> From<RemoveLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RemoveLiquidityAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RemoveLiquidityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]>
     for RemoveLiquidityAccounts<'me, 'info>
 {
@@ -3078,7 +3206,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
     }
 }
 pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveLiquidityIxArgs {
     pub tokens: u64,
@@ -3096,13 +3226,10 @@ impl RemoveLiquidityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RemoveLiquidityIxArgs::deserialize(&mut reader)?))
     }
@@ -3266,6 +3393,12 @@ impl From<SetLpParamsKeys> for [AccountMeta; SET_LP_PARAMS_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<SetLpParamsKeys> for Vec<AccountMeta> {
+    fn from(keys: SetLpParamsKeys) -> Self {
+        let accounts: [AccountMeta; SET_LP_PARAMS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SET_LP_PARAMS_IX_ACCOUNTS_LEN]> for SetLpParamsKeys {
     fn from(pubkeys: [Pubkey; SET_LP_PARAMS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3281,6 +3414,12 @@ impl<'info> From<SetLpParamsAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.admin_authority.clone()]
     }
 }
+impl<'info> From<SetLpParamsAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetLpParamsAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN]>
     for SetLpParamsAccounts<'me, 'info>
 {
@@ -3292,7 +3431,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN]>
     }
 }
 pub const SET_LP_PARAMS_IX_DISCM: [u8; 8] = [227, 163, 242, 45, 79, 203, 106, 44];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetLpParamsIxArgs {
     pub min_fee: Fee,
@@ -3312,13 +3453,10 @@ impl SetLpParamsIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_LP_PARAMS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_LP_PARAMS_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_LP_PARAMS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SetLpParamsIxArgs::deserialize(&mut reader)?))
     }
@@ -3459,6 +3597,12 @@ impl From<ConfigMarinadeKeys> for [AccountMeta; CONFIG_MARINADE_IX_ACCOUNTS_LEN]
         ]
     }
 }
+impl From<ConfigMarinadeKeys> for Vec<AccountMeta> {
+    fn from(keys: ConfigMarinadeKeys) -> Self {
+        let accounts: [AccountMeta; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CONFIG_MARINADE_IX_ACCOUNTS_LEN]> for ConfigMarinadeKeys {
     fn from(pubkeys: [Pubkey; CONFIG_MARINADE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3474,6 +3618,12 @@ impl<'info> From<ConfigMarinadeAccounts<'_, 'info>>
         [accounts.state.clone(), accounts.admin_authority.clone()]
     }
 }
+impl<'info> From<ConfigMarinadeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ConfigMarinadeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN]>
     for ConfigMarinadeAccounts<'me, 'info>
 {
@@ -3485,7 +3635,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN]
     }
 }
 pub const CONFIG_MARINADE_IX_DISCM: [u8; 8] = [67, 3, 34, 114, 190, 185, 17, 62];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigMarinadeIxArgs {
     pub params: ConfigMarinadeParams,
@@ -3503,13 +3655,10 @@ impl ConfigMarinadeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CONFIG_MARINADE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CONFIG_MARINADE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CONFIG_MARINADE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ConfigMarinadeIxArgs::deserialize(&mut reader)?))
     }
@@ -3698,6 +3847,12 @@ impl From<OrderUnstakeKeys> for [AccountMeta; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<OrderUnstakeKeys> for Vec<AccountMeta> {
+    fn from(keys: OrderUnstakeKeys) -> Self {
+        let accounts: [AccountMeta; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]> for OrderUnstakeKeys {
     fn from(pubkeys: [Pubkey; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3728,6 +3883,12 @@ impl<'info> From<OrderUnstakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<OrderUnstakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: OrderUnstakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]>
     for OrderUnstakeAccounts<'me, 'info>
 {
@@ -3745,7 +3906,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const ORDER_UNSTAKE_IX_DISCM: [u8; 8] = [97, 167, 144, 107, 117, 190, 128, 36];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderUnstakeIxArgs {
     pub msol_amount: u64,
@@ -3763,13 +3926,10 @@ impl OrderUnstakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ORDER_UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ORDER_UNSTAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ORDER_UNSTAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(OrderUnstakeIxArgs::deserialize(&mut reader)?))
     }
@@ -3953,6 +4113,12 @@ impl From<ClaimKeys> for [AccountMeta; CLAIM_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<ClaimKeys> for Vec<AccountMeta> {
+    fn from(keys: ClaimKeys) -> Self {
+        let accounts: [AccountMeta; CLAIM_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CLAIM_IX_ACCOUNTS_LEN]> for ClaimKeys {
     fn from(pubkeys: [Pubkey; CLAIM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -3977,6 +4143,12 @@ impl<'info> From<ClaimAccounts<'_, 'info>> for [AccountInfo<'info>; CLAIM_IX_ACC
         ]
     }
 }
+impl<'info> From<ClaimAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ClaimAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN]>
     for ClaimAccounts<'me, 'info>
 {
@@ -4000,13 +4172,10 @@ impl ClaimIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CLAIM_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CLAIM_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CLAIM_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -4226,6 +4395,12 @@ impl From<StakeReserveKeys> for [AccountMeta; STAKE_RESERVE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<StakeReserveKeys> for Vec<AccountMeta> {
+    fn from(keys: StakeReserveKeys) -> Self {
+        let accounts: [AccountMeta; STAKE_RESERVE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; STAKE_RESERVE_IX_ACCOUNTS_LEN]> for StakeReserveKeys {
     fn from(pubkeys: [Pubkey; STAKE_RESERVE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -4268,6 +4443,12 @@ impl<'info> From<StakeReserveAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<StakeReserveAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: StakeReserveAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN]>
     for StakeReserveAccounts<'me, 'info>
 {
@@ -4291,7 +4472,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const STAKE_RESERVE_IX_DISCM: [u8; 8] = [87, 217, 23, 179, 205, 25, 113, 129];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeReserveIxArgs {
     pub validator_index: u32,
@@ -4309,13 +4492,10 @@ impl StakeReserveIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != STAKE_RESERVE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    STAKE_RESERVE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                STAKE_RESERVE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(StakeReserveIxArgs::deserialize(&mut reader)?))
     }
@@ -4555,6 +4735,12 @@ impl From<UpdateActiveKeys> for [AccountMeta; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<UpdateActiveKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateActiveKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]> for UpdateActiveKeys {
     fn from(pubkeys: [Pubkey; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -4595,6 +4781,12 @@ impl<'info> From<UpdateActiveAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateActiveAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateActiveAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]>
     for UpdateActiveAccounts<'me, 'info>
 {
@@ -4617,7 +4809,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const UPDATE_ACTIVE_IX_DISCM: [u8; 8] = [4, 67, 81, 64, 136, 245, 93, 152];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateActiveIxArgs {
     pub stake_index: u32,
@@ -4636,13 +4830,10 @@ impl UpdateActiveIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_ACTIVE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_ACTIVE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_ACTIVE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateActiveIxArgs::deserialize(&mut reader)?))
     }
@@ -4908,6 +5099,12 @@ impl From<UpdateDeactivatedKeys> for [AccountMeta; UPDATE_DEACTIVATED_IX_ACCOUNT
         ]
     }
 }
+impl From<UpdateDeactivatedKeys> for Vec<AccountMeta> {
+    fn from(keys: UpdateDeactivatedKeys) -> Self {
+        let accounts: [AccountMeta; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN]> for UpdateDeactivatedKeys {
     fn from(pubkeys: [Pubkey; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -4950,6 +5147,13 @@ impl<'info> From<UpdateDeactivatedAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UpdateDeactivatedAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UpdateDeactivatedAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN]>
     for UpdateDeactivatedAccounts<'me, 'info>
 {
@@ -4973,7 +5177,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_L
     }
 }
 pub const UPDATE_DEACTIVATED_IX_DISCM: [u8; 8] = [16, 232, 131, 115, 156, 100, 239, 50];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateDeactivatedIxArgs {
     pub stake_index: u32,
@@ -4991,13 +5197,10 @@ impl UpdateDeactivatedIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_DEACTIVATED_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_DEACTIVATED_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_DEACTIVATED_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateDeactivatedIxArgs::deserialize(&mut reader)?))
     }
@@ -5267,6 +5470,12 @@ impl From<DeactivateStakeKeys> for [AccountMeta; DEACTIVATE_STAKE_IX_ACCOUNTS_LE
         ]
     }
 }
+impl From<DeactivateStakeKeys> for Vec<AccountMeta> {
+    fn from(keys: DeactivateStakeKeys) -> Self {
+        let accounts: [AccountMeta; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN]> for DeactivateStakeKeys {
     fn from(pubkeys: [Pubkey; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -5309,6 +5518,13 @@ impl<'info> From<DeactivateStakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<DeactivateStakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DeactivateStakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN]>
     for DeactivateStakeAccounts<'me, 'info>
 {
@@ -5332,7 +5548,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN
     }
 }
 pub const DEACTIVATE_STAKE_IX_DISCM: [u8; 8] = [165, 158, 229, 97, 168, 220, 187, 225];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeactivateStakeIxArgs {
     pub stake_index: u32,
@@ -5351,13 +5569,10 @@ impl DeactivateStakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEACTIVATE_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEACTIVATE_STAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEACTIVATE_STAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DeactivateStakeIxArgs::deserialize(&mut reader)?))
     }
@@ -5574,6 +5789,12 @@ impl From<EmergencyUnstakeKeys> for [AccountMeta; EMERGENCY_UNSTAKE_IX_ACCOUNTS_
         ]
     }
 }
+impl From<EmergencyUnstakeKeys> for Vec<AccountMeta> {
+    fn from(keys: EmergencyUnstakeKeys) -> Self {
+        let accounts: [AccountMeta; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN]> for EmergencyUnstakeKeys {
     fn from(pubkeys: [Pubkey; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -5604,6 +5825,13 @@ impl<'info> From<EmergencyUnstakeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<EmergencyUnstakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: EmergencyUnstakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN]>
     for EmergencyUnstakeAccounts<'me, 'info>
 {
@@ -5621,7 +5849,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LE
     }
 }
 pub const EMERGENCY_UNSTAKE_IX_DISCM: [u8; 8] = [123, 69, 168, 195, 183, 213, 199, 214];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmergencyUnstakeIxArgs {
     pub stake_index: u32,
@@ -5640,13 +5870,10 @@ impl EmergencyUnstakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != EMERGENCY_UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    EMERGENCY_UNSTAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                EMERGENCY_UNSTAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(EmergencyUnstakeIxArgs::deserialize(&mut reader)?))
     }
@@ -5876,6 +6103,12 @@ impl From<MergeStakesKeys> for [AccountMeta; MERGE_STAKES_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<MergeStakesKeys> for Vec<AccountMeta> {
+    fn from(keys: MergeStakesKeys) -> Self {
+        let accounts: [AccountMeta; MERGE_STAKES_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; MERGE_STAKES_IX_ACCOUNTS_LEN]> for MergeStakesKeys {
     fn from(pubkeys: [Pubkey; MERGE_STAKES_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -5912,6 +6145,12 @@ impl<'info> From<MergeStakesAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<MergeStakesAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: MergeStakesAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN]>
     for MergeStakesAccounts<'me, 'info>
 {
@@ -5932,7 +6171,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN]>
     }
 }
 pub const MERGE_STAKES_IX_DISCM: [u8; 8] = [216, 36, 141, 225, 243, 78, 125, 237];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MergeStakesIxArgs {
     pub destination_stake_index: u32,
@@ -5952,13 +6193,10 @@ impl MergeStakesIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != MERGE_STAKES_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    MERGE_STAKES_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                MERGE_STAKES_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(MergeStakesIxArgs::deserialize(&mut reader)?))
     }
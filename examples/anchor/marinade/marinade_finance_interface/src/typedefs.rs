@@ -1,11 +1,55 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    pub msol_mint: Pubkey,
+    pub admin_authority: Pubkey,
+    pub operational_sol_account: Pubkey,
+    pub treasury_msol_account: Pubkey,
+    pub reserve_bump_seed: u8,
+    pub msol_mint_authority_bump_seed: u8,
+    pub rent_exempt_for_token_acc: u64,
+    pub reward_fee: Fee,
+    pub stake_system: StakeSystem,
+    pub validator_system: ValidatorSystem,
+    pub liq_pool: LiqPool,
+    pub available_reserve_balance: u64,
+    pub msol_supply: u64,
+    pub msol_price: u64,
+    pub circulating_ticket_count: u64,
+    pub circulating_ticket_balance: u64,
+    pub lent_from_reserve: u64,
+    pub min_deposit: u64,
+    pub min_withdraw: u64,
+    pub staking_sol_cap: u64,
+    pub emergency_cooling_down: u64,
+}
+pub const STATE_LEN: usize = 568;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TicketAccountData {
+    pub state_address: Pubkey,
+    pub beneficiary: Pubkey,
+    pub lamports_amount: u64,
+    pub created_epoch: u64,
+}
+pub const TICKET_ACCOUNT_DATA_LEN: usize = 80;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fee {
     pub basis_points: u32,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const FEE_LEN: usize = 4;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeData {
     pub admin_authority: Pubkey,
@@ -17,7 +61,10 @@ pub struct InitializeData {
     pub additional_validator_record_space: u32,
     pub slots_for_stake_delta: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const INITIALIZE_DATA_LEN: usize = 112;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiqPoolInitializeData {
     pub lp_liquidity_target: u64,
@@ -25,7 +72,10 @@ pub struct LiqPoolInitializeData {
     pub lp_min_fee: Fee,
     pub lp_treasury_cut: Fee,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQ_POOL_INITIALIZE_DATA_LEN: usize = 20;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeAuthorityData {
     pub admin: Option<Pubkey>,
@@ -33,7 +83,31 @@ pub struct ChangeAuthorityData {
     pub operational_sol_account: Option<Pubkey>,
     pub treasury_msol_account: Option<Pubkey>,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl ChangeAuthorityData {
+    pub const MIN_LEN: usize = 4;
+    /// Borsh-serialized length of this [`ChangeAuthorityData`]
+    pub fn serialized_len(&self) -> usize {
+        4 + if self.admin.is_some() { 32 } else { 0 }
+            + if self.validator_manager.is_some() {
+                32
+            } else {
+                0
+            }
+            + if self.operational_sol_account.is_some() {
+                32
+            } else {
+                0
+            }
+            + if self.treasury_msol_account.is_some() {
+                32
+            } else {
+                0
+            }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigMarinadeParams {
     pub rewards_fee: Option<Fee>,
@@ -45,7 +119,35 @@ pub struct ConfigMarinadeParams {
     pub liquidity_sol_cap: Option<u64>,
     pub auto_add_validator_enabled: Option<bool>,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl ConfigMarinadeParams {
+    pub const MIN_LEN: usize = 8;
+    /// Borsh-serialized length of this [`ConfigMarinadeParams`]
+    pub fn serialized_len(&self) -> usize {
+        8 + if self.rewards_fee.is_some() { 4 } else { 0 }
+            + if self.slots_for_stake_delta.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.min_stake.is_some() { 8 } else { 0 }
+            + if self.min_deposit.is_some() { 8 } else { 0 }
+            + if self.min_withdraw.is_some() { 8 } else { 0 }
+            + if self.staking_sol_cap.is_some() { 8 } else { 0 }
+            + if self.liquidity_sol_cap.is_some() {
+                8
+            } else {
+                0
+            }
+            + if self.auto_add_validator_enabled.is_some() {
+                1
+            } else {
+                0
+            }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiqPool {
     pub lp_mint: Pubkey,
@@ -61,7 +163,10 @@ pub struct LiqPool {
     pub lent_from_sol_leg: u64,
     pub liquidity_sol_cap: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQ_POOL_LEN: usize = 111;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct List {
     pub account: Pubkey,
@@ -70,7 +175,10 @@ pub struct List {
     pub new_account: Pubkey,
     pub copied_count: u32,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIST_LEN: usize = 76;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeRecord {
     pub stake_account: Pubkey,
@@ -78,7 +186,10 @@ pub struct StakeRecord {
     pub last_update_epoch: u64,
     pub is_emergency_unstaking: u8,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const STAKE_RECORD_LEN: usize = 49;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeSystem {
     pub stake_list: List,
@@ -90,7 +201,10 @@ pub struct StakeSystem {
     pub min_stake: u64,
     pub extra_stake_delta_runs: u32,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const STAKE_SYSTEM_LEN: usize = 114;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidatorRecord {
     pub validator_account: Pubkey,
@@ -99,7 +213,10 @@ pub struct ValidatorRecord {
     pub last_stake_delta_epoch: u64,
     pub duplication_flag_bump_seed: u8,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const VALIDATOR_RECORD_LEN: usize = 53;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidatorSystem {
     pub validator_list: List,
@@ -108,3 +225,4 @@ pub struct ValidatorSystem {
     pub total_active_balance: u64,
     pub auto_add_validator_enabled: u8,
 }
+pub const VALIDATOR_SYSTEM_LEN: usize = 121;
@@ -0,0 +1,799 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorNestedAccountsProgramIx {
+    Deposit(DepositIxArgs),
+    Withdraw(WithdrawIxArgs),
+    Close,
+}
+impl AnchorNestedAccountsProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            DEPOSIT_IX_DISCM => Ok(Self::Deposit(DepositIxArgs::deserialize(&mut reader)?)),
+            WITHDRAW_IX_DISCM => Ok(Self::Withdraw(WithdrawIxArgs::deserialize(&mut reader)?)),
+            CLOSE_IX_DISCM => Ok(Self::Close),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::Deposit(args) => {
+                writer.write_all(&DEPOSIT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Withdraw(args) => {
+                writer.write_all(&WITHDRAW_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Close => writer.write_all(&CLOSE_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+#[derive(Copy, Clone, Debug)]
+pub struct PoolAccounts<'me, 'info> {
+    pub state: &'me AccountInfo<'info>,
+    pub vault: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PoolKeys {
+    pub state: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct FeesAccounts<'me, 'info> {
+    pub pool: PoolAccounts<'me, 'info>,
+    pub fee_vault: &'me AccountInfo<'info>,
+    pub referrer: Option<&'me AccountInfo<'info>>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FeesKeys {
+    pub pool: PoolKeys,
+    pub fee_vault: Pubkey,
+    pub referrer: Option<Pubkey>,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ClosePoolAccounts<'me, 'info> {
+    pub state: &'me AccountInfo<'info>,
+    pub vault: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClosePoolKeys {
+    pub state: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+}
+pub const DEPOSIT_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct DepositAccounts<'me, 'info> {
+    pub user: &'me AccountInfo<'info>,
+    pub pool: PoolAccounts<'me, 'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DepositKeys {
+    pub user: Pubkey,
+    pub pool: PoolKeys,
+}
+impl From<DepositAccounts<'_, '_>> for DepositKeys {
+    fn from(accounts: DepositAccounts) -> Self {
+        Self {
+            user: *accounts.user.key,
+            pool: PoolKeys {
+                state: *accounts.pool.state.key,
+                vault: *accounts.pool.vault.key,
+                authority: *accounts.pool.authority.key,
+            },
+        }
+    }
+}
+impl From<DepositKeys> for [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] {
+    fn from(keys: DepositKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.user,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool.authority,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<DepositKeys> for Vec<AccountMeta> {
+    fn from(keys: DepositKeys) -> Self {
+        let accounts: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]> for DepositKeys {
+    fn from(pubkeys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            user: pubkeys[0],
+            pool: PoolKeys {
+                state: pubkeys[1],
+                vault: pubkeys[2],
+                authority: pubkeys[3],
+            },
+        }
+    }
+}
+impl<'info> From<DepositAccounts<'_, 'info>> for [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
+        [
+            accounts.user.clone(),
+            accounts.pool.state.clone(),
+            accounts.pool.vault.clone(),
+            accounts.pool.authority.clone(),
+        ]
+    }
+}
+impl<'info> From<DepositAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
+    for DepositAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            user: &arr[0],
+            pool: PoolAccounts {
+                state: &arr[1],
+                vault: &arr[2],
+                authority: &arr[3],
+            },
+        }
+    }
+}
+pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositIxData(pub DepositIxArgs);
+impl From<DepositIxArgs> for DepositIxData {
+    fn from(args: DepositIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DepositIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != DEPOSIT_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(DepositIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&DEPOSIT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn deposit_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DepositKeys,
+    args: DepositIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: DepositIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deposit_ix(keys: DepositKeys, args: DepositIxArgs) -> std::io::Result<Instruction> {
+    deposit_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn deposit_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+) -> ProgramResult {
+    let keys: DepositKeys = accounts.into();
+    let ix = deposit_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn deposit_invoke(accounts: DepositAccounts<'_, '_>, args: DepositIxArgs) -> ProgramResult {
+    deposit_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn deposit_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DepositKeys = accounts.into();
+    let ix = deposit_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn deposit_invoke_signed(
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    deposit_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn deposit_verify_account_keys(
+    accounts: DepositAccounts<'_, '_>,
+    keys: DepositKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.user.key, keys.user),
+        (*accounts.pool.state.key, keys.pool.state),
+        (*accounts.pool.vault.key, keys.pool.vault),
+        (*accounts.pool.authority.key, keys.pool.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_writable_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.user, accounts.pool.state, accounts.pool.vault] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_signer_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.user] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_account_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    deposit_verify_writable_privileges(accounts)?;
+    deposit_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawAccounts<'me, 'info> {
+    pub user: &'me AccountInfo<'info>,
+    pub fees: FeesAccounts<'me, 'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WithdrawKeys {
+    pub user: Pubkey,
+    pub fees: FeesKeys,
+}
+impl From<WithdrawAccounts<'_, '_>> for WithdrawKeys {
+    fn from(accounts: WithdrawAccounts) -> Self {
+        Self {
+            user: *accounts.user.key,
+            fees: FeesKeys {
+                pool: PoolKeys {
+                    state: *accounts.fees.pool.state.key,
+                    vault: *accounts.fees.pool.vault.key,
+                    authority: *accounts.fees.pool.authority.key,
+                },
+                fee_vault: *accounts.fees.fee_vault.key,
+                referrer: accounts.fees.referrer.map(|a| *a.key),
+            },
+        }
+    }
+}
+impl WithdrawKeys {
+    /// Absent optional accounts are passed as `program_id`
+    pub fn to_account_metas(&self, program_id: Pubkey) -> [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] {
+        let keys = self;
+        [
+            AccountMeta {
+                pubkey: keys.user,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fees.pool.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fees.pool.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fees.pool.authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fees.fee_vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            match keys.fees.referrer {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+        ]
+    }
+}
+impl From<WithdrawKeys> for [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] {
+    fn from(keys: WithdrawKeys) -> Self {
+        keys.to_account_metas(crate::ID)
+    }
+}
+impl From<WithdrawKeys> for Vec<AccountMeta> {
+    fn from(keys: WithdrawKeys) -> Self {
+        let accounts: [AccountMeta; WITHDRAW_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]> for WithdrawKeys {
+    fn from(pubkeys: [Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            user: pubkeys[0],
+            fees: FeesKeys {
+                pool: PoolKeys {
+                    state: pubkeys[1],
+                    vault: pubkeys[2],
+                    authority: pubkeys[3],
+                },
+                fee_vault: pubkeys[4],
+                referrer: Some(pubkeys[5]).filter(|k| *k != crate::ID),
+            },
+        }
+    }
+}
+impl<'info> From<WithdrawAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: WithdrawAccounts<'_, 'info>) -> Self {
+        let mut res = Vec::with_capacity(WITHDRAW_IX_ACCOUNTS_LEN);
+        res.push(accounts.user.clone());
+        res.push(accounts.fees.pool.state.clone());
+        res.push(accounts.fees.pool.vault.clone());
+        res.push(accounts.fees.pool.authority.clone());
+        res.push(accounts.fees.fee_vault.clone());
+        if let Some(a) = accounts.fees.referrer {
+            res.push(a.clone());
+        }
+        res
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
+    for WithdrawAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            user: &arr[0],
+            fees: FeesAccounts {
+                pool: PoolAccounts {
+                    state: &arr[1],
+                    vault: &arr[2],
+                    authority: &arr[3],
+                },
+                fee_vault: &arr[4],
+                referrer: Some(&arr[5]).filter(|a| *a.key != crate::ID),
+            },
+        }
+    }
+}
+pub const WITHDRAW_IX_DISCM: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithdrawIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawIxData(pub WithdrawIxArgs);
+impl From<WithdrawIxArgs> for WithdrawIxData {
+    fn from(args: WithdrawIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl WithdrawIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != WITHDRAW_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                WITHDRAW_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(WithdrawIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&WITHDRAW_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn withdraw_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawKeys,
+    args: WithdrawIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas = keys.to_account_metas(program_id);
+    let data: WithdrawIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn withdraw_ix(keys: WithdrawKeys, args: WithdrawIxArgs) -> std::io::Result<Instruction> {
+    withdraw_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn withdraw_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawAccounts<'_, '_>,
+    args: WithdrawIxArgs,
+) -> ProgramResult {
+    let keys: WithdrawKeys = accounts.into();
+    let ix = withdraw_ix_with_program_id(program_id, keys, args)?;
+    let account_info: Vec<AccountInfo> = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn withdraw_invoke(accounts: WithdrawAccounts<'_, '_>, args: WithdrawIxArgs) -> ProgramResult {
+    withdraw_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn withdraw_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawAccounts<'_, '_>,
+    args: WithdrawIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: WithdrawKeys = accounts.into();
+    let ix = withdraw_ix_with_program_id(program_id, keys, args)?;
+    let account_info: Vec<AccountInfo> = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn withdraw_invoke_signed(
+    accounts: WithdrawAccounts<'_, '_>,
+    args: WithdrawIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    withdraw_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn withdraw_verify_account_keys(
+    accounts: WithdrawAccounts<'_, '_>,
+    keys: WithdrawKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.user.key, keys.user),
+        (*accounts.fees.pool.state.key, keys.fees.pool.state),
+        (*accounts.fees.pool.vault.key, keys.fees.pool.vault),
+        (*accounts.fees.pool.authority.key, keys.fees.pool.authority),
+        (*accounts.fees.fee_vault.key, keys.fees.fee_vault),
+        (
+            accounts.fees.referrer.map_or(crate::ID, |a| *a.key),
+            keys.fees.referrer.unwrap_or(crate::ID),
+        ),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_writable_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [
+        Some(accounts.user),
+        Some(accounts.fees.pool.state),
+        Some(accounts.fees.pool.vault),
+        Some(accounts.fees.fee_vault),
+        accounts.fees.referrer,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_signer_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [Some(accounts.user)].into_iter().flatten() {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn withdraw_verify_account_privileges<'me, 'info>(
+    accounts: WithdrawAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    withdraw_verify_writable_privileges(accounts)?;
+    withdraw_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CLOSE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct CloseAccounts<'me, 'info> {
+    pub user: &'me AccountInfo<'info>,
+    pub pool: ClosePoolAccounts<'me, 'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CloseKeys {
+    pub user: Pubkey,
+    pub pool: ClosePoolKeys,
+}
+impl From<CloseAccounts<'_, '_>> for CloseKeys {
+    fn from(accounts: CloseAccounts) -> Self {
+        Self {
+            user: *accounts.user.key,
+            pool: ClosePoolKeys {
+                state: *accounts.pool.state.key,
+                vault: *accounts.pool.vault.key,
+                authority: *accounts.pool.authority.key,
+            },
+        }
+    }
+}
+impl From<CloseKeys> for [AccountMeta; CLOSE_IX_ACCOUNTS_LEN] {
+    fn from(keys: CloseKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.user,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool.state,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool.vault,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool.authority,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<CloseKeys> for Vec<AccountMeta> {
+    fn from(keys: CloseKeys) -> Self {
+        let accounts: [AccountMeta; CLOSE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; CLOSE_IX_ACCOUNTS_LEN]> for CloseKeys {
+    fn from(pubkeys: [Pubkey; CLOSE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            user: pubkeys[0],
+            pool: ClosePoolKeys {
+                state: pubkeys[1],
+                vault: pubkeys[2],
+                authority: pubkeys[3],
+            },
+        }
+    }
+}
+impl<'info> From<CloseAccounts<'_, 'info>> for [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CloseAccounts<'_, 'info>) -> Self {
+        [
+            accounts.user.clone(),
+            accounts.pool.state.clone(),
+            accounts.pool.vault.clone(),
+            accounts.pool.authority.clone(),
+        ]
+    }
+}
+impl<'info> From<CloseAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: CloseAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN]>
+    for CloseAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            user: &arr[0],
+            pool: ClosePoolAccounts {
+                state: &arr[1],
+                vault: &arr[2],
+                authority: &arr[3],
+            },
+        }
+    }
+}
+pub const CLOSE_IX_DISCM: [u8; 8] = [98, 165, 201, 177, 108, 65, 206, 96];
+#[derive(Clone, Debug, PartialEq)]
+pub struct CloseIxData;
+impl CloseIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CLOSE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CLOSE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CLOSE_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn close_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CloseKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CLOSE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: CloseIxData.try_to_vec()?,
+    })
+}
+pub fn close_ix(keys: CloseKeys) -> std::io::Result<Instruction> {
+    close_ix_with_program_id(crate::ID, keys)
+}
+pub fn close_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: CloseKeys = accounts.into();
+    let ix = close_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn close_invoke(accounts: CloseAccounts<'_, '_>) -> ProgramResult {
+    close_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn close_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CloseKeys = accounts.into();
+    let ix = close_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn close_invoke_signed(accounts: CloseAccounts<'_, '_>, seeds: &[&[&[u8]]]) -> ProgramResult {
+    close_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn close_verify_account_keys(
+    accounts: CloseAccounts<'_, '_>,
+    keys: CloseKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.user.key, keys.user),
+        (*accounts.pool.state.key, keys.pool.state),
+        (*accounts.pool.vault.key, keys.pool.vault),
+        (*accounts.pool.authority.key, keys.pool.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn close_verify_writable_privileges<'me, 'info>(
+    accounts: CloseAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.user, accounts.pool.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn close_verify_signer_privileges<'me, 'info>(
+    accounts: CloseAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.user] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn close_verify_account_privileges<'me, 'info>(
+    accounts: CloseAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    close_verify_writable_privileges(accounts)?;
+    close_verify_signer_privileges(accounts)?;
+    Ok(())
+}
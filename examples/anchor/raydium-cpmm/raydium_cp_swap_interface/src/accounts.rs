@@ -1 +1,562 @@
+use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use std::cell::{Ref, RefMut};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
+}
+pub const AMM_CONFIG_ACCOUNT_DISCM: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
+pub struct AmmConfigAccount(pub AmmConfig);
+impl AmmConfigAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != AMM_CONFIG_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                AMM_CONFIG_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(AmmConfig::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&AMM_CONFIG_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const AMM_CONFIG_ACCOUNT_LEN: usize = 236;
+impl AmmConfigAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, AMM_CONFIG_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `bump`
+    pub fn filter_bump(bump: u8) -> (usize, Vec<u8>) {
+        (8, bump.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `disable_create_pool`
+    pub fn filter_disable_create_pool(disable_create_pool: bool) -> (usize, Vec<u8>) {
+        (9, vec![disable_create_pool as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `index`
+    pub fn filter_index(index: u16) -> (usize, Vec<u8>) {
+        (10, index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `trade_fee_rate`
+    pub fn filter_trade_fee_rate(trade_fee_rate: u64) -> (usize, Vec<u8>) {
+        (12, trade_fee_rate.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `protocol_fee_rate`
+    pub fn filter_protocol_fee_rate(protocol_fee_rate: u64) -> (usize, Vec<u8>) {
+        (20, protocol_fee_rate.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fund_fee_rate`
+    pub fn filter_fund_fee_rate(fund_fee_rate: u64) -> (usize, Vec<u8>) {
+        (28, fund_fee_rate.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `create_pool_fee`
+    pub fn filter_create_pool_fee(create_pool_fee: u64) -> (usize, Vec<u8>) {
+        (36, create_pool_fee.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `protocol_owner`
+    pub fn filter_protocol_owner(protocol_owner: &Pubkey) -> (usize, Vec<u8>) {
+        (44, protocol_owner.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fund_owner`
+    pub fn filter_fund_owner(fund_owner: &Pubkey) -> (usize, Vec<u8>) {
+        (76, fund_owner.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u64; 16]) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((108, borsh::to_vec(padding)?))
+    }
+}
+#[cfg(feature = "client")]
+impl AmmConfigAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_bump(bump: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_bump(bump))
+    }
+    pub fn rpc_filter_disable_create_pool(disable_create_pool: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_disable_create_pool(disable_create_pool))
+    }
+    pub fn rpc_filter_index(index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_index(index))
+    }
+    pub fn rpc_filter_trade_fee_rate(trade_fee_rate: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_trade_fee_rate(trade_fee_rate))
+    }
+    pub fn rpc_filter_protocol_fee_rate(protocol_fee_rate: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_protocol_fee_rate(protocol_fee_rate))
+    }
+    pub fn rpc_filter_fund_fee_rate(fund_fee_rate: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_fund_fee_rate(fund_fee_rate))
+    }
+    pub fn rpc_filter_create_pool_fee(create_pool_fee: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_create_pool_fee(create_pool_fee))
+    }
+    pub fn rpc_filter_protocol_owner(protocol_owner: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_protocol_owner(protocol_owner))
+    }
+    pub fn rpc_filter_fund_owner(fund_owner: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_fund_owner(fund_owner))
+    }
+    pub fn rpc_filter_padding(padding: &[u64; 16]) -> std::io::Result<RpcFilterType> {
+        Self::filter_padding(padding).map(memcmp_filter)
+    }
+}
+pub const OBSERVATION_STATE_ACCOUNT_DISCM: [u8; 8] = [122, 174, 197, 53, 129, 9, 165, 132];
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd)]
+pub struct ObservationStateAccount(pub ObservationState);
+impl ObservationStateAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != OBSERVATION_STATE_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                OBSERVATION_STATE_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(ObservationState::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&OBSERVATION_STATE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const OBSERVATION_STATE_ACCOUNT_LEN: usize = 4075;
+impl ObservationStateAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, OBSERVATION_STATE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `initialized`
+    pub fn filter_initialized(initialized: bool) -> (usize, Vec<u8>) {
+        (8, vec![initialized as u8])
+    }
+    /// Memcmp filter `(offset, bytes)` matching `observation_index`
+    pub fn filter_observation_index(observation_index: u16) -> (usize, Vec<u8>) {
+        (9, observation_index.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pool_id`
+    pub fn filter_pool_id(pool_id: &Pubkey) -> (usize, Vec<u8>) {
+        (11, pool_id.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `observations`
+    pub fn filter_observations(
+        observations: &[Observation; 100],
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((43, borsh::to_vec(observations)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u64; 4]) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((4043, borsh::to_vec(padding)?))
+    }
+}
+#[cfg(feature = "client")]
+impl ObservationStateAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_initialized(initialized: bool) -> RpcFilterType {
+        memcmp_filter(Self::filter_initialized(initialized))
+    }
+    pub fn rpc_filter_observation_index(observation_index: u16) -> RpcFilterType {
+        memcmp_filter(Self::filter_observation_index(observation_index))
+    }
+    pub fn rpc_filter_pool_id(pool_id: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_pool_id(pool_id))
+    }
+    pub fn rpc_filter_observations(
+        observations: &[Observation; 100],
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_observations(observations).map(memcmp_filter)
+    }
+    pub fn rpc_filter_padding(padding: &[u64; 4]) -> std::io::Result<RpcFilterType> {
+        Self::filter_padding(padding).map(memcmp_filter)
+    }
+}
+impl ObservationStateAccount {
+    /// Range of the account struct in `data` after checking the discriminant and length
+    fn pod_range(data: &[u8]) -> std::io::Result<std::ops::Range<usize>> {
+        let discm_len = OBSERVATION_STATE_ACCOUNT_DISCM.len();
+        let end = discm_len + std::mem::size_of::<ObservationState>();
+        if data.len() < end {
+            return Err(std::io::Error::other(format!(
+                "account data too short. Expected at least: {}. Received: {}",
+                end,
+                data.len()
+            )));
+        }
+        if data[..discm_len] != OBSERVATION_STATE_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                OBSERVATION_STATE_ACCOUNT_DISCM,
+                &data[..discm_len]
+            )));
+        }
+        Ok(discm_len..end)
+    }
+    /// Casts the account data to a [`ObservationState`] without copying.
+    /// Errs if `data` is not aligned for it, see [`Self::read_unaligned`].
+    pub fn load(data: &[u8]) -> std::io::Result<&ObservationState> {
+        bytemuck::try_from_bytes(&data[Self::pod_range(data)?])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Casts the account data to a mutable [`ObservationState`] without copying.
+    /// Errs if `data` is not aligned for it.
+    pub fn load_mut(data: &mut [u8]) -> std::io::Result<&mut ObservationState> {
+        let range = Self::pod_range(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[range])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Copies the account data into a [`ObservationState`] regardless of its alignment
+    pub fn read_unaligned(data: &[u8]) -> std::io::Result<ObservationState> {
+        Ok(bytemuck::pod_read_unaligned(&data[Self::pod_range(data)?]))
+    }
+    /// [`Self::load`] on the account's borrowed data
+    pub fn load_account_info<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<Ref<'a, ObservationState>, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        Ref::filter_map(data, |data| bytemuck::try_from_bytes(&data[range]).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// [`Self::load_mut`] on the account's mutably borrowed data
+    pub fn load_account_info_mut<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<RefMut<'a, ObservationState>, ProgramError> {
+        let data = account.try_borrow_mut_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        RefMut::filter_map(data, |data| {
+            bytemuck::try_from_bytes_mut(&mut data[range]).ok()
+        })
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+pub const POOL_STATE_ACCOUNT_DISCM: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
+pub struct PoolStateAccount(pub PoolState);
+impl PoolStateAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != POOL_STATE_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                POOL_STATE_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(PoolState::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&POOL_STATE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const POOL_STATE_ACCOUNT_LEN: usize = 637;
+impl PoolStateAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, POOL_STATE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `amm_config`
+    pub fn filter_amm_config(amm_config: &Pubkey) -> (usize, Vec<u8>) {
+        (8, amm_config.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `pool_creator`
+    pub fn filter_pool_creator(pool_creator: &Pubkey) -> (usize, Vec<u8>) {
+        (40, pool_creator.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `token_0_vault`
+    pub fn filter_token_0_vault(token_0_vault: &Pubkey) -> (usize, Vec<u8>) {
+        (72, token_0_vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `token_1_vault`
+    pub fn filter_token_1_vault(token_1_vault: &Pubkey) -> (usize, Vec<u8>) {
+        (104, token_1_vault.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lp_mint`
+    pub fn filter_lp_mint(lp_mint: &Pubkey) -> (usize, Vec<u8>) {
+        (136, lp_mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `token_0_mint`
+    pub fn filter_token_0_mint(token_0_mint: &Pubkey) -> (usize, Vec<u8>) {
+        (168, token_0_mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `token_1_mint`
+    pub fn filter_token_1_mint(token_1_mint: &Pubkey) -> (usize, Vec<u8>) {
+        (200, token_1_mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `token_0_program`
+    pub fn filter_token_0_program(token_0_program: &Pubkey) -> (usize, Vec<u8>) {
+        (232, token_0_program.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `token_1_program`
+    pub fn filter_token_1_program(token_1_program: &Pubkey) -> (usize, Vec<u8>) {
+        (264, token_1_program.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `observation_key`
+    pub fn filter_observation_key(observation_key: &Pubkey) -> (usize, Vec<u8>) {
+        (296, observation_key.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `auth_bump`
+    pub fn filter_auth_bump(auth_bump: u8) -> (usize, Vec<u8>) {
+        (328, auth_bump.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `status`
+    pub fn filter_status(status: u8) -> (usize, Vec<u8>) {
+        (329, status.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lp_mint_decimals`
+    pub fn filter_lp_mint_decimals(lp_mint_decimals: u8) -> (usize, Vec<u8>) {
+        (330, lp_mint_decimals.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `mint_0_decimals`
+    pub fn filter_mint_0_decimals(mint_0_decimals: u8) -> (usize, Vec<u8>) {
+        (331, mint_0_decimals.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `mint_1_decimals`
+    pub fn filter_mint_1_decimals(mint_1_decimals: u8) -> (usize, Vec<u8>) {
+        (332, mint_1_decimals.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lp_supply`
+    pub fn filter_lp_supply(lp_supply: u64) -> (usize, Vec<u8>) {
+        (333, lp_supply.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `protocol_fees_token_0`
+    pub fn filter_protocol_fees_token_0(protocol_fees_token_0: u64) -> (usize, Vec<u8>) {
+        (341, protocol_fees_token_0.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `protocol_fees_token_1`
+    pub fn filter_protocol_fees_token_1(protocol_fees_token_1: u64) -> (usize, Vec<u8>) {
+        (349, protocol_fees_token_1.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fund_fees_token_0`
+    pub fn filter_fund_fees_token_0(fund_fees_token_0: u64) -> (usize, Vec<u8>) {
+        (357, fund_fees_token_0.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fund_fees_token_1`
+    pub fn filter_fund_fees_token_1(fund_fees_token_1: u64) -> (usize, Vec<u8>) {
+        (365, fund_fees_token_1.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `open_time`
+    pub fn filter_open_time(open_time: u64) -> (usize, Vec<u8>) {
+        (373, open_time.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `recent_epoch`
+    pub fn filter_recent_epoch(recent_epoch: u64) -> (usize, Vec<u8>) {
+        (381, recent_epoch.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `padding`
+    pub fn filter_padding(padding: &[u64; 31]) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((389, borsh::to_vec(padding)?))
+    }
+}
+#[cfg(feature = "client")]
+impl PoolStateAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_amm_config(amm_config: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_amm_config(amm_config))
+    }
+    pub fn rpc_filter_pool_creator(pool_creator: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_pool_creator(pool_creator))
+    }
+    pub fn rpc_filter_token_0_vault(token_0_vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_token_0_vault(token_0_vault))
+    }
+    pub fn rpc_filter_token_1_vault(token_1_vault: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_token_1_vault(token_1_vault))
+    }
+    pub fn rpc_filter_lp_mint(lp_mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_lp_mint(lp_mint))
+    }
+    pub fn rpc_filter_token_0_mint(token_0_mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_token_0_mint(token_0_mint))
+    }
+    pub fn rpc_filter_token_1_mint(token_1_mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_token_1_mint(token_1_mint))
+    }
+    pub fn rpc_filter_token_0_program(token_0_program: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_token_0_program(token_0_program))
+    }
+    pub fn rpc_filter_token_1_program(token_1_program: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_token_1_program(token_1_program))
+    }
+    pub fn rpc_filter_observation_key(observation_key: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_observation_key(observation_key))
+    }
+    pub fn rpc_filter_auth_bump(auth_bump: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_auth_bump(auth_bump))
+    }
+    pub fn rpc_filter_status(status: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_status(status))
+    }
+    pub fn rpc_filter_lp_mint_decimals(lp_mint_decimals: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_lp_mint_decimals(lp_mint_decimals))
+    }
+    pub fn rpc_filter_mint_0_decimals(mint_0_decimals: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_mint_0_decimals(mint_0_decimals))
+    }
+    pub fn rpc_filter_mint_1_decimals(mint_1_decimals: u8) -> RpcFilterType {
+        memcmp_filter(Self::filter_mint_1_decimals(mint_1_decimals))
+    }
+    pub fn rpc_filter_lp_supply(lp_supply: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_lp_supply(lp_supply))
+    }
+    pub fn rpc_filter_protocol_fees_token_0(protocol_fees_token_0: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_protocol_fees_token_0(protocol_fees_token_0))
+    }
+    pub fn rpc_filter_protocol_fees_token_1(protocol_fees_token_1: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_protocol_fees_token_1(protocol_fees_token_1))
+    }
+    pub fn rpc_filter_fund_fees_token_0(fund_fees_token_0: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_fund_fees_token_0(fund_fees_token_0))
+    }
+    pub fn rpc_filter_fund_fees_token_1(fund_fees_token_1: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_fund_fees_token_1(fund_fees_token_1))
+    }
+    pub fn rpc_filter_open_time(open_time: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_open_time(open_time))
+    }
+    pub fn rpc_filter_recent_epoch(recent_epoch: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_recent_epoch(recent_epoch))
+    }
+    pub fn rpc_filter_padding(padding: &[u64; 31]) -> std::io::Result<RpcFilterType> {
+        Self::filter_padding(padding).map(memcmp_filter)
+    }
+}
+impl PoolStateAccount {
+    /// Range of the account struct in `data` after checking the discriminant and length
+    fn pod_range(data: &[u8]) -> std::io::Result<std::ops::Range<usize>> {
+        let discm_len = POOL_STATE_ACCOUNT_DISCM.len();
+        let end = discm_len + std::mem::size_of::<PoolState>();
+        if data.len() < end {
+            return Err(std::io::Error::other(format!(
+                "account data too short. Expected at least: {}. Received: {}",
+                end,
+                data.len()
+            )));
+        }
+        if data[..discm_len] != POOL_STATE_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                POOL_STATE_ACCOUNT_DISCM,
+                &data[..discm_len]
+            )));
+        }
+        Ok(discm_len..end)
+    }
+    /// Casts the account data to a [`PoolState`] without copying.
+    /// Errs if `data` is not aligned for it, see [`Self::read_unaligned`].
+    pub fn load(data: &[u8]) -> std::io::Result<&PoolState> {
+        bytemuck::try_from_bytes(&data[Self::pod_range(data)?])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Casts the account data to a mutable [`PoolState`] without copying.
+    /// Errs if `data` is not aligned for it.
+    pub fn load_mut(data: &mut [u8]) -> std::io::Result<&mut PoolState> {
+        let range = Self::pod_range(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[range])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Copies the account data into a [`PoolState`] regardless of its alignment
+    pub fn read_unaligned(data: &[u8]) -> std::io::Result<PoolState> {
+        Ok(bytemuck::pod_read_unaligned(&data[Self::pod_range(data)?]))
+    }
+    /// [`Self::load`] on the account's borrowed data
+    pub fn load_account_info<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<Ref<'a, PoolState>, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        Ref::filter_map(data, |data| bytemuck::try_from_bytes(&data[range]).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// [`Self::load_mut`] on the account's mutably borrowed data
+    pub fn load_account_info_mut<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<RefMut<'a, PoolState>, ProgramError> {
+        let data = account.try_borrow_mut_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        RefMut::filter_map(data, |data| {
+            bytemuck::try_from_bytes_mut(&mut data[range]).ok()
+        })
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RaydiumCpSwapAccountData {
+    AmmConfig(AmmConfigAccount),
+    ObservationState(ObservationStateAccount),
+    PoolState(PoolStateAccount),
+}
+impl RaydiumCpSwapAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&AMM_CONFIG_ACCOUNT_DISCM) {
+            return Ok(Self::AmmConfig(AmmConfigAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&OBSERVATION_STATE_ACCOUNT_DISCM) {
+            return Ok(Self::ObservationState(
+                ObservationStateAccount::deserialize(buf)?,
+            ));
+        }
+        if buf.starts_with(&POOL_STATE_ACCOUNT_DISCM) {
+            return Ok(Self::PoolState(PoolStateAccount::deserialize(buf)?));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::AmmConfig(_) => &AMM_CONFIG_ACCOUNT_DISCM,
+            Self::ObservationState(_) => &OBSERVATION_STATE_ACCOUNT_DISCM,
+            Self::PoolState(_) => &POOL_STATE_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AmmConfig(_) => "AmmConfig",
+            Self::ObservationState(_) => "ObservationState",
+            Self::PoolState(_) => "PoolState",
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use solana_program::{msg, program_error::ProgramError};
+use solana_program::program_error::ProgramError;
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
 pub enum RaydiumCpSwapError {
@@ -1 +1,133 @@
+use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
+pub const LP_CHANGE_EVENT_EVENT_DISCM: [u8; 8] = [121, 163, 205, 201, 57, 218, 117, 60];
+#[derive(Clone, Debug, PartialEq)]
+pub struct LpChangeEventEvent(pub LpChangeEvent);
+impl BorshSerialize for LpChangeEventEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        LP_CHANGE_EVENT_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl LpChangeEventEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != LP_CHANGE_EVENT_EVENT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                LP_CHANGE_EVENT_EVENT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(LpChangeEvent::deserialize(buf)?))
+    }
+}
+pub const SWAP_EVENT_EVENT_DISCM: [u8; 8] = [64, 198, 205, 232, 38, 8, 113, 226];
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwapEventEvent(pub SwapEvent);
+impl BorshSerialize for SwapEventEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        SWAP_EVENT_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl SwapEventEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != SWAP_EVENT_EVENT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SWAP_EVENT_EVENT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(SwapEvent::deserialize(buf)?))
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum RaydiumCpSwapEvent {
+    LpChangeEvent(LpChangeEventEvent),
+    SwapEvent(SwapEventEvent),
+}
+impl RaydiumCpSwapEvent {
+    /// Decodes any of the program's events from its discriminator-prefixed bytes
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        if buf.starts_with(&LP_CHANGE_EVENT_EVENT_DISCM) {
+            return Ok(Self::LpChangeEvent(LpChangeEventEvent::deserialize(
+                &mut reader,
+            )?));
+        }
+        if buf.starts_with(&SWAP_EVENT_EVENT_DISCM) {
+            return Ok(Self::SwapEvent(SwapEventEvent::deserialize(&mut reader)?));
+        }
+        Err(std::io::Error::other(format!(
+            "event discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+}
+/// Prefix of the self-CPI instruction data anchor's `emit_cpi!` emits events with
+pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+impl RaydiumCpSwapEvent {
+    fn has_known_discm(buf: &[u8]) -> bool {
+        buf.starts_with(&LP_CHANGE_EVENT_EVENT_DISCM) || buf.starts_with(&SWAP_EVENT_EVENT_DISCM)
+    }
+    /// Decodes the events emitted by this program with `emit!` from a transaction's log messages.
+    ///
+    /// Only `Program data:` lines logged while this program is the innermost invoked program are considered.
+    /// Data that does not start with any of the program's event discms is skipped.
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> std::io::Result<Vec<Self>> {
+        use base64::Engine;
+        let program_id = crate::ID.to_string();
+        let mut invoke_stack: Vec<&str> = Vec::new();
+        let mut res = Vec::new();
+        for log in logs {
+            let log = log.as_ref();
+            if let Some(data) = log.strip_prefix("Program data: ") {
+                if invoke_stack.last() != Some(&program_id.as_str()) {
+                    continue;
+                }
+                let data = data.split(' ').next().unwrap_or_default();
+                let buf = base64::engine::general_purpose::STANDARD
+                    .decode(data)
+                    .map_err(std::io::Error::other)?;
+                if Self::has_known_discm(&buf) {
+                    res.push(Self::deserialize(&buf)?);
+                }
+            } else if let Some(rest) = log.strip_prefix("Program ") {
+                let mut words = rest.split(' ');
+                let (Some(id), Some(status)) = (words.next(), words.next()) else {
+                    continue;
+                };
+                if status == "invoke" {
+                    invoke_stack.push(id);
+                } else if status == "success" || status.starts_with("failed") {
+                    invoke_stack.pop();
+                }
+            }
+        }
+        Ok(res)
+    }
+    /// Decodes an event emitted by this program with `emit_cpi!` from the data of
+    /// the self-CPI inner instruction, which is [`EVENT_IX_TAG`] followed by the event's bytes.
+    ///
+    /// Callers should check that the inner instruction's program ID is this program's.
+    pub fn from_cpi_ix_data(data: &[u8]) -> std::io::Result<Self> {
+        let buf = data.strip_prefix(&EVENT_IX_TAG).ok_or_else(|| {
+            std::io::Error::other(format!(
+                "event ix tag does not match. Expected: {:?}. Received: {:?}",
+                EVENT_IX_TAG,
+                &data[..data.len().min(EVENT_IX_TAG.len())]
+            ))
+        })?;
+        Self::deserialize(buf)
+    }
+}
+impl BorshSerialize for RaydiumCpSwapEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::LpChangeEvent(e) => e.serialize(writer),
+            Self::SwapEvent(e) => e.serialize(writer),
+        }
+    }
+}
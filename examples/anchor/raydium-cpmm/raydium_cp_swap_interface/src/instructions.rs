@@ -1,9 +1,11 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult,
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
     program::{invoke, invoke_signed},
-    pubkey::Pubkey, program_error::ProgramError,
+    program_error::ProgramError,
+    pubkey::Pubkey,
 };
 use std::io::Read;
 #[derive(Clone, Debug, PartialEq)]
@@ -25,60 +27,36 @@ impl RaydiumCpSwapProgramIx {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         match maybe_discm {
-            COLLECT_FUND_FEE_IX_DISCM => {
-                Ok(Self::CollectFundFee(CollectFundFeeIxArgs::deserialize(&mut reader)?))
-            }
-            COLLECT_PROTOCOL_FEE_IX_DISCM => {
-                Ok(
-                    Self::CollectProtocolFee(
-                        CollectProtocolFeeIxArgs::deserialize(&mut reader)?,
-                    ),
-                )
-            }
-            CREATE_AMM_CONFIG_IX_DISCM => {
-                Ok(
-                    Self::CreateAmmConfig(
-                        CreateAmmConfigIxArgs::deserialize(&mut reader)?,
-                    ),
-                )
-            }
-            DEPOSIT_IX_DISCM => {
-                Ok(Self::Deposit(DepositIxArgs::deserialize(&mut reader)?))
-            }
-            INITIALIZE_IX_DISCM => {
-                Ok(Self::Initialize(InitializeIxArgs::deserialize(&mut reader)?))
-            }
-            SWAP_BASE_INPUT_IX_DISCM => {
-                Ok(Self::SwapBaseInput(SwapBaseInputIxArgs::deserialize(&mut reader)?))
-            }
-            SWAP_BASE_OUTPUT_IX_DISCM => {
-                Ok(Self::SwapBaseOutput(SwapBaseOutputIxArgs::deserialize(&mut reader)?))
-            }
-            UPDATE_AMM_CONFIG_IX_DISCM => {
-                Ok(
-                    Self::UpdateAmmConfig(
-                        UpdateAmmConfigIxArgs::deserialize(&mut reader)?,
-                    ),
-                )
-            }
-            UPDATE_POOL_STATUS_IX_DISCM => {
-                Ok(
-                    Self::UpdatePoolStatus(
-                        UpdatePoolStatusIxArgs::deserialize(&mut reader)?,
-                    ),
-                )
-            }
-            WITHDRAW_IX_DISCM => {
-                Ok(Self::Withdraw(WithdrawIxArgs::deserialize(&mut reader)?))
-            }
-            _ => {
-                Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!("discm {:?} not found", maybe_discm),
-                    ),
-                )
-            }
+            COLLECT_FUND_FEE_IX_DISCM => Ok(Self::CollectFundFee(
+                CollectFundFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            COLLECT_PROTOCOL_FEE_IX_DISCM => Ok(Self::CollectProtocolFee(
+                CollectProtocolFeeIxArgs::deserialize(&mut reader)?,
+            )),
+            CREATE_AMM_CONFIG_IX_DISCM => Ok(Self::CreateAmmConfig(
+                CreateAmmConfigIxArgs::deserialize(&mut reader)?,
+            )),
+            DEPOSIT_IX_DISCM => Ok(Self::Deposit(DepositIxArgs::deserialize(&mut reader)?)),
+            INITIALIZE_IX_DISCM => Ok(Self::Initialize(InitializeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            SWAP_BASE_INPUT_IX_DISCM => Ok(Self::SwapBaseInput(SwapBaseInputIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            SWAP_BASE_OUTPUT_IX_DISCM => Ok(Self::SwapBaseOutput(
+                SwapBaseOutputIxArgs::deserialize(&mut reader)?,
+            )),
+            UPDATE_AMM_CONFIG_IX_DISCM => Ok(Self::UpdateAmmConfig(
+                UpdateAmmConfigIxArgs::deserialize(&mut reader)?,
+            )),
+            UPDATE_POOL_STATUS_IX_DISCM => Ok(Self::UpdatePoolStatus(
+                UpdatePoolStatusIxArgs::deserialize(&mut reader)?,
+            )),
+            WITHDRAW_IX_DISCM => Ok(Self::Withdraw(WithdrawIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -149,32 +127,54 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 pub const COLLECT_FUND_FEE_IX_ACCOUNTS_LEN: usize = 12;
 #[derive(Copy, Clone, Debug)]
 pub struct CollectFundFeeAccounts<'me, 'info> {
+    /// Only admin or fund_owner can collect fee now
     pub owner: &'me AccountInfo<'info>,
     pub authority: &'me AccountInfo<'info>,
+    /// Pool state stores accumulated protocol fee amount
     pub pool_state: &'me AccountInfo<'info>,
+    /// Amm config account stores fund_owner
     pub amm_config: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: &'me AccountInfo<'info>,
+    /// The mint of token_0 vault
     pub vault_0_mint: &'me AccountInfo<'info>,
+    /// The mint of token_1 vault
     pub vault_1_mint: &'me AccountInfo<'info>,
+    /// The address that receives the collected token_0 fund fees
     pub recipient_token_0_account: &'me AccountInfo<'info>,
+    /// The address that receives the collected token_1 fund fees
     pub recipient_token_1_account: &'me AccountInfo<'info>,
+    /// The SPL program to perform token transfers
     pub token_program: &'me AccountInfo<'info>,
+    /// The SPL program 2022 to perform token transfers
     pub token_program_2022: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CollectFundFeeKeys {
+    /// Only admin or fund_owner can collect fee now
     pub owner: Pubkey,
     pub authority: Pubkey,
+    /// Pool state stores accumulated protocol fee amount
     pub pool_state: Pubkey,
+    /// Amm config account stores fund_owner
     pub amm_config: Pubkey,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: Pubkey,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: Pubkey,
+    /// The mint of token_0 vault
     pub vault_0_mint: Pubkey,
+    /// The mint of token_1 vault
     pub vault_1_mint: Pubkey,
+    /// The address that receives the collected token_0 fund fees
     pub recipient_token_0_account: Pubkey,
+    /// The address that receives the collected token_1 fund fees
     pub recipient_token_1_account: Pubkey,
+    /// The SPL program to perform token transfers
     pub token_program: Pubkey,
+    /// The SPL program 2022 to perform token transfers
     pub token_program_2022: Pubkey,
 }
 impl From<CollectFundFeeAccounts<'_, '_>> for CollectFundFeeKeys {
@@ -286,7 +286,8 @@ impl From<[Pubkey; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN]> for CollectFundFeeKeys {
     }
 }
 impl<'info> From<CollectFundFeeAccounts<'_, 'info>>
-for [AccountInfo<'info>; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: CollectFundFeeAccounts<'_, 'info>) -> Self {
         [
             accounts.owner.clone(),
@@ -306,13 +307,14 @@ for [AccountInfo<'info>; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<CollectFundFeeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: CollectFundFeeAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN] =
+            accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN]>
-for CollectFundFeeAccounts<'me, 'info> {
+    for CollectFundFeeAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; COLLECT_FUND_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             owner: &arr[0],
@@ -331,7 +333,17 @@ for CollectFundFeeAccounts<'me, 'info> {
     }
 }
 pub const COLLECT_FUND_FEE_IX_DISCM: [u8; 8] = [167, 138, 78, 149, 223, 194, 6, 126];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Collect the fund fee accrued to the pool
+///
+/// # Arguments
+///
+/// * `ctx` - The context of accounts
+/// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
+/// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectFundFeeIxArgs {
     pub amount_0_requested: u64,
@@ -350,15 +362,10 @@ impl CollectFundFeeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != COLLECT_FUND_FEE_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        COLLECT_FUND_FEE_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                COLLECT_FUND_FEE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CollectFundFeeIxArgs::deserialize(&mut reader)?))
     }
@@ -372,6 +379,14 @@ impl CollectFundFeeIxData {
         Ok(data)
     }
 }
+/// Collect the fund fee accrued to the pool
+///
+/// # Arguments
+///
+/// * `ctx` - The context of accounts
+/// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
+/// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
+///
 pub fn collect_fund_fee_ix_with_program_id(
     program_id: Pubkey,
     keys: CollectFundFeeKeys,
@@ -385,6 +400,14 @@ pub fn collect_fund_fee_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
+/// Collect the fund fee accrued to the pool
+///
+/// # Arguments
+///
+/// * `ctx` - The context of accounts
+/// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
+/// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
+///
 pub fn collect_fund_fee_ix(
     keys: CollectFundFeeKeys,
     args: CollectFundFeeIxArgs,
@@ -436,8 +459,14 @@ pub fn collect_fund_fee_verify_account_keys(
         (*accounts.token_1_vault.key, keys.token_1_vault),
         (*accounts.vault_0_mint.key, keys.vault_0_mint),
         (*accounts.vault_1_mint.key, keys.vault_1_mint),
-        (*accounts.recipient_token_0_account.key, keys.recipient_token_0_account),
-        (*accounts.recipient_token_1_account.key, keys.recipient_token_1_account),
+        (
+            *accounts.recipient_token_0_account.key,
+            keys.recipient_token_0_account,
+        ),
+        (
+            *accounts.recipient_token_1_account.key,
+            keys.recipient_token_1_account,
+        ),
         (*accounts.token_program.key, keys.token_program),
         (*accounts.token_program_2022.key, keys.token_program_2022),
     ] {
@@ -480,35 +509,170 @@ pub fn collect_fund_fee_verify_account_privileges<'me, 'info>(
     collect_fund_fee_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`CollectFundFeeKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CollectFundFeeKeysBuilder {
+    pub owner: Option<Pubkey>,
+    pub authority: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+    pub amm_config: Option<Pubkey>,
+    pub token_0_vault: Option<Pubkey>,
+    pub token_1_vault: Option<Pubkey>,
+    pub vault_0_mint: Option<Pubkey>,
+    pub vault_1_mint: Option<Pubkey>,
+    pub recipient_token_0_account: Option<Pubkey>,
+    pub recipient_token_1_account: Option<Pubkey>,
+    pub token_program: Option<Pubkey>,
+    pub token_program_2022: Option<Pubkey>,
+}
+impl CollectFundFeeKeysBuilder {
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn amm_config(mut self, amm_config: Pubkey) -> Self {
+        self.amm_config = Some(amm_config);
+        self
+    }
+    pub fn token_0_vault(mut self, token_0_vault: Pubkey) -> Self {
+        self.token_0_vault = Some(token_0_vault);
+        self
+    }
+    pub fn token_1_vault(mut self, token_1_vault: Pubkey) -> Self {
+        self.token_1_vault = Some(token_1_vault);
+        self
+    }
+    pub fn vault_0_mint(mut self, vault_0_mint: Pubkey) -> Self {
+        self.vault_0_mint = Some(vault_0_mint);
+        self
+    }
+    pub fn vault_1_mint(mut self, vault_1_mint: Pubkey) -> Self {
+        self.vault_1_mint = Some(vault_1_mint);
+        self
+    }
+    pub fn recipient_token_0_account(mut self, recipient_token_0_account: Pubkey) -> Self {
+        self.recipient_token_0_account = Some(recipient_token_0_account);
+        self
+    }
+    pub fn recipient_token_1_account(mut self, recipient_token_1_account: Pubkey) -> Self {
+        self.recipient_token_1_account = Some(recipient_token_1_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn token_program_2022(mut self, token_program_2022: Pubkey) -> Self {
+        self.token_program_2022 = Some(token_program_2022);
+        self
+    }
+    pub fn build(self) -> Result<CollectFundFeeKeys, MissingAccountError> {
+        let owner = self.owner.ok_or(MissingAccountError("owner"))?;
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        let amm_config = self.amm_config.ok_or(MissingAccountError("amm_config"))?;
+        let token_0_vault = self
+            .token_0_vault
+            .ok_or(MissingAccountError("token_0_vault"))?;
+        let token_1_vault = self
+            .token_1_vault
+            .ok_or(MissingAccountError("token_1_vault"))?;
+        let vault_0_mint = self
+            .vault_0_mint
+            .ok_or(MissingAccountError("vault_0_mint"))?;
+        let vault_1_mint = self
+            .vault_1_mint
+            .ok_or(MissingAccountError("vault_1_mint"))?;
+        let recipient_token_0_account = self
+            .recipient_token_0_account
+            .ok_or(MissingAccountError("recipient_token_0_account"))?;
+        let recipient_token_1_account = self
+            .recipient_token_1_account
+            .ok_or(MissingAccountError("recipient_token_1_account"))?;
+        let token_program = self.token_program.unwrap_or(solana_program::pubkey!(
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        ));
+        let token_program_2022 = self.token_program_2022.unwrap_or(solana_program::pubkey!(
+            "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        ));
+        let authority = self.authority.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(CollectFundFeeKeys {
+            owner,
+            authority,
+            pool_state,
+            amm_config,
+            token_0_vault,
+            token_1_vault,
+            vault_0_mint,
+            vault_1_mint,
+            recipient_token_0_account,
+            recipient_token_1_account,
+            token_program,
+            token_program_2022,
+        })
+    }
+}
 pub const COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN: usize = 12;
 #[derive(Copy, Clone, Debug)]
 pub struct CollectProtocolFeeAccounts<'me, 'info> {
+    /// Only admin or owner can collect fee now
     pub owner: &'me AccountInfo<'info>,
     pub authority: &'me AccountInfo<'info>,
+    /// Pool state stores accumulated protocol fee amount
     pub pool_state: &'me AccountInfo<'info>,
+    /// Amm config account stores owner
     pub amm_config: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: &'me AccountInfo<'info>,
+    /// The mint of token_0 vault
     pub vault_0_mint: &'me AccountInfo<'info>,
+    /// The mint of token_1 vault
     pub vault_1_mint: &'me AccountInfo<'info>,
+    /// The address that receives the collected token_0 protocol fees
     pub recipient_token_0_account: &'me AccountInfo<'info>,
+    /// The address that receives the collected token_1 protocol fees
     pub recipient_token_1_account: &'me AccountInfo<'info>,
+    /// The SPL program to perform token transfers
     pub token_program: &'me AccountInfo<'info>,
+    /// The SPL program 2022 to perform token transfers
     pub token_program_2022: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CollectProtocolFeeKeys {
+    /// Only admin or owner can collect fee now
     pub owner: Pubkey,
     pub authority: Pubkey,
+    /// Pool state stores accumulated protocol fee amount
     pub pool_state: Pubkey,
+    /// Amm config account stores owner
     pub amm_config: Pubkey,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: Pubkey,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: Pubkey,
+    /// The mint of token_0 vault
     pub vault_0_mint: Pubkey,
+    /// The mint of token_1 vault
     pub vault_1_mint: Pubkey,
+    /// The address that receives the collected token_0 protocol fees
     pub recipient_token_0_account: Pubkey,
+    /// The address that receives the collected token_1 protocol fees
     pub recipient_token_1_account: Pubkey,
+    /// The SPL program to perform token transfers
     pub token_program: Pubkey,
+    /// The SPL program 2022 to perform token transfers
     pub token_program_2022: Pubkey,
 }
 impl From<CollectProtocolFeeAccounts<'_, '_>> for CollectProtocolFeeKeys {
@@ -529,8 +693,7 @@ impl From<CollectProtocolFeeAccounts<'_, '_>> for CollectProtocolFeeKeys {
         }
     }
 }
-impl From<CollectProtocolFeeKeys>
-for [AccountMeta; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+impl From<CollectProtocolFeeKeys> for [AccountMeta; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
     fn from(keys: CollectProtocolFeeKeys) -> Self {
         [
             AccountMeta {
@@ -621,7 +784,8 @@ impl From<[Pubkey; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]> for CollectProtocolFee
     }
 }
 impl<'info> From<CollectProtocolFeeAccounts<'_, 'info>>
-for [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: CollectProtocolFeeAccounts<'_, 'info>) -> Self {
         [
             accounts.owner.clone(),
@@ -641,16 +805,15 @@ for [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<CollectProtocolFeeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: CollectProtocolFeeAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] =
+            accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]>
-for CollectProtocolFeeAccounts<'me, 'info> {
-    fn from(
-        arr: &'me [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN],
-    ) -> Self {
+    for CollectProtocolFeeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; COLLECT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             owner: &arr[0],
             authority: &arr[1],
@@ -667,10 +830,18 @@ for CollectProtocolFeeAccounts<'me, 'info> {
         }
     }
 }
-pub const COLLECT_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [
-    136, 136, 252, 221, 194, 66, 126, 89,
-];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+pub const COLLECT_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [136, 136, 252, 221, 194, 66, 126, 89];
+/// Collect the protocol fee accrued to the pool
+///
+/// # Arguments
+///
+/// * `ctx` - The context of accounts
+/// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
+/// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectProtocolFeeIxArgs {
     pub amount_0_requested: u64,
@@ -689,15 +860,10 @@ impl CollectProtocolFeeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != COLLECT_PROTOCOL_FEE_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        COLLECT_PROTOCOL_FEE_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                COLLECT_PROTOCOL_FEE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CollectProtocolFeeIxArgs::deserialize(&mut reader)?))
     }
@@ -711,6 +877,14 @@ impl CollectProtocolFeeIxData {
         Ok(data)
     }
 }
+/// Collect the protocol fee accrued to the pool
+///
+/// # Arguments
+///
+/// * `ctx` - The context of accounts
+/// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
+/// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
+///
 pub fn collect_protocol_fee_ix_with_program_id(
     program_id: Pubkey,
     keys: CollectProtocolFeeKeys,
@@ -724,6 +898,14 @@ pub fn collect_protocol_fee_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
+/// Collect the protocol fee accrued to the pool
+///
+/// # Arguments
+///
+/// * `ctx` - The context of accounts
+/// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
+/// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
+///
 pub fn collect_protocol_fee_ix(
     keys: CollectProtocolFeeKeys,
     args: CollectProtocolFeeIxArgs,
@@ -775,8 +957,14 @@ pub fn collect_protocol_fee_verify_account_keys(
         (*accounts.token_1_vault.key, keys.token_1_vault),
         (*accounts.vault_0_mint.key, keys.vault_0_mint),
         (*accounts.vault_1_mint.key, keys.vault_1_mint),
-        (*accounts.recipient_token_0_account.key, keys.recipient_token_0_account),
-        (*accounts.recipient_token_1_account.key, keys.recipient_token_1_account),
+        (
+            *accounts.recipient_token_0_account.key,
+            keys.recipient_token_0_account,
+        ),
+        (
+            *accounts.recipient_token_1_account.key,
+            keys.recipient_token_1_account,
+        ),
         (*accounts.token_program.key, keys.token_program),
         (*accounts.token_program_2022.key, keys.token_program_2022),
     ] {
@@ -819,16 +1007,133 @@ pub fn collect_protocol_fee_verify_account_privileges<'me, 'info>(
     collect_protocol_fee_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`CollectProtocolFeeKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CollectProtocolFeeKeysBuilder {
+    pub owner: Option<Pubkey>,
+    pub authority: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+    pub amm_config: Option<Pubkey>,
+    pub token_0_vault: Option<Pubkey>,
+    pub token_1_vault: Option<Pubkey>,
+    pub vault_0_mint: Option<Pubkey>,
+    pub vault_1_mint: Option<Pubkey>,
+    pub recipient_token_0_account: Option<Pubkey>,
+    pub recipient_token_1_account: Option<Pubkey>,
+    pub token_program: Option<Pubkey>,
+    pub token_program_2022: Option<Pubkey>,
+}
+impl CollectProtocolFeeKeysBuilder {
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn amm_config(mut self, amm_config: Pubkey) -> Self {
+        self.amm_config = Some(amm_config);
+        self
+    }
+    pub fn token_0_vault(mut self, token_0_vault: Pubkey) -> Self {
+        self.token_0_vault = Some(token_0_vault);
+        self
+    }
+    pub fn token_1_vault(mut self, token_1_vault: Pubkey) -> Self {
+        self.token_1_vault = Some(token_1_vault);
+        self
+    }
+    pub fn vault_0_mint(mut self, vault_0_mint: Pubkey) -> Self {
+        self.vault_0_mint = Some(vault_0_mint);
+        self
+    }
+    pub fn vault_1_mint(mut self, vault_1_mint: Pubkey) -> Self {
+        self.vault_1_mint = Some(vault_1_mint);
+        self
+    }
+    pub fn recipient_token_0_account(mut self, recipient_token_0_account: Pubkey) -> Self {
+        self.recipient_token_0_account = Some(recipient_token_0_account);
+        self
+    }
+    pub fn recipient_token_1_account(mut self, recipient_token_1_account: Pubkey) -> Self {
+        self.recipient_token_1_account = Some(recipient_token_1_account);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn token_program_2022(mut self, token_program_2022: Pubkey) -> Self {
+        self.token_program_2022 = Some(token_program_2022);
+        self
+    }
+    pub fn build(self) -> Result<CollectProtocolFeeKeys, MissingAccountError> {
+        let owner = self.owner.ok_or(MissingAccountError("owner"))?;
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        let amm_config = self.amm_config.ok_or(MissingAccountError("amm_config"))?;
+        let token_0_vault = self
+            .token_0_vault
+            .ok_or(MissingAccountError("token_0_vault"))?;
+        let token_1_vault = self
+            .token_1_vault
+            .ok_or(MissingAccountError("token_1_vault"))?;
+        let vault_0_mint = self
+            .vault_0_mint
+            .ok_or(MissingAccountError("vault_0_mint"))?;
+        let vault_1_mint = self
+            .vault_1_mint
+            .ok_or(MissingAccountError("vault_1_mint"))?;
+        let recipient_token_0_account = self
+            .recipient_token_0_account
+            .ok_or(MissingAccountError("recipient_token_0_account"))?;
+        let recipient_token_1_account = self
+            .recipient_token_1_account
+            .ok_or(MissingAccountError("recipient_token_1_account"))?;
+        let token_program = self.token_program.unwrap_or(solana_program::pubkey!(
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        ));
+        let token_program_2022 = self.token_program_2022.unwrap_or(solana_program::pubkey!(
+            "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        ));
+        let authority = self.authority.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(CollectProtocolFeeKeys {
+            owner,
+            authority,
+            pool_state,
+            amm_config,
+            token_0_vault,
+            token_1_vault,
+            vault_0_mint,
+            vault_1_mint,
+            recipient_token_0_account,
+            recipient_token_1_account,
+            token_program,
+            token_program_2022,
+        })
+    }
+}
 pub const CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
 pub struct CreateAmmConfigAccounts<'me, 'info> {
+    /// Address to be set as protocol owner.
     pub owner: &'me AccountInfo<'info>,
+    /// Initialize config state account to store protocol owner address and fee rates.
     pub amm_config: &'me AccountInfo<'info>,
     pub system_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct CreateAmmConfigKeys {
+    /// Address to be set as protocol owner.
     pub owner: Pubkey,
+    /// Initialize config state account to store protocol owner address and fee rates.
     pub amm_config: Pubkey,
     pub system_program: Pubkey,
 }
@@ -878,7 +1183,8 @@ impl From<[Pubkey; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN]> for CreateAmmConfigKeys {
     }
 }
 impl<'info> From<CreateAmmConfigAccounts<'_, 'info>>
-for [AccountInfo<'info>; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: CreateAmmConfigAccounts<'_, 'info>) -> Self {
         [
             accounts.owner.clone(),
@@ -889,13 +1195,14 @@ for [AccountInfo<'info>; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<CreateAmmConfigAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: CreateAmmConfigAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN] =
+            accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN]>
-for CreateAmmConfigAccounts<'me, 'info> {
+    for CreateAmmConfigAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; CREATE_AMM_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             owner: &arr[0],
@@ -905,7 +1212,17 @@ for CreateAmmConfigAccounts<'me, 'info> {
     }
 }
 pub const CREATE_AMM_CONFIG_IX_DISCM: [u8; 8] = [137, 52, 237, 212, 215, 117, 108, 104];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// # Arguments
+///
+/// * `ctx`- The accounts needed by instruction.
+/// * `index` - The index of amm config, there may be multiple config.
+/// * `trade_fee_rate` - Trade fee rate, can be changed.
+/// * `protocol_fee_rate` - The rate of protocol fee within trade fee.
+/// * `fund_fee_rate` - The rate of fund fee within trade fee.
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateAmmConfigIxArgs {
     pub index: u16,
@@ -927,15 +1244,10 @@ impl CreateAmmConfigIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CREATE_AMM_CONFIG_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        CREATE_AMM_CONFIG_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CREATE_AMM_CONFIG_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CreateAmmConfigIxArgs::deserialize(&mut reader)?))
     }
@@ -949,6 +1261,14 @@ impl CreateAmmConfigIxData {
         Ok(data)
     }
 }
+/// # Arguments
+///
+/// * `ctx`- The accounts needed by instruction.
+/// * `index` - The index of amm config, there may be multiple config.
+/// * `trade_fee_rate` - Trade fee rate, can be changed.
+/// * `protocol_fee_rate` - The rate of protocol fee within trade fee.
+/// * `fund_fee_rate` - The rate of fund fee within trade fee.
+///
 pub fn create_amm_config_ix_with_program_id(
     program_id: Pubkey,
     keys: CreateAmmConfigKeys,
@@ -962,6 +1282,14 @@ pub fn create_amm_config_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
+/// # Arguments
+///
+/// * `ctx`- The accounts needed by instruction.
+/// * `index` - The index of amm config, there may be multiple config.
+/// * `trade_fee_rate` - Trade fee rate, can be changed.
+/// * `protocol_fee_rate` - The rate of protocol fee within trade fee.
+/// * `fund_fee_rate` - The rate of fund fee within trade fee.
+///
 pub fn create_amm_config_ix(
     keys: CreateAmmConfigKeys,
     args: CreateAmmConfigIxArgs,
@@ -1042,37 +1370,100 @@ pub fn create_amm_config_verify_account_privileges<'me, 'info>(
     create_amm_config_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`CreateAmmConfigKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CreateAmmConfigKeysBuilder {
+    pub owner: Option<Pubkey>,
+    pub amm_config: Option<Pubkey>,
+    pub system_program: Option<Pubkey>,
+}
+impl CreateAmmConfigKeysBuilder {
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn amm_config(mut self, amm_config: Pubkey) -> Self {
+        self.amm_config = Some(amm_config);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn build(
+        self,
+        args: &CreateAmmConfigIxArgs,
+    ) -> Result<CreateAmmConfigKeys, MissingAccountError> {
+        let owner = self.owner.unwrap_or(solana_program::pubkey!(
+            "GThUX1Atko4tqhN2NaiTazWSeFWMuiUvfFnyJyUghFMJ"
+        ));
+        let system_program = self
+            .system_program
+            .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111"));
+        let amm_config = self.amm_config.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"amm_config", &args.index.to_le_bytes()];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(CreateAmmConfigKeys {
+            owner,
+            amm_config,
+            system_program,
+        })
+    }
+}
 pub const DEPOSIT_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct DepositAccounts<'me, 'info> {
+    /// Pays to mint the position
     pub owner: &'me AccountInfo<'info>,
     pub authority: &'me AccountInfo<'info>,
     pub pool_state: &'me AccountInfo<'info>,
+    /// Owner lp token account
     pub owner_lp_token: &'me AccountInfo<'info>,
+    /// The payer's token account for token_0
     pub token_0_account: &'me AccountInfo<'info>,
+    /// The payer's token account for token_1
     pub token_1_account: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: &'me AccountInfo<'info>,
+    /// token Program
     pub token_program: &'me AccountInfo<'info>,
+    /// Token program 2022
     pub token_program_2022: &'me AccountInfo<'info>,
+    /// The mint of token_0 vault
     pub vault_0_mint: &'me AccountInfo<'info>,
+    /// The mint of token_1 vault
     pub vault_1_mint: &'me AccountInfo<'info>,
+    /// Lp token mint
     pub lp_mint: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DepositKeys {
+    /// Pays to mint the position
     pub owner: Pubkey,
     pub authority: Pubkey,
     pub pool_state: Pubkey,
+    /// Owner lp token account
     pub owner_lp_token: Pubkey,
+    /// The payer's token account for token_0
     pub token_0_account: Pubkey,
+    /// The payer's token account for token_1
     pub token_1_account: Pubkey,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: Pubkey,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: Pubkey,
+    /// token Program
     pub token_program: Pubkey,
+    /// Token program 2022
     pub token_program_2022: Pubkey,
+    /// The mint of token_0 vault
     pub vault_0_mint: Pubkey,
+    /// The mint of token_1 vault
     pub vault_1_mint: Pubkey,
+    /// Lp token mint
     pub lp_mint: Pubkey,
 }
 impl From<DepositAccounts<'_, '_>> for DepositKeys {
@@ -1190,8 +1581,7 @@ impl From<[Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]> for DepositKeys {
         }
     }
 }
-impl<'info> From<DepositAccounts<'_, 'info>>
-for [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] {
+impl<'info> From<DepositAccounts<'_, 'info>> for [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] {
     fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
         [
             accounts.owner.clone(),
@@ -1212,13 +1602,13 @@ for [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<DepositAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
-for DepositAccounts<'me, 'info> {
+    for DepositAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             owner: &arr[0],
@@ -1238,7 +1628,18 @@ for DepositAccounts<'me, 'info> {
     }
 }
 pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Deposit lp token to the pool
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `lp_token_amount` - Pool token amount to transfer. token_a and token_b amount are set by the current exchange rate and size of the pool
+/// * `maximum_token_0_amount` -  Maximum token 0 amount to deposit, prevents excessive slippage
+/// * `maximum_token_1_amount` - Maximum token 1 amount to deposit, prevents excessive slippage
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositIxArgs {
     pub lp_token_amount: u64,
@@ -1258,15 +1659,10 @@ impl DepositIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEPOSIT_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        DEPOSIT_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(DepositIxArgs::deserialize(&mut reader)?))
     }
@@ -1280,6 +1676,15 @@ impl DepositIxData {
         Ok(data)
     }
 }
+/// Deposit lp token to the pool
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `lp_token_amount` - Pool token amount to transfer. token_a and token_b amount are set by the current exchange rate and size of the pool
+/// * `maximum_token_0_amount` -  Maximum token 0 amount to deposit, prevents excessive slippage
+/// * `maximum_token_1_amount` - Maximum token 1 amount to deposit, prevents excessive slippage
+///
 pub fn deposit_ix_with_program_id(
     program_id: Pubkey,
     keys: DepositKeys,
@@ -1293,10 +1698,16 @@ pub fn deposit_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn deposit_ix(
-    keys: DepositKeys,
-    args: DepositIxArgs,
-) -> std::io::Result<Instruction> {
+/// Deposit lp token to the pool
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `lp_token_amount` - Pool token amount to transfer. token_a and token_b amount are set by the current exchange rate and size of the pool
+/// * `maximum_token_0_amount` -  Maximum token 0 amount to deposit, prevents excessive slippage
+/// * `maximum_token_1_amount` - Maximum token 1 amount to deposit, prevents excessive slippage
+///
+pub fn deposit_ix(keys: DepositKeys, args: DepositIxArgs) -> std::io::Result<Instruction> {
     deposit_ix_with_program_id(crate::ID, keys, args)
 }
 pub fn deposit_invoke_with_program_id(
@@ -1308,10 +1719,7 @@ pub fn deposit_invoke_with_program_id(
     let ix = deposit_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn deposit_invoke(
-    accounts: DepositAccounts<'_, '_>,
-    args: DepositIxArgs,
-) -> ProgramResult {
+pub fn deposit_invoke(accounts: DepositAccounts<'_, '_>, args: DepositIxArgs) -> ProgramResult {
     deposit_invoke_with_program_id(crate::ID, accounts, args)
 }
 pub fn deposit_invoke_signed_with_program_id(
@@ -1391,51 +1799,225 @@ pub fn deposit_verify_account_privileges<'me, 'info>(
     deposit_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`DepositKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DepositKeysBuilder {
+    pub owner: Option<Pubkey>,
+    pub authority: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+    pub owner_lp_token: Option<Pubkey>,
+    pub token_0_account: Option<Pubkey>,
+    pub token_1_account: Option<Pubkey>,
+    pub token_0_vault: Option<Pubkey>,
+    pub token_1_vault: Option<Pubkey>,
+    pub token_program: Option<Pubkey>,
+    pub token_program_2022: Option<Pubkey>,
+    pub vault_0_mint: Option<Pubkey>,
+    pub vault_1_mint: Option<Pubkey>,
+    pub lp_mint: Option<Pubkey>,
+}
+impl DepositKeysBuilder {
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn owner_lp_token(mut self, owner_lp_token: Pubkey) -> Self {
+        self.owner_lp_token = Some(owner_lp_token);
+        self
+    }
+    pub fn token_0_account(mut self, token_0_account: Pubkey) -> Self {
+        self.token_0_account = Some(token_0_account);
+        self
+    }
+    pub fn token_1_account(mut self, token_1_account: Pubkey) -> Self {
+        self.token_1_account = Some(token_1_account);
+        self
+    }
+    pub fn token_0_vault(mut self, token_0_vault: Pubkey) -> Self {
+        self.token_0_vault = Some(token_0_vault);
+        self
+    }
+    pub fn token_1_vault(mut self, token_1_vault: Pubkey) -> Self {
+        self.token_1_vault = Some(token_1_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn token_program_2022(mut self, token_program_2022: Pubkey) -> Self {
+        self.token_program_2022 = Some(token_program_2022);
+        self
+    }
+    pub fn vault_0_mint(mut self, vault_0_mint: Pubkey) -> Self {
+        self.vault_0_mint = Some(vault_0_mint);
+        self
+    }
+    pub fn vault_1_mint(mut self, vault_1_mint: Pubkey) -> Self {
+        self.vault_1_mint = Some(vault_1_mint);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn build(self) -> Result<DepositKeys, MissingAccountError> {
+        let owner = self.owner.ok_or(MissingAccountError("owner"))?;
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        let owner_lp_token = self
+            .owner_lp_token
+            .ok_or(MissingAccountError("owner_lp_token"))?;
+        let token_0_account = self
+            .token_0_account
+            .ok_or(MissingAccountError("token_0_account"))?;
+        let token_1_account = self
+            .token_1_account
+            .ok_or(MissingAccountError("token_1_account"))?;
+        let token_0_vault = self
+            .token_0_vault
+            .ok_or(MissingAccountError("token_0_vault"))?;
+        let token_1_vault = self
+            .token_1_vault
+            .ok_or(MissingAccountError("token_1_vault"))?;
+        let token_program = self.token_program.unwrap_or(solana_program::pubkey!(
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        ));
+        let token_program_2022 = self.token_program_2022.unwrap_or(solana_program::pubkey!(
+            "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        ));
+        let vault_0_mint = self
+            .vault_0_mint
+            .ok_or(MissingAccountError("vault_0_mint"))?;
+        let vault_1_mint = self
+            .vault_1_mint
+            .ok_or(MissingAccountError("vault_1_mint"))?;
+        let lp_mint = self.lp_mint.ok_or(MissingAccountError("lp_mint"))?;
+        let authority = self.authority.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(DepositKeys {
+            owner,
+            authority,
+            pool_state,
+            owner_lp_token,
+            token_0_account,
+            token_1_account,
+            token_0_vault,
+            token_1_vault,
+            token_program,
+            token_program_2022,
+            vault_0_mint,
+            vault_1_mint,
+            lp_mint,
+        })
+    }
+}
 pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 20;
 #[derive(Copy, Clone, Debug)]
 pub struct InitializeAccounts<'me, 'info> {
+    /// Address paying to create the pool. Can be anyone
     pub creator: &'me AccountInfo<'info>,
+    /// Which config the pool belongs to.
     pub amm_config: &'me AccountInfo<'info>,
+    /// pool vault and lp mint authority
     pub authority: &'me AccountInfo<'info>,
+    /// PDA account:
+    /// seeds = [
+    /// POOL_SEED.as_bytes(),
+    /// amm_config.key().as_ref(),
+    /// token_0_mint.key().as_ref(),
+    /// token_1_mint.key().as_ref(),
+    /// ],
+    ///
+    /// Or random account: must be signed by cli
     pub pool_state: &'me AccountInfo<'info>,
+    /// Token_0 mint, the key must smaller than token_1 mint.
     pub token_0_mint: &'me AccountInfo<'info>,
+    /// Token_1 mint, the key must grater then token_0 mint.
     pub token_1_mint: &'me AccountInfo<'info>,
+    /// pool lp mint
     pub lp_mint: &'me AccountInfo<'info>,
+    /// payer token0 account
     pub creator_token_0: &'me AccountInfo<'info>,
+    /// creator token1 account
     pub creator_token_1: &'me AccountInfo<'info>,
+    /// creator lp token account
     pub creator_lp_token: &'me AccountInfo<'info>,
     pub token_0_vault: &'me AccountInfo<'info>,
     pub token_1_vault: &'me AccountInfo<'info>,
+    /// create pool fee account
     pub create_pool_fee: &'me AccountInfo<'info>,
+    /// an account to store oracle observations
     pub observation_state: &'me AccountInfo<'info>,
+    /// Program to create mint account and mint tokens
     pub token_program: &'me AccountInfo<'info>,
+    /// Spl token program or token program 2022
     pub token_0_program: &'me AccountInfo<'info>,
+    /// Spl token program or token program 2022
     pub token_1_program: &'me AccountInfo<'info>,
+    /// Program to create an ATA for receiving position NFT
     pub associated_token_program: &'me AccountInfo<'info>,
+    /// To create a new program account
     pub system_program: &'me AccountInfo<'info>,
+    /// Sysvar for program account
     pub rent: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct InitializeKeys {
+    /// Address paying to create the pool. Can be anyone
     pub creator: Pubkey,
+    /// Which config the pool belongs to.
     pub amm_config: Pubkey,
+    /// pool vault and lp mint authority
     pub authority: Pubkey,
+    /// PDA account:
+    /// seeds = [
+    /// POOL_SEED.as_bytes(),
+    /// amm_config.key().as_ref(),
+    /// token_0_mint.key().as_ref(),
+    /// token_1_mint.key().as_ref(),
+    /// ],
+    ///
+    /// Or random account: must be signed by cli
     pub pool_state: Pubkey,
+    /// Token_0 mint, the key must smaller than token_1 mint.
     pub token_0_mint: Pubkey,
+    /// Token_1 mint, the key must grater then token_0 mint.
     pub token_1_mint: Pubkey,
+    /// pool lp mint
     pub lp_mint: Pubkey,
+    /// payer token0 account
     pub creator_token_0: Pubkey,
+    /// creator token1 account
     pub creator_token_1: Pubkey,
+    /// creator lp token account
     pub creator_lp_token: Pubkey,
     pub token_0_vault: Pubkey,
     pub token_1_vault: Pubkey,
+    /// create pool fee account
     pub create_pool_fee: Pubkey,
+    /// an account to store oracle observations
     pub observation_state: Pubkey,
+    /// Program to create mint account and mint tokens
     pub token_program: Pubkey,
+    /// Spl token program or token program 2022
     pub token_0_program: Pubkey,
+    /// Spl token program or token program 2022
     pub token_1_program: Pubkey,
+    /// Program to create an ATA for receiving position NFT
     pub associated_token_program: Pubkey,
+    /// To create a new program account
     pub system_program: Pubkey,
+    /// Sysvar for program account
     pub rent: Pubkey,
 }
 impl From<InitializeAccounts<'_, '_>> for InitializeKeys {
@@ -1603,7 +2185,8 @@ impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
     }
 }
 impl<'info> From<InitializeAccounts<'_, 'info>>
-for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
         [
             accounts.creator.clone(),
@@ -1631,13 +2214,13 @@ for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<InitializeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
-for InitializeAccounts<'me, 'info> {
+    for InitializeAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             creator: &arr[0],
@@ -1664,7 +2247,18 @@ for InitializeAccounts<'me, 'info> {
     }
 }
 pub const INITIALIZE_IX_DISCM: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Creates a pool for the given token pair and the initial price
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `init_amount_0` - the initial amount_0 to deposit
+/// * `init_amount_1` - the initial amount_1 to deposit
+/// * `open_time` - the timestamp allowed for swap
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeIxArgs {
     pub init_amount_0: u64,
@@ -1684,15 +2278,10 @@ impl InitializeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        INITIALIZE_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(InitializeIxArgs::deserialize(&mut reader)?))
     }
@@ -1706,6 +2295,15 @@ impl InitializeIxData {
         Ok(data)
     }
 }
+/// Creates a pool for the given token pair and the initial price
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `init_amount_0` - the initial amount_0 to deposit
+/// * `init_amount_1` - the initial amount_1 to deposit
+/// * `open_time` - the timestamp allowed for swap
+///
 pub fn initialize_ix_with_program_id(
     program_id: Pubkey,
     keys: InitializeKeys,
@@ -1719,10 +2317,16 @@ pub fn initialize_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn initialize_ix(
-    keys: InitializeKeys,
-    args: InitializeIxArgs,
-) -> std::io::Result<Instruction> {
+/// Creates a pool for the given token pair and the initial price
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `init_amount_0` - the initial amount_0 to deposit
+/// * `init_amount_1` - the initial amount_1 to deposit
+/// * `open_time` - the timestamp allowed for swap
+///
+pub fn initialize_ix(keys: InitializeKeys, args: InitializeIxArgs) -> std::io::Result<Instruction> {
     initialize_ix_with_program_id(crate::ID, keys, args)
 }
 pub fn initialize_invoke_with_program_id(
@@ -1779,7 +2383,10 @@ pub fn initialize_verify_account_keys(
         (*accounts.token_program.key, keys.token_program),
         (*accounts.token_0_program.key, keys.token_0_program),
         (*accounts.token_1_program.key, keys.token_1_program),
-        (*accounts.associated_token_program.key, keys.associated_token_program),
+        (
+            *accounts.associated_token_program.key,
+            keys.associated_token_program,
+        ),
         (*accounts.system_program.key, keys.system_program),
         (*accounts.rent.key, keys.rent),
     ] {
@@ -1827,37 +2434,267 @@ pub fn initialize_verify_account_privileges<'me, 'info>(
     initialize_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`InitializeKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InitializeKeysBuilder {
+    pub creator: Option<Pubkey>,
+    pub amm_config: Option<Pubkey>,
+    pub authority: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+    pub token_0_mint: Option<Pubkey>,
+    pub token_1_mint: Option<Pubkey>,
+    pub lp_mint: Option<Pubkey>,
+    pub creator_token_0: Option<Pubkey>,
+    pub creator_token_1: Option<Pubkey>,
+    pub creator_lp_token: Option<Pubkey>,
+    pub token_0_vault: Option<Pubkey>,
+    pub token_1_vault: Option<Pubkey>,
+    pub create_pool_fee: Option<Pubkey>,
+    pub observation_state: Option<Pubkey>,
+    pub token_program: Option<Pubkey>,
+    pub token_0_program: Option<Pubkey>,
+    pub token_1_program: Option<Pubkey>,
+    pub associated_token_program: Option<Pubkey>,
+    pub system_program: Option<Pubkey>,
+    pub rent: Option<Pubkey>,
+}
+impl InitializeKeysBuilder {
+    pub fn creator(mut self, creator: Pubkey) -> Self {
+        self.creator = Some(creator);
+        self
+    }
+    pub fn amm_config(mut self, amm_config: Pubkey) -> Self {
+        self.amm_config = Some(amm_config);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn token_0_mint(mut self, token_0_mint: Pubkey) -> Self {
+        self.token_0_mint = Some(token_0_mint);
+        self
+    }
+    pub fn token_1_mint(mut self, token_1_mint: Pubkey) -> Self {
+        self.token_1_mint = Some(token_1_mint);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn creator_token_0(mut self, creator_token_0: Pubkey) -> Self {
+        self.creator_token_0 = Some(creator_token_0);
+        self
+    }
+    pub fn creator_token_1(mut self, creator_token_1: Pubkey) -> Self {
+        self.creator_token_1 = Some(creator_token_1);
+        self
+    }
+    pub fn creator_lp_token(mut self, creator_lp_token: Pubkey) -> Self {
+        self.creator_lp_token = Some(creator_lp_token);
+        self
+    }
+    pub fn token_0_vault(mut self, token_0_vault: Pubkey) -> Self {
+        self.token_0_vault = Some(token_0_vault);
+        self
+    }
+    pub fn token_1_vault(mut self, token_1_vault: Pubkey) -> Self {
+        self.token_1_vault = Some(token_1_vault);
+        self
+    }
+    pub fn create_pool_fee(mut self, create_pool_fee: Pubkey) -> Self {
+        self.create_pool_fee = Some(create_pool_fee);
+        self
+    }
+    pub fn observation_state(mut self, observation_state: Pubkey) -> Self {
+        self.observation_state = Some(observation_state);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn token_0_program(mut self, token_0_program: Pubkey) -> Self {
+        self.token_0_program = Some(token_0_program);
+        self
+    }
+    pub fn token_1_program(mut self, token_1_program: Pubkey) -> Self {
+        self.token_1_program = Some(token_1_program);
+        self
+    }
+    pub fn associated_token_program(mut self, associated_token_program: Pubkey) -> Self {
+        self.associated_token_program = Some(associated_token_program);
+        self
+    }
+    pub fn system_program(mut self, system_program: Pubkey) -> Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    pub fn rent(mut self, rent: Pubkey) -> Self {
+        self.rent = Some(rent);
+        self
+    }
+    pub fn build(self) -> Result<InitializeKeys, MissingAccountError> {
+        let creator = self.creator.ok_or(MissingAccountError("creator"))?;
+        let amm_config = self.amm_config.ok_or(MissingAccountError("amm_config"))?;
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        let token_0_mint = self
+            .token_0_mint
+            .ok_or(MissingAccountError("token_0_mint"))?;
+        let token_1_mint = self
+            .token_1_mint
+            .ok_or(MissingAccountError("token_1_mint"))?;
+        let creator_token_0 = self
+            .creator_token_0
+            .ok_or(MissingAccountError("creator_token_0"))?;
+        let creator_token_1 = self
+            .creator_token_1
+            .ok_or(MissingAccountError("creator_token_1"))?;
+        let create_pool_fee = self.create_pool_fee.unwrap_or(solana_program::pubkey!(
+            "DNXgeM9EiiaAbaWvwjHj9fQQLAX5ZsfHyvmYUNRAdNC8"
+        ));
+        let token_program = self.token_program.unwrap_or(solana_program::pubkey!(
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        ));
+        let token_0_program = self
+            .token_0_program
+            .ok_or(MissingAccountError("token_0_program"))?;
+        let token_1_program = self
+            .token_1_program
+            .ok_or(MissingAccountError("token_1_program"))?;
+        let associated_token_program =
+            self.associated_token_program
+                .unwrap_or(solana_program::pubkey!(
+                    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+                ));
+        let system_program = self
+            .system_program
+            .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111"));
+        let rent = self.rent.unwrap_or(solana_program::pubkey!(
+            "SysvarRent111111111111111111111111111111111"
+        ));
+        let authority = self.authority.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        let lp_mint = self.lp_mint.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"pool_lp_mint", pool_state.as_ref()];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        let creator_lp_token = self.creator_lp_token.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[
+                creator.as_ref(),
+                &[
+                    6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172,
+                    28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+                ],
+                lp_mint.as_ref(),
+            ];
+            Pubkey::find_program_address(
+                seeds,
+                &Pubkey::new_from_array([
+                    140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90,
+                    19, 153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89,
+                ]),
+            )
+            .0
+        });
+        let token_0_vault = self.token_0_vault.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"pool_vault", pool_state.as_ref(), token_0_mint.as_ref()];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        let token_1_vault = self.token_1_vault.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"pool_vault", pool_state.as_ref(), token_1_mint.as_ref()];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        let observation_state = self.observation_state.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"observation", pool_state.as_ref()];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(InitializeKeys {
+            creator,
+            amm_config,
+            authority,
+            pool_state,
+            token_0_mint,
+            token_1_mint,
+            lp_mint,
+            creator_token_0,
+            creator_token_1,
+            creator_lp_token,
+            token_0_vault,
+            token_1_vault,
+            create_pool_fee,
+            observation_state,
+            token_program,
+            token_0_program,
+            token_1_program,
+            associated_token_program,
+            system_program,
+            rent,
+        })
+    }
+}
 pub const SWAP_BASE_INPUT_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct SwapBaseInputAccounts<'me, 'info> {
+    /// The user performing the swap
     pub payer: &'me AccountInfo<'info>,
     pub authority: &'me AccountInfo<'info>,
+    /// The factory state to read protocol fees
     pub amm_config: &'me AccountInfo<'info>,
+    /// The program account of the pool in which the swap will be performed
     pub pool_state: &'me AccountInfo<'info>,
+    /// The user token account for input token
     pub input_token_account: &'me AccountInfo<'info>,
+    /// The user token account for output token
     pub output_token_account: &'me AccountInfo<'info>,
+    /// The vault token account for input token
     pub input_vault: &'me AccountInfo<'info>,
+    /// The vault token account for output token
     pub output_vault: &'me AccountInfo<'info>,
+    /// SPL program for input token transfers
     pub input_token_program: &'me AccountInfo<'info>,
+    /// SPL program for output token transfers
     pub output_token_program: &'me AccountInfo<'info>,
+    /// The mint of input token
     pub input_token_mint: &'me AccountInfo<'info>,
+    /// The mint of output token
     pub output_token_mint: &'me AccountInfo<'info>,
+    /// The program account for the most recent oracle observation
     pub observation_state: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SwapBaseInputKeys {
+    /// The user performing the swap
     pub payer: Pubkey,
     pub authority: Pubkey,
+    /// The factory state to read protocol fees
     pub amm_config: Pubkey,
+    /// The program account of the pool in which the swap will be performed
     pub pool_state: Pubkey,
+    /// The user token account for input token
     pub input_token_account: Pubkey,
+    /// The user token account for output token
     pub output_token_account: Pubkey,
+    /// The vault token account for input token
     pub input_vault: Pubkey,
+    /// The vault token account for output token
     pub output_vault: Pubkey,
+    /// SPL program for input token transfers
     pub input_token_program: Pubkey,
+    /// SPL program for output token transfers
     pub output_token_program: Pubkey,
+    /// The mint of input token
     pub input_token_mint: Pubkey,
+    /// The mint of output token
     pub output_token_mint: Pubkey,
+    /// The program account for the most recent oracle observation
     pub observation_state: Pubkey,
 }
 impl From<SwapBaseInputAccounts<'_, '_>> for SwapBaseInputKeys {
@@ -1976,7 +2813,8 @@ impl From<[Pubkey; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN]> for SwapBaseInputKeys {
     }
 }
 impl<'info> From<SwapBaseInputAccounts<'_, 'info>>
-for [AccountInfo<'info>; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: SwapBaseInputAccounts<'_, 'info>) -> Self {
         [
             accounts.payer.clone(),
@@ -1997,13 +2835,13 @@ for [AccountInfo<'info>; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<SwapBaseInputAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: SwapBaseInputAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN] = accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN]>
-for SwapBaseInputAccounts<'me, 'info> {
+    for SwapBaseInputAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; SWAP_BASE_INPUT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             payer: &arr[0],
@@ -2023,7 +2861,17 @@ for SwapBaseInputAccounts<'me, 'info> {
     }
 }
 pub const SWAP_BASE_INPUT_IX_DISCM: [u8; 8] = [143, 190, 90, 218, 196, 30, 51, 222];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Swap the tokens in the pool base input amount
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `amount_in` -  input amount to transfer, output to DESTINATION is based on the exchange rate
+/// * `minimum_amount_out` -  Minimum amount of output token, prevents excessive slippage
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwapBaseInputIxArgs {
     pub amount_in: u64,
@@ -2042,15 +2890,10 @@ impl SwapBaseInputIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SWAP_BASE_INPUT_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        SWAP_BASE_INPUT_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SWAP_BASE_INPUT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SwapBaseInputIxArgs::deserialize(&mut reader)?))
     }
@@ -2064,6 +2907,14 @@ impl SwapBaseInputIxData {
         Ok(data)
     }
 }
+/// Swap the tokens in the pool base input amount
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `amount_in` -  input amount to transfer, output to DESTINATION is based on the exchange rate
+/// * `minimum_amount_out` -  Minimum amount of output token, prevents excessive slippage
+///
 pub fn swap_base_input_ix_with_program_id(
     program_id: Pubkey,
     keys: SwapBaseInputKeys,
@@ -2077,6 +2928,14 @@ pub fn swap_base_input_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
+/// Swap the tokens in the pool base input amount
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `amount_in` -  input amount to transfer, output to DESTINATION is based on the exchange rate
+/// * `minimum_amount_out` -  Minimum amount of output token, prevents excessive slippage
+///
 pub fn swap_base_input_ix(
     keys: SwapBaseInputKeys,
     args: SwapBaseInputIxArgs,
@@ -2125,11 +2984,17 @@ pub fn swap_base_input_verify_account_keys(
         (*accounts.amm_config.key, keys.amm_config),
         (*accounts.pool_state.key, keys.pool_state),
         (*accounts.input_token_account.key, keys.input_token_account),
-        (*accounts.output_token_account.key, keys.output_token_account),
+        (
+            *accounts.output_token_account.key,
+            keys.output_token_account,
+        ),
         (*accounts.input_vault.key, keys.input_vault),
         (*accounts.output_vault.key, keys.output_vault),
         (*accounts.input_token_program.key, keys.input_token_program),
-        (*accounts.output_token_program.key, keys.output_token_program),
+        (
+            *accounts.output_token_program.key,
+            keys.output_token_program,
+        ),
         (*accounts.input_token_mint.key, keys.input_token_mint),
         (*accounts.output_token_mint.key, keys.output_token_mint),
         (*accounts.observation_state.key, keys.observation_state),
@@ -2174,37 +3039,181 @@ pub fn swap_base_input_verify_account_privileges<'me, 'info>(
     swap_base_input_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`SwapBaseInputKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SwapBaseInputKeysBuilder {
+    pub payer: Option<Pubkey>,
+    pub authority: Option<Pubkey>,
+    pub amm_config: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+    pub input_token_account: Option<Pubkey>,
+    pub output_token_account: Option<Pubkey>,
+    pub input_vault: Option<Pubkey>,
+    pub output_vault: Option<Pubkey>,
+    pub input_token_program: Option<Pubkey>,
+    pub output_token_program: Option<Pubkey>,
+    pub input_token_mint: Option<Pubkey>,
+    pub output_token_mint: Option<Pubkey>,
+    pub observation_state: Option<Pubkey>,
+}
+impl SwapBaseInputKeysBuilder {
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn amm_config(mut self, amm_config: Pubkey) -> Self {
+        self.amm_config = Some(amm_config);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn input_token_account(mut self, input_token_account: Pubkey) -> Self {
+        self.input_token_account = Some(input_token_account);
+        self
+    }
+    pub fn output_token_account(mut self, output_token_account: Pubkey) -> Self {
+        self.output_token_account = Some(output_token_account);
+        self
+    }
+    pub fn input_vault(mut self, input_vault: Pubkey) -> Self {
+        self.input_vault = Some(input_vault);
+        self
+    }
+    pub fn output_vault(mut self, output_vault: Pubkey) -> Self {
+        self.output_vault = Some(output_vault);
+        self
+    }
+    pub fn input_token_program(mut self, input_token_program: Pubkey) -> Self {
+        self.input_token_program = Some(input_token_program);
+        self
+    }
+    pub fn output_token_program(mut self, output_token_program: Pubkey) -> Self {
+        self.output_token_program = Some(output_token_program);
+        self
+    }
+    pub fn input_token_mint(mut self, input_token_mint: Pubkey) -> Self {
+        self.input_token_mint = Some(input_token_mint);
+        self
+    }
+    pub fn output_token_mint(mut self, output_token_mint: Pubkey) -> Self {
+        self.output_token_mint = Some(output_token_mint);
+        self
+    }
+    pub fn observation_state(mut self, observation_state: Pubkey) -> Self {
+        self.observation_state = Some(observation_state);
+        self
+    }
+    pub fn build(self) -> Result<SwapBaseInputKeys, MissingAccountError> {
+        let payer = self.payer.ok_or(MissingAccountError("payer"))?;
+        let amm_config = self.amm_config.ok_or(MissingAccountError("amm_config"))?;
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        let input_token_account = self
+            .input_token_account
+            .ok_or(MissingAccountError("input_token_account"))?;
+        let output_token_account = self
+            .output_token_account
+            .ok_or(MissingAccountError("output_token_account"))?;
+        let input_vault = self.input_vault.ok_or(MissingAccountError("input_vault"))?;
+        let output_vault = self
+            .output_vault
+            .ok_or(MissingAccountError("output_vault"))?;
+        let input_token_program = self
+            .input_token_program
+            .ok_or(MissingAccountError("input_token_program"))?;
+        let output_token_program = self
+            .output_token_program
+            .ok_or(MissingAccountError("output_token_program"))?;
+        let input_token_mint = self
+            .input_token_mint
+            .ok_or(MissingAccountError("input_token_mint"))?;
+        let output_token_mint = self
+            .output_token_mint
+            .ok_or(MissingAccountError("output_token_mint"))?;
+        let observation_state = self
+            .observation_state
+            .ok_or(MissingAccountError("observation_state"))?;
+        let authority = self.authority.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(SwapBaseInputKeys {
+            payer,
+            authority,
+            amm_config,
+            pool_state,
+            input_token_account,
+            output_token_account,
+            input_vault,
+            output_vault,
+            input_token_program,
+            output_token_program,
+            input_token_mint,
+            output_token_mint,
+            observation_state,
+        })
+    }
+}
 pub const SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
 pub struct SwapBaseOutputAccounts<'me, 'info> {
+    /// The user performing the swap
     pub payer: &'me AccountInfo<'info>,
     pub authority: &'me AccountInfo<'info>,
+    /// The factory state to read protocol fees
     pub amm_config: &'me AccountInfo<'info>,
+    /// The program account of the pool in which the swap will be performed
     pub pool_state: &'me AccountInfo<'info>,
+    /// The user token account for input token
     pub input_token_account: &'me AccountInfo<'info>,
+    /// The user token account for output token
     pub output_token_account: &'me AccountInfo<'info>,
+    /// The vault token account for input token
     pub input_vault: &'me AccountInfo<'info>,
+    /// The vault token account for output token
     pub output_vault: &'me AccountInfo<'info>,
+    /// SPL program for input token transfers
     pub input_token_program: &'me AccountInfo<'info>,
+    /// SPL program for output token transfers
     pub output_token_program: &'me AccountInfo<'info>,
+    /// The mint of input token
     pub input_token_mint: &'me AccountInfo<'info>,
+    /// The mint of output token
     pub output_token_mint: &'me AccountInfo<'info>,
+    /// The program account for the most recent oracle observation
     pub observation_state: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct SwapBaseOutputKeys {
+    /// The user performing the swap
     pub payer: Pubkey,
     pub authority: Pubkey,
+    /// The factory state to read protocol fees
     pub amm_config: Pubkey,
+    /// The program account of the pool in which the swap will be performed
     pub pool_state: Pubkey,
+    /// The user token account for input token
     pub input_token_account: Pubkey,
+    /// The user token account for output token
     pub output_token_account: Pubkey,
+    /// The vault token account for input token
     pub input_vault: Pubkey,
+    /// The vault token account for output token
     pub output_vault: Pubkey,
+    /// SPL program for input token transfers
     pub input_token_program: Pubkey,
+    /// SPL program for output token transfers
     pub output_token_program: Pubkey,
+    /// The mint of input token
     pub input_token_mint: Pubkey,
+    /// The mint of output token
     pub output_token_mint: Pubkey,
+    /// The program account for the most recent oracle observation
     pub observation_state: Pubkey,
 }
 impl From<SwapBaseOutputAccounts<'_, '_>> for SwapBaseOutputKeys {
@@ -2323,7 +3332,8 @@ impl From<[Pubkey; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN]> for SwapBaseOutputKeys {
     }
 }
 impl<'info> From<SwapBaseOutputAccounts<'_, 'info>>
-for [AccountInfo<'info>; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: SwapBaseOutputAccounts<'_, 'info>) -> Self {
         [
             accounts.payer.clone(),
@@ -2344,13 +3354,14 @@ for [AccountInfo<'info>; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<SwapBaseOutputAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: SwapBaseOutputAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN] =
+            accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN]>
-for SwapBaseOutputAccounts<'me, 'info> {
+    for SwapBaseOutputAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; SWAP_BASE_OUTPUT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             payer: &arr[0],
@@ -2370,7 +3381,17 @@ for SwapBaseOutputAccounts<'me, 'info> {
     }
 }
 pub const SWAP_BASE_OUTPUT_IX_DISCM: [u8; 8] = [55, 217, 98, 86, 163, 74, 180, 173];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Swap the tokens in the pool base output amount
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `max_amount_in` -  input amount prevents excessive slippage
+/// * `amount_out` -  amount of output token
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwapBaseOutputIxArgs {
     pub max_amount_in: u64,
@@ -2389,15 +3410,10 @@ impl SwapBaseOutputIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SWAP_BASE_OUTPUT_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        SWAP_BASE_OUTPUT_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SWAP_BASE_OUTPUT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SwapBaseOutputIxArgs::deserialize(&mut reader)?))
     }
@@ -2411,6 +3427,14 @@ impl SwapBaseOutputIxData {
         Ok(data)
     }
 }
+/// Swap the tokens in the pool base output amount
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `max_amount_in` -  input amount prevents excessive slippage
+/// * `amount_out` -  amount of output token
+///
 pub fn swap_base_output_ix_with_program_id(
     program_id: Pubkey,
     keys: SwapBaseOutputKeys,
@@ -2424,6 +3448,14 @@ pub fn swap_base_output_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
+/// Swap the tokens in the pool base output amount
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `max_amount_in` -  input amount prevents excessive slippage
+/// * `amount_out` -  amount of output token
+///
 pub fn swap_base_output_ix(
     keys: SwapBaseOutputKeys,
     args: SwapBaseOutputIxArgs,
@@ -2472,11 +3504,17 @@ pub fn swap_base_output_verify_account_keys(
         (*accounts.amm_config.key, keys.amm_config),
         (*accounts.pool_state.key, keys.pool_state),
         (*accounts.input_token_account.key, keys.input_token_account),
-        (*accounts.output_token_account.key, keys.output_token_account),
+        (
+            *accounts.output_token_account.key,
+            keys.output_token_account,
+        ),
         (*accounts.input_vault.key, keys.input_vault),
         (*accounts.output_vault.key, keys.output_vault),
         (*accounts.input_token_program.key, keys.input_token_program),
-        (*accounts.output_token_program.key, keys.output_token_program),
+        (
+            *accounts.output_token_program.key,
+            keys.output_token_program,
+        ),
         (*accounts.input_token_mint.key, keys.input_token_mint),
         (*accounts.output_token_mint.key, keys.output_token_mint),
         (*accounts.observation_state.key, keys.observation_state),
@@ -2521,15 +3559,139 @@ pub fn swap_base_output_verify_account_privileges<'me, 'info>(
     swap_base_output_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`SwapBaseOutputKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SwapBaseOutputKeysBuilder {
+    pub payer: Option<Pubkey>,
+    pub authority: Option<Pubkey>,
+    pub amm_config: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+    pub input_token_account: Option<Pubkey>,
+    pub output_token_account: Option<Pubkey>,
+    pub input_vault: Option<Pubkey>,
+    pub output_vault: Option<Pubkey>,
+    pub input_token_program: Option<Pubkey>,
+    pub output_token_program: Option<Pubkey>,
+    pub input_token_mint: Option<Pubkey>,
+    pub output_token_mint: Option<Pubkey>,
+    pub observation_state: Option<Pubkey>,
+}
+impl SwapBaseOutputKeysBuilder {
+    pub fn payer(mut self, payer: Pubkey) -> Self {
+        self.payer = Some(payer);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn amm_config(mut self, amm_config: Pubkey) -> Self {
+        self.amm_config = Some(amm_config);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn input_token_account(mut self, input_token_account: Pubkey) -> Self {
+        self.input_token_account = Some(input_token_account);
+        self
+    }
+    pub fn output_token_account(mut self, output_token_account: Pubkey) -> Self {
+        self.output_token_account = Some(output_token_account);
+        self
+    }
+    pub fn input_vault(mut self, input_vault: Pubkey) -> Self {
+        self.input_vault = Some(input_vault);
+        self
+    }
+    pub fn output_vault(mut self, output_vault: Pubkey) -> Self {
+        self.output_vault = Some(output_vault);
+        self
+    }
+    pub fn input_token_program(mut self, input_token_program: Pubkey) -> Self {
+        self.input_token_program = Some(input_token_program);
+        self
+    }
+    pub fn output_token_program(mut self, output_token_program: Pubkey) -> Self {
+        self.output_token_program = Some(output_token_program);
+        self
+    }
+    pub fn input_token_mint(mut self, input_token_mint: Pubkey) -> Self {
+        self.input_token_mint = Some(input_token_mint);
+        self
+    }
+    pub fn output_token_mint(mut self, output_token_mint: Pubkey) -> Self {
+        self.output_token_mint = Some(output_token_mint);
+        self
+    }
+    pub fn observation_state(mut self, observation_state: Pubkey) -> Self {
+        self.observation_state = Some(observation_state);
+        self
+    }
+    pub fn build(self) -> Result<SwapBaseOutputKeys, MissingAccountError> {
+        let payer = self.payer.ok_or(MissingAccountError("payer"))?;
+        let amm_config = self.amm_config.ok_or(MissingAccountError("amm_config"))?;
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        let input_token_account = self
+            .input_token_account
+            .ok_or(MissingAccountError("input_token_account"))?;
+        let output_token_account = self
+            .output_token_account
+            .ok_or(MissingAccountError("output_token_account"))?;
+        let input_vault = self.input_vault.ok_or(MissingAccountError("input_vault"))?;
+        let output_vault = self
+            .output_vault
+            .ok_or(MissingAccountError("output_vault"))?;
+        let input_token_program = self
+            .input_token_program
+            .ok_or(MissingAccountError("input_token_program"))?;
+        let output_token_program = self
+            .output_token_program
+            .ok_or(MissingAccountError("output_token_program"))?;
+        let input_token_mint = self
+            .input_token_mint
+            .ok_or(MissingAccountError("input_token_mint"))?;
+        let output_token_mint = self
+            .output_token_mint
+            .ok_or(MissingAccountError("output_token_mint"))?;
+        let observation_state = self
+            .observation_state
+            .ok_or(MissingAccountError("observation_state"))?;
+        let authority = self.authority.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(SwapBaseOutputKeys {
+            payer,
+            authority,
+            amm_config,
+            pool_state,
+            input_token_account,
+            output_token_account,
+            input_vault,
+            output_vault,
+            input_token_program,
+            output_token_program,
+            input_token_mint,
+            output_token_mint,
+            observation_state,
+        })
+    }
+}
 pub const UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct UpdateAmmConfigAccounts<'me, 'info> {
+    /// The amm config owner or admin
     pub owner: &'me AccountInfo<'info>,
+    /// Amm config account to be changed
     pub amm_config: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct UpdateAmmConfigKeys {
+    /// The amm config owner or admin
     pub owner: Pubkey,
+    /// Amm config account to be changed
     pub amm_config: Pubkey,
 }
 impl From<UpdateAmmConfigAccounts<'_, '_>> for UpdateAmmConfigKeys {
@@ -2571,20 +3733,22 @@ impl From<[Pubkey; UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN]> for UpdateAmmConfigKeys {
     }
 }
 impl<'info> From<UpdateAmmConfigAccounts<'_, 'info>>
-for [AccountInfo<'info>; UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: UpdateAmmConfigAccounts<'_, 'info>) -> Self {
         [accounts.owner.clone(), accounts.amm_config.clone()]
     }
 }
 impl<'info> From<UpdateAmmConfigAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: UpdateAmmConfigAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN] =
+            accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN]>
-for UpdateAmmConfigAccounts<'me, 'info> {
+    for UpdateAmmConfigAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; UPDATE_AMM_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             owner: &arr[0],
@@ -2593,7 +3757,22 @@ for UpdateAmmConfigAccounts<'me, 'info> {
     }
 }
 pub const UPDATE_AMM_CONFIG_IX_DISCM: [u8; 8] = [49, 60, 174, 136, 154, 28, 116, 200];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Updates the owner of the amm config
+/// Must be called by the current owner or admin
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `trade_fee_rate`- The new trade fee rate of amm config, be set when `param` is 0
+/// * `protocol_fee_rate`- The new protocol fee rate of amm config, be set when `param` is 1
+/// * `fund_fee_rate`- The new fund fee rate of amm config, be set when `param` is 2
+/// * `new_owner`- The config's new owner, be set when `param` is 3
+/// * `new_fund_owner`- The config's new fund owner, be set when `param` is 4
+/// * `param`- The value can be 0 | 1 | 2 | 3 | 4, otherwise will report a error
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateAmmConfigIxArgs {
     pub param: u8,
@@ -2612,15 +3791,10 @@ impl UpdateAmmConfigIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_AMM_CONFIG_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        UPDATE_AMM_CONFIG_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_AMM_CONFIG_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdateAmmConfigIxArgs::deserialize(&mut reader)?))
     }
@@ -2634,6 +3808,19 @@ impl UpdateAmmConfigIxData {
         Ok(data)
     }
 }
+/// Updates the owner of the amm config
+/// Must be called by the current owner or admin
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `trade_fee_rate`- The new trade fee rate of amm config, be set when `param` is 0
+/// * `protocol_fee_rate`- The new protocol fee rate of amm config, be set when `param` is 1
+/// * `fund_fee_rate`- The new fund fee rate of amm config, be set when `param` is 2
+/// * `new_owner`- The config's new owner, be set when `param` is 3
+/// * `new_fund_owner`- The config's new fund owner, be set when `param` is 4
+/// * `param`- The value can be 0 | 1 | 2 | 3 | 4, otherwise will report a error
+///
 pub fn update_amm_config_ix_with_program_id(
     program_id: Pubkey,
     keys: UpdateAmmConfigKeys,
@@ -2647,6 +3834,19 @@ pub fn update_amm_config_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
+/// Updates the owner of the amm config
+/// Must be called by the current owner or admin
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `trade_fee_rate`- The new trade fee rate of amm config, be set when `param` is 0
+/// * `protocol_fee_rate`- The new protocol fee rate of amm config, be set when `param` is 1
+/// * `fund_fee_rate`- The new fund fee rate of amm config, be set when `param` is 2
+/// * `new_owner`- The config's new owner, be set when `param` is 3
+/// * `new_fund_owner`- The config's new fund owner, be set when `param` is 4
+/// * `param`- The value can be 0 | 1 | 2 | 3 | 4, otherwise will report a error
+///
 pub fn update_amm_config_ix(
     keys: UpdateAmmConfigKeys,
     args: UpdateAmmConfigIxArgs,
@@ -2726,6 +3926,29 @@ pub fn update_amm_config_verify_account_privileges<'me, 'info>(
     update_amm_config_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`UpdateAmmConfigKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct UpdateAmmConfigKeysBuilder {
+    pub owner: Option<Pubkey>,
+    pub amm_config: Option<Pubkey>,
+}
+impl UpdateAmmConfigKeysBuilder {
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn amm_config(mut self, amm_config: Pubkey) -> Self {
+        self.amm_config = Some(amm_config);
+        self
+    }
+    pub fn build(self) -> Result<UpdateAmmConfigKeys, MissingAccountError> {
+        let owner = self.owner.unwrap_or(solana_program::pubkey!(
+            "GThUX1Atko4tqhN2NaiTazWSeFWMuiUvfFnyJyUghFMJ"
+        ));
+        let amm_config = self.amm_config.ok_or(MissingAccountError("amm_config"))?;
+        Ok(UpdateAmmConfigKeys { owner, amm_config })
+    }
+}
 pub const UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
 pub struct UpdatePoolStatusAccounts<'me, 'info> {
@@ -2776,20 +3999,22 @@ impl From<[Pubkey; UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN]> for UpdatePoolStatusKeys
     }
 }
 impl<'info> From<UpdatePoolStatusAccounts<'_, 'info>>
-for [AccountInfo<'info>; UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN] {
+    for [AccountInfo<'info>; UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN]
+{
     fn from(accounts: UpdatePoolStatusAccounts<'_, 'info>) -> Self {
         [accounts.authority.clone(), accounts.pool_state.clone()]
     }
 }
 impl<'info> From<UpdatePoolStatusAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: UpdatePoolStatusAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN] =
+            accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN]>
-for UpdatePoolStatusAccounts<'me, 'info> {
+    for UpdatePoolStatusAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; UPDATE_POOL_STATUS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             authority: &arr[0],
@@ -2798,7 +4023,16 @@ for UpdatePoolStatusAccounts<'me, 'info> {
     }
 }
 pub const UPDATE_POOL_STATUS_IX_DISCM: [u8; 8] = [130, 87, 108, 6, 46, 224, 117, 123];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Update pool status for given value
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `status` - The value of status
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdatePoolStatusIxArgs {
     pub status: u8,
@@ -2816,15 +4050,10 @@ impl UpdatePoolStatusIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_POOL_STATUS_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        UPDATE_POOL_STATUS_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UPDATE_POOL_STATUS_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(UpdatePoolStatusIxArgs::deserialize(&mut reader)?))
     }
@@ -2838,6 +4067,13 @@ impl UpdatePoolStatusIxData {
         Ok(data)
     }
 }
+/// Update pool status for given value
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `status` - The value of status
+///
 pub fn update_pool_status_ix_with_program_id(
     program_id: Pubkey,
     keys: UpdatePoolStatusKeys,
@@ -2851,6 +4087,13 @@ pub fn update_pool_status_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
+/// Update pool status for given value
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `status` - The value of status
+///
 pub fn update_pool_status_ix(
     keys: UpdatePoolStatusKeys,
     args: UpdatePoolStatusIxArgs,
@@ -2930,39 +4173,91 @@ pub fn update_pool_status_verify_account_privileges<'me, 'info>(
     update_pool_status_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`UpdatePoolStatusKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct UpdatePoolStatusKeysBuilder {
+    pub authority: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+}
+impl UpdatePoolStatusKeysBuilder {
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn build(self) -> Result<UpdatePoolStatusKeys, MissingAccountError> {
+        let authority = self.authority.unwrap_or(solana_program::pubkey!(
+            "GThUX1Atko4tqhN2NaiTazWSeFWMuiUvfFnyJyUghFMJ"
+        ));
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        Ok(UpdatePoolStatusKeys {
+            authority,
+            pool_state,
+        })
+    }
+}
 pub const WITHDRAW_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
 pub struct WithdrawAccounts<'me, 'info> {
+    /// Pays to mint the position
     pub owner: &'me AccountInfo<'info>,
     pub authority: &'me AccountInfo<'info>,
+    /// Pool state account
     pub pool_state: &'me AccountInfo<'info>,
+    /// Owner lp token account
     pub owner_lp_token: &'me AccountInfo<'info>,
+    /// The token account for receive token_0,
     pub token_0_account: &'me AccountInfo<'info>,
+    /// The token account for receive token_1
     pub token_1_account: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: &'me AccountInfo<'info>,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: &'me AccountInfo<'info>,
+    /// token Program
     pub token_program: &'me AccountInfo<'info>,
+    /// Token program 2022
     pub token_program_2022: &'me AccountInfo<'info>,
+    /// The mint of token_0 vault
     pub vault_0_mint: &'me AccountInfo<'info>,
+    /// The mint of token_1 vault
     pub vault_1_mint: &'me AccountInfo<'info>,
+    /// Pool lp token mint
     pub lp_mint: &'me AccountInfo<'info>,
+    /// memo program
     pub memo_program: &'me AccountInfo<'info>,
 }
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct WithdrawKeys {
+    /// Pays to mint the position
     pub owner: Pubkey,
     pub authority: Pubkey,
+    /// Pool state account
     pub pool_state: Pubkey,
+    /// Owner lp token account
     pub owner_lp_token: Pubkey,
+    /// The token account for receive token_0,
     pub token_0_account: Pubkey,
+    /// The token account for receive token_1
     pub token_1_account: Pubkey,
+    /// The address that holds pool tokens for token_0
     pub token_0_vault: Pubkey,
+    /// The address that holds pool tokens for token_1
     pub token_1_vault: Pubkey,
+    /// token Program
     pub token_program: Pubkey,
+    /// Token program 2022
     pub token_program_2022: Pubkey,
+    /// The mint of token_0 vault
     pub vault_0_mint: Pubkey,
+    /// The mint of token_1 vault
     pub vault_1_mint: Pubkey,
+    /// Pool lp token mint
     pub lp_mint: Pubkey,
+    /// memo program
     pub memo_program: Pubkey,
 }
 impl From<WithdrawAccounts<'_, '_>> for WithdrawKeys {
@@ -3087,8 +4382,7 @@ impl From<[Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]> for WithdrawKeys {
         }
     }
 }
-impl<'info> From<WithdrawAccounts<'_, 'info>>
-for [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] {
+impl<'info> From<WithdrawAccounts<'_, 'info>> for [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] {
     fn from(accounts: WithdrawAccounts<'_, 'info>) -> Self {
         [
             accounts.owner.clone(),
@@ -3110,13 +4404,13 @@ for [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] {
 }
 impl<'info> From<WithdrawAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
     fn from(accounts: WithdrawAccounts<'_, 'info>) -> Self {
-        let accounts_array: [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] = accounts
-            .into();
+        let accounts_array: [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] = accounts.into();
         Vec::from(accounts_array)
     }
 }
 impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
-for WithdrawAccounts<'me, 'info> {
+    for WithdrawAccounts<'me, 'info>
+{
     fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             owner: &arr[0],
@@ -3137,7 +4431,18 @@ for WithdrawAccounts<'me, 'info> {
     }
 }
 pub const WITHDRAW_IX_DISCM: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+/// Withdraw lp for token0 and token1
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `lp_token_amount` - Amount of pool tokens to burn. User receives an output of token a and b based on the percentage of the pool tokens that are returned.
+/// * `minimum_token_0_amount` -  Minimum amount of token 0 to receive, prevents excessive slippage
+/// * `minimum_token_1_amount` -  Minimum amount of token 1 to receive, prevents excessive slippage
+///
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawIxArgs {
     pub lp_token_amount: u64,
@@ -3157,15 +4462,10 @@ impl WithdrawIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != WITHDRAW_IX_DISCM {
-            return Err(
-                std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!(
-                        "discm does not match. Expected: {:?}. Received: {:?}",
-                        WITHDRAW_IX_DISCM, maybe_discm
-                    ),
-                ),
-            );
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                WITHDRAW_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(WithdrawIxArgs::deserialize(&mut reader)?))
     }
@@ -3179,6 +4479,15 @@ impl WithdrawIxData {
         Ok(data)
     }
 }
+/// Withdraw lp for token0 and token1
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `lp_token_amount` - Amount of pool tokens to burn. User receives an output of token a and b based on the percentage of the pool tokens that are returned.
+/// * `minimum_token_0_amount` -  Minimum amount of token 0 to receive, prevents excessive slippage
+/// * `minimum_token_1_amount` -  Minimum amount of token 1 to receive, prevents excessive slippage
+///
 pub fn withdraw_ix_with_program_id(
     program_id: Pubkey,
     keys: WithdrawKeys,
@@ -3192,10 +4501,16 @@ pub fn withdraw_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn withdraw_ix(
-    keys: WithdrawKeys,
-    args: WithdrawIxArgs,
-) -> std::io::Result<Instruction> {
+/// Withdraw lp for token0 and token1
+///
+/// # Arguments
+///
+/// * `ctx`- The context of accounts
+/// * `lp_token_amount` - Amount of pool tokens to burn. User receives an output of token a and b based on the percentage of the pool tokens that are returned.
+/// * `minimum_token_0_amount` -  Minimum amount of token 0 to receive, prevents excessive slippage
+/// * `minimum_token_1_amount` -  Minimum amount of token 1 to receive, prevents excessive slippage
+///
+pub fn withdraw_ix(keys: WithdrawKeys, args: WithdrawIxArgs) -> std::io::Result<Instruction> {
     withdraw_ix_with_program_id(crate::ID, keys, args)
 }
 pub fn withdraw_invoke_with_program_id(
@@ -3207,10 +4522,7 @@ pub fn withdraw_invoke_with_program_id(
     let ix = withdraw_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn withdraw_invoke(
-    accounts: WithdrawAccounts<'_, '_>,
-    args: WithdrawIxArgs,
-) -> ProgramResult {
+pub fn withdraw_invoke(accounts: WithdrawAccounts<'_, '_>, args: WithdrawIxArgs) -> ProgramResult {
     withdraw_invoke_with_program_id(crate::ID, accounts, args)
 }
 pub fn withdraw_invoke_signed_with_program_id(
@@ -3291,3 +4603,143 @@ pub fn withdraw_verify_account_privileges<'me, 'info>(
     withdraw_verify_signer_privileges(accounts)?;
     Ok(())
 }
+/// Builds [`WithdrawKeys`], resolving fixed address and PDA accounts that were not set
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WithdrawKeysBuilder {
+    pub owner: Option<Pubkey>,
+    pub authority: Option<Pubkey>,
+    pub pool_state: Option<Pubkey>,
+    pub owner_lp_token: Option<Pubkey>,
+    pub token_0_account: Option<Pubkey>,
+    pub token_1_account: Option<Pubkey>,
+    pub token_0_vault: Option<Pubkey>,
+    pub token_1_vault: Option<Pubkey>,
+    pub token_program: Option<Pubkey>,
+    pub token_program_2022: Option<Pubkey>,
+    pub vault_0_mint: Option<Pubkey>,
+    pub vault_1_mint: Option<Pubkey>,
+    pub lp_mint: Option<Pubkey>,
+    pub memo_program: Option<Pubkey>,
+}
+impl WithdrawKeysBuilder {
+    pub fn owner(mut self, owner: Pubkey) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+    pub fn pool_state(mut self, pool_state: Pubkey) -> Self {
+        self.pool_state = Some(pool_state);
+        self
+    }
+    pub fn owner_lp_token(mut self, owner_lp_token: Pubkey) -> Self {
+        self.owner_lp_token = Some(owner_lp_token);
+        self
+    }
+    pub fn token_0_account(mut self, token_0_account: Pubkey) -> Self {
+        self.token_0_account = Some(token_0_account);
+        self
+    }
+    pub fn token_1_account(mut self, token_1_account: Pubkey) -> Self {
+        self.token_1_account = Some(token_1_account);
+        self
+    }
+    pub fn token_0_vault(mut self, token_0_vault: Pubkey) -> Self {
+        self.token_0_vault = Some(token_0_vault);
+        self
+    }
+    pub fn token_1_vault(mut self, token_1_vault: Pubkey) -> Self {
+        self.token_1_vault = Some(token_1_vault);
+        self
+    }
+    pub fn token_program(mut self, token_program: Pubkey) -> Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    pub fn token_program_2022(mut self, token_program_2022: Pubkey) -> Self {
+        self.token_program_2022 = Some(token_program_2022);
+        self
+    }
+    pub fn vault_0_mint(mut self, vault_0_mint: Pubkey) -> Self {
+        self.vault_0_mint = Some(vault_0_mint);
+        self
+    }
+    pub fn vault_1_mint(mut self, vault_1_mint: Pubkey) -> Self {
+        self.vault_1_mint = Some(vault_1_mint);
+        self
+    }
+    pub fn lp_mint(mut self, lp_mint: Pubkey) -> Self {
+        self.lp_mint = Some(lp_mint);
+        self
+    }
+    pub fn memo_program(mut self, memo_program: Pubkey) -> Self {
+        self.memo_program = Some(memo_program);
+        self
+    }
+    pub fn build(self) -> Result<WithdrawKeys, MissingAccountError> {
+        let owner = self.owner.ok_or(MissingAccountError("owner"))?;
+        let pool_state = self.pool_state.ok_or(MissingAccountError("pool_state"))?;
+        let owner_lp_token = self
+            .owner_lp_token
+            .ok_or(MissingAccountError("owner_lp_token"))?;
+        let token_0_account = self
+            .token_0_account
+            .ok_or(MissingAccountError("token_0_account"))?;
+        let token_1_account = self
+            .token_1_account
+            .ok_or(MissingAccountError("token_1_account"))?;
+        let token_0_vault = self
+            .token_0_vault
+            .ok_or(MissingAccountError("token_0_vault"))?;
+        let token_1_vault = self
+            .token_1_vault
+            .ok_or(MissingAccountError("token_1_vault"))?;
+        let token_program = self.token_program.unwrap_or(solana_program::pubkey!(
+            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        ));
+        let token_program_2022 = self.token_program_2022.unwrap_or(solana_program::pubkey!(
+            "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        ));
+        let vault_0_mint = self
+            .vault_0_mint
+            .ok_or(MissingAccountError("vault_0_mint"))?;
+        let vault_1_mint = self
+            .vault_1_mint
+            .ok_or(MissingAccountError("vault_1_mint"))?;
+        let lp_mint = self.lp_mint.ok_or(MissingAccountError("lp_mint"))?;
+        let memo_program = self.memo_program.unwrap_or(solana_program::pubkey!(
+            "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+        ));
+        let authority = self.authority.unwrap_or_else(|| {
+            let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+            Pubkey::find_program_address(seeds, &crate::ID).0
+        });
+        Ok(WithdrawKeys {
+            owner,
+            authority,
+            pool_state,
+            owner_lp_token,
+            token_0_account,
+            token_1_account,
+            token_0_vault,
+            token_1_vault,
+            token_program,
+            token_program_2022,
+            vault_0_mint,
+            vault_1_mint,
+            lp_mint,
+            memo_program,
+        })
+    }
+}
+/// An account that a `*KeysBuilder` could not resolve and was not set
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MissingAccountError(pub &'static str);
+impl std::fmt::Display for MissingAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "missing account {}", self.0)
+    }
+}
+impl std::error::Error for MissingAccountError {}
@@ -5,6 +5,8 @@ pub mod typedefs;
 pub use typedefs::*;
 pub mod instructions;
 pub use instructions::*;
+pub mod pdas;
+pub use pdas::*;
 pub mod errors;
 pub use errors::*;
 pub mod events;
@@ -0,0 +1,109 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+pub fn find_authority_pda() -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed"];
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+pub fn create_authority_pda_with_bump(bump: u8) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[b"vault_and_lp_mint_auth_seed", &[bump]];
+    Pubkey::create_program_address(seeds, &crate::ID)
+}
+pub fn find_amm_config_pda(index: u16) -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[b"amm_config", &index.to_le_bytes()];
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+pub fn create_amm_config_pda_with_bump(index: u16, bump: u8) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[b"amm_config", &index.to_le_bytes(), &[bump]];
+    Pubkey::create_program_address(seeds, &crate::ID)
+}
+pub fn find_lp_mint_pda(pool_state: &Pubkey) -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[b"pool_lp_mint", pool_state.as_ref()];
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+pub fn create_lp_mint_pda_with_bump(pool_state: &Pubkey, bump: u8) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[b"pool_lp_mint", pool_state.as_ref(), &[bump]];
+    Pubkey::create_program_address(seeds, &crate::ID)
+}
+pub fn find_creator_lp_token_pda(creator: &Pubkey, lp_mint: &Pubkey) -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[
+        creator.as_ref(),
+        &[
+            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180,
+            133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+        ],
+        lp_mint.as_ref(),
+    ];
+    Pubkey::find_program_address(
+        seeds,
+        &Pubkey::new_from_array([
+            140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19,
+            153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89,
+        ]),
+    )
+}
+pub fn create_creator_lp_token_pda_with_bump(
+    creator: &Pubkey,
+    lp_mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[
+        creator.as_ref(),
+        &[
+            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180,
+            133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+        ],
+        lp_mint.as_ref(),
+        &[bump],
+    ];
+    Pubkey::create_program_address(
+        seeds,
+        &Pubkey::new_from_array([
+            140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19,
+            153, 218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89,
+        ]),
+    )
+}
+pub fn find_token_0_vault_pda(pool_state: &Pubkey, token_0_mint: &Pubkey) -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[b"pool_vault", pool_state.as_ref(), token_0_mint.as_ref()];
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+pub fn create_token_0_vault_pda_with_bump(
+    pool_state: &Pubkey,
+    token_0_mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[
+        b"pool_vault",
+        pool_state.as_ref(),
+        token_0_mint.as_ref(),
+        &[bump],
+    ];
+    Pubkey::create_program_address(seeds, &crate::ID)
+}
+pub fn find_token_1_vault_pda(pool_state: &Pubkey, token_1_mint: &Pubkey) -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[b"pool_vault", pool_state.as_ref(), token_1_mint.as_ref()];
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+pub fn create_token_1_vault_pda_with_bump(
+    pool_state: &Pubkey,
+    token_1_mint: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[
+        b"pool_vault",
+        pool_state.as_ref(),
+        token_1_mint.as_ref(),
+        &[bump],
+    ];
+    Pubkey::create_program_address(seeds, &crate::ID)
+}
+pub fn find_observation_state_pda(pool_state: &Pubkey) -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[b"observation", pool_state.as_ref()];
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+pub fn create_observation_state_pda_with_bump(
+    pool_state: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[b"observation", pool_state.as_ref(), &[bump]];
+    Pubkey::create_program_address(seeds, &crate::ID)
+}
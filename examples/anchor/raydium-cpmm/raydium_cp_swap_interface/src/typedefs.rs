@@ -1,94 +1,242 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 use solana_program::pubkey::Pubkey;
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+/// Holds the current owner of the factory
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AmmConfig {
+    /// Bump to identify PDA
     pub bump: u8,
+    /// Status to control if new pool can be create
     pub disable_create_pool: bool,
+    /// Config index
     pub index: u16,
+    /// The trade fee, denominated in hundredths of a bip (10^-6)
     pub trade_fee_rate: u64,
+    /// The protocol fee
     pub protocol_fee_rate: u64,
+    /// The fund fee, denominated in hundredths of a bip (10^-6)
     pub fund_fee_rate: u64,
+    /// Fee for create a new pool
     pub create_pool_fee: u64,
+    /// Address of the protocol fee owner
     pub protocol_owner: Pubkey,
+    /// Address of the fund fee owner
     pub fund_owner: Pubkey,
+    /// padding
     pub padding: [u64; 16],
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const AMM_CONFIG_LEN: usize = 228;
+/// Emitted when deposit and withdraw
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LpChangeEvent {
     pub pool_id: Pubkey,
     pub lp_amount_before: u64,
+    /// pool vault sub trade fees
     pub token_0_vault_before: u64,
+    /// pool vault sub trade fees
     pub token_1_vault_before: u64,
+    /// calculate result without transfer fee
     pub token_0_amount: u64,
+    /// calculate result without transfer fee
     pub token_1_amount: u64,
     pub token_0_transfer_fee: u64,
     pub token_1_transfer_fee: u64,
     pub change_type: u8,
 }
+pub const LP_CHANGE_EVENT_LEN: usize = 89;
+/// The element of observations in ObservationState
 #[repr(packed, C)]
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Observation {
+    /// The block timestamp of the observation
     pub block_timestamp: u64,
+    /// the cumulative of token0 price during the duration time, Q32.32, the remaining 64 bit for overflow
     pub cumulative_token_0_price_x32: u128,
+    /// the cumulative of token1 price during the duration time, Q32.32, the remaining 64 bit for overflow
     pub cumulative_token_1_price_x32: u128,
 }
 unsafe impl Pod for Observation {}
 unsafe impl Zeroable for Observation {}
+impl BorshSerialize for Observation {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let Self {
+            block_timestamp,
+            cumulative_token_0_price_x32,
+            cumulative_token_1_price_x32,
+        } = *self;
+        block_timestamp.serialize(writer)?;
+        cumulative_token_0_price_x32.serialize(writer)?;
+        cumulative_token_1_price_x32.serialize(writer)?;
+        Ok(())
+    }
+}
+const _: () = assert!(std::mem::size_of::<Observation>() == 40);
+pub const OBSERVATION_LEN: usize = 40;
 #[repr(packed, C)]
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObservationState {
+    /// Whether the ObservationState is initialized
     pub initialized: bool,
+    /// the most-recently updated index of the observations array
     pub observation_index: u16,
     pub pool_id: Pubkey,
+    /// observation array
+    #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))]
     pub observations: [Observation; 100],
+    /// padding for feature update
     pub padding: [u64; 4],
 }
 unsafe impl Pod for ObservationState {}
 unsafe impl Zeroable for ObservationState {}
+impl BorshSerialize for ObservationState {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let Self {
+            initialized,
+            observation_index,
+            pool_id,
+            observations,
+            padding,
+        } = *self;
+        initialized.serialize(writer)?;
+        observation_index.serialize(writer)?;
+        pool_id.serialize(writer)?;
+        observations.serialize(writer)?;
+        padding.serialize(writer)?;
+        Ok(())
+    }
+}
+pub const OBSERVATION_STATE_LEN: usize = 4067;
 #[repr(packed, C)]
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PoolState {
+    /// Which config the pool belongs
     pub amm_config: Pubkey,
+    /// pool creator
     pub pool_creator: Pubkey,
+    /// Token A
     pub token_0_vault: Pubkey,
+    /// Token B
     pub token_1_vault: Pubkey,
+    /// Pool tokens are issued when A or B tokens are deposited.
+    /// Pool tokens can be withdrawn back to the original A or B token.
     pub lp_mint: Pubkey,
+    /// Mint information for token A
     pub token_0_mint: Pubkey,
+    /// Mint information for token B
     pub token_1_mint: Pubkey,
+    /// token_0 program
     pub token_0_program: Pubkey,
+    /// token_1 program
     pub token_1_program: Pubkey,
+    /// observation account to store oracle data
     pub observation_key: Pubkey,
     pub auth_bump: u8,
+    /// Bitwise representation of the state of the pool
+    /// bit0, 1: disable deposit(value is 1), 0: normal
+    /// bit1, 1: disable withdraw(value is 2), 0: normal
+    /// bit2, 1: disable swap(value is 4), 0: normal
     pub status: u8,
     pub lp_mint_decimals: u8,
+    /// mint0 and mint1 decimals
     pub mint_0_decimals: u8,
     pub mint_1_decimals: u8,
+    /// True circulating supply without burns and lock ups
     pub lp_supply: u64,
+    /// The amounts of token_0 and token_1 that are owed to the liquidity provider.
     pub protocol_fees_token_0: u64,
     pub protocol_fees_token_1: u64,
     pub fund_fees_token_0: u64,
     pub fund_fees_token_1: u64,
+    /// The timestamp allowed for swap in the pool.
     pub open_time: u64,
+    /// recent epoch
     pub recent_epoch: u64,
+    /// padding for future updates
     pub padding: [u64; 31],
 }
 unsafe impl Pod for PoolState {}
 unsafe impl Zeroable for PoolState {}
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+impl BorshSerialize for PoolState {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let Self {
+            amm_config,
+            pool_creator,
+            token_0_vault,
+            token_1_vault,
+            lp_mint,
+            token_0_mint,
+            token_1_mint,
+            token_0_program,
+            token_1_program,
+            observation_key,
+            auth_bump,
+            status,
+            lp_mint_decimals,
+            mint_0_decimals,
+            mint_1_decimals,
+            lp_supply,
+            protocol_fees_token_0,
+            protocol_fees_token_1,
+            fund_fees_token_0,
+            fund_fees_token_1,
+            open_time,
+            recent_epoch,
+            padding,
+        } = *self;
+        amm_config.serialize(writer)?;
+        pool_creator.serialize(writer)?;
+        token_0_vault.serialize(writer)?;
+        token_1_vault.serialize(writer)?;
+        lp_mint.serialize(writer)?;
+        token_0_mint.serialize(writer)?;
+        token_1_mint.serialize(writer)?;
+        token_0_program.serialize(writer)?;
+        token_1_program.serialize(writer)?;
+        observation_key.serialize(writer)?;
+        auth_bump.serialize(writer)?;
+        status.serialize(writer)?;
+        lp_mint_decimals.serialize(writer)?;
+        mint_0_decimals.serialize(writer)?;
+        mint_1_decimals.serialize(writer)?;
+        lp_supply.serialize(writer)?;
+        protocol_fees_token_0.serialize(writer)?;
+        protocol_fees_token_1.serialize(writer)?;
+        fund_fees_token_0.serialize(writer)?;
+        fund_fees_token_1.serialize(writer)?;
+        open_time.serialize(writer)?;
+        recent_epoch.serialize(writer)?;
+        padding.serialize(writer)?;
+        Ok(())
+    }
+}
+const _: () = assert!(std::mem::size_of::<PoolState>() == 629);
+pub const POOL_STATE_LEN: usize = 629;
+/// Emitted when swap
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwapEvent {
     pub pool_id: Pubkey,
+    /// pool vault sub trade fees
     pub input_vault_before: u64,
+    /// pool vault sub trade fees
     pub output_vault_before: u64,
+    /// calculate result without transfer fee
     pub input_amount: u64,
+    /// calculate result without transfer fee
     pub output_amount: u64,
     pub input_transfer_fee: u64,
     pub output_transfer_fee: u64,
     pub base_input: bool,
 }
+pub const SWAP_EVENT_LEN: usize = 81;
@@ -0,0 +1,225 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorTypeMapProgramIx {
+    SetConfig(SetConfigIxArgs),
+}
+impl AnchorTypeMapProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            SET_CONFIG_IX_DISCM => Ok(Self::SetConfig(SetConfigIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::SetConfig(args) => {
+                writer.write_all(&SET_CONFIG_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const SET_CONFIG_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct SetConfigAccounts<'me, 'info> {
+    pub config: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetConfigKeys {
+    pub config: Pubkey,
+}
+impl From<SetConfigAccounts<'_, '_>> for SetConfigKeys {
+    fn from(accounts: SetConfigAccounts) -> Self {
+        Self {
+            config: *accounts.config.key,
+        }
+    }
+}
+impl From<SetConfigKeys> for [AccountMeta; SET_CONFIG_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetConfigKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.config,
+            is_signer: false,
+            is_writable: true,
+        }]
+    }
+}
+impl From<SetConfigKeys> for Vec<AccountMeta> {
+    fn from(keys: SetConfigKeys) -> Self {
+        let accounts: [AccountMeta; SET_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; SET_CONFIG_IX_ACCOUNTS_LEN]> for SetConfigKeys {
+    fn from(pubkeys: [Pubkey; SET_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self { config: pubkeys[0] }
+    }
+}
+impl<'info> From<SetConfigAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_CONFIG_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetConfigAccounts<'_, 'info>) -> Self {
+        [accounts.config.clone()]
+    }
+}
+impl<'info> From<SetConfigAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetConfigAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_CONFIG_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_CONFIG_IX_ACCOUNTS_LEN]>
+    for SetConfigAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_CONFIG_IX_ACCOUNTS_LEN]) -> Self {
+        Self { config: &arr[0] }
+    }
+}
+pub const SET_CONFIG_IX_DISCM: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetConfigIxArgs {
+    pub blockhash: Hash,
+    pub rate: Fraction,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetConfigIxData(pub SetConfigIxArgs);
+impl From<SetConfigIxArgs> for SetConfigIxData {
+    fn from(args: SetConfigIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetConfigIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_CONFIG_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_CONFIG_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(SetConfigIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_CONFIG_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_config_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetConfigKeys,
+    args: SetConfigIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_CONFIG_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetConfigIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_config_ix(keys: SetConfigKeys, args: SetConfigIxArgs) -> std::io::Result<Instruction> {
+    set_config_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_config_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetConfigAccounts<'_, '_>,
+    args: SetConfigIxArgs,
+) -> ProgramResult {
+    let keys: SetConfigKeys = accounts.into();
+    let ix = set_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_config_invoke(
+    accounts: SetConfigAccounts<'_, '_>,
+    args: SetConfigIxArgs,
+) -> ProgramResult {
+    set_config_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_config_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetConfigAccounts<'_, '_>,
+    args: SetConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetConfigKeys = accounts.into();
+    let ix = set_config_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_config_invoke_signed(
+    accounts: SetConfigAccounts<'_, '_>,
+    args: SetConfigIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_config_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_config_verify_account_keys(
+    accounts: SetConfigAccounts<'_, '_>,
+    keys: SetConfigKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(*accounts.config.key, keys.config)] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_config_verify_writable_privileges<'me, 'info>(
+    accounts: SetConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.config] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_config_verify_account_privileges<'me, 'info>(
+    accounts: SetConfigAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_config_verify_writable_privileges(accounts)?;
+    Ok(())
+}
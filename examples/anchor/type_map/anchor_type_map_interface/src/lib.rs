@@ -0,0 +1,5 @@
+solana_program::declare_id!("TypeMap111111111111111111111111111111111111");
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
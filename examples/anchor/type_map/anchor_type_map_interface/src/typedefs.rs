@@ -0,0 +1,9 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub last_blockhash: Hash,
+    pub rates: [Fraction; 4],
+}
+pub type Fraction = u128;
+pub use solana_program::hash::Hash;
@@ -0,0 +1,227 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorTypedefKindsProgramIx {
+    SetOwners(SetOwnersIxArgs),
+}
+impl AnchorTypedefKindsProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            SET_OWNERS_IX_DISCM => Ok(Self::SetOwners(SetOwnersIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::SetOwners(args) => {
+                writer.write_all(&SET_OWNERS_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const SET_OWNERS_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+pub struct SetOwnersAccounts<'me, 'info> {
+    pub state: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetOwnersKeys {
+    pub state: Pubkey,
+}
+impl From<SetOwnersAccounts<'_, '_>> for SetOwnersKeys {
+    fn from(accounts: SetOwnersAccounts) -> Self {
+        Self {
+            state: *accounts.state.key,
+        }
+    }
+}
+impl From<SetOwnersKeys> for [AccountMeta; SET_OWNERS_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetOwnersKeys) -> Self {
+        [AccountMeta {
+            pubkey: keys.state,
+            is_signer: false,
+            is_writable: true,
+        }]
+    }
+}
+impl From<SetOwnersKeys> for Vec<AccountMeta> {
+    fn from(keys: SetOwnersKeys) -> Self {
+        let accounts: [AccountMeta; SET_OWNERS_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; SET_OWNERS_IX_ACCOUNTS_LEN]> for SetOwnersKeys {
+    fn from(pubkeys: [Pubkey; SET_OWNERS_IX_ACCOUNTS_LEN]) -> Self {
+        Self { state: pubkeys[0] }
+    }
+}
+impl<'info> From<SetOwnersAccounts<'_, 'info>>
+    for [AccountInfo<'info>; SET_OWNERS_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: SetOwnersAccounts<'_, 'info>) -> Self {
+        [accounts.state.clone()]
+    }
+}
+impl<'info> From<SetOwnersAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetOwnersAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_OWNERS_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_OWNERS_IX_ACCOUNTS_LEN]>
+    for SetOwnersAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; SET_OWNERS_IX_ACCOUNTS_LEN]) -> Self {
+        Self { state: &arr[0] }
+    }
+}
+pub const SET_OWNERS_IX_DISCM: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetOwnersIxArgs {
+    pub owners: Owners,
+    pub amount: Amount,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetOwnersIxData(pub SetOwnersIxArgs);
+impl From<SetOwnersIxArgs> for SetOwnersIxData {
+    fn from(args: SetOwnersIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetOwnersIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_OWNERS_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_OWNERS_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(SetOwnersIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&SET_OWNERS_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn set_owners_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetOwnersKeys,
+    args: SetOwnersIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_OWNERS_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetOwnersIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_owners_ix(keys: SetOwnersKeys, args: SetOwnersIxArgs) -> std::io::Result<Instruction> {
+    set_owners_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn set_owners_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetOwnersAccounts<'_, '_>,
+    args: SetOwnersIxArgs,
+) -> ProgramResult {
+    let keys: SetOwnersKeys = accounts.into();
+    let ix = set_owners_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn set_owners_invoke(
+    accounts: SetOwnersAccounts<'_, '_>,
+    args: SetOwnersIxArgs,
+) -> ProgramResult {
+    set_owners_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_owners_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetOwnersAccounts<'_, '_>,
+    args: SetOwnersIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: SetOwnersKeys = accounts.into();
+    let ix = set_owners_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn set_owners_invoke_signed(
+    accounts: SetOwnersAccounts<'_, '_>,
+    args: SetOwnersIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    set_owners_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn set_owners_verify_account_keys(
+    accounts: SetOwnersAccounts<'_, '_>,
+    keys: SetOwnersKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(*accounts.state.key, keys.state)] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn set_owners_verify_writable_privileges<'me, 'info>(
+    accounts: SetOwnersAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.state] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn set_owners_verify_account_privileges<'me, 'info>(
+    accounts: SetOwnersAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    set_owners_verify_writable_privileges(accounts)?;
+    Ok(())
+}
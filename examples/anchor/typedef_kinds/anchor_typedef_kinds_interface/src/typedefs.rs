@@ -0,0 +1,29 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+/// Token amount in atomics
+pub type Amount = u64;
+pub const AMOUNT_LEN: usize = 8;
+pub type Owners = [Pubkey; 2];
+pub const OWNERS_LEN: usize = 64;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Marker;
+pub const MARKER_LEN: usize = 0;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pair(pub u64, pub Amount);
+pub const PAIR_LEN: usize = 16;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tuples {
+    pub pair: (u8, Pubkey),
+    pub single: (u16,),
+    pub marker: Marker,
+}
+pub const TUPLES_LEN: usize = 35;
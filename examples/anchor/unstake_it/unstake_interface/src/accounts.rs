@@ -1,13 +1,14 @@
 use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
-pub const FEE_ACCOUNT_DISCM: [u8; 8] = [24, 55, 150, 250, 168, 27, 101, 178];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Fee {
-    pub fee: FeeEnum,
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
 }
-#[derive(Clone, Debug, PartialEq)]
+pub const FEE_ACCOUNT_DISCM: [u8; 8] = [24, 55, 150, 250, 168, 27, 101, 178];
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd)]
 pub struct FeeAccount(pub Fee);
 impl FeeAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -16,13 +17,10 @@ impl FeeAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != FEE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FEE_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                FEE_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(Fee::deserialize(&mut reader)?))
     }
@@ -36,15 +34,34 @@ impl FeeAccount {
         Ok(data)
     }
 }
-pub const POOL_ACCOUNT_DISCM: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Pool {
-    pub fee_authority: Pubkey,
-    pub lp_mint: Pubkey,
-    pub incoming_stake: u64,
+impl FeeAccount {
+    pub const MIN_LEN: usize = 25;
+    /// Borsh-serialized length of this account's data, including the discriminant
+    pub fn serialized_len(&self) -> usize {
+        FEE_ACCOUNT_DISCM.len() + self.0.serialized_len()
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+impl FeeAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, FEE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fee`
+    pub fn filter_fee(fee: &FeeEnum) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((8, borsh::to_vec(fee)?))
+    }
+}
+#[cfg(feature = "client")]
+impl FeeAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_fee(fee: &FeeEnum) -> std::io::Result<RpcFilterType> {
+        Self::filter_fee(fee).map(memcmp_filter)
+    }
+}
+pub const POOL_ACCOUNT_DISCM: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct PoolAccount(pub Pool);
 impl PoolAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -53,13 +70,10 @@ impl PoolAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != POOL_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    POOL_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                POOL_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(Pool::deserialize(&mut reader)?))
     }
@@ -73,16 +87,42 @@ impl PoolAccount {
         Ok(data)
     }
 }
-pub const PROTOCOL_FEE_ACCOUNT_DISCM: [u8; 8] = [121, 127, 98, 139, 72, 110, 44, 118];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ProtocolFee {
-    pub destination: Pubkey,
-    pub authority: Pubkey,
-    pub fee_ratio: Rational,
-    pub referrer_fee_ratio: Rational,
+pub const POOL_ACCOUNT_LEN: usize = 80;
+impl PoolAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, POOL_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fee_authority`
+    pub fn filter_fee_authority(fee_authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, fee_authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lp_mint`
+    pub fn filter_lp_mint(lp_mint: &Pubkey) -> (usize, Vec<u8>) {
+        (40, lp_mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `incoming_stake`
+    pub fn filter_incoming_stake(incoming_stake: u64) -> (usize, Vec<u8>) {
+        (72, incoming_stake.to_le_bytes().to_vec())
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl PoolAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_fee_authority(fee_authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_fee_authority(fee_authority))
+    }
+    pub fn rpc_filter_lp_mint(lp_mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_lp_mint(lp_mint))
+    }
+    pub fn rpc_filter_incoming_stake(incoming_stake: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_incoming_stake(incoming_stake))
+    }
+}
+pub const PROTOCOL_FEE_ACCOUNT_DISCM: [u8; 8] = [121, 127, 98, 139, 72, 110, 44, 118];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct ProtocolFeeAccount(pub ProtocolFee);
 impl ProtocolFeeAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -91,13 +131,10 @@ impl ProtocolFeeAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PROTOCOL_FEE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PROTOCOL_FEE_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                PROTOCOL_FEE_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(ProtocolFee::deserialize(&mut reader)?))
     }
@@ -111,13 +148,53 @@ impl ProtocolFeeAccount {
         Ok(data)
     }
 }
-pub const STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM: [u8; 8] = [144, 205, 183, 241, 3, 250, 208, 215];
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct StakeAccountRecord {
-    pub lamports_at_creation: u64,
+pub const PROTOCOL_FEE_ACCOUNT_LEN: usize = 104;
+impl ProtocolFeeAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, PROTOCOL_FEE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `destination`
+    pub fn filter_destination(destination: &Pubkey) -> (usize, Vec<u8>) {
+        (8, destination.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (40, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `fee_ratio`
+    pub fn filter_fee_ratio(fee_ratio: &Rational) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((72, borsh::to_vec(fee_ratio)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `referrer_fee_ratio`
+    pub fn filter_referrer_fee_ratio(
+        referrer_fee_ratio: &Rational,
+    ) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((88, borsh::to_vec(referrer_fee_ratio)?))
+    }
 }
-#[derive(Clone, Debug, PartialEq)]
+#[cfg(feature = "client")]
+impl ProtocolFeeAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_destination(destination: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_destination(destination))
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_fee_ratio(fee_ratio: &Rational) -> std::io::Result<RpcFilterType> {
+        Self::filter_fee_ratio(fee_ratio).map(memcmp_filter)
+    }
+    pub fn rpc_filter_referrer_fee_ratio(
+        referrer_fee_ratio: &Rational,
+    ) -> std::io::Result<RpcFilterType> {
+        Self::filter_referrer_fee_ratio(referrer_fee_ratio).map(memcmp_filter)
+    }
+}
+pub const STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM: [u8; 8] = [144, 205, 183, 241, 3, 250, 208, 215];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
 pub struct StakeAccountRecordAccount(pub StakeAccountRecord);
 impl StakeAccountRecordAccount {
     pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
@@ -126,13 +203,10 @@ impl StakeAccountRecordAccount {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM, maybe_discm
+            )));
         }
         Ok(Self(StakeAccountRecord::deserialize(&mut reader)?))
     }
@@ -146,3 +220,72 @@ impl StakeAccountRecordAccount {
         Ok(data)
     }
 }
+pub const STAKE_ACCOUNT_RECORD_ACCOUNT_LEN: usize = 16;
+impl StakeAccountRecordAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `lamports_at_creation`
+    pub fn filter_lamports_at_creation(lamports_at_creation: u64) -> (usize, Vec<u8>) {
+        (8, lamports_at_creation.to_le_bytes().to_vec())
+    }
+}
+#[cfg(feature = "client")]
+impl StakeAccountRecordAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_lamports_at_creation(lamports_at_creation: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_lamports_at_creation(lamports_at_creation))
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnstakeAccountData {
+    Fee(FeeAccount),
+    Pool(PoolAccount),
+    ProtocolFee(ProtocolFeeAccount),
+    StakeAccountRecord(StakeAccountRecordAccount),
+}
+impl UnstakeAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&FEE_ACCOUNT_DISCM) {
+            return Ok(Self::Fee(FeeAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&POOL_ACCOUNT_DISCM) {
+            return Ok(Self::Pool(PoolAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&PROTOCOL_FEE_ACCOUNT_DISCM) {
+            return Ok(Self::ProtocolFee(ProtocolFeeAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM) {
+            return Ok(Self::StakeAccountRecord(
+                StakeAccountRecordAccount::deserialize(buf)?,
+            ));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::Fee(_) => &FEE_ACCOUNT_DISCM,
+            Self::Pool(_) => &POOL_ACCOUNT_DISCM,
+            Self::ProtocolFee(_) => &PROTOCOL_FEE_ACCOUNT_DISCM,
+            Self::StakeAccountRecord(_) => &STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fee(_) => "Fee",
+            Self::Pool(_) => "Pool",
+            Self::ProtocolFee(_) => "ProtocolFee",
+            Self::StakeAccountRecord(_) => "StakeAccountRecord",
+        }
+    }
+}
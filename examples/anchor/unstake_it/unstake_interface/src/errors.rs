@@ -1,8 +1,4 @@
-use solana_program::{
-    decode_error::DecodeError,
-    msg,
-    program_error::{PrintProgramError, ProgramError},
-};
+use solana_program::program_error::ProgramError;
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
 pub enum UnstakeError {
@@ -46,20 +42,3 @@ impl From<UnstakeError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
-impl<T> DecodeError<T> for UnstakeError {
-    fn type_of() -> &'static str {
-        "UnstakeError"
-    }
-}
-impl PrintProgramError for UnstakeError {
-    fn print<E>(&self)
-    where
-        E: 'static
-            + std::error::Error
-            + DecodeError<E>
-            + PrintProgramError
-            + num_traits::FromPrimitive,
-    {
-        msg!(&self.to_string());
-    }
-}
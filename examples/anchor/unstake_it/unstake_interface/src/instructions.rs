@@ -48,10 +48,10 @@ impl UnstakeProgramIx {
             RECLAIM_STAKE_ACCOUNT_IX_DISCM => Ok(Self::ReclaimStakeAccount),
             UNSTAKE_IX_DISCM => Ok(Self::Unstake),
             UNSTAKE_WSOL_IX_DISCM => Ok(Self::UnstakeWsol),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
         }
     }
     pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -148,6 +148,12 @@ impl From<InitProtocolFeeKeys> for [AccountMeta; INIT_PROTOCOL_FEE_IX_ACCOUNTS_L
         ]
     }
 }
+impl From<InitProtocolFeeKeys> for Vec<AccountMeta> {
+    fn from(keys: InitProtocolFeeKeys) -> Self {
+        let accounts: [AccountMeta; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]> for InitProtocolFeeKeys {
     fn from(pubkeys: [Pubkey; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -168,6 +174,13 @@ impl<'info> From<InitProtocolFeeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<InitProtocolFeeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitProtocolFeeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]>
     for InitProtocolFeeAccounts<'me, 'info>
 {
@@ -188,13 +201,10 @@ impl InitProtocolFeeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INIT_PROTOCOL_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INIT_PROTOCOL_FEE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INIT_PROTOCOL_FEE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -327,6 +337,12 @@ impl From<SetProtocolFeeKeys> for [AccountMeta; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN
         ]
     }
 }
+impl From<SetProtocolFeeKeys> for Vec<AccountMeta> {
+    fn from(keys: SetProtocolFeeKeys) -> Self {
+        let accounts: [AccountMeta; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]> for SetProtocolFeeKeys {
     fn from(pubkeys: [Pubkey; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -345,6 +361,13 @@ impl<'info> From<SetProtocolFeeAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<SetProtocolFeeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetProtocolFeeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]>
     for SetProtocolFeeAccounts<'me, 'info>
 {
@@ -356,7 +379,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN
     }
 }
 pub const SET_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [173, 239, 83, 242, 136, 43, 144, 217];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetProtocolFeeIxArgs {
     pub protocol_fee: ProtocolFee,
@@ -374,13 +399,10 @@ impl SetProtocolFeeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_PROTOCOL_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_PROTOCOL_FEE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_PROTOCOL_FEE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SetProtocolFeeIxArgs::deserialize(&mut reader)?))
     }
@@ -580,6 +602,12 @@ impl From<CreatePoolKeys> for [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<CreatePoolKeys> for Vec<AccountMeta> {
+    fn from(keys: CreatePoolKeys) -> Self {
+        let accounts: [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; CREATE_POOL_IX_ACCOUNTS_LEN]> for CreatePoolKeys {
     fn from(pubkeys: [Pubkey; CREATE_POOL_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -612,6 +640,12 @@ impl<'info> From<CreatePoolAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<CreatePoolAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: CreatePoolAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN]>
     for CreatePoolAccounts<'me, 'info>
 {
@@ -630,7 +664,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN]>
     }
 }
 pub const CREATE_POOL_IX_DISCM: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreatePoolIxArgs {
     pub fee: Fee,
@@ -648,13 +682,10 @@ impl CreatePoolIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CREATE_POOL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CREATE_POOL_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CREATE_POOL_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(CreatePoolIxArgs::deserialize(&mut reader)?))
     }
@@ -852,6 +883,12 @@ impl From<AddLiquidityKeys> for [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<AddLiquidityKeys> for Vec<AccountMeta> {
+    fn from(keys: AddLiquidityKeys) -> Self {
+        let accounts: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]> for AddLiquidityKeys {
     fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -880,6 +917,12 @@ impl<'info> From<AddLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<AddLiquidityAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: AddLiquidityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
     for AddLiquidityAccounts<'me, 'info>
 {
@@ -896,7 +939,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
     }
 }
 pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddLiquidityIxArgs {
     pub amount: u64,
@@ -914,13 +959,10 @@ impl AddLiquidityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_LIQUIDITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ADD_LIQUIDITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(AddLiquidityIxArgs::deserialize(&mut reader)?))
     }
@@ -1120,6 +1162,12 @@ impl From<RemoveLiquidityKeys> for [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LE
         ]
     }
 }
+impl From<RemoveLiquidityKeys> for Vec<AccountMeta> {
+    fn from(keys: RemoveLiquidityKeys) -> Self {
+        let accounts: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]> for RemoveLiquidityKeys {
     fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1150,6 +1198,13 @@ impl<'info> From<RemoveLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<RemoveLiquidityAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RemoveLiquidityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]>
     for RemoveLiquidityAccounts<'me, 'info>
 {
@@ -1167,7 +1222,9 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
     }
 }
 pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveLiquidityIxArgs {
     pub amount_lp: u64,
@@ -1185,13 +1242,10 @@ impl RemoveLiquidityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(RemoveLiquidityIxArgs::deserialize(&mut reader)?))
     }
@@ -1371,6 +1425,12 @@ impl From<SetFeeKeys> for [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<SetFeeKeys> for Vec<AccountMeta> {
+    fn from(keys: SetFeeKeys) -> Self {
+        let accounts: [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SET_FEE_IX_ACCOUNTS_LEN]> for SetFeeKeys {
     fn from(pubkeys: [Pubkey; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1393,6 +1453,12 @@ impl<'info> From<SetFeeAccounts<'_, 'info>> for [AccountInfo<'info>; SET_FEE_IX_
         ]
     }
 }
+impl<'info> From<SetFeeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetFeeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]>
     for SetFeeAccounts<'me, 'info>
 {
@@ -1407,7 +1473,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const SET_FEE_IX_DISCM: [u8; 8] = [18, 154, 24, 18, 237, 214, 19, 80];
-#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetFeeIxArgs {
     pub fee: Fee,
@@ -1425,13 +1491,10 @@ impl SetFeeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_FEE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_FEE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self(SetFeeIxArgs::deserialize(&mut reader)?))
     }
@@ -1577,6 +1640,12 @@ impl From<SetFeeAuthorityKeys> for [AccountMeta; SET_FEE_AUTHORITY_IX_ACCOUNTS_L
         ]
     }
 }
+impl From<SetFeeAuthorityKeys> for Vec<AccountMeta> {
+    fn from(keys: SetFeeAuthorityKeys) -> Self {
+        let accounts: [AccountMeta; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]> for SetFeeAuthorityKeys {
     fn from(pubkeys: [Pubkey; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1597,6 +1666,13 @@ impl<'info> From<SetFeeAuthorityAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<SetFeeAuthorityAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: SetFeeAuthorityAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]>
     for SetFeeAuthorityAccounts<'me, 'info>
 {
@@ -1617,13 +1693,10 @@ impl SetFeeAuthorityIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_FEE_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_FEE_AUTHORITY_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                SET_FEE_AUTHORITY_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -1777,6 +1850,12 @@ impl From<DeactivateStakeAccountKeys> for [AccountMeta; DEACTIVATE_STAKE_ACCOUNT
         ]
     }
 }
+impl From<DeactivateStakeAccountKeys> for Vec<AccountMeta> {
+    fn from(keys: DeactivateStakeAccountKeys) -> Self {
+        let accounts: [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for DeactivateStakeAccountKeys {
     fn from(pubkeys: [Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -1801,6 +1880,13 @@ impl<'info> From<DeactivateStakeAccountAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<DeactivateStakeAccountAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DeactivateStakeAccountAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
     for DeactivateStakeAccountAccounts<'me, 'info>
 {
@@ -1823,13 +1909,10 @@ impl DeactivateStakeAccountIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEACTIVATE_STAKE_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEACTIVATE_STAKE_ACCOUNT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEACTIVATE_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -1994,6 +2077,12 @@ impl From<ReclaimStakeAccountKeys> for [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_AC
         ]
     }
 }
+impl From<ReclaimStakeAccountKeys> for Vec<AccountMeta> {
+    fn from(keys: ReclaimStakeAccountKeys) -> Self {
+        let accounts: [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for ReclaimStakeAccountKeys {
     fn from(pubkeys: [Pubkey; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2022,6 +2111,13 @@ impl<'info> From<ReclaimStakeAccountAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<ReclaimStakeAccountAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: ReclaimStakeAccountAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] =
+            accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
     for ReclaimStakeAccountAccounts<'me, 'info>
 {
@@ -2046,13 +2142,10 @@ impl ReclaimStakeAccountIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != RECLAIM_STAKE_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    RECLAIM_STAKE_ACCOUNT_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                RECLAIM_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -2273,6 +2366,12 @@ impl From<UnstakeKeys> for [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<UnstakeKeys> for Vec<AccountMeta> {
+    fn from(keys: UnstakeKeys) -> Self {
+        let accounts: [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UNSTAKE_IX_ACCOUNTS_LEN]> for UnstakeKeys {
     fn from(pubkeys: [Pubkey; UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2311,6 +2410,12 @@ impl<'info> From<UnstakeAccounts<'_, 'info>> for [AccountInfo<'info>; UNSTAKE_IX
         ]
     }
 }
+impl<'info> From<UnstakeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UnstakeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN]>
     for UnstakeAccounts<'me, 'info>
 {
@@ -2341,13 +2446,10 @@ impl UnstakeIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UNSTAKE_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UNSTAKE_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
@@ -2600,6 +2702,12 @@ impl From<UnstakeWsolKeys> for [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] {
         ]
     }
 }
+impl From<UnstakeWsolKeys> for Vec<AccountMeta> {
+    fn from(keys: UnstakeWsolKeys) -> Self {
+        let accounts: [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
 impl From<[Pubkey; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]> for UnstakeWsolKeys {
     fn from(pubkeys: [Pubkey; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]) -> Self {
         Self {
@@ -2642,6 +2750,12 @@ impl<'info> From<UnstakeWsolAccounts<'_, 'info>>
         ]
     }
 }
+impl<'info> From<UnstakeWsolAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: UnstakeWsolAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]>
     for UnstakeWsolAccounts<'me, 'info>
 {
@@ -2673,13 +2787,10 @@ impl UnstakeWsolIxData {
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UNSTAKE_WSOL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UNSTAKE_WSOL_IX_DISCM, maybe_discm
-                ),
-            ));
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                UNSTAKE_WSOL_IX_DISCM, maybe_discm
+            )));
         }
         Ok(Self)
     }
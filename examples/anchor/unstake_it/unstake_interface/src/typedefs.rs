@@ -1,19 +1,77 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fee {
+    pub fee: FeeEnum,
+}
+impl Fee {
+    pub const MIN_LEN: usize = 17;
+    /// Borsh-serialized length of this [`Fee`]
+    pub fn serialized_len(&self) -> usize {
+        self.fee.serialized_len()
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pool {
+    pub fee_authority: Pubkey,
+    pub lp_mint: Pubkey,
+    pub incoming_stake: u64,
+}
+pub const POOL_LEN: usize = 72;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolFee {
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    pub fee_ratio: Rational,
+    pub referrer_fee_ratio: Rational,
+}
+pub const PROTOCOL_FEE_LEN: usize = 96;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeAccountRecord {
+    pub lamports_at_creation: u64,
+}
+pub const STAKE_ACCOUNT_RECORD_LEN: usize = 8;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rational {
     pub num: u64,
     pub denom: u64,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const RATIONAL_LEN: usize = 16;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidityLinearParams {
     pub max_liq_remaining: Rational,
     pub zero_liq_remaining: Rational,
 }
-#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub const LIQUIDITY_LINEAR_PARAMS_LEN: usize = 32;
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FeeEnum {
     Flat { ratio: Rational },
     LiquidityLinear { params: LiquidityLinearParams },
 }
+impl FeeEnum {
+    pub const MIN_LEN: usize = 17;
+    /// Borsh-serialized length of this [`FeeEnum`]
+    pub fn serialized_len(&self) -> usize {
+        match self {
+            Self::Flat { .. } => 17,
+            Self::LiquidityLinear { .. } => 33,
+        }
+    }
+}
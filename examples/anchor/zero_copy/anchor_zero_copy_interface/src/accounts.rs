@@ -0,0 +1,371 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use std::cell::{Ref, RefMut};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
+}
+pub const POOL_ACCOUNT_DISCM: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
+pub struct PoolAccount(pub Pool);
+impl PoolAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != POOL_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                POOL_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(Pool::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&POOL_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const POOL_ACCOUNT_LEN: usize = 136;
+impl PoolAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, POOL_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `mint`
+    pub fn filter_mint(mint: &Pubkey) -> (usize, Vec<u8>) {
+        (40, mint.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `config`
+    pub fn filter_config(config: &Config) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((72, borsh::to_vec(config)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `reserves`
+    pub fn filter_reserves(reserves: &[u64; 2]) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((88, borsh::to_vec(reserves)?))
+    }
+    /// Memcmp filter `(offset, bytes)` matching `total_shares`
+    pub fn filter_total_shares(total_shares: u128) -> (usize, Vec<u8>) {
+        (104, total_shares.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `last_update`
+    pub fn filter_last_update(last_update: i64) -> (usize, Vec<u8>) {
+        (120, last_update.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `reserved`
+    pub fn filter_reserved(reserved: &[u8; 8]) -> (usize, Vec<u8>) {
+        (128, reserved.to_vec())
+    }
+}
+#[cfg(feature = "client")]
+impl PoolAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_mint(mint: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_mint(mint))
+    }
+    pub fn rpc_filter_config(config: &Config) -> std::io::Result<RpcFilterType> {
+        Self::filter_config(config).map(memcmp_filter)
+    }
+    pub fn rpc_filter_reserves(reserves: &[u64; 2]) -> std::io::Result<RpcFilterType> {
+        Self::filter_reserves(reserves).map(memcmp_filter)
+    }
+    pub fn rpc_filter_total_shares(total_shares: u128) -> RpcFilterType {
+        memcmp_filter(Self::filter_total_shares(total_shares))
+    }
+    pub fn rpc_filter_last_update(last_update: i64) -> RpcFilterType {
+        memcmp_filter(Self::filter_last_update(last_update))
+    }
+    pub fn rpc_filter_reserved(reserved: &[u8; 8]) -> RpcFilterType {
+        memcmp_filter(Self::filter_reserved(reserved))
+    }
+}
+impl PoolAccount {
+    /// Range of the account struct in `data` after checking the discriminant and length
+    fn pod_range(data: &[u8]) -> std::io::Result<std::ops::Range<usize>> {
+        let discm_len = POOL_ACCOUNT_DISCM.len();
+        let end = discm_len + std::mem::size_of::<Pool>();
+        if data.len() < end {
+            return Err(std::io::Error::other(format!(
+                "account data too short. Expected at least: {}. Received: {}",
+                end,
+                data.len()
+            )));
+        }
+        if data[..discm_len] != POOL_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                POOL_ACCOUNT_DISCM,
+                &data[..discm_len]
+            )));
+        }
+        Ok(discm_len..end)
+    }
+    /// Casts the account data to a [`Pool`] without copying.
+    /// Errs if `data` is not aligned for it, see [`Self::read_unaligned`].
+    pub fn load(data: &[u8]) -> std::io::Result<&Pool> {
+        bytemuck::try_from_bytes(&data[Self::pod_range(data)?])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Casts the account data to a mutable [`Pool`] without copying.
+    /// Errs if `data` is not aligned for it.
+    pub fn load_mut(data: &mut [u8]) -> std::io::Result<&mut Pool> {
+        let range = Self::pod_range(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[range])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Copies the account data into a [`Pool`] regardless of its alignment
+    pub fn read_unaligned(data: &[u8]) -> std::io::Result<Pool> {
+        Ok(bytemuck::pod_read_unaligned(&data[Self::pod_range(data)?]))
+    }
+    /// [`Self::load`] on the account's borrowed data
+    pub fn load_account_info<'a>(account: &'a AccountInfo) -> Result<Ref<'a, Pool>, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        Ref::filter_map(data, |data| bytemuck::try_from_bytes(&data[range]).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// [`Self::load_mut`] on the account's mutably borrowed data
+    pub fn load_account_info_mut<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<RefMut<'a, Pool>, ProgramError> {
+        let data = account.try_borrow_mut_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        RefMut::filter_map(data, |data| {
+            bytemuck::try_from_bytes_mut(&mut data[range]).ok()
+        })
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+pub const ORACLE_ACCOUNT_DISCM: [u8; 8] = [139, 194, 131, 179, 140, 179, 229, 244];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
+pub struct OracleAccount(pub Oracle);
+impl OracleAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != ORACLE_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ORACLE_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(Oracle::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&ORACLE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub const ORACLE_ACCOUNT_LEN: usize = 108;
+impl OracleAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, ORACLE_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `samples`
+    pub fn filter_samples(samples: &[PriceSample; 4]) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((40, borsh::to_vec(samples)?))
+    }
+}
+#[cfg(feature = "client")]
+impl OracleAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_samples(samples: &[PriceSample; 4]) -> std::io::Result<RpcFilterType> {
+        Self::filter_samples(samples).map(memcmp_filter)
+    }
+}
+impl OracleAccount {
+    /// Range of the account struct in `data` after checking the discriminant and length
+    fn pod_range(data: &[u8]) -> std::io::Result<std::ops::Range<usize>> {
+        let discm_len = ORACLE_ACCOUNT_DISCM.len();
+        let end = discm_len + std::mem::size_of::<Oracle>();
+        if data.len() < end {
+            return Err(std::io::Error::other(format!(
+                "account data too short. Expected at least: {}. Received: {}",
+                end,
+                data.len()
+            )));
+        }
+        if data[..discm_len] != ORACLE_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                ORACLE_ACCOUNT_DISCM,
+                &data[..discm_len]
+            )));
+        }
+        Ok(discm_len..end)
+    }
+    /// Casts the account data to a [`Oracle`] without copying.
+    /// Errs if `data` is not aligned for it, see [`Self::read_unaligned`].
+    pub fn load(data: &[u8]) -> std::io::Result<&Oracle> {
+        bytemuck::try_from_bytes(&data[Self::pod_range(data)?])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Casts the account data to a mutable [`Oracle`] without copying.
+    /// Errs if `data` is not aligned for it.
+    pub fn load_mut(data: &mut [u8]) -> std::io::Result<&mut Oracle> {
+        let range = Self::pod_range(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[range])
+            .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+    }
+    /// Copies the account data into a [`Oracle`] regardless of its alignment
+    pub fn read_unaligned(data: &[u8]) -> std::io::Result<Oracle> {
+        Ok(bytemuck::pod_read_unaligned(&data[Self::pod_range(data)?]))
+    }
+    /// [`Self::load`] on the account's borrowed data
+    pub fn load_account_info<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<Ref<'a, Oracle>, ProgramError> {
+        let data = account.try_borrow_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        Ref::filter_map(data, |data| bytemuck::try_from_bytes(&data[range]).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+    /// [`Self::load_mut`] on the account's mutably borrowed data
+    pub fn load_account_info_mut<'a>(
+        account: &'a AccountInfo,
+    ) -> Result<RefMut<'a, Oracle>, ProgramError> {
+        let data = account.try_borrow_mut_data()?;
+        let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+        RefMut::filter_map(data, |data| {
+            bytemuck::try_from_bytes_mut(&mut data[range]).ok()
+        })
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+pub const REGISTRY_ACCOUNT_DISCM: [u8; 8] = [47, 174, 110, 246, 184, 182, 252, 218];
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
+pub struct RegistryAccount(pub Registry);
+impl RegistryAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REGISTRY_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REGISTRY_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(Registry::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REGISTRY_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+impl RegistryAccount {
+    pub const MIN_LEN: usize = 44;
+    /// Borsh-serialized length of this account's data, including the discriminant
+    pub fn serialized_len(&self) -> usize {
+        REGISTRY_ACCOUNT_DISCM.len() + self.0.serialized_len()
+    }
+}
+impl RegistryAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, REGISTRY_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `names`
+    pub fn filter_names(names: &[String]) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((40, borsh::to_vec(names)?))
+    }
+}
+#[cfg(feature = "client")]
+impl RegistryAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_names(names: &[String]) -> std::io::Result<RpcFilterType> {
+        Self::filter_names(names).map(memcmp_filter)
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorZeroCopyAccountData {
+    Pool(PoolAccount),
+    Oracle(OracleAccount),
+    Registry(RegistryAccount),
+}
+impl AnchorZeroCopyAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&POOL_ACCOUNT_DISCM) {
+            return Ok(Self::Pool(PoolAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&ORACLE_ACCOUNT_DISCM) {
+            return Ok(Self::Oracle(OracleAccount::deserialize(buf)?));
+        }
+        if buf.starts_with(&REGISTRY_ACCOUNT_DISCM) {
+            return Ok(Self::Registry(RegistryAccount::deserialize(buf)?));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::Pool(_) => &POOL_ACCOUNT_DISCM,
+            Self::Oracle(_) => &ORACLE_ACCOUNT_DISCM,
+            Self::Registry(_) => &REGISTRY_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Pool(_) => "Pool",
+            Self::Oracle(_) => "Oracle",
+            Self::Registry(_) => "Registry",
+        }
+    }
+}
@@ -0,0 +1,461 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorZeroCopyProgramIx {
+    InitializePool(InitializePoolIxArgs),
+    Register(RegisterIxArgs),
+}
+impl AnchorZeroCopyProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INITIALIZE_POOL_IX_DISCM => Ok(Self::InitializePool(
+                InitializePoolIxArgs::deserialize(&mut reader)?,
+            )),
+            REGISTER_IX_DISCM => Ok(Self::Register(RegisterIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::InitializePool(args) => {
+                writer.write_all(&INITIALIZE_POOL_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Register(args) => {
+                writer.write_all(&REGISTER_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INITIALIZE_POOL_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializePoolAccounts<'me, 'info> {
+    pub pool: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitializePoolKeys {
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<InitializePoolAccounts<'_, '_>> for InitializePoolKeys {
+    fn from(accounts: InitializePoolAccounts) -> Self {
+        Self {
+            pool: *accounts.pool.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<InitializePoolKeys> for [AccountMeta; INITIALIZE_POOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializePoolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.pool,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<InitializePoolKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializePoolKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_POOL_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; INITIALIZE_POOL_IX_ACCOUNTS_LEN]> for InitializePoolKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_POOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<InitializePoolAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_POOL_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializePoolAccounts<'_, 'info>) -> Self {
+        [accounts.pool.clone(), accounts.authority.clone()]
+    }
+}
+impl<'info> From<InitializePoolAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializePoolAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_POOL_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_POOL_IX_ACCOUNTS_LEN]>
+    for InitializePoolAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_POOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            pool: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const INITIALIZE_POOL_IX_DISCM: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializePoolIxArgs {
+    pub config: Config,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializePoolIxData(pub InitializePoolIxArgs);
+impl From<InitializePoolIxArgs> for InitializePoolIxData {
+    fn from(args: InitializePoolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializePoolIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INITIALIZE_POOL_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_POOL_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(InitializePoolIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_POOL_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_pool_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializePoolKeys,
+    args: InitializePoolIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_POOL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializePoolIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn initialize_pool_ix(
+    keys: InitializePoolKeys,
+    args: InitializePoolIxArgs,
+) -> std::io::Result<Instruction> {
+    initialize_pool_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn initialize_pool_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializePoolAccounts<'_, '_>,
+    args: InitializePoolIxArgs,
+) -> ProgramResult {
+    let keys: InitializePoolKeys = accounts.into();
+    let ix = initialize_pool_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_pool_invoke(
+    accounts: InitializePoolAccounts<'_, '_>,
+    args: InitializePoolIxArgs,
+) -> ProgramResult {
+    initialize_pool_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn initialize_pool_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializePoolAccounts<'_, '_>,
+    args: InitializePoolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializePoolKeys = accounts.into();
+    let ix = initialize_pool_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_pool_invoke_signed(
+    accounts: InitializePoolAccounts<'_, '_>,
+    args: InitializePoolIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_pool_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn initialize_pool_verify_account_keys(
+    accounts: InitializePoolAccounts<'_, '_>,
+    keys: InitializePoolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.pool.key, keys.pool),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_pool_verify_writable_privileges<'me, 'info>(
+    accounts: InitializePoolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.pool] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_pool_verify_signer_privileges<'me, 'info>(
+    accounts: InitializePoolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_pool_verify_account_privileges<'me, 'info>(
+    accounts: InitializePoolAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_pool_verify_writable_privileges(accounts)?;
+    initialize_pool_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REGISTER_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct RegisterAccounts<'me, 'info> {
+    pub registry: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RegisterKeys {
+    pub registry: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<RegisterAccounts<'_, '_>> for RegisterKeys {
+    fn from(accounts: RegisterAccounts) -> Self {
+        Self {
+            registry: *accounts.registry.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<RegisterKeys> for [AccountMeta; REGISTER_IX_ACCOUNTS_LEN] {
+    fn from(keys: RegisterKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.registry,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<RegisterKeys> for Vec<AccountMeta> {
+    fn from(keys: RegisterKeys) -> Self {
+        let accounts: [AccountMeta; REGISTER_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; REGISTER_IX_ACCOUNTS_LEN]> for RegisterKeys {
+    fn from(pubkeys: [Pubkey; REGISTER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            registry: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<RegisterAccounts<'_, 'info>> for [AccountInfo<'info>; REGISTER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RegisterAccounts<'_, 'info>) -> Self {
+        [accounts.registry.clone(), accounts.authority.clone()]
+    }
+}
+impl<'info> From<RegisterAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: RegisterAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; REGISTER_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; REGISTER_IX_ACCOUNTS_LEN]>
+    for RegisterAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; REGISTER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            registry: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const REGISTER_IX_DISCM: [u8; 8] = [211, 124, 67, 15, 211, 194, 178, 240];
+#[derive(
+    Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterIxArgs {
+    pub name: String,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegisterIxData(pub RegisterIxArgs);
+impl From<RegisterIxArgs> for RegisterIxData {
+    fn from(args: RegisterIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RegisterIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REGISTER_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                REGISTER_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(RegisterIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&REGISTER_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn register_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RegisterKeys,
+    args: RegisterIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; REGISTER_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RegisterIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn register_ix(keys: RegisterKeys, args: RegisterIxArgs) -> std::io::Result<Instruction> {
+    register_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn register_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RegisterAccounts<'_, '_>,
+    args: RegisterIxArgs,
+) -> ProgramResult {
+    let keys: RegisterKeys = accounts.into();
+    let ix = register_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn register_invoke(accounts: RegisterAccounts<'_, '_>, args: RegisterIxArgs) -> ProgramResult {
+    register_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn register_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RegisterAccounts<'_, '_>,
+    args: RegisterIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: RegisterKeys = accounts.into();
+    let ix = register_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn register_invoke_signed(
+    accounts: RegisterAccounts<'_, '_>,
+    args: RegisterIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    register_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn register_verify_account_keys(
+    accounts: RegisterAccounts<'_, '_>,
+    keys: RegisterKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.registry.key, keys.registry),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn register_verify_writable_privileges<'me, 'info>(
+    accounts: RegisterAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.registry] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn register_verify_signer_privileges<'me, 'info>(
+    accounts: RegisterAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn register_verify_account_privileges<'me, 'info>(
+    accounts: RegisterAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    register_verify_writable_privileges(accounts)?;
+    register_verify_signer_privileges(accounts)?;
+    Ok(())
+}
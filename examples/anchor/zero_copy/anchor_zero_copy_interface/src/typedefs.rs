@@ -0,0 +1,119 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+#[repr(C)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    BorshDeserialize,
+    BorshSerialize,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Pod,
+    Zeroable,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pool {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub config: Config,
+    pub reserves: [u64; 2],
+    pub total_shares: u128,
+    pub last_update: i64,
+    pub reserved: [u8; 8],
+}
+const _: () = assert!(std::mem::size_of::<Pool>() == 128);
+pub const POOL_LEN: usize = 128;
+#[repr(C)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    BorshDeserialize,
+    BorshSerialize,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Pod,
+    Zeroable,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Config {
+    pub fee_bps: u16,
+    pub bump: u8,
+    pub padding: [u8; 5],
+    pub cap: u64,
+}
+const _: () = assert!(std::mem::size_of::<Config>() == 16);
+pub const CONFIG_LEN: usize = 16;
+#[repr(packed, C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oracle {
+    pub authority: Pubkey,
+    pub samples: [PriceSample; 4],
+}
+unsafe impl Pod for Oracle {}
+unsafe impl Zeroable for Oracle {}
+impl BorshSerialize for Oracle {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let Self { authority, samples } = *self;
+        authority.serialize(writer)?;
+        samples.serialize(writer)?;
+        Ok(())
+    }
+}
+const _: () = assert!(std::mem::size_of::<Oracle>() == 100);
+pub const ORACLE_LEN: usize = 100;
+#[repr(packed, C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriceSample {
+    pub expo: i8,
+    pub price: u64,
+    pub slot: u64,
+}
+unsafe impl Pod for PriceSample {}
+unsafe impl Zeroable for PriceSample {}
+impl BorshSerialize for PriceSample {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let Self { expo, price, slot } = *self;
+        expo.serialize(writer)?;
+        price.serialize(writer)?;
+        slot.serialize(writer)?;
+        Ok(())
+    }
+}
+const _: () = assert!(std::mem::size_of::<PriceSample>() == 17);
+pub const PRICE_SAMPLE_LEN: usize = 17;
+#[repr(C)]
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Status {
+    pub active: bool,
+    pub count: u32,
+}
+pub const STATUS_LEN: usize = 5;
+#[derive(
+    Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Registry {
+    pub authority: Pubkey,
+    pub names: Vec<String>,
+}
+impl Registry {
+    pub const MIN_LEN: usize = 36;
+    /// Borsh-serialized length of this [`Registry`]
+    pub fn serialized_len(&self) -> usize {
+        36 + self.names.iter().map(|x| 4 + x.len()).sum::<usize>()
+    }
+}
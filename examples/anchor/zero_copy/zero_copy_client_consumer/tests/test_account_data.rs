@@ -0,0 +1,31 @@
+use anchor_zero_copy_interface::{
+    AnchorZeroCopyAccountData, Pool, PoolAccount, Registry, RegistryAccount, POOL_ACCOUNT_DISCM,
+    REGISTRY_ACCOUNT_DISCM,
+};
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_dispatch_on_discm() {
+    let pool = PoolAccount(Pool::default());
+    let data = pool.try_to_vec().unwrap();
+    let decoded = AnchorZeroCopyAccountData::deserialize(&data).unwrap();
+    assert_eq!(decoded, AnchorZeroCopyAccountData::Pool(pool));
+    assert_eq!(decoded.discriminator(), POOL_ACCOUNT_DISCM);
+    assert_eq!(decoded.name(), "Pool");
+
+    let registry = RegistryAccount(Registry {
+        authority: Pubkey::new_unique(),
+        names: vec!["a".to_owned()],
+    });
+    let data = registry.try_to_vec().unwrap();
+    let decoded = AnchorZeroCopyAccountData::deserialize(&data).unwrap();
+    assert_eq!(decoded.discriminator(), REGISTRY_ACCOUNT_DISCM);
+    assert_eq!(decoded.name(), "Registry");
+    assert_eq!(decoded, AnchorZeroCopyAccountData::Registry(registry));
+}
+
+#[test]
+fn test_unknown_discm() {
+    assert!(AnchorZeroCopyAccountData::deserialize(&[0; 64]).is_err());
+    assert!(AnchorZeroCopyAccountData::deserialize(&[]).is_err());
+}
@@ -0,0 +1,102 @@
+use anchor_zero_copy_interface::{
+    Config, Pool, PoolAccount, Registry, RegistryAccount, POOL_ACCOUNT_DISCM,
+};
+use solana_program::pubkey::Pubkey;
+use solana_rpc_client_api::filter::RpcFilterType;
+
+fn pool_account() -> PoolAccount {
+    PoolAccount(Pool {
+        authority: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        config: Config {
+            fee_bps: 30,
+            bump: 255,
+            padding: [0; 5],
+            cap: 1_000_000,
+        },
+        reserves: [1, 2],
+        total_shares: 3,
+        last_update: -1,
+        reserved: [7; 8],
+    })
+}
+
+fn matches((offset, bytes): (usize, Vec<u8>), data: &[u8]) -> bool {
+    data.get(offset..offset + bytes.len()) == Some(bytes.as_slice())
+}
+
+#[test]
+fn test_fixed_offset_filters() {
+    let account = pool_account();
+    let pool = account.0;
+    let data = account.try_to_vec().unwrap();
+    assert!(matches(PoolAccount::filter_discm(), &data));
+    assert!(matches(
+        PoolAccount::filter_authority(&pool.authority),
+        &data
+    ));
+    assert!(matches(PoolAccount::filter_mint(&pool.mint), &data));
+    assert!(matches(
+        PoolAccount::filter_config(&pool.config).unwrap(),
+        &data
+    ));
+    assert!(matches(
+        PoolAccount::filter_reserves(&pool.reserves).unwrap(),
+        &data
+    ));
+    assert!(matches(
+        PoolAccount::filter_total_shares(pool.total_shares),
+        &data
+    ));
+    assert!(matches(
+        PoolAccount::filter_last_update(pool.last_update),
+        &data
+    ));
+    assert!(matches(PoolAccount::filter_reserved(&pool.reserved), &data));
+    assert!(!matches(PoolAccount::filter_mint(&pool.authority), &data));
+    assert_eq!(
+        PoolAccount::filter_discm(),
+        (0, POOL_ACCOUNT_DISCM.to_vec())
+    );
+}
+
+#[test]
+fn test_variable_len_field_filter() {
+    let names = vec!["a".to_owned(), "b".to_owned()];
+    let account = RegistryAccount(Registry {
+        authority: Pubkey::new_unique(),
+        names: names.clone(),
+    });
+    let data = account.try_to_vec().unwrap();
+    assert!(matches(
+        RegistryAccount::filter_authority(&account.0.authority),
+        &data
+    ));
+    assert!(matches(
+        RegistryAccount::filter_names(&names).unwrap(),
+        &data
+    ));
+    assert!(!matches(
+        RegistryAccount::filter_names(&names[..1]).unwrap(),
+        &data
+    ));
+}
+
+#[test]
+fn test_rpc_filters() {
+    let account = pool_account();
+    let data = account.try_to_vec().unwrap();
+    for filter in [
+        PoolAccount::rpc_filter_discm(),
+        PoolAccount::rpc_filter_authority(&account.0.authority),
+    ] {
+        let RpcFilterType::Memcmp(memcmp) = filter else {
+            panic!("expected memcmp filter");
+        };
+        assert!(memcmp.bytes_match(&data));
+    }
+    let RpcFilterType::Memcmp(memcmp) = RegistryAccount::rpc_filter_discm() else {
+        panic!("expected memcmp filter");
+    };
+    assert!(!memcmp.bytes_match(&data));
+}
@@ -0,0 +1,32 @@
+use anchor_zero_copy_interface::{
+    Registry, RegistryAccount, Status, POOL_ACCOUNT_LEN, POOL_LEN, STATUS_LEN,
+};
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_fixed_lens() {
+    assert_eq!(POOL_LEN, 128);
+    assert_eq!(POOL_ACCOUNT_LEN, 8 + POOL_LEN);
+    let status = Status {
+        active: true,
+        count: 1,
+    };
+    assert_eq!(borsh::to_vec(&status).unwrap().len(), STATUS_LEN);
+}
+
+#[test]
+fn test_variable_lens() {
+    let mut registry = Registry {
+        authority: Pubkey::new_unique(),
+        names: vec![],
+    };
+    assert_eq!(registry.serialized_len(), Registry::MIN_LEN);
+    registry.names = vec!["a".to_owned(), "longer name".to_owned()];
+    let data = borsh::to_vec(&registry).unwrap();
+    assert_eq!(registry.serialized_len(), data.len());
+
+    let account = RegistryAccount(registry);
+    let data = account.try_to_vec().unwrap();
+    assert_eq!(account.serialized_len(), data.len());
+    assert!(data.len() > RegistryAccount::MIN_LEN);
+}
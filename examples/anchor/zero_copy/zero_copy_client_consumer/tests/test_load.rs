@@ -0,0 +1,96 @@
+use anchor_zero_copy_interface::{Config, Pool, PoolAccount, POOL_ACCOUNT_DISCM};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+fn pool() -> Pool {
+    Pool {
+        authority: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        config: Config {
+            fee_bps: 30,
+            bump: 255,
+            padding: [0; 5],
+            cap: 1_000_000,
+        },
+        reserves: [1, 2],
+        total_shares: u128::MAX,
+        last_update: -1,
+        reserved: [0; 8],
+    }
+}
+
+/// Pool is 16-aligned on x86_64 and aarch64 because of its u128,
+/// so the account data must start 8 bytes into a 16-aligned buffer for it to be aligned
+const ALIGNED_OFFSET: usize = 8;
+
+/// 16-aligned buffer with the account data starting at `offset`
+fn aligned_buf(pool: &Pool, offset: usize) -> Vec<u128> {
+    let mut data = POOL_ACCOUNT_DISCM.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(pool));
+    let mut res = vec![0u128; (offset + data.len()).div_ceil(16)];
+    bytemuck::cast_slice_mut::<u128, u8>(&mut res)[offset..offset + data.len()]
+        .copy_from_slice(&data);
+    res
+}
+
+#[test]
+fn test_load_matches_borsh() {
+    let pool = pool();
+    let buf = aligned_buf(&pool, ALIGNED_OFFSET);
+    let data = &bytemuck::cast_slice::<u128, u8>(&buf)[ALIGNED_OFFSET..];
+    assert_eq!(*PoolAccount::load(data).unwrap(), pool);
+    assert_eq!(PoolAccount::deserialize(data).unwrap().0, pool);
+}
+
+#[test]
+fn test_load_mut() {
+    let mut buf = aligned_buf(&pool(), ALIGNED_OFFSET);
+    let data = &mut bytemuck::cast_slice_mut::<u128, u8>(&mut buf)[ALIGNED_OFFSET..];
+    PoolAccount::load_mut(data).unwrap().config.fee_bps = 50;
+    assert_eq!(PoolAccount::load(data).unwrap().config.fee_bps, 50);
+}
+
+#[test]
+fn test_load_errs() {
+    let buf = aligned_buf(&pool(), ALIGNED_OFFSET);
+    let data = &bytemuck::cast_slice::<u128, u8>(&buf)[ALIGNED_OFFSET..];
+    let too_short = &data[..8 + std::mem::size_of::<Pool>() - 1];
+    assert!(PoolAccount::load(too_short).is_err());
+    let mut wrong_discm = buf.clone();
+    let wrong_discm = &mut bytemuck::cast_slice_mut::<u128, u8>(&mut wrong_discm)[ALIGNED_OFFSET..];
+    wrong_discm[0] ^= 1;
+    assert!(PoolAccount::load(wrong_discm).is_err());
+}
+
+#[test]
+fn test_read_unaligned() {
+    let pool = pool();
+    let buf = aligned_buf(&pool, 1);
+    let data = &bytemuck::cast_slice::<u128, u8>(&buf)[1..];
+    assert!(PoolAccount::load(data).is_err());
+    assert_eq!(PoolAccount::read_unaligned(data).unwrap(), pool);
+}
+
+#[test]
+fn test_load_account_info() {
+    let pool = pool();
+    let mut buf = aligned_buf(&pool, ALIGNED_OFFSET);
+    let data = &mut bytemuck::cast_slice_mut::<u128, u8>(&mut buf)[ALIGNED_OFFSET..];
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        data,
+        &anchor_zero_copy_interface::ID,
+        false,
+        0,
+    );
+    PoolAccount::load_account_info_mut(&account)
+        .unwrap()
+        .reserves[0] = 3;
+    let loaded = PoolAccount::load_account_info(&account).unwrap();
+    assert_eq!(loaded.reserves, [3, 2]);
+    assert_eq!(loaded.authority, pool.authority);
+}
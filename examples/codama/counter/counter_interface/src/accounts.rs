@@ -0,0 +1,105 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+#[cfg(feature = "client")]
+fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
+}
+pub const COUNTER_ACCOUNT_DISCM: [u8; 8] = [255, 176, 4, 245, 188, 253, 124, 25];
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd)]
+pub struct CounterAccount(pub Counter);
+impl CounterAccount {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        use std::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != COUNTER_ACCOUNT_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                COUNTER_ACCOUNT_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(Counter::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&COUNTER_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+impl CounterAccount {
+    pub const MIN_LEN: usize = 66;
+    /// Borsh-serialized length of this account's data, including the discriminant
+    pub fn serialized_len(&self) -> usize {
+        COUNTER_ACCOUNT_DISCM.len() + self.0.serialized_len()
+    }
+}
+impl CounterAccount {
+    /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+    pub fn filter_discm() -> (usize, Vec<u8>) {
+        (0, COUNTER_ACCOUNT_DISCM.to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `authority`
+    pub fn filter_authority(authority: &Pubkey) -> (usize, Vec<u8>) {
+        (8, authority.to_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `count`
+    pub fn filter_count(count: u64) -> (usize, Vec<u8>) {
+        (40, count.to_le_bytes().to_vec())
+    }
+    /// Memcmp filter `(offset, bytes)` matching `mode`
+    pub fn filter_mode(mode: &CounterMode) -> std::io::Result<(usize, Vec<u8>)> {
+        Ok((48, borsh::to_vec(mode)?))
+    }
+}
+#[cfg(feature = "client")]
+impl CounterAccount {
+    pub fn rpc_filter_discm() -> RpcFilterType {
+        memcmp_filter(Self::filter_discm())
+    }
+    pub fn rpc_filter_authority(authority: &Pubkey) -> RpcFilterType {
+        memcmp_filter(Self::filter_authority(authority))
+    }
+    pub fn rpc_filter_count(count: u64) -> RpcFilterType {
+        memcmp_filter(Self::filter_count(count))
+    }
+    pub fn rpc_filter_mode(mode: &CounterMode) -> std::io::Result<RpcFilterType> {
+        Self::filter_mode(mode).map(memcmp_filter)
+    }
+}
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum CounterAccountData {
+    Counter(CounterAccount),
+}
+impl CounterAccountData {
+    /// Decodes any of the program's accounts from its discriminator-prefixed data
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        if buf.starts_with(&COUNTER_ACCOUNT_DISCM) {
+            return Ok(Self::Counter(CounterAccount::deserialize(buf)?));
+        }
+        Err(std::io::Error::other(format!(
+            "account discm {:?} not found",
+            &buf[..buf.len().min(8usize)]
+        )))
+    }
+    /// The account's `*_ACCOUNT_DISCM`
+    pub fn discriminator(&self) -> &'static [u8] {
+        match self {
+            Self::Counter(_) => &COUNTER_ACCOUNT_DISCM,
+        }
+    }
+    /// The account's name in the IDL
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Counter(_) => "Counter",
+        }
+    }
+}
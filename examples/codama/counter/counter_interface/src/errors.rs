@@ -0,0 +1,14 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum CounterError {
+    #[error("Counter overflowed")]
+    Overflow = 6000,
+    #[error("Signer is not the counter authority")]
+    Unauthorized = 6001,
+}
+impl From<CounterError> for ProgramError {
+    fn from(e: CounterError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
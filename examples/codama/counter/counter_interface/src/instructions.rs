@@ -0,0 +1,563 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum CounterProgramIx {
+    Initialize(InitializeIxArgs),
+    Increment(IncrementIxArgs),
+}
+impl CounterProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INITIALIZE_IX_DISCM => Ok(Self::Initialize(InitializeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            INCREMENT_IX_DISCM => Ok(Self::Increment(IncrementIxArgs::deserialize(&mut reader)?)),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::Initialize(args) => {
+                writer.write_all(&INITIALIZE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Increment(args) => {
+                writer.write_all(&INCREMENT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeAccounts<'me, 'info> {
+    pub counter: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitializeKeys {
+    pub counter: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<InitializeAccounts<'_, '_>> for InitializeKeys {
+    fn from(accounts: InitializeAccounts) -> Self {
+        Self {
+            counter: *accounts.counter.key,
+            authority: *accounts.authority.key,
+            payer: *accounts.payer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitializeKeys> for [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<InitializeKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            counter: pubkeys[0],
+            authority: pubkeys[1],
+            payer: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<InitializeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.counter.clone(),
+            accounts.authority.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'info> From<InitializeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
+    for InitializeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            counter: &arr[0],
+            authority: &arr[1],
+            payer: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const INITIALIZE_IX_DISCM: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+#[derive(
+    Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeIxArgs {
+    pub label: String,
+    pub mode: CounterMode,
+    pub delegate: Option<Pubkey>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeIxData(pub InitializeIxArgs);
+impl From<InitializeIxArgs> for InitializeIxData {
+    fn from(args: InitializeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INITIALIZE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(InitializeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeKeys,
+    args: InitializeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn initialize_ix(keys: InitializeKeys, args: InitializeIxArgs) -> std::io::Result<Instruction> {
+    initialize_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn initialize_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+) -> ProgramResult {
+    let keys: InitializeKeys = accounts.into();
+    let ix = initialize_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_invoke(
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+) -> ProgramResult {
+    initialize_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn initialize_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeKeys = accounts.into();
+    let ix = initialize_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_invoke_signed(
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn initialize_verify_account_keys(
+    accounts: InitializeAccounts<'_, '_>,
+    keys: InitializeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.counter.key, keys.counter),
+        (*accounts.authority.key, keys.authority),
+        (*accounts.payer.key, keys.payer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.counter, accounts.payer] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_signer_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_account_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_verify_writable_privileges(accounts)?;
+    initialize_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const INCREMENT_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone, Debug)]
+pub struct IncrementAccounts<'me, 'info> {
+    pub counter: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+    pub delegate: Option<&'me AccountInfo<'info>>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IncrementKeys {
+    pub counter: Pubkey,
+    pub authority: Pubkey,
+    pub delegate: Option<Pubkey>,
+}
+impl From<IncrementAccounts<'_, '_>> for IncrementKeys {
+    fn from(accounts: IncrementAccounts) -> Self {
+        Self {
+            counter: *accounts.counter.key,
+            authority: *accounts.authority.key,
+            delegate: accounts.delegate.map(|a| *a.key),
+        }
+    }
+}
+impl IncrementKeys {
+    /// Absent optional accounts are passed as `program_id`
+    pub fn to_account_metas(&self, program_id: Pubkey) -> [AccountMeta; INCREMENT_IX_ACCOUNTS_LEN] {
+        let keys = self;
+        [
+            AccountMeta {
+                pubkey: keys.counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            match keys.delegate {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: true,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+        ]
+    }
+}
+impl From<IncrementKeys> for [AccountMeta; INCREMENT_IX_ACCOUNTS_LEN] {
+    fn from(keys: IncrementKeys) -> Self {
+        keys.to_account_metas(crate::ID)
+    }
+}
+impl From<IncrementKeys> for Vec<AccountMeta> {
+    fn from(keys: IncrementKeys) -> Self {
+        let accounts: [AccountMeta; INCREMENT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+/// Whether each of the optional signer accounts signs, they are non-signers by default
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct IncrementOptionalSigners {
+    pub authority: bool,
+}
+impl IncrementKeys {
+    /// Like the `[AccountMeta]` conversion, but with the optional signer accounts
+    /// set as signers according to `optional_signers`
+    pub fn to_account_metas_with_signers(
+        &self,
+        program_id: Pubkey,
+        optional_signers: IncrementOptionalSigners,
+    ) -> [AccountMeta; INCREMENT_IX_ACCOUNTS_LEN] {
+        let keys = self;
+        [
+            AccountMeta {
+                pubkey: keys.counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: optional_signers.authority,
+                is_writable: false,
+            },
+            match keys.delegate {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: true,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INCREMENT_IX_ACCOUNTS_LEN]> for IncrementKeys {
+    fn from(pubkeys: [Pubkey; INCREMENT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            counter: pubkeys[0],
+            authority: pubkeys[1],
+            delegate: Some(pubkeys[2]).filter(|k| *k != crate::ID),
+        }
+    }
+}
+impl<'info> From<IncrementAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: IncrementAccounts<'_, 'info>) -> Self {
+        let mut res = Vec::with_capacity(INCREMENT_IX_ACCOUNTS_LEN);
+        res.push(accounts.counter.clone());
+        res.push(accounts.authority.clone());
+        if let Some(a) = accounts.delegate {
+            res.push(a.clone());
+        }
+        res
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INCREMENT_IX_ACCOUNTS_LEN]>
+    for IncrementAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INCREMENT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            counter: &arr[0],
+            authority: &arr[1],
+            delegate: Some(&arr[2]).filter(|a| *a.key != crate::ID),
+        }
+    }
+}
+pub const INCREMENT_IX_DISCM: [u8; 8] = [11, 18, 104, 9, 104, 174, 59, 33];
+#[derive(
+    Clone, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IncrementIxArgs {
+    pub amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
+    pub memo: Vec<u8>,
+    pub checkpoints: Vec<u32>,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncrementIxData(pub IncrementIxArgs);
+impl From<IncrementIxArgs> for IncrementIxData {
+    fn from(args: IncrementIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl IncrementIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INCREMENT_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INCREMENT_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(IncrementIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INCREMENT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn increment_ix_with_program_id(
+    program_id: Pubkey,
+    keys: IncrementKeys,
+    args: IncrementIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas = keys.to_account_metas(program_id);
+    let data: IncrementIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn increment_ix(keys: IncrementKeys, args: IncrementIxArgs) -> std::io::Result<Instruction> {
+    increment_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn increment_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: IncrementAccounts<'_, '_>,
+    args: IncrementIxArgs,
+) -> ProgramResult {
+    let keys: IncrementKeys = accounts.into();
+    let ix = increment_ix_with_program_id(program_id, keys, args)?;
+    let account_info: Vec<AccountInfo> = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn increment_invoke(
+    accounts: IncrementAccounts<'_, '_>,
+    args: IncrementIxArgs,
+) -> ProgramResult {
+    increment_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn increment_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: IncrementAccounts<'_, '_>,
+    args: IncrementIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: IncrementKeys = accounts.into();
+    let ix = increment_ix_with_program_id(program_id, keys, args)?;
+    let account_info: Vec<AccountInfo> = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn increment_invoke_signed(
+    accounts: IncrementAccounts<'_, '_>,
+    args: IncrementIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    increment_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn increment_verify_account_keys(
+    accounts: IncrementAccounts<'_, '_>,
+    keys: IncrementKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.counter.key, keys.counter),
+        (*accounts.authority.key, keys.authority),
+        (
+            accounts.delegate.map_or(crate::ID, |a| *a.key),
+            keys.delegate.unwrap_or(crate::ID),
+        ),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn increment_verify_writable_privileges<'me, 'info>(
+    accounts: IncrementAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [Some(accounts.counter)].into_iter().flatten() {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn increment_verify_signer_privileges<'me, 'info>(
+    accounts: IncrementAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.delegate].into_iter().flatten() {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn increment_verify_account_privileges<'me, 'info>(
+    accounts: IncrementAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    increment_verify_writable_privileges(accounts)?;
+    increment_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+solana_program::declare_id!("CounterR5rTm9ChFMd1Ke4g6pbUsZDPBuqsZWzMvB1YF");
+pub mod accounts;
+pub use accounts::*;
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod pdas;
+pub use pdas::*;
+pub mod errors;
+pub use errors::*;
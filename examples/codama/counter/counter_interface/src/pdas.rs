@@ -0,0 +1,9 @@
+use solana_program::pubkey::{Pubkey, PubkeyError};
+pub fn find_counter_pda(authority: &Pubkey) -> (Pubkey, u8) {
+    let seeds: &[&[u8]] = &[b"counter", authority.as_ref()];
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+pub fn create_counter_pda_with_bump(authority: &Pubkey, bump: u8) -> Result<Pubkey, PubkeyError> {
+    let seeds: &[&[u8]] = &[b"counter", authority.as_ref(), &[bump]];
+    Pubkey::create_program_address(seeds, &crate::ID)
+}
@@ -0,0 +1,45 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+/// Counter state
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Counter {
+    pub authority: Pubkey,
+    pub count: u64,
+    pub mode: CounterMode,
+    pub label: [u8; 16],
+    pub bump: u8,
+}
+impl Counter {
+    pub const MIN_LEN: usize = 58;
+    /// Borsh-serialized length of this [`Counter`]
+    pub fn serialized_len(&self) -> usize {
+        57 + self.mode.serialized_len()
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CounterMode {
+    #[default]
+    Wrapping,
+    Capped(u64),
+    Stepped {
+        step: u32,
+        max: u64,
+    },
+}
+impl CounterMode {
+    pub const MIN_LEN: usize = 1;
+    /// Borsh-serialized length of this [`CounterMode`]
+    pub fn serialized_len(&self) -> usize {
+        match self {
+            Self::Wrapping => 1,
+            Self::Capped(_) => 9,
+            Self::Stepped { .. } => 13,
+        }
+    }
+}
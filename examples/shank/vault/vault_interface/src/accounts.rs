@@ -0,0 +1,21 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vault {
+    pub key: Key,
+    pub authority: Pubkey,
+    pub delegate: Option<Pubkey>,
+    pub amount: u64,
+    pub bump: u8,
+}
+impl Vault {
+    pub const MIN_LEN: usize = 43;
+    /// Borsh-serialized length of this [`Vault`]
+    pub fn serialized_len(&self) -> usize {
+        43 + if self.delegate.is_some() { 32 } else { 0 }
+    }
+}
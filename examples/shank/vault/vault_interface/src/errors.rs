@@ -0,0 +1,14 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum VaultError {
+    #[error("Invalid vault authority")]
+    InvalidAuthority = 0,
+    #[error("Insufficient funds in vault")]
+    InsufficientFunds = 1,
+}
+impl From<VaultError> for ProgramError {
+    fn from(e: VaultError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
@@ -0,0 +1,765 @@
+use crate::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use std::io::Read;
+#[derive(Clone, Debug, PartialEq)]
+pub enum VaultProgramIx {
+    Initialize(InitializeIxArgs),
+    Deposit(DepositIxArgs),
+    Close,
+}
+impl VaultProgramIx {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INITIALIZE_IX_DISCM => Ok(Self::Initialize(InitializeIxArgs::deserialize(
+                &mut reader,
+            )?)),
+            DEPOSIT_IX_DISCM => Ok(Self::Deposit(DepositIxArgs::deserialize(&mut reader)?)),
+            CLOSE_IX_DISCM => Ok(Self::Close),
+            _ => Err(std::io::Error::other(format!(
+                "discm {:?} not found",
+                maybe_discm
+            ))),
+        }
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        match self {
+            Self::Initialize(args) => {
+                writer.write_all(&INITIALIZE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Deposit(args) => {
+                writer.write_all(&DEPOSIT_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::Close => writer.write_all(&CLOSE_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeAccounts<'me, 'info> {
+    pub vault: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+    pub payer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitializeKeys {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub payer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<InitializeAccounts<'_, '_>> for InitializeKeys {
+    fn from(accounts: InitializeAccounts) -> Self {
+        Self {
+            vault: *accounts.vault.key,
+            authority: *accounts.authority.key,
+            payer: *accounts.payer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl From<InitializeKeys> for [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<InitializeKeys> for Vec<AccountMeta> {
+    fn from(keys: InitializeKeys) -> Self {
+        let accounts: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: pubkeys[0],
+            authority: pubkeys[1],
+            payer: pubkeys[2],
+            system_program: pubkeys[3],
+        }
+    }
+}
+impl<'info> From<InitializeAccounts<'_, 'info>>
+    for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]
+{
+    fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
+        [
+            accounts.vault.clone(),
+            accounts.authority.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+        ]
+    }
+}
+impl<'info> From<InitializeAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
+    for InitializeAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: &arr[0],
+            authority: &arr[1],
+            payer: &arr[2],
+            system_program: &arr[3],
+        }
+    }
+}
+pub const INITIALIZE_IX_DISCM: [u8; 1] = [0];
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeIxArgs {
+    pub initialize_args: InitializeArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitializeIxData(pub InitializeIxArgs);
+impl From<InitializeIxArgs> for InitializeIxData {
+    fn from(args: InitializeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl InitializeIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INITIALIZE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                INITIALIZE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(InitializeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&INITIALIZE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn initialize_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeKeys,
+    args: InitializeIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: InitializeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn initialize_ix(keys: InitializeKeys, args: InitializeIxArgs) -> std::io::Result<Instruction> {
+    initialize_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn initialize_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+) -> ProgramResult {
+    let keys: InitializeKeys = accounts.into();
+    let ix = initialize_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn initialize_invoke(
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+) -> ProgramResult {
+    initialize_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn initialize_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: InitializeKeys = accounts.into();
+    let ix = initialize_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn initialize_invoke_signed(
+    accounts: InitializeAccounts<'_, '_>,
+    args: InitializeIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    initialize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn initialize_verify_account_keys(
+    accounts: InitializeAccounts<'_, '_>,
+    keys: InitializeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.vault.key, keys.vault),
+        (*accounts.authority.key, keys.authority),
+        (*accounts.payer.key, keys.payer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_writable_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vault, accounts.payer] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_signer_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority, accounts.payer] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn initialize_verify_account_privileges<'me, 'info>(
+    accounts: InitializeAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    initialize_verify_writable_privileges(accounts)?;
+    initialize_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPOSIT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone, Debug)]
+pub struct DepositAccounts<'me, 'info> {
+    pub vault: &'me AccountInfo<'info>,
+    pub depositor: &'me AccountInfo<'info>,
+    pub delegate: Option<&'me AccountInfo<'info>>,
+    pub fee_payer: &'me AccountInfo<'info>,
+    pub system_program: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DepositKeys {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    pub delegate: Option<Pubkey>,
+    pub fee_payer: Pubkey,
+    pub system_program: Pubkey,
+}
+impl From<DepositAccounts<'_, '_>> for DepositKeys {
+    fn from(accounts: DepositAccounts) -> Self {
+        Self {
+            vault: *accounts.vault.key,
+            depositor: *accounts.depositor.key,
+            delegate: accounts.delegate.map(|a| *a.key),
+            fee_payer: *accounts.fee_payer.key,
+            system_program: *accounts.system_program.key,
+        }
+    }
+}
+impl DepositKeys {
+    /// Absent optional accounts are passed as `program_id`
+    pub fn to_account_metas(&self, program_id: Pubkey) -> [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] {
+        let keys = self;
+        [
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.depositor,
+                is_signer: true,
+                is_writable: true,
+            },
+            match keys.delegate {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+            AccountMeta {
+                pubkey: keys.fee_payer,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<DepositKeys> for [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] {
+    fn from(keys: DepositKeys) -> Self {
+        keys.to_account_metas(crate::ID)
+    }
+}
+impl From<DepositKeys> for Vec<AccountMeta> {
+    fn from(keys: DepositKeys) -> Self {
+        let accounts: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+/// Whether each of the optional signer accounts signs, they are non-signers by default
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DepositOptionalSigners {
+    pub fee_payer: bool,
+}
+impl DepositKeys {
+    /// Like the `[AccountMeta]` conversion, but with the optional signer accounts
+    /// set as signers according to `optional_signers`
+    pub fn to_account_metas_with_signers(
+        &self,
+        program_id: Pubkey,
+        optional_signers: DepositOptionalSigners,
+    ) -> [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] {
+        let keys = self;
+        [
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.depositor,
+                is_signer: true,
+                is_writable: true,
+            },
+            match keys.delegate {
+                Some(pubkey) => AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                None => AccountMeta {
+                    pubkey: program_id,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            },
+            AccountMeta {
+                pubkey: keys.fee_payer,
+                is_signer: optional_signers.fee_payer,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]> for DepositKeys {
+    fn from(pubkeys: [Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: pubkeys[0],
+            depositor: pubkeys[1],
+            delegate: Some(pubkeys[2]).filter(|k| *k != crate::ID),
+            fee_payer: pubkeys[3],
+            system_program: pubkeys[4],
+        }
+    }
+}
+impl<'info> From<DepositAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
+        let mut res = Vec::with_capacity(DEPOSIT_IX_ACCOUNTS_LEN);
+        res.push(accounts.vault.clone());
+        res.push(accounts.depositor.clone());
+        if let Some(a) = accounts.delegate {
+            res.push(a.clone());
+        }
+        res.push(accounts.fee_payer.clone());
+        res.push(accounts.system_program.clone());
+        res
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
+    for DepositAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: &arr[0],
+            depositor: &arr[1],
+            delegate: Some(&arr[2]).filter(|a| *a.key != crate::ID),
+            fee_payer: &arr[3],
+            system_program: &arr[4],
+        }
+    }
+}
+pub const DEPOSIT_IX_DISCM: [u8; 1] = [1];
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DepositIxData(pub DepositIxArgs);
+impl From<DepositIxArgs> for DepositIxData {
+    fn from(args: DepositIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DepositIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != DEPOSIT_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                DEPOSIT_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self(DepositIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&DEPOSIT_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn deposit_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DepositKeys,
+    args: DepositIxArgs,
+) -> std::io::Result<Instruction> {
+    let metas = keys.to_account_metas(program_id);
+    let data: DepositIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deposit_ix(keys: DepositKeys, args: DepositIxArgs) -> std::io::Result<Instruction> {
+    deposit_ix_with_program_id(crate::ID, keys, args)
+}
+pub fn deposit_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+) -> ProgramResult {
+    let keys: DepositKeys = accounts.into();
+    let ix = deposit_ix_with_program_id(program_id, keys, args)?;
+    let account_info: Vec<AccountInfo> = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn deposit_invoke(accounts: DepositAccounts<'_, '_>, args: DepositIxArgs) -> ProgramResult {
+    deposit_invoke_with_program_id(crate::ID, accounts, args)
+}
+pub fn deposit_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: DepositKeys = accounts.into();
+    let ix = deposit_ix_with_program_id(program_id, keys, args)?;
+    let account_info: Vec<AccountInfo> = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn deposit_invoke_signed(
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    deposit_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+pub fn deposit_verify_account_keys(
+    accounts: DepositAccounts<'_, '_>,
+    keys: DepositKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.vault.key, keys.vault),
+        (*accounts.depositor.key, keys.depositor),
+        (
+            accounts.delegate.map_or(crate::ID, |a| *a.key),
+            keys.delegate.unwrap_or(crate::ID),
+        ),
+        (*accounts.fee_payer.key, keys.fee_payer),
+        (*accounts.system_program.key, keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_writable_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vault, accounts.depositor, accounts.fee_payer] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_signer_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.depositor] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn deposit_verify_account_privileges<'me, 'info>(
+    accounts: DepositAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    deposit_verify_writable_privileges(accounts)?;
+    deposit_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CLOSE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone, Debug)]
+pub struct CloseAccounts<'me, 'info> {
+    pub vault: &'me AccountInfo<'info>,
+    pub authority: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CloseKeys {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+}
+impl From<CloseAccounts<'_, '_>> for CloseKeys {
+    fn from(accounts: CloseAccounts) -> Self {
+        Self {
+            vault: *accounts.vault.key,
+            authority: *accounts.authority.key,
+        }
+    }
+}
+impl From<CloseKeys> for [AccountMeta; CLOSE_IX_ACCOUNTS_LEN] {
+    fn from(keys: CloseKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<CloseKeys> for Vec<AccountMeta> {
+    fn from(keys: CloseKeys) -> Self {
+        let accounts: [AccountMeta; CLOSE_IX_ACCOUNTS_LEN] = keys.into();
+        Vec::from(accounts)
+    }
+}
+impl From<[Pubkey; CLOSE_IX_ACCOUNTS_LEN]> for CloseKeys {
+    fn from(pubkeys: [Pubkey; CLOSE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+impl<'info> From<CloseAccounts<'_, 'info>> for [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CloseAccounts<'_, 'info>) -> Self {
+        [accounts.vault.clone(), accounts.authority.clone()]
+    }
+}
+impl<'info> From<CloseAccounts<'_, 'info>> for Vec<AccountInfo<'info>> {
+    fn from(accounts: CloseAccounts<'_, 'info>) -> Self {
+        let accounts_array: [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN] = accounts.into();
+        Vec::from(accounts_array)
+    }
+}
+impl<'me, 'info> From<&'me [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN]>
+    for CloseAccounts<'me, 'info>
+{
+    fn from(arr: &'me [AccountInfo<'info>; CLOSE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            vault: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+pub const CLOSE_IX_DISCM: [u8; 1] = [2];
+#[derive(Clone, Debug, PartialEq)]
+pub struct CloseIxData;
+impl CloseIxData {
+    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 1];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CLOSE_IX_DISCM {
+            return Err(std::io::Error::other(format!(
+                "discm does not match. Expected: {:?}. Received: {:?}",
+                CLOSE_IX_DISCM, maybe_discm
+            )));
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&CLOSE_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+pub fn close_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CloseKeys,
+) -> std::io::Result<Instruction> {
+    let metas: [AccountMeta; CLOSE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: CloseIxData.try_to_vec()?,
+    })
+}
+pub fn close_ix(keys: CloseKeys) -> std::io::Result<Instruction> {
+    close_ix_with_program_id(crate::ID, keys)
+}
+pub fn close_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseAccounts<'_, '_>,
+) -> ProgramResult {
+    let keys: CloseKeys = accounts.into();
+    let ix = close_ix_with_program_id(program_id, keys)?;
+    invoke_instruction(&ix, accounts)
+}
+pub fn close_invoke(accounts: CloseAccounts<'_, '_>) -> ProgramResult {
+    close_invoke_with_program_id(crate::ID, accounts)
+}
+pub fn close_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CloseAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: CloseKeys = accounts.into();
+    let ix = close_ix_with_program_id(program_id, keys)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+pub fn close_invoke_signed(accounts: CloseAccounts<'_, '_>, seeds: &[&[&[u8]]]) -> ProgramResult {
+    close_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+}
+pub fn close_verify_account_keys(
+    accounts: CloseAccounts<'_, '_>,
+    keys: CloseKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.vault.key, keys.vault),
+        (*accounts.authority.key, keys.authority),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+pub fn close_verify_writable_privileges<'me, 'info>(
+    accounts: CloseAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts.vault, accounts.authority] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+pub fn close_verify_signer_privileges<'me, 'info>(
+    accounts: CloseAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+pub fn close_verify_account_privileges<'me, 'info>(
+    accounts: CloseAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    close_verify_writable_privileges(accounts)?;
+    close_verify_signer_privileges(accounts)?;
+    Ok(())
+}
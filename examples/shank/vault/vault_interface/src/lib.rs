@@ -0,0 +1,9 @@
+solana_program::declare_id!("6SMHT295WpSBUSXpVntxTtW3HPLWdth1qgVP5HbTxeJi");
+pub mod accounts;
+pub use accounts::*;
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
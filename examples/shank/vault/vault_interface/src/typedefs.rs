@@ -0,0 +1,27 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeArgs {
+    pub bump: u8,
+    pub delegate: Option<Pubkey>,
+}
+impl InitializeArgs {
+    pub const MIN_LEN: usize = 2;
+    /// Borsh-serialized length of this [`InitializeArgs`]
+    pub fn serialized_len(&self) -> usize {
+        2 + if self.delegate.is_some() { 32 } else { 0 }
+    }
+}
+#[derive(
+    Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize, PartialEq, Eq, Hash, PartialOrd,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Key {
+    #[default]
+    Uninitialized,
+    Vault,
+}
+pub const KEY_LEN: usize = 1;
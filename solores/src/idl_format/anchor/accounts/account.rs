@@ -1,51 +1,136 @@
-use heck::{ToPascalCase, ToShoutySnakeCase};
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
-use sha2::{Digest, Sha256};
+use syn::LitInt;
 
-use crate::idl_format::anchor::typedefs::NamedType;
+use crate::idl_format::anchor::{
+    discm_or_sighash,
+    typedefs::{
+        DeriveAnalysis, NamedType, SerializedLen, SerializedLenAnalysis, TypedefField,
+        TypedefFieldArray, TypedefFieldType, TypedefType,
+    },
+};
 use crate::utils::conditional_pascal_case;
 
+/// An anchor account.
+///
+/// The account's layout is the typedef of the same name in the IDL's `types`,
+/// which is generated in the typedefs module. Legacy IDLs' inline account layouts
+/// are moved into `types` on conversion.
 #[derive(Deserialize)]
-pub struct NamedAccount(pub NamedType);
+pub struct NamedAccount {
+    pub name: String,
+    pub discriminator: Option<Vec<u8>>,
+}
 
 impl NamedAccount {
-    pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
-        let name = &self.0.name;
-        // discriminant
-        let account_discm_ident = format_ident!("{}_ACCOUNT_DISCM", name.to_shouty_snake_case());
-        // pre-image: "account:{AccountStructName}"
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("account:{}", name.to_pascal_case()).as_bytes()).as_slice()
-                [..8],
+    pub fn struct_ident(&self) -> Ident {
+        format_ident!("{}", conditional_pascal_case(&self.name))
+    }
+
+    pub fn account_ident(&self) -> Ident {
+        format_ident!("{}Account", conditional_pascal_case(&self.name))
+    }
+
+    pub fn discm_ident(&self) -> Ident {
+        format_ident!("{}_ACCOUNT_DISCM", self.name.to_shouty_snake_case())
+    }
+
+    /// pre-image: "account:{AccountStructName}"
+    pub fn discm(&self) -> Vec<u8> {
+        discm_or_sighash(
+            self.discriminator.as_deref(),
+            &format!("account:{}", self.name.to_pascal_case()),
         )
-        .unwrap();
-        let discm_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
+    }
 
-        let struct_def = self.0.to_token_stream(cli_args);
+    /// Returns the typedef in `types` that defines this account's layout
+    pub fn resolve_type<'a>(&self, named_types: &'a [NamedType]) -> &'a NamedType {
+        named_types
+            .iter()
+            .find(|t| t.name == self.name)
+            .unwrap_or_else(|| {
+                log::error!("Could not find layout for account {} in types", self.name);
+                panic!();
+            })
+    }
 
-        let struct_ident = format_ident!("{}", conditional_pascal_case(name));
-        let account_ident = format_ident!("{}Account", conditional_pascal_case(name));
+    /// The fields of this account at a fixed offset in its data, with their offsets
+    /// including the discriminant
+    pub fn filter_fields<'t>(
+        &self,
+        named_types: &'t [NamedType],
+        lens: &SerializedLenAnalysis,
+    ) -> Vec<(&'t TypedefField, usize)> {
+        let discm_len = self.discm().len();
+        lens.fixed_offset_fields(self.resolve_type(named_types))
+            .into_iter()
+            .filter(|(field, _)| {
+                let is_discm = field.name.to_snake_case() == "discm";
+                if is_discm {
+                    log::warn!(
+                        "Skipping filter for {}.{}: conflicts with filter_discm()",
+                        self.name,
+                        field.name
+                    );
+                }
+                !is_discm
+            })
+            .map(|(field, offset)| (field, discm_len + offset))
+            .collect()
+    }
 
-        quote! {
-            pub const #account_discm_ident: [u8; 8] = #discm_tokens;
+    pub fn to_token_stream(
+        &self,
+        named_types: &[NamedType],
+        analysis: &DeriveAnalysis,
+        lens: &SerializedLenAnalysis,
+    ) -> TokenStream {
+        // fail early if layout is missing
+        self.resolve_type(named_types);
 
-            #struct_def
+        let account_discm_ident = self.discm_ident();
+        let discm = self.discm();
+        let discm_len = LitInt::new(&discm.len().to_string(), Span::call_site());
+        let discm_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
 
-            #[derive(Clone, Debug, PartialEq)]
+        let struct_ident = self.struct_ident();
+        let account_ident = self.account_ident();
+        let derives = analysis.of_type(&self.name);
+        let derive = derives.wrapper_derive_tokens();
+        // aliases don't get a serialized_len()
+        let len_tokens = if matches!(
+            self.resolve_type(named_types).r#type,
+            TypedefType::r#type { .. }
+        ) {
+            TokenStream::new()
+        } else {
+            self.serialized_len_tokens(lens)
+        };
+        let filters = self.filters_tokens(named_types, lens);
+        let zero_copy_loaders = if derives.pod {
+            self.zero_copy_loaders_tokens()
+        } else {
+            TokenStream::new()
+        };
+
+        quote! {
+            pub const #account_discm_ident: [u8; #discm_len] = #discm_tokens;
+
+            #derive
             pub struct #account_ident(pub #struct_ident);
 
             impl #account_ident {
                 pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
                     use std::io::Read;
                     let mut reader = buf;
-                    let mut maybe_discm = [0u8; 8];
+                    let mut maybe_discm = [0u8; #discm_len];
                     reader.read_exact(&mut maybe_discm)?;
                     if maybe_discm != #account_discm_ident {
                         return Err(
-                            std::io::Error::new(
-                                std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, maybe_discm)
+                            std::io::Error::other(
+                                format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, maybe_discm)
                             )
                         );
                     }
@@ -63,6 +148,232 @@ impl NamedAccount {
                     Ok(data)
                 }
             }
+
+            #len_tokens
+            #filters
+            #zero_copy_loaders
+        }
+    }
+
+    /// `pub const X_ACCOUNT_LEN: usize` for fixed-size accounts,
+    /// `MIN_LEN` and `serialized_len()` for variable-size ones, both including the discriminant
+    fn serialized_len_tokens(&self, lens: &SerializedLenAnalysis) -> TokenStream {
+        let discm_len = self.discm().len();
+        match lens.of_type(&self.name) {
+            None => TokenStream::new(),
+            Some(SerializedLen::Fixed(n)) => {
+                let len_ident = format_ident!("{}_ACCOUNT_LEN", self.name.to_shouty_snake_case());
+                let len = LitInt::new(&(discm_len + n).to_string(), Span::call_site());
+                quote! {
+                    pub const #len_ident: usize = #len;
+                }
+            }
+            Some(SerializedLen::Variable(min)) => {
+                let account_ident = self.account_ident();
+                let account_discm_ident = self.discm_ident();
+                let min_len = LitInt::new(&(discm_len + min).to_string(), Span::call_site());
+                quote! {
+                    impl #account_ident {
+                        pub const MIN_LEN: usize = #min_len;
+
+                        /// Borsh-serialized length of this account's data, including the discriminant
+                        pub fn serialized_len(&self) -> usize {
+                            #account_discm_ident.len() + self.0.serialized_len()
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// `filter_*()` memcmp filters on the discriminant and the fields at a fixed offset,
+    /// and `rpc_filter_*()`s wrapping them in `RpcFilterType`s under the `client` feature
+    fn filters_tokens(
+        &self,
+        named_types: &[NamedType],
+        lens: &SerializedLenAnalysis,
+    ) -> TokenStream {
+        let account_ident = self.account_ident();
+        let account_discm_ident = self.discm_ident();
+        let mut filters = vec![quote! {
+            /// Memcmp filter `(offset, bytes)` matching this account's discriminant
+            pub fn filter_discm() -> (usize, Vec<u8>) {
+                (0, #account_discm_ident.to_vec())
+            }
+        }];
+        let mut rpc_filters = vec![quote! {
+            pub fn rpc_filter_discm() -> RpcFilterType {
+                memcmp_filter(Self::filter_discm())
+            }
+        }];
+        for (field, offset) in self.filter_fields(named_types, lens) {
+            let field_name = field.name.to_snake_case();
+            let param = format_ident!("{}", field_name);
+            let filter_ident = format_ident!("filter_{}", field_name);
+            let rpc_filter_ident = format_ident!("rpc_filter_{}", field_name);
+            let offset = LitInt::new(&offset.to_string(), Span::call_site());
+            let doc = format!(" Memcmp filter `(offset, bytes)` matching `{}`", field_name);
+            let (param_ty, bytes) = filter_value_tokens(&field.r#type, &param);
+            match bytes {
+                FilterBytes::Infallible(bytes) => {
+                    filters.push(quote! {
+                        #[doc = #doc]
+                        pub fn #filter_ident(#param: #param_ty) -> (usize, Vec<u8>) {
+                            (#offset, #bytes)
+                        }
+                    });
+                    rpc_filters.push(quote! {
+                        pub fn #rpc_filter_ident(#param: #param_ty) -> RpcFilterType {
+                            memcmp_filter(Self::#filter_ident(#param))
+                        }
+                    });
+                }
+                FilterBytes::Borsh(bytes) => {
+                    filters.push(quote! {
+                        #[doc = #doc]
+                        pub fn #filter_ident(#param: #param_ty) -> std::io::Result<(usize, Vec<u8>)> {
+                            Ok((#offset, #bytes))
+                        }
+                    });
+                    rpc_filters.push(quote! {
+                        pub fn #rpc_filter_ident(#param: #param_ty) -> std::io::Result<RpcFilterType> {
+                            Self::#filter_ident(#param).map(memcmp_filter)
+                        }
+                    });
+                }
+            }
+        }
+        quote! {
+            impl #account_ident {
+                #(#filters)*
+            }
+
+            #[cfg(feature = "client")]
+            impl #account_ident {
+                #(#rpc_filters)*
+            }
+        }
+    }
+
+    /// `load()`s that cast the account data in place with bytemuck instead of deserializing it,
+    /// for accounts whose typedef is `Pod`
+    fn zero_copy_loaders_tokens(&self) -> TokenStream {
+        let account_discm_ident = self.discm_ident();
+        let struct_ident = self.struct_ident();
+        let account_ident = self.account_ident();
+        let load_doc = format!(
+            " Casts the account data to a [`{}`] without copying.",
+            struct_ident
+        );
+        let load_mut_doc = format!(
+            " Casts the account data to a mutable [`{}`] without copying.",
+            struct_ident
+        );
+        let read_unaligned_doc = format!(
+            " Copies the account data into a [`{}`] regardless of its alignment",
+            struct_ident
+        );
+        quote! {
+            impl #account_ident {
+                /// Range of the account struct in `data` after checking the discriminant and length
+                fn pod_range(data: &[u8]) -> std::io::Result<std::ops::Range<usize>> {
+                    let discm_len = #account_discm_ident.len();
+                    let end = discm_len + std::mem::size_of::<#struct_ident>();
+                    if data.len() < end {
+                        return Err(std::io::Error::other(format!(
+                            "account data too short. Expected at least: {}. Received: {}",
+                            end,
+                            data.len()
+                        )));
+                    }
+                    if data[..discm_len] != #account_discm_ident {
+                        return Err(
+                            std::io::Error::other(
+                                format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, &data[..discm_len])
+                            )
+                        );
+                    }
+                    Ok(discm_len..end)
+                }
+
+                #[doc = #load_doc]
+                /// Errs if `data` is not aligned for it, see [`Self::read_unaligned`].
+                pub fn load(data: &[u8]) -> std::io::Result<&#struct_ident> {
+                    bytemuck::try_from_bytes(&data[Self::pod_range(data)?])
+                        .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+                }
+
+                #[doc = #load_mut_doc]
+                /// Errs if `data` is not aligned for it.
+                pub fn load_mut(data: &mut [u8]) -> std::io::Result<&mut #struct_ident> {
+                    let range = Self::pod_range(data)?;
+                    bytemuck::try_from_bytes_mut(&mut data[range])
+                        .map_err(|e| std::io::Error::other(format!("could not cast account data: {:?}", e)))
+                }
+
+                #[doc = #read_unaligned_doc]
+                pub fn read_unaligned(data: &[u8]) -> std::io::Result<#struct_ident> {
+                    Ok(bytemuck::pod_read_unaligned(&data[Self::pod_range(data)?]))
+                }
+
+                /// [`Self::load`] on the account's borrowed data
+                pub fn load_account_info<'a>(account: &'a AccountInfo) -> Result<Ref<'a, #struct_ident>, ProgramError> {
+                    let data = account.try_borrow_data()?;
+                    let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+                    Ref::filter_map(data, |data| bytemuck::try_from_bytes(&data[range]).ok())
+                        .map_err(|_| ProgramError::InvalidAccountData)
+                }
+
+                /// [`Self::load_mut`] on the account's mutably borrowed data
+                pub fn load_account_info_mut<'a>(account: &'a AccountInfo) -> Result<RefMut<'a, #struct_ident>, ProgramError> {
+                    let data = account.try_borrow_mut_data()?;
+                    let range = Self::pod_range(&data).map_err(|_| ProgramError::InvalidAccountData)?;
+                    RefMut::filter_map(data, |data| bytemuck::try_from_bytes_mut(&mut data[range]).ok())
+                        .map_err(|_| ProgramError::InvalidAccountData)
+                }
+            }
+        }
+    }
+}
+
+/// Bytes expr of a filter's value
+enum FilterBytes {
+    Infallible(TokenStream),
+    /// `borsh::to_vec()?`, which errs on e.g. NaN floats nested in defined types
+    Borsh(TokenStream),
+}
+
+/// Param type and bytes expr of the filter on a field of type `ty`.
+/// Numbers and bools are taken by value, everything else by reference.
+fn filter_value_tokens(ty: &TypedefFieldType, param: &Ident) -> (TokenStream, FilterBytes) {
+    let borsh = FilterBytes::Borsh(quote! { borsh::to_vec(#param)? });
+    match ty {
+        _ if ty.is_bytes() => (quote! { &[u8] }, borsh),
+        TypedefFieldType::PrimitiveOrPubkey(s) => match s.as_str() {
+            "pubkey" | "publicKey" => (
+                quote! { &Pubkey },
+                FilterBytes::Infallible(quote! { #param.to_bytes().to_vec() }),
+            ),
+            "bool" => (
+                quote! { bool },
+                FilterBytes::Infallible(quote! { vec![#param as u8] }),
+            ),
+            "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128"
+            | "f32" | "f64" => (
+                ty.to_token_stream(),
+                FilterBytes::Infallible(quote! { #param.to_le_bytes().to_vec() }),
+            ),
+            "string" => (quote! { &str }, borsh),
+            _ => (quote! { &#ty }, borsh),
+        },
+        TypedefFieldType::array(TypedefFieldArray(elem, _)) if matches!(elem.as_ref(), TypedefFieldType::PrimitiveOrPubkey(s) if s == "u8") => {
+            (
+                quote! { &#ty },
+                FilterBytes::Infallible(quote! { #param.to_vec() }),
+            )
         }
+        // slices serialize the same as vecs
+        TypedefFieldType::vec(elem) => (quote! { &[#elem] }, borsh),
+        _ => (quote! { &#ty }, borsh),
     }
 }
@@ -1,14 +1,25 @@
+use heck::ToPascalCase;
+use itertools::Itertools;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    idl_format::{
+        anchor::typedefs::{DeriveAnalysis, NamedType, SerializedLenAnalysis},
+        IdlCodegenModule,
+    },
+    type_map::TypeMap,
+};
 
 mod account;
 pub use account::*;
 
 pub struct AccountsCodegenModule<'a> {
     pub cli_args: &'a crate::Args,
+    pub type_map: &'a TypeMap,
+    pub program_name: &'a str,
     pub named_accounts: &'a [NamedAccount],
+    pub named_types: &'a [NamedType],
 }
 
 impl IdlCodegenModule for AccountsCodegenModule<'_> {
@@ -17,47 +28,143 @@ impl IdlCodegenModule for AccountsCodegenModule<'_> {
     }
 
     fn gen_head(&self) -> TokenStream {
+        // account structs are defined in typedefs
         let mut res = quote! {
             use borsh::{BorshDeserialize, BorshSerialize};
+            use crate::*;
         };
-        for a in self.named_accounts {
-            let use_zero_copy = self.cli_args.zero_copy.iter().any(|e| e == &a.0.name)
-                || a.0.serialization.as_ref().map_or(false, |s| s == "bytemuck");
-            let use_unsafe_bytemuck = a.0.serialization.as_ref().map_or(false, |s| s == "bytemuckunsafe");
-
-            if use_zero_copy || use_unsafe_bytemuck {
-                res.extend(quote! {
-                    use bytemuck::{Pod, Zeroable};
-                });
-                break;
-            }
+        let analysis = self.derive_analysis();
+        let lens = SerializedLenAnalysis::new(self.named_types, self.type_map);
+        if self.named_accounts.iter().any(|a| {
+            a.filter_fields(self.named_types, &lens)
+                .iter()
+                .any(|(field, _)| field.r#type.is_or_has_pubkey())
+        }) {
+            res.extend(quote! {
+                use solana_program::pubkey::Pubkey;
+            });
         }
-        let mut has_pubkey = false;
-        let mut has_defined = false;
-        for a in self.named_accounts {
-            if a.0.r#type.has_pubkey_field() && !has_pubkey {
-                has_pubkey = true;
-                res.extend(quote! {
-                    use solana_program::pubkey::Pubkey;
-                });
-            }
-            if a.0.r#type.has_defined_field() && !has_defined {
-                has_defined = true;
-                res.extend(quote! {
-                    use crate::*;
-                })
-            }
-            if has_defined && has_pubkey {
-                break;
-            }
+        if !self.named_accounts.is_empty() {
+            res.extend(quote! {
+                #[cfg(feature = "client")]
+                use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+            });
+        }
+        if self
+            .named_accounts
+            .iter()
+            .any(|a| analysis.of_type(&a.name).pod)
+        {
+            res.extend(quote! {
+                use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+                use std::cell::{Ref, RefMut};
+            });
         }
         res
     }
 
     fn gen_body(&self) -> TokenStream {
-        self.named_accounts
+        let analysis = self.derive_analysis();
+        let lens = SerializedLenAnalysis::new(self.named_types, self.type_map);
+        let mut res = if self.named_accounts.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! {
+                #[cfg(feature = "client")]
+                fn memcmp_filter((offset, bytes): (usize, Vec<u8>)) -> RpcFilterType {
+                    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(offset, bytes))
+                }
+            }
+        };
+        res.extend(
+            self.named_accounts
+                .iter()
+                .map(|e| e.to_token_stream(self.named_types, &analysis, &lens)),
+        );
+        if !self.named_accounts.is_empty() {
+            res.extend(self.program_account_data_enum_tokens());
+        }
+        res
+    }
+}
+
+impl AccountsCodegenModule<'_> {
+    fn derive_analysis(&self) -> DeriveAnalysis {
+        DeriveAnalysis::new(self.named_types, &self.cli_args.zero_copy, self.type_map)
+    }
+
+    /// `{Program}AccountData` enum of all the program's `*Account`s
+    fn program_account_data_enum_tokens(&self) -> TokenStream {
+        let enum_ident = format_ident!("{}AccountData", self.program_name.to_pascal_case());
+        let variants = self.named_accounts.iter().map(|a| {
+            let variant_ident = a.struct_ident();
+            let account_ident = a.account_ident();
+            quote! { #variant_ident(#account_ident) }
+        });
+        // longest first in case a discm is a prefix of another
+        let deserialize_variant_if_arms = self
+            .named_accounts
+            .iter()
+            .sorted_by_key(|a| std::cmp::Reverse(a.discm().len()))
+            .map(|a| {
+                let variant_ident = a.struct_ident();
+                let account_ident = a.account_ident();
+                let discm_ident = a.discm_ident();
+                quote! {
+                    if buf.starts_with(&#discm_ident) {
+                        return Ok(Self::#variant_ident(#account_ident::deserialize(buf)?));
+                    }
+                }
+            });
+        let max_discm_len = self
+            .named_accounts
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
-            .collect()
+            .map(|a| a.discm().len())
+            .max()
+            .unwrap_or_default();
+        let discriminator_match_arms = self.named_accounts.iter().map(|a| {
+            let variant_ident = a.struct_ident();
+            let discm_ident = a.discm_ident();
+            quote! { Self::#variant_ident(_) => &#discm_ident }
+        });
+        let name_match_arms = self.named_accounts.iter().map(|a| {
+            let variant_ident = a.struct_ident();
+            let name = &a.name;
+            quote! { Self::#variant_ident(_) => #name }
+        });
+        quote! {
+            // account sizes can vary widely, boxing them would make matching on variants clunkier
+            #[allow(clippy::large_enum_variant)]
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum #enum_ident {
+                #(#variants),*
+            }
+
+            impl #enum_ident {
+                /// Decodes any of the program's accounts from its discriminator-prefixed data
+                pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+                    #(#deserialize_variant_if_arms)*
+                    Err(
+                        std::io::Error::other(
+                            format!("account discm {:?} not found", &buf[..buf.len().min(#max_discm_len)])
+                        )
+                    )
+                }
+
+                /// The account's `*_ACCOUNT_DISCM`
+                pub fn discriminator(&self) -> &'static [u8] {
+                    match self {
+                        #(#discriminator_match_arms),*
+                    }
+                }
+
+                /// The account's name in the IDL
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        #(#name_match_arms),*
+                    }
+                }
+            }
+        }
     }
 }
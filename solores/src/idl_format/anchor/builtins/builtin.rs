@@ -0,0 +1,487 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::idl_format::anchor::typedefs::LenPrefix;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Builtin {
+    /// `COption<T>`, a u32-tagged fixed-size option
+    COption,
+    /// `U256`, 32 little-endian bytes
+    U256,
+    /// `I256`, 32 little-endian two's complement bytes
+    I256,
+    /// `Vec<T>` newtype with a non-u32 length prefix, e.g. `U8PrefixVec<T>`
+    PrefixedVec(LenPrefix),
+    /// `String` newtype with a non-u32 length prefix, e.g. `U8PrefixString`
+    PrefixedString(LenPrefix),
+}
+
+impl Builtin {
+    /// The builtin named by an IDL primitive type string, if any
+    pub fn from_primitive(s: &str) -> Option<Self> {
+        match s {
+            "u256" => Some(Self::U256),
+            "i256" => Some(Self::I256),
+            _ => None,
+        }
+    }
+
+    pub fn is_int(&self) -> bool {
+        matches!(self, Self::U256 | Self::I256)
+    }
+
+    pub fn len_prefix(&self) -> Option<LenPrefix> {
+        match self {
+            Self::PrefixedVec(p) | Self::PrefixedString(p) => Some(*p),
+            _ => None,
+        }
+    }
+
+    pub fn ident(&self) -> Ident {
+        match self {
+            Self::COption => format_ident!("COption"),
+            Self::U256 => format_ident!("U256"),
+            Self::I256 => format_ident!("I256"),
+            Self::PrefixedVec(LenPrefix::ShortU16) => format_ident!("ShortVec"),
+            Self::PrefixedString(LenPrefix::ShortU16) => format_ident!("ShortVecString"),
+            Self::PrefixedVec(p) => format_ident!("{}PrefixVec", len_prefix_pascal(*p)),
+            Self::PrefixedString(p) => format_ident!("{}PrefixString", len_prefix_pascal(*p)),
+        }
+    }
+
+    pub fn to_token_stream(&self) -> TokenStream {
+        match self {
+            Self::COption => coption_tokens(),
+            Self::U256 => u256_tokens(),
+            Self::I256 => i256_tokens(),
+            Self::PrefixedVec(p) => prefixed_vec_tokens(self.ident(), *p),
+            Self::PrefixedString(p) => prefixed_string_tokens(self.ident(), *p),
+        }
+    }
+}
+
+fn len_prefix_pascal(prefix: LenPrefix) -> &'static str {
+    match prefix {
+        LenPrefix::U8 => "U8",
+        LenPrefix::U16 => "U16",
+        LenPrefix::U32 => "U32",
+        LenPrefix::U64 => "U64",
+        LenPrefix::ShortU16 => "ShortU16",
+    }
+}
+
+fn len_fn_idents(prefix: LenPrefix) -> (Ident, Ident) {
+    let name = match prefix {
+        LenPrefix::ShortU16 => "short_u16",
+        _ => &len_prefix_pascal(prefix).to_lowercase(),
+    };
+    (
+        format_ident!("write_{}_len", name),
+        format_ident!("read_{}_len", name),
+    )
+}
+
+/// `write_*_len()` and `read_*_len()` fns shared by the builtins with the given length prefix
+pub fn len_prefix_fns_tokens(prefix: LenPrefix) -> TokenStream {
+    let (write_fn, read_fn) = len_fn_idents(prefix);
+    if prefix == LenPrefix::ShortU16 {
+        return quote! {
+            fn #write_fn<W: std::io::Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+                let mut rem = u16::try_from(len).map_err(|_| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidInput, "length overflows shortvec prefix")
+                })?;
+                loop {
+                    let mut byte = (rem & 0x7f) as u8;
+                    rem >>= 7;
+                    if rem != 0 {
+                        byte |= 0x80;
+                    }
+                    byte.serialize(writer)?;
+                    if rem == 0 {
+                        return Ok(());
+                    }
+                }
+            }
+
+            fn #read_fn<R: std::io::Read>(reader: &mut R) -> std::io::Result<usize> {
+                let mut len = 0usize;
+                for i in 0..3 {
+                    let byte = u8::deserialize_reader(reader)?;
+                    len |= usize::from(byte & 0x7f) << (i * 7);
+                    if byte & 0x80 == 0 {
+                        if len > usize::from(u16::MAX) {
+                            break;
+                        }
+                        return Ok(len);
+                    }
+                }
+                Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid shortvec prefix"))
+            }
+        };
+    }
+    let prefix_ty = format_ident!("{}", len_prefix_pascal(prefix).to_lowercase());
+    let overflow_msg = format!("length overflows {} prefix", prefix_ty);
+    let to_usize = if prefix == LenPrefix::U64 {
+        quote! {
+            usize::try_from(len).map_err(|_| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "length overflows usize")
+            })
+        }
+    } else {
+        quote! { Ok(usize::from(len)) }
+    };
+    quote! {
+        fn #write_fn<W: std::io::Write>(len: usize, writer: &mut W) -> std::io::Result<()> {
+            #prefix_ty::try_from(len)
+                .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidInput, #overflow_msg))?
+                .serialize(writer)
+        }
+
+        fn #read_fn<R: std::io::Read>(reader: &mut R) -> std::io::Result<usize> {
+            let len = #prefix_ty::deserialize_reader(reader)?;
+            #to_usize
+        }
+    }
+}
+
+fn prefixed_vec_tokens(ident: Ident, prefix: LenPrefix) -> TokenStream {
+    let (write_fn, read_fn) = len_fn_idents(prefix);
+    let doc = format!("`Vec` with a {} length prefix", len_prefix_doc(prefix));
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        pub struct #ident<T>(pub Vec<T>);
+
+        impl<T> std::ops::Deref for #ident<T> {
+            type Target = Vec<T>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<T> std::ops::DerefMut for #ident<T> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl<T> From<Vec<T>> for #ident<T> {
+            fn from(v: Vec<T>) -> Self {
+                Self(v)
+            }
+        }
+
+        impl<T> From<#ident<T>> for Vec<T> {
+            fn from(v: #ident<T>) -> Self {
+                v.0
+            }
+        }
+
+        impl<T: BorshSerialize> BorshSerialize for #ident<T> {
+            fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                #write_fn(self.0.len(), writer)?;
+                self.0.iter().try_for_each(|v| v.serialize(writer))
+            }
+        }
+
+        impl<T: BorshDeserialize> BorshDeserialize for #ident<T> {
+            fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                let len = #read_fn(reader)?;
+                (0..len)
+                    .map(|_| T::deserialize_reader(reader))
+                    .collect::<std::io::Result<Vec<T>>>()
+                    .map(Self)
+            }
+        }
+    }
+}
+
+fn prefixed_string_tokens(ident: Ident, prefix: LenPrefix) -> TokenStream {
+    let (write_fn, read_fn) = len_fn_idents(prefix);
+    let doc = format!("`String` with a {} length prefix", len_prefix_doc(prefix));
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        pub struct #ident(pub String);
+
+        impl std::ops::Deref for #ident {
+            type Target = String;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for #ident {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl From<String> for #ident {
+            fn from(s: String) -> Self {
+                Self(s)
+            }
+        }
+
+        impl From<&str> for #ident {
+            fn from(s: &str) -> Self {
+                Self(s.to_owned())
+            }
+        }
+
+        impl From<#ident> for String {
+            fn from(s: #ident) -> Self {
+                s.0
+            }
+        }
+
+        impl BorshSerialize for #ident {
+            fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                #write_fn(self.0.len(), writer)?;
+                writer.write_all(self.0.as_bytes())
+            }
+        }
+
+        impl BorshDeserialize for #ident {
+            fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                let len = #read_fn(reader)?;
+                // dont preallocate untrusted lengths
+                let mut buf = Vec::new();
+                reader.take(len as u64).read_to_end(&mut buf)?;
+                if buf.len() != len {
+                    return Err(std::io::ErrorKind::UnexpectedEof.into());
+                }
+                String::from_utf8(buf)
+                    .map(Self)
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+            }
+        }
+    }
+}
+
+fn len_prefix_doc(prefix: LenPrefix) -> &'static str {
+    match prefix {
+        LenPrefix::U8 => "u8",
+        LenPrefix::U16 => "u16",
+        LenPrefix::U32 => "u32",
+        LenPrefix::U64 => "u64",
+        LenPrefix::ShortU16 => "compact-u16 (shortvec)",
+    }
+}
+
+fn coption_tokens() -> TokenStream {
+    quote! {
+        /// Option with a u32 tag, laid out like `solana_program::program_option::COption`.
+        ///
+        /// The value is always serialized, as `T::default()` if `None`,
+        /// so that the serialized size is fixed.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub enum COption<T> {
+            #[default]
+            None,
+            Some(T),
+        }
+
+        impl<T> COption<T> {
+            pub fn is_some(&self) -> bool {
+                matches!(self, Self::Some(_))
+            }
+
+            pub fn is_none(&self) -> bool {
+                matches!(self, Self::None)
+            }
+
+            pub fn as_option(&self) -> Option<&T> {
+                match self {
+                    Self::None => None,
+                    Self::Some(v) => Some(v),
+                }
+            }
+        }
+
+        impl<T> From<Option<T>> for COption<T> {
+            fn from(o: Option<T>) -> Self {
+                match o {
+                    None => Self::None,
+                    Some(v) => Self::Some(v),
+                }
+            }
+        }
+
+        impl<T> From<COption<T>> for Option<T> {
+            fn from(o: COption<T>) -> Self {
+                match o {
+                    COption::None => None,
+                    COption::Some(v) => Some(v),
+                }
+            }
+        }
+
+        impl<T: BorshSerialize + Default> BorshSerialize for COption<T> {
+            fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                match self {
+                    Self::None => {
+                        0u32.serialize(writer)?;
+                        T::default().serialize(writer)
+                    }
+                    Self::Some(v) => {
+                        1u32.serialize(writer)?;
+                        v.serialize(writer)
+                    }
+                }
+            }
+        }
+
+        impl<T: BorshDeserialize> BorshDeserialize for COption<T> {
+            fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                let tag = u32::deserialize_reader(reader)?;
+                let v = T::deserialize_reader(reader)?;
+                match tag {
+                    0 => Ok(Self::None),
+                    1 => Ok(Self::Some(v)),
+                    _ => Err(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("invalid COption tag {}", tag),
+                    )),
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<T: serde::Serialize> serde::Serialize for COption<T> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.as_option().serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for COption<T> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Option::<T>::deserialize(deserializer).map(Into::into)
+            }
+        }
+    }
+}
+
+fn u256_tokens() -> TokenStream {
+    quote! {
+        /// Unsigned 256-bit integer as 32 little-endian bytes
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, BorshDeserialize, BorshSerialize, Pod, Zeroable)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct U256(pub [u8; 32]);
+
+        impl U256 {
+            pub const ZERO: Self = Self([0u8; 32]);
+
+            pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+
+            pub const fn to_le_bytes(self) -> [u8; 32] {
+                self.0
+            }
+        }
+
+        impl From<u128> for U256 {
+            fn from(n: u128) -> Self {
+                let mut bytes = [0u8; 32];
+                bytes[..16].copy_from_slice(&n.to_le_bytes());
+                Self(bytes)
+            }
+        }
+
+        impl From<u64> for U256 {
+            fn from(n: u64) -> Self {
+                u128::from(n).into()
+            }
+        }
+
+        impl TryFrom<U256> for u128 {
+            type Error = IntOverflowError;
+
+            fn try_from(n: U256) -> Result<Self, Self::Error> {
+                let (lo, hi) = n.0.split_at(16);
+                if hi.iter().any(|b| *b != 0) {
+                    return Err(IntOverflowError);
+                }
+                Ok(u128::from_le_bytes(lo.try_into().unwrap()))
+            }
+        }
+    }
+}
+
+fn i256_tokens() -> TokenStream {
+    quote! {
+        /// Signed 256-bit integer as 32 little-endian two's complement bytes
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, BorshDeserialize, BorshSerialize, Pod, Zeroable)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct I256(pub [u8; 32]);
+
+        impl I256 {
+            pub const ZERO: Self = Self([0u8; 32]);
+
+            pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+
+            pub const fn to_le_bytes(self) -> [u8; 32] {
+                self.0
+            }
+
+            pub const fn is_negative(&self) -> bool {
+                self.0[31] & 0x80 != 0
+            }
+        }
+
+        impl From<i128> for I256 {
+            fn from(n: i128) -> Self {
+                let sign_ext = if n < 0 { 0xff } else { 0 };
+                let mut bytes = [sign_ext; 32];
+                bytes[..16].copy_from_slice(&n.to_le_bytes());
+                Self(bytes)
+            }
+        }
+
+        impl From<i64> for I256 {
+            fn from(n: i64) -> Self {
+                i128::from(n).into()
+            }
+        }
+
+        impl TryFrom<I256> for i128 {
+            type Error = IntOverflowError;
+
+            fn try_from(n: I256) -> Result<Self, Self::Error> {
+                let (lo, hi) = n.0.split_at(16);
+                let sign_ext = if lo[15] & 0x80 != 0 { 0xff } else { 0 };
+                if hi.iter().any(|b| *b != sign_ext) {
+                    return Err(IntOverflowError);
+                }
+                Ok(i128::from_le_bytes(lo.try_into().unwrap()))
+            }
+        }
+    }
+}
+
+pub fn int_overflow_error_tokens() -> TokenStream {
+    quote! {
+        /// A 256-bit integer did not fit in the target integer type
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct IntOverflowError;
+
+        impl std::fmt::Display for IntOverflowError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("integer overflow")
+            }
+        }
+
+        impl std::error::Error for IntOverflowError {}
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::BTreeSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::idl_format::IdlCodegenModule;
+
+use super::typedefs::{NamedType, TypedefField};
+
+mod builtin;
+pub use builtin::*;
+
+/// Support types referenced by IDL field types that have no std or solana_program equivalent.
+/// Only the builtins used by the IDL are generated.
+pub struct BuiltinsCodegenModule {
+    pub builtins: BTreeSet<Builtin>,
+}
+
+impl IdlCodegenModule for BuiltinsCodegenModule {
+    fn name(&self) -> &str {
+        "builtins"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        let mut res = quote! {
+            use borsh::{BorshDeserialize, BorshSerialize};
+        };
+        if self.builtins.iter().any(|b| b.is_int()) {
+            res.extend(quote! {
+                use bytemuck::{Pod, Zeroable};
+            });
+        }
+        if self
+            .builtins
+            .iter()
+            .any(|b| matches!(b, Builtin::PrefixedString(_)))
+        {
+            res.extend(quote! {
+                use std::io::Read;
+            });
+        }
+        res
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let mut res: TokenStream = self.builtins.iter().map(|b| b.to_token_stream()).collect();
+        if self.builtins.iter().any(|b| b.is_int()) {
+            res.extend(int_overflow_error_tokens());
+        }
+        let len_prefixes: BTreeSet<_> = self
+            .builtins
+            .iter()
+            .filter_map(|b| b.len_prefix())
+            .collect();
+        res.extend(len_prefixes.into_iter().map(len_prefix_fns_tokens));
+        res
+    }
+}
+
+/// Builtins used by `named_types` and by standalone `fields`, e.g. instruction args
+pub fn used_builtins<'a>(
+    named_types: impl IntoIterator<Item = &'a NamedType>,
+    fields: impl IntoIterator<Item = &'a TypedefField>,
+) -> BTreeSet<Builtin> {
+    let mut res = BTreeSet::new();
+    for t in named_types {
+        res.extend(t.r#type.builtins());
+    }
+    for f in fields {
+        f.r#type.collect_builtins(&mut res);
+    }
+    res
+}
@@ -0,0 +1,436 @@
+use heck::ToShoutySnakeCase;
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use serde::Deserialize;
+use syn::{LitByteStr, LitInt, LitStr};
+
+use crate::{
+    idl_format::anchor::typedefs::{TypedefFieldArray, TypedefFieldType},
+    utils::{docs_tokens, string_or_struct},
+};
+
+const INT_TYPES: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// An entry of the IDL's `constants` section.
+///
+/// `value` is the stringified rust expression the constant was declared with,
+/// e.g. `"1_000_000"`, `"b\"vault\""`, `"[118, 97, 117, 108, 116]"` or `"\"vault\""`
+#[derive(Deserialize)]
+pub struct Constant {
+    pub name: String,
+    #[serde(deserialize_with = "string_or_struct")]
+    pub r#type: TypedefFieldType,
+    pub value: String,
+    pub docs: Option<Vec<String>>,
+}
+
+impl Constant {
+    /// Returns the (type, value expression) tokens of this constant,
+    /// or None if `value` could not be parsed as the declared type.
+    /// `consts` are the other constants that are generated, which `value` may refer to.
+    pub fn type_and_value_tokens(
+        &self,
+        consts: &[&Constant],
+    ) -> Option<(TokenStream, TokenStream)> {
+        const_type_and_value(&self.r#type, self.value.trim(), consts)
+    }
+
+    pub fn is_pubkey(&self) -> bool {
+        primitive_name(&self.r#type).is_some_and(is_pubkey)
+    }
+
+    /// `pub const` item, or None if `value` could not be parsed as the declared type.
+    /// See [`Self::type_and_value_tokens`].
+    pub fn to_const_tokens(&self, consts: &[&Constant]) -> Option<TokenStream> {
+        let (ty, value) = self.type_and_value_tokens(consts)?;
+        let docs = docs_tokens(self.docs.as_deref());
+        let name = format_ident!("{}", self.name.to_shouty_snake_case());
+        Some(quote! {
+            #docs
+            pub const #name: #ty = #value;
+        })
+    }
+}
+
+/// Legacy anchor IDLs declare `usize` constants as `{ "defined": "usize" }`
+fn primitive_name(ty: &TypedefFieldType) -> Option<&str> {
+    match ty {
+        TypedefFieldType::PrimitiveOrPubkey(s) => Some(s),
+        TypedefFieldType::defined(d) if d.generics().is_empty() => Some(d.name()),
+        _ => None,
+    }
+}
+
+fn is_pubkey(s: &str) -> bool {
+    matches!(s, "pubkey" | "publicKey")
+}
+
+fn const_type_and_value(
+    ty: &TypedefFieldType,
+    value: &str,
+    consts: &[&Constant],
+) -> Option<(TokenStream, TokenStream)> {
+    match ty {
+        TypedefFieldType::array(TypedefFieldArray(elem, len)) => {
+            let len = len.value()?;
+            let elem_ty = primitive_name(elem)?;
+            let elems = list_elems(elem_ty, value)?;
+            if elems.len() != len as usize {
+                return None;
+            }
+            let elem_ty = elem_type_tokens(elem_ty)?;
+            let len = LitInt::new(&len.to_string(), Span::call_site());
+            Some((quote! { [#elem_ty; #len] }, quote! { [#(#elems),*] }))
+        }
+        TypedefFieldType::vec(elem) => {
+            let elem_ty = primitive_name(elem)?;
+            let elems = list_elems(elem_ty, value)?;
+            let elem_ty = elem_type_tokens(elem_ty)?;
+            Some((quote! { &[#elem_ty] }, quote! { &[#(#elems),*] }))
+        }
+        TypedefFieldType::option(_)
+        | TypedefFieldType::generic(_)
+        | TypedefFieldType::tuple(_)
+        | TypedefFieldType::hashMap(_)
+        | TypedefFieldType::bTreeMap(_)
+        | TypedefFieldType::hashSet(_)
+        | TypedefFieldType::bTreeSet(_)
+        | TypedefFieldType::coption(_)
+        | TypedefFieldType::sizePrefixed(_) => None,
+        TypedefFieldType::PrimitiveOrPubkey(_) | TypedefFieldType::defined(_) => {
+            match primitive_name(ty)? {
+                "bytes" => {
+                    if let Ok(lit) = syn::parse_str::<LitByteStr>(value) {
+                        return Some((quote! { &[u8] }, quote! { #lit }));
+                    }
+                    let elems = list_elems("u8", value)?;
+                    Some((quote! { &[u8] }, quote! { &[#(#elems),*] }))
+                }
+                "string" => {
+                    let s = syn::parse_str::<LitStr>(value)
+                        .map(|lit| lit.value())
+                        .unwrap_or_else(|_| value.to_owned());
+                    Some((quote! { &str }, quote! { #s }))
+                }
+                s if is_pubkey(s) => {
+                    let pubkey = pubkey_base58(value)?;
+                    Some((
+                        quote! { Pubkey },
+                        quote! { solana_program::pubkey!(#pubkey) },
+                    ))
+                }
+                s => {
+                    let elem_ty = elem_type_tokens(s)?;
+                    let value = scalar_value(s, value).or_else(|| {
+                        let expr = syn::parse_str::<syn::Expr>(value).ok()?;
+                        scalar_expr(s, &expr, consts)
+                    })?;
+                    Some((elem_ty, value))
+                }
+            }
+        }
+    }
+}
+
+fn elem_type_tokens(ty: &str) -> Option<TokenStream> {
+    if INT_TYPES.contains(&ty) || matches!(ty, "bool" | "f32" | "f64") {
+        ty.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses an integer, float or bool literal,
+/// allowing `_` separators, type suffixes and hex integers
+fn scalar_value(ty: &str, value: &str) -> Option<TokenStream> {
+    match ty {
+        "bool" => match value {
+            "true" => Some(quote! { true }),
+            "false" => Some(quote! { false }),
+            _ => None,
+        },
+        "f32" => {
+            let v: f32 = strip_suffix(value, ty).replace('_', "").parse().ok()?;
+            Some(Literal::f32_unsuffixed(v).into_token_stream())
+        }
+        "f64" => {
+            let v: f64 = strip_suffix(value, ty).replace('_', "").parse().ok()?;
+            Some(Literal::f64_unsuffixed(v).into_token_stream())
+        }
+        _ => {
+            let decimal = int_decimal_str(strip_suffix(value, ty))?;
+            let in_range = match ty {
+                "u8" => decimal.parse::<u8>().is_ok(),
+                "u16" => decimal.parse::<u16>().is_ok(),
+                "u32" => decimal.parse::<u32>().is_ok(),
+                "u64" => decimal.parse::<u64>().is_ok(),
+                "u128" => decimal.parse::<u128>().is_ok(),
+                "usize" => decimal.parse::<usize>().is_ok(),
+                "i8" => decimal.parse::<i8>().is_ok(),
+                "i16" => decimal.parse::<i16>().is_ok(),
+                "i32" => decimal.parse::<i32>().is_ok(),
+                "i64" => decimal.parse::<i64>().is_ok(),
+                "i128" => decimal.parse::<i128>().is_ok(),
+                "isize" => decimal.parse::<isize>().is_ok(),
+                _ => false,
+            };
+            if !in_range {
+                return None;
+            }
+            Some(decimal.parse().ok()?)
+        }
+    }
+}
+
+/// Checks and re-emits an expression such as `60 * 60 * 24` or `MAX_LEN + 1`.
+/// Only unary, binary and paren expressions whose leaves are literals of `ty`
+/// or paths naming another constant of `ty` in `consts` are accepted,
+/// so that the generated constant always compiles.
+fn scalar_expr(ty: &str, expr: &syn::Expr, consts: &[&Constant]) -> Option<TokenStream> {
+    use syn::{BinOp, Expr, UnOp};
+
+    let is_int = INT_TYPES.contains(&ty);
+    let is_float = matches!(ty, "f32" | "f64");
+    match expr {
+        Expr::Lit(lit) => scalar_value(ty, &lit.lit.to_token_stream().to_string()),
+        Expr::Path(p) if p.qself.is_none() => {
+            let ident = p.path.get_ident()?.to_string();
+            let c = consts
+                .iter()
+                .find(|c| c.name == ident && primitive_name(&c.r#type) == Some(ty))?;
+            let ident = format_ident!("{}", c.name.to_shouty_snake_case());
+            Some(quote! { #ident })
+        }
+        Expr::Paren(p) => {
+            let inner = scalar_expr(ty, &p.expr, consts)?;
+            Some(quote! { (#inner) })
+        }
+        Expr::Unary(u) => {
+            let is_allowed = match u.op {
+                UnOp::Neg(_) => is_float || ty.starts_with('i'),
+                UnOp::Not(_) => is_int || ty == "bool",
+                _ => false,
+            };
+            if !is_allowed {
+                return None;
+            }
+            let op = u.op;
+            let inner = scalar_expr(ty, &u.expr, consts)?;
+            Some(quote! { #op #inner })
+        }
+        Expr::Binary(b) => {
+            let is_arithmetic = matches!(
+                b.op,
+                BinOp::Add(_) | BinOp::Sub(_) | BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_)
+            );
+            let is_bitwise = matches!(b.op, BinOp::BitXor(_) | BinOp::BitAnd(_) | BinOp::BitOr(_));
+            let is_allowed = match ty {
+                "bool" => {
+                    is_bitwise
+                        || matches!(
+                            b.op,
+                            BinOp::And(_) | BinOp::Or(_) | BinOp::Eq(_) | BinOp::Ne(_)
+                        )
+                }
+                _ if is_float => is_arithmetic,
+                _ => is_arithmetic || is_bitwise || matches!(b.op, BinOp::Shl(_) | BinOp::Shr(_)),
+            };
+            if !is_allowed {
+                return None;
+            }
+            let op = b.op;
+            let left = scalar_expr(ty, &b.left, consts)?;
+            let right = scalar_expr(ty, &b.right, consts)?;
+            Some(quote! { #left #op #right })
+        }
+        _ => None,
+    }
+}
+
+fn strip_suffix<'a>(value: &'a str, ty: &str) -> &'a str {
+    value
+        .strip_suffix(ty)
+        .unwrap_or(value)
+        .trim_end_matches('_')
+}
+
+/// Normalizes an integer literal to decimal, keeping the sign
+fn int_decimal_str(value: &str) -> Option<String> {
+    let value = value.replace('_', "");
+    let (neg, abs) = match value.strip_prefix('-') {
+        Some(abs) => (true, abs),
+        None => (false, value.as_str()),
+    };
+    let abs = match abs.strip_prefix("0x").or_else(|| abs.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16).ok()?,
+        None => abs.parse::<u128>().ok()?,
+    };
+    Some(if neg {
+        format!("-{abs}")
+    } else {
+        abs.to_string()
+    })
+}
+
+/// Parses `[a, b, c]`, `[a; n]` or, for `u8` elements, `b"..."`
+fn list_elems(elem_ty: &str, value: &str) -> Option<Vec<TokenStream>> {
+    if elem_ty == "u8" {
+        if let Ok(lit) = syn::parse_str::<LitByteStr>(value) {
+            return Some(
+                lit.value()
+                    .into_iter()
+                    .map(|b| Literal::u8_unsuffixed(b).into_token_stream())
+                    .collect(),
+            );
+        }
+    }
+    let inner = value.strip_prefix('[')?.strip_suffix(']')?.trim();
+    if let Some((elem, n)) = inner.split_once(';') {
+        let elem = scalar_value(elem_ty, elem.trim())?;
+        let n: usize = int_decimal_str(strip_suffix(n.trim(), "usize"))?
+            .parse()
+            .ok()?;
+        return Some(vec![elem; n]);
+    }
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| scalar_value(elem_ty, s))
+        .collect()
+}
+
+/// Accepts a bare base58 string or one wrapped in e.g. `pubkey!("...")`
+fn pubkey_base58(value: &str) -> Option<String> {
+    let s = match value.split_once('"') {
+        Some((_, rest)) => rest.split_once('"')?.0,
+        None => value,
+    };
+    let bytes = bs58::decode(s).into_vec().ok()?;
+    (bytes.len() == 32).then(|| s.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant_tokens(json: &str) -> Option<String> {
+        let c: Constant = serde_json::from_str(json).unwrap();
+        c.to_const_tokens(&[]).map(|t| t.to_string())
+    }
+
+    #[test]
+    fn test_int_constants() {
+        assert_eq!(
+            constant_tokens(r#"{ "name": "maxSize", "type": "u64", "value": "1_000_000" }"#)
+                .unwrap(),
+            quote! { pub const MAX_SIZE: u64 = 1000000; }.to_string()
+        );
+        assert_eq!(
+            constant_tokens(
+                r#"{ "name": "N", "type": { "defined": "usize" }, "value": "0xffusize" }"#
+            )
+            .unwrap(),
+            quote! { pub const N: usize = 255; }.to_string()
+        );
+        assert!(constant_tokens(r#"{ "name": "X", "type": "u8", "value": "256" }"#).is_none());
+        assert!(constant_tokens(r#"{ "name": "X", "type": "u8", "value": "-1" }"#).is_none());
+    }
+
+    #[test]
+    fn test_expr_constants() {
+        assert_eq!(
+            constant_tokens(
+                r#"{ "name": "LOCK_DURATION", "type": "i64", "value": "60 * 60 * 24" }"#
+            )
+            .unwrap(),
+            quote! { pub const LOCK_DURATION: i64 = 60 * 60 * 24; }.to_string()
+        );
+        assert!(constant_tokens(r#"{ "name": "X", "type": "u64", "value": "10 *" }"#).is_none());
+        assert!(constant_tokens(r#"{ "name": "X", "type": "u8", "value": "-(1)" }"#).is_none());
+    }
+
+    #[test]
+    fn test_expr_constants_skip_unknown_idents_and_calls() {
+        assert!(constant_tokens(
+            r#"{ "name": "STAKE", "type": "u64", "value": "10 * LAMPORTS_PER_SOL" }"#
+        )
+        .is_none());
+        assert!(constant_tokens(
+            r#"{ "name": "LEN", "type": "usize", "value": "8 + std::mem::size_of::<Pool>()" }"#
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_expr_constants_referring_to_constants() {
+        let consts: Vec<Constant> = serde_json::from_str(
+            r#"[
+                { "name": "maxLen", "type": "u64", "value": "32" },
+                { "name": "ratio", "type": "f64", "value": "0.5" }
+            ]"#,
+        )
+        .unwrap();
+        let consts: Vec<&Constant> = consts.iter().collect();
+        let c: Constant = serde_json::from_str(
+            r#"{ "name": "LEN", "type": "u64", "value": "(maxLen + 1) * 2" }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            c.to_const_tokens(&consts).unwrap().to_string(),
+            quote! { pub const LEN: u64 = (MAX_LEN + 1) * 2; }.to_string()
+        );
+        // different type
+        let c: Constant =
+            serde_json::from_str(r#"{ "name": "X", "type": "u64", "value": "ratio * 2" }"#)
+                .unwrap();
+        assert!(c.to_const_tokens(&consts).is_none());
+    }
+
+    #[test]
+    fn test_seed_constants() {
+        let expected = quote! { pub const VAULT_SEED: &[u8] = b"vault"; }.to_string();
+        assert_eq!(
+            constant_tokens(r#"{ "name": "VAULT_SEED", "type": "bytes", "value": "b\"vault\"" }"#)
+                .unwrap(),
+            expected
+        );
+        assert_eq!(
+            constant_tokens(
+                r#"{ "name": "VAULT_SEED", "type": "bytes", "value": "[118, 97, 117, 108, 116]" }"#
+            )
+            .unwrap(),
+            quote! { pub const VAULT_SEED: &[u8] = &[118, 97, 117, 108, 116]; }.to_string()
+        );
+        assert_eq!(
+            constant_tokens(r#"{ "name": "SEED", "type": "string", "value": "\"vault\"" }"#)
+                .unwrap(),
+            quote! { pub const SEED: &str = "vault"; }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_array_and_pubkey_constants() {
+        assert_eq!(
+            constant_tokens(
+                r#"{ "name": "A", "type": { "array": ["u16", 3] }, "value": "[0; 3]" }"#
+            )
+            .unwrap(),
+            quote! { pub const A: [u16; 3] = [0, 0, 0]; }.to_string()
+        );
+        assert!(constant_tokens(
+            r#"{ "name": "A", "type": { "array": ["u8", 2] }, "value": "[1]" }"#
+        )
+        .is_none());
+        assert_eq!(
+            constant_tokens(
+                r#"{ "name": "ADMIN", "type": "pubkey", "value": "pubkey!(\"11111111111111111111111111111111\")" }"#
+            )
+            .unwrap(),
+            quote! { pub const ADMIN: Pubkey = solana_program::pubkey!("11111111111111111111111111111111"); }
+                .to_string()
+        );
+    }
+}
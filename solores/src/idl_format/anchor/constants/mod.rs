@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::idl_format::IdlCodegenModule;
+
+mod constant;
+pub use constant::*;
+
+pub struct ConstantsCodegenModule<'a> {
+    pub constants: &'a [Constant],
+}
+
+impl IdlCodegenModule for ConstantsCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "constants"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        let has_pubkey = self
+            .generated_constants()
+            .iter()
+            .any(|(c, _)| c.is_pubkey());
+        if has_pubkey {
+            quote! {
+                use solana_program::pubkey::Pubkey;
+            }
+        } else {
+            quote! {}
+        }
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let generated = self.generated_constants();
+        for c in self.constants {
+            if !generated.iter().any(|(g, _)| g.name == c.name) {
+                log::warn!(
+                    "Skipping constant {}: could not parse value {:?} for its declared type",
+                    c.name,
+                    c.value
+                );
+            }
+        }
+        generated.into_iter().map(|(_, tokens)| tokens).collect()
+    }
+}
+
+impl ConstantsCodegenModule<'_> {
+    /// The constants whose values parse, in IDL order.
+    /// Repeated until nothing changes, since values may refer to other constants.
+    fn generated_constants(&self) -> Vec<(&Constant, TokenStream)> {
+        let mut generated: Vec<&Constant> = Vec::new();
+        loop {
+            let newly: Vec<&Constant> = self
+                .constants
+                .iter()
+                .filter(|c| !generated.iter().any(|g| g.name == c.name))
+                .filter(|c| c.to_const_tokens(&generated).is_some())
+                .collect();
+            if newly.is_empty() {
+                break;
+            }
+            generated.extend(newly);
+        }
+        self.constants
+            .iter()
+            .filter_map(|c| {
+                let others: Vec<&Constant> = generated
+                    .iter()
+                    .copied()
+                    .filter(|g| g.name != c.name)
+                    .collect();
+                generated
+                    .iter()
+                    .any(|g| g.name == c.name)
+                    .then(|| c.to_const_tokens(&others))
+                    .flatten()
+                    .map(|tokens| (c, tokens))
+            })
+            .collect()
+    }
+}
@@ -6,9 +6,9 @@ use syn::LitInt;
 
 #[derive(Deserialize)]
 pub struct ErrorEnumVariant {
-    code: u32,
-    name: String,
-    msg: Option<String>,
+    pub code: u32,
+    pub name: String,
+    pub msg: Option<String>,
 }
 
 impl ToTokens for ErrorEnumVariant {
@@ -1,47 +1,70 @@
-use heck::{ToPascalCase, ToShoutySnakeCase};
-use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote, ToTokens};
+use heck::ToShoutySnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
 use serde::Deserialize;
-use sha2::{Digest, Sha256};
-
-use crate::idl_format::anchor::typedefs::TypedefField;
-
-#[derive(Deserialize)]
-pub struct Event(pub EventType);
-
+use syn::LitInt;
+
+use crate::{
+    idl_format::anchor::{discm_or_sighash, typedefs::NamedType},
+    utils::conditional_pascal_case,
+};
+
+/// An anchor event.
+///
+/// The event's layout is the typedef of the same name in the IDL's `types`,
+/// which is generated in the typedefs module. Legacy IDLs' inline event layouts
+/// are moved into `types` on conversion.
 #[derive(Deserialize)]
-pub struct EventType {
+pub struct Event {
     pub name: String,
-    // NB: theres also an `index` field that's ignored for now since we dk what it does:
-    // https://github.com/coral-xyz/anchor/blob/8f30f00ec363b7e82aa0b3c7041e912919b33cf5/lang/attribute/event/src/lib.rs#L62C1-L64
-    pub fields: Vec<TypedefField>,
+    pub discriminator: Option<Vec<u8>>,
 }
 
-impl EventType {
+impl Event {
     pub fn struct_ident(&self) -> Ident {
-        format_ident!("{}", self.name.to_pascal_case())
+        format_ident!("{}", conditional_pascal_case(&self.name))
+    }
+
+    pub fn event_ident(&self) -> Ident {
+        format_ident!("{}Event", self.struct_ident())
+    }
+
+    pub fn discm_ident(&self) -> Ident {
+        format_ident!("{}_EVENT_DISCM", self.name.to_shouty_snake_case())
     }
-}
 
-impl ToTokens for Event {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        // discriminant
-        let event_discm_ident = format_ident!("{}_EVENT_DISCM", self.0.name.to_shouty_snake_case());
-        // pre-image: "event:{EventName}"
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("event:{}", self.0.name).as_bytes()).as_slice()[..8],
+    /// pre-image: "event:{EventName}"
+    pub fn discm(&self) -> Vec<u8> {
+        discm_or_sighash(
+            self.discriminator.as_deref(),
+            &format!("event:{}", self.name),
         )
-        .unwrap();
-        let discm_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
+    }
 
-        let struct_def = &self.0;
+    /// Returns the typedef in `types` that defines this event's layout
+    pub fn resolve_type<'a>(&self, named_types: &'a [NamedType]) -> &'a NamedType {
+        named_types
+            .iter()
+            .find(|t| t.name == self.name)
+            .unwrap_or_else(|| {
+                log::error!("Could not find layout for event {} in types", self.name);
+                panic!();
+            })
+    }
+
+    pub fn to_token_stream(&self, named_types: &[NamedType]) -> TokenStream {
+        // fail early if layout is missing
+        self.resolve_type(named_types);
+        let struct_ident = self.struct_ident();
 
-        let struct_ident = self.0.struct_ident();
-        let event_ident = format_ident!("{}Event", struct_ident);
-        tokens.extend(quote! {
-            pub const #event_discm_ident: [u8; 8] = #discm_tokens;
+        let event_discm_ident = self.discm_ident();
+        let discm = self.discm();
+        let discm_len = LitInt::new(&discm.len().to_string(), Span::call_site());
+        let discm_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
 
-            #struct_def
+        let event_ident = self.event_ident();
+        quote! {
+            pub const #event_discm_ident: [u8; #discm_len] = #discm_tokens;
 
             #[derive(Clone, Debug, PartialEq)]
             pub struct #event_ident(pub #struct_ident);
@@ -55,72 +78,39 @@ impl ToTokens for Event {
 
             impl #event_ident {
                 pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
-                    let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+                    let maybe_discm = <[u8; #discm_len]>::deserialize(buf)?;
                     if maybe_discm != #event_discm_ident {
                         return Err(
-                            std::io::Error::new(
-                                std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #event_discm_ident, maybe_discm)
+                            std::io::Error::other(
+                                format!("discm does not match. Expected: {:?}. Received: {:?}", #event_discm_ident, maybe_discm)
                             )
                         );
                     }
                     Ok(Self(#struct_ident::deserialize(buf)?))
                 }
             }
-        });
-    }
-}
-
-impl ToTokens for EventType {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let struct_ident = self.struct_ident();
-        let struct_fields = &self.fields;
-        tokens.extend(quote! {
-            #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
-            pub struct #struct_ident {
-                #(pub #struct_fields),*
-            }
-        });
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::idl_format::anchor::typedefs::TypedefFieldType;
 
     #[test]
-    fn test_event_type_to_tokens_with_pub_fields() {
-        // Define some fields for the EventType struct.
-        let field1 = TypedefField {
-            name: "field1".to_string(),
-            r#type: TypedefFieldType::PrimitiveOrPubkey("u32".into()),
-            docs: None,
-        };
-
-        let field2 = TypedefField {
-            name: "field2".to_string(),
-            r#type: TypedefFieldType::PrimitiveOrPubkey("String".into()),
-            docs: None,
-        };
-
-        // Create an EventType with the fields.
-        let event_type = EventType {
+    fn test_event_wraps_typedef() {
+        let named_types: Vec<NamedType> = serde_json::from_str(
+            r#"[{ "name": "TestEvent", "type": { "kind": "struct", "fields": [{ "name": "field1", "type": "u32" }] } }]"#,
+        )
+        .unwrap();
+        let event = Event {
             name: "TestEvent".to_string(),
-            fields: vec![field1, field2],
+            discriminator: Some(vec![1, 2]),
         };
-
-        // Generate the tokens.
-        let mut tokens = proc_macro2::TokenStream::new();
-        event_type.to_tokens(&mut tokens);
-
-        // Convert the tokens to a string for comparison.
-        let generated_code = tokens.to_string();
-
-        // Check that the generated code includes "pub" for each field.
-        assert!(generated_code.contains("pub field1 : u32"));
-        assert!(generated_code.contains("pub field2 : String"));
-
-        // Check that the struct name is correct.
-        assert!(generated_code.contains("pub struct TestEvent"));
+        let generated_code = event.to_token_stream(&named_types).to_string();
+        assert!(generated_code.contains("pub const TEST_EVENT_EVENT_DISCM : [u8 ; 2] = [1 , 2]"));
+        assert!(generated_code.contains("pub struct TestEventEvent (pub TestEvent)"));
+        // the struct itself is generated in typedefs
+        assert!(!generated_code.contains("pub struct TestEvent {"));
     }
 }
@@ -1,12 +1,22 @@
+use heck::ToPascalCase;
+use itertools::Itertools;
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::idl_format::{anchor::typedefs::NamedType, IdlCodegenModule};
 
 mod event;
 pub use event::*;
 
-pub struct EventsCodegenModule<'a>(pub &'a [Event]);
+/// anchor's `EVENT_IX_TAG`, the u64 `0x1d9acb512ea545e4`
+/// (first 8 bytes of sha256("anchor:event") read big-endian), in little-endian bytes
+pub const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+pub struct EventsCodegenModule<'a> {
+    pub program_name: &'a str,
+    pub events: &'a [Event],
+    pub named_types: &'a [NamedType],
+}
 
 impl IdlCodegenModule for EventsCodegenModule<'_> {
     fn name(&self) -> &str {
@@ -14,34 +24,161 @@ impl IdlCodegenModule for EventsCodegenModule<'_> {
     }
 
     fn gen_head(&self) -> TokenStream {
-        let mut res = quote! {
+        // event structs are defined in typedefs
+        quote! {
             use borsh::{BorshDeserialize, BorshSerialize};
-        };
-        let mut has_pubkey = false;
-        let mut has_defined = false;
-        for a in self.0 {
-            for field in &a.0.fields {
-                if field.r#type.is_or_has_pubkey() && !has_pubkey {
-                    has_pubkey = true;
-                    res.extend(quote! {
-                        use solana_program::pubkey::Pubkey;
-                    });
+            use crate::*;
+        }
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        let mut res: TokenStream = self
+            .events
+            .iter()
+            .map(|e| e.to_token_stream(self.named_types))
+            .collect();
+
+        // program event enum
+        let program_event_enum_ident = format_ident!("{}Event", self.program_name.to_pascal_case());
+        let program_event_enum_variants = self.events.iter().map(|e| {
+            let variant_ident = e.struct_ident();
+            let event_ident = e.event_ident();
+            quote! { #variant_ident(#event_ident) }
+        });
+        // longest first in case a discm is a prefix of another
+        let deserialize_variant_if_arms = self
+            .events
+            .iter()
+            .sorted_by_key(|e| std::cmp::Reverse(e.discm().len()))
+            .map(|e| {
+                let variant_ident = e.struct_ident();
+                let event_ident = e.event_ident();
+                let discm_ident = e.discm_ident();
+                quote! {
+                    if buf.starts_with(&#discm_ident) {
+                        return Ok(Self::#variant_ident(#event_ident::deserialize(&mut reader)?));
+                    }
                 }
-                if field.r#type.is_or_has_defined() && !has_defined {
-                    has_defined = true;
-                    res.extend(quote! {
-                        use crate::*;
-                    })
+            });
+        let max_discm_len = self
+            .events
+            .iter()
+            .map(|e| e.discm().len())
+            .max()
+            .unwrap_or_default();
+        let event_ix_tag: TokenStream = format!("{:?}", EVENT_IX_TAG).parse().unwrap();
+        let discm_idents = self.events.iter().map(|e| e.discm_ident());
+        let serialize_variant_match_arms = self.events.iter().map(|e| {
+            let variant_ident = e.struct_ident();
+            quote! { Self::#variant_ident(e) => e.serialize(writer) }
+        });
+        res.extend(quote! {
+            // event sizes can vary widely, boxing them would make matching on variants clunkier
+            #[allow(clippy::large_enum_variant)]
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum #program_event_enum_ident {
+                #(#program_event_enum_variants),*
+            }
+
+            impl #program_event_enum_ident {
+                /// Decodes any of the program's events from its discriminator-prefixed bytes
+                pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+                    let mut reader = buf;
+                    #(#deserialize_variant_if_arms)*
+                    Err(
+                        std::io::Error::other(
+                            format!("event discm {:?} not found", &buf[..buf.len().min(#max_discm_len)])
+                        )
+                    )
                 }
             }
-            if has_defined && has_pubkey {
-                break;
+
+            /// Prefix of the self-CPI instruction data anchor's `emit_cpi!` emits events with
+            pub const EVENT_IX_TAG: [u8; 8] = #event_ix_tag;
+
+            impl #program_event_enum_ident {
+                fn has_known_discm(buf: &[u8]) -> bool {
+                    #(buf.starts_with(&#discm_idents))||*
+                }
+
+                /// Decodes the events emitted by this program with `emit!` from a transaction's log messages.
+                ///
+                /// Only `Program data:` lines logged while this program is the innermost invoked program are considered.
+                /// Data that does not start with any of the program's event discms is skipped.
+                pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> std::io::Result<Vec<Self>> {
+                    use base64::Engine;
+
+                    let program_id = crate::ID.to_string();
+                    let mut invoke_stack: Vec<&str> = Vec::new();
+                    let mut res = Vec::new();
+                    for log in logs {
+                        let log = log.as_ref();
+                        if let Some(data) = log.strip_prefix("Program data: ") {
+                            if invoke_stack.last() != Some(&program_id.as_str()) {
+                                continue;
+                            }
+                            // sol_log_data() logs each slice as a separate space-delimited field,
+                            // anchor events are a single slice
+                            let data = data.split(' ').next().unwrap_or_default();
+                            let buf = base64::engine::general_purpose::STANDARD
+                                .decode(data)
+                                .map_err(std::io::Error::other)?;
+                            if Self::has_known_discm(&buf) {
+                                res.push(Self::deserialize(&buf)?);
+                            }
+                        } else if let Some(rest) = log.strip_prefix("Program ") {
+                            let mut words = rest.split(' ');
+                            let (Some(id), Some(status)) = (words.next(), words.next()) else {
+                                continue;
+                            };
+                            if status == "invoke" {
+                                invoke_stack.push(id);
+                            } else if status == "success" || status.starts_with("failed") {
+                                invoke_stack.pop();
+                            }
+                        }
+                    }
+                    Ok(res)
+                }
+
+                /// Decodes an event emitted by this program with `emit_cpi!` from the data of
+                /// the self-CPI inner instruction, which is [`EVENT_IX_TAG`] followed by the event's bytes.
+                ///
+                /// Callers should check that the inner instruction's program ID is this program's.
+                pub fn from_cpi_ix_data(data: &[u8]) -> std::io::Result<Self> {
+                    let buf = data.strip_prefix(&EVENT_IX_TAG).ok_or_else(|| {
+                        std::io::Error::other(format!(
+                            "event ix tag does not match. Expected: {:?}. Received: {:?}",
+                            EVENT_IX_TAG,
+                            &data[..data.len().min(EVENT_IX_TAG.len())]
+                        ))
+                    })?;
+                    Self::deserialize(buf)
+                }
             }
-        }
+
+            impl BorshSerialize for #program_event_enum_ident {
+                fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                    match self {
+                        #(#serialize_variant_match_arms),*
+                    }
+                }
+            }
+        });
         res
     }
+}
 
-    fn gen_body(&self) -> TokenStream {
-        self.0.iter().map(|e| e.into_token_stream()).collect()
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+
+    #[test]
+    fn test_event_ix_tag() {
+        let mut expected = Sha256::digest(b"anchor:event")[..8].to_vec();
+        expected.reverse();
+        assert_eq!(EVENT_IX_TAG.as_slice(), expected);
     }
 }
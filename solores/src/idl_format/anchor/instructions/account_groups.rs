@@ -0,0 +1,267 @@
+use heck::{ToPascalCase, ToSnakeCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use super::{
+    accounts_struct_fields, keys_struct_fields, InnerAccountStruct, IxAccount, IxAccountEntry,
+    NamedInstruction,
+};
+
+/// Field of a composite account group in nested `*Keys`/`*Accounts` structs
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountGroupField {
+    pub field: String,
+    /// Name of the group's structs without the `Keys`/`Accounts` suffix
+    pub struct_name: String,
+}
+
+/// Path of an account's field in nested `*Keys`/`*Accounts` structs
+#[derive(Clone, Debug, PartialEq)]
+pub struct NestedAccountPath {
+    /// Enclosing groups, outermost first
+    pub groups: Vec<AccountGroupField>,
+    pub field: String,
+}
+
+/// A composite account group generated as `{struct_name}Keys` and `{struct_name}Accounts`
+pub struct AccountGroup {
+    pub struct_name: String,
+    /// Flattened accounts of the group, with paths relative to the group
+    pub accounts: Vec<IxAccount>,
+    shape: String,
+}
+
+/// Composite account groups of all instructions, deduplicated by their accounts.
+///
+/// Groups are named after their field. Groups of the same name but with different accounts
+/// in different instructions are prefixed with the instruction name.
+pub struct AccountGroups {
+    pub groups: Vec<AccountGroup>,
+}
+
+impl AccountGroups {
+    pub fn new(instructions: &[NamedInstruction]) -> Self {
+        let mut res = Self { groups: Vec::new() };
+        for ix in instructions {
+            res.add_groups(&ix.name, ix.accounts.as_deref().unwrap_or_default());
+        }
+        res
+    }
+
+    /// Inner groups are added before the groups containing them
+    fn add_groups(&mut self, ix_name: &str, entries: &[IxAccountEntry]) {
+        for entry in entries {
+            let s = match entry {
+                IxAccountEntry::Account(_) => continue,
+                IxAccountEntry::Struct(s) => s,
+            };
+            self.add_groups(ix_name, &s.accounts);
+            let shape = group_shape(&s.accounts);
+            if self.groups.iter().any(|g| g.shape == shape) {
+                continue;
+            }
+            let mut struct_name = s.name.to_pascal_case();
+            if self.has_struct_name(&struct_name) {
+                struct_name = format!("{}{}", ix_name.to_pascal_case(), struct_name);
+            }
+            let base_len = struct_name.len();
+            let mut n = 2;
+            while self.has_struct_name(&struct_name) {
+                struct_name.truncate(base_len);
+                struct_name.push_str(&n.to_string());
+                n += 1;
+            }
+            let accounts = self.to_nested_ix_accounts(&s.accounts);
+            self.groups.push(AccountGroup {
+                struct_name,
+                accounts,
+                shape,
+            });
+        }
+    }
+
+    fn has_struct_name(&self, struct_name: &str) -> bool {
+        self.groups.iter().any(|g| g.struct_name == struct_name)
+    }
+
+    fn struct_name(&self, s: &InnerAccountStruct) -> &str {
+        let shape = group_shape(&s.accounts);
+        &self
+            .groups
+            .iter()
+            .find(|g| g.shape == shape)
+            .unwrap()
+            .struct_name
+    }
+
+    /// Same as [`super::to_ix_accounts`], but with each grouped account's [`NestedAccountPath`]
+    pub fn to_nested_ix_accounts(&self, entries: &[IxAccountEntry]) -> Vec<IxAccount> {
+        entries.iter().fold(Vec::new(), |mut vec, entry| {
+            match entry {
+                IxAccountEntry::Account(a) => vec.push(a.clone()),
+                IxAccountEntry::Struct(s) => {
+                    let group = AccountGroupField {
+                        field: s.name.to_snake_case(),
+                        struct_name: self.struct_name(s).to_owned(),
+                    };
+                    vec.extend(self.to_nested_ix_accounts(&s.accounts).into_iter().map(
+                        |mut acc| {
+                            let mut path = acc.nested.take().unwrap_or_else(|| {
+                                Box::new(NestedAccountPath {
+                                    groups: Vec::new(),
+                                    field: acc.name.to_snake_case(),
+                                })
+                            });
+                            path.groups.insert(0, group.clone());
+                            acc.name = format!("{}_{}", s.name, acc.name.to_snake_case());
+                            acc.nested = Some(path);
+                            acc
+                        },
+                    ))
+                }
+            };
+            vec
+        })
+    }
+}
+
+impl ToTokens for AccountGroups {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for group in self.groups.iter() {
+            let keys_ident = format_ident!("{}Keys", group.struct_name);
+            let accounts_ident = format_ident!("{}Accounts", group.struct_name);
+            let keys_fields = keys_struct_fields(&group.accounts);
+            let accounts_fields = accounts_struct_fields(&group.accounts);
+            tokens.extend(quote! {
+                #[derive(Copy, Clone, Debug)]
+                pub struct #accounts_ident<'me, 'info> {
+                    #(#accounts_fields),*
+                }
+
+                #[derive(Copy, Clone, Debug, PartialEq)]
+                pub struct #keys_ident {
+                    #(#keys_fields),*
+                }
+            });
+        }
+    }
+}
+
+/// Groups with the same shape generate the same structs
+fn group_shape(entries: &[IxAccountEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| match entry {
+            IxAccountEntry::Account(a) => format!(
+                "{}:{}{}{}",
+                a.name.to_snake_case(),
+                a.writable as u8,
+                a.signer as u8,
+                a.optional as u8
+            ),
+            IxAccountEntry::Struct(s) => {
+                format!("{}{{{}}}", s.name.to_snake_case(), group_shape(&s.accounts))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// `field: value` initializers of nested `*Keys` (`suffix = "Keys"`) or `*Accounts` structs.
+/// `value` is called with the account's index in the flattened `accounts`.
+pub fn nested_field_inits(
+    accounts: &[IxAccount],
+    suffix: &str,
+    value: impl Fn(usize, &IxAccount) -> TokenStream,
+) -> Vec<TokenStream> {
+    field_inits_at_depth(accounts, 0, 0, suffix, &value)
+}
+
+fn field_inits_at_depth(
+    accounts: &[IxAccount],
+    offset: usize,
+    depth: usize,
+    suffix: &str,
+    value: &dyn Fn(usize, &IxAccount) -> TokenStream,
+) -> Vec<TokenStream> {
+    let mut res = Vec::new();
+    let mut i = 0;
+    while i < accounts.len() {
+        let acc = &accounts[i];
+        let group = acc.nested.as_ref().and_then(|p| p.groups.get(depth));
+        if let Some(group) = group {
+            let end = accounts[i..]
+                .iter()
+                .position(|a| a.nested.as_ref().and_then(|p| p.groups.get(depth)) != Some(group))
+                .map_or(accounts.len(), |n| i + n);
+            let field = format_ident!("{}", group.field);
+            let struct_ident = format_ident!("{}{}", group.struct_name, suffix);
+            let inner =
+                field_inits_at_depth(&accounts[i..end], offset + i, depth + 1, suffix, value);
+            res.push(quote! {
+                #field: #struct_ident {
+                    #(#inner),*
+                }
+            });
+            i = end;
+            continue;
+        }
+        let field = acc.leaf_field_ident();
+        let value = value(offset + i, acc);
+        if field == value.to_string() {
+            res.push(quote! { #field });
+        } else {
+            res.push(quote! { #field: #value });
+        }
+        i += 1;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groups_dedup_and_rename() {
+        let instructions: Vec<NamedInstruction> = serde_json::from_str(
+            r#"[
+                {
+                    "name": "a",
+                    "accounts": [
+                        { "name": "pool", "accounts": [{ "name": "state", "isMut": true, "isSigner": false }] }
+                    ]
+                },
+                {
+                    "name": "b",
+                    "accounts": [
+                        { "name": "pool", "accounts": [{ "name": "state", "isMut": true, "isSigner": false }] }
+                    ]
+                },
+                {
+                    "name": "c",
+                    "accounts": [
+                        { "name": "pool", "accounts": [{ "name": "state", "isMut": false, "isSigner": false }] }
+                    ]
+                }
+            ]"#,
+        )
+        .unwrap();
+        let groups = AccountGroups::new(&instructions);
+        let names: Vec<&str> = groups
+            .groups
+            .iter()
+            .map(|g| g.struct_name.as_str())
+            .collect();
+        assert_eq!(names, ["Pool", "CPool"]);
+
+        let accounts = groups.to_nested_ix_accounts(instructions[2].accounts.as_deref().unwrap());
+        assert_eq!(accounts[0].name, "pool_state");
+        assert_eq!(accounts[0].field_path().to_string(), "pool . state");
+        let inits = nested_field_inits(&accounts, "Keys", |i, _| quote! { pubkeys[#i] });
+        assert_eq!(
+            inits[0].to_string(),
+            "pool : CPoolKeys { state : pubkeys [0usize] }"
+        );
+    }
+}
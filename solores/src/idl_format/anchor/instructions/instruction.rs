@@ -5,20 +5,32 @@ use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
 use itertools::Itertools;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use serde::Deserialize;
-use sha2::{Digest, Sha256};
+use serde::{de, Deserialize, Deserializer};
 use syn::{LitBool, LitInt};
 
 use crate::{
-    idl_format::anchor::typedefs::TypedefField,
-    utils::{unique_by_report_dups, UniqueByReportDupsResult},
+    idl_format::anchor::{
+        discm_or_sighash,
+        pdas::{deserialize_pda, Pda},
+        typedefs::{DeriveAnalysis, TypedefField},
+    },
+    utils::{docs_tokens, unique_by_report_dups, UniqueByReportDupsResult},
 };
 
+use super::{nested_field_inits, AccountGroupField, NestedAccountPath};
+
 #[derive(Deserialize)]
 pub struct NamedInstruction {
     pub name: String,
+    #[serde(
+        default,
+        alias = "discriminant",
+        deserialize_with = "deserialize_discriminator"
+    )]
+    pub discriminator: Option<Vec<u8>>,
     pub accounts: Option<Vec<IxAccountEntry>>,
     pub args: Option<Vec<TypedefField>>,
+    pub docs: Option<Vec<String>>,
 }
 
 impl NamedInstruction {
@@ -42,6 +54,19 @@ impl NamedInstruction {
         format_ident!("{}_IX_DISCM", &self.name.to_shouty_snake_case())
     }
 
+    /// pre-image: "global:{instruction_fn_name}"
+    // TODO: handle non-global instructions (state methods - idk if thats deprecated)
+    pub fn discm(&self) -> Vec<u8> {
+        discm_or_sighash(
+            self.discriminator.as_deref(),
+            &format!("global:{}", self.name.to_snake_case()),
+        )
+    }
+
+    pub fn discm_len_lit(&self) -> LitInt {
+        LitInt::new(&self.discm().len().to_string(), Span::call_site())
+    }
+
     pub fn accounts_ident(&self) -> Ident {
         format_ident!("{}Accounts", self.name.to_pascal_case())
     }
@@ -76,7 +101,7 @@ impl NamedInstruction {
         } else {
             self.args.as_ref().unwrap()
         };
-        args.iter().map(|a| a.r#type.is_or_has_defined()).any(|b| b)
+        args.iter().any(|a| a.r#type.is_or_has_defined())
     }
 
     pub fn args_has_pubkeys(&self) -> bool {
@@ -85,7 +110,7 @@ impl NamedInstruction {
         } else {
             self.args.as_ref().unwrap()
         };
-        args.iter().map(|a| a.r#type.is_or_has_pubkey()).any(|b| b)
+        args.iter().any(|a| a.r#type.is_or_has_pubkey())
     }
 
     pub fn has_privileged_accounts(&self) -> bool {
@@ -94,10 +119,23 @@ impl NamedInstruction {
         } else {
             self.accounts.as_ref().unwrap()
         };
-        accounts
-            .iter()
-            .map(|a| a.has_privileged_accounts())
-            .any(|b| b)
+        accounts.iter().any(|a| a.has_privileged_accounts())
+    }
+
+    pub fn has_optional_accounts(&self) -> bool {
+        self.accounts
+            .as_deref()
+            .is_some_and(|a| to_ix_accounts(a).iter().any(|a| a.optional))
+    }
+
+    pub fn has_optional_signers(&self) -> bool {
+        self.accounts
+            .as_deref()
+            .is_some_and(|a| to_ix_accounts(a).iter().any(|a| a.optional_signer))
+    }
+
+    pub fn optional_signers_ident(&self) -> Ident {
+        format_ident!("{}OptionalSigners", self.name.to_pascal_case())
     }
 
     /// export accounts_len as const
@@ -117,12 +155,7 @@ impl NamedInstruction {
             return;
         }
         let accounts_ident = self.accounts_ident();
-        let accounts_fields = accounts.iter().map(|acc| {
-            let account_name = format_ident!("{}", &acc.name.to_snake_case());
-            quote! {
-                pub #account_name: &'me AccountInfo<'info>
-            }
-        });
+        let accounts_fields = accounts_struct_fields(accounts);
         tokens.extend(quote! {
             #[derive(Copy, Clone, Debug)]
             pub struct #accounts_ident<'me, 'info> {
@@ -136,12 +169,7 @@ impl NamedInstruction {
             return;
         }
         let keys_ident = self.keys_ident();
-        let keys_fields = accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            quote! {
-                pub #account_ident: Pubkey
-            }
-        });
+        let keys_fields = keys_struct_fields(accounts);
         tokens.extend(quote! {
             #[derive(Copy, Clone, Debug, PartialEq)]
             pub struct #keys_ident {
@@ -157,10 +185,12 @@ impl NamedInstruction {
         }
         let accounts_ident = self.accounts_ident();
         let keys_ident = self.keys_ident();
-        let from_keys_fields = accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            quote! {
-                #account_ident: *accounts.#account_ident.key
+        let from_keys_fields = nested_field_inits(accounts, "Keys", |_, acc| {
+            let path = acc.field_path();
+            if acc.optional {
+                quote! { accounts.#path.map(|a| *a.key) }
+            } else {
+                quote! { *accounts.#path.key }
             }
         });
         tokens.extend(quote! {
@@ -182,6 +212,26 @@ impl NamedInstruction {
         let keys_ident = self.keys_ident();
         let accounts_len_ident = self.accounts_len_ident();
         let from_keys_meta = accounts.iter().map(|acc| acc.to_keys_account_meta_tokens());
+        if self.has_optional_accounts() {
+            tokens.extend(quote! {
+                impl #keys_ident {
+                    /// Absent optional accounts are passed as `program_id`
+                    pub fn to_account_metas(&self, program_id: Pubkey) -> [AccountMeta; #accounts_len_ident] {
+                        let keys = self;
+                        [
+                            #(#from_keys_meta),*
+                        ]
+                    }
+                }
+
+                impl From<#keys_ident> for [AccountMeta; #accounts_len_ident] {
+                    fn from(keys: #keys_ident) -> Self {
+                        keys.to_account_metas(crate::ID)
+                    }
+                }
+            });
+            return;
+        }
         tokens.extend(quote! {
             impl From<#keys_ident> for [AccountMeta; #accounts_len_ident] {
                 fn from(keys: #keys_ident) -> Self {
@@ -193,6 +243,46 @@ impl NamedInstruction {
         });
     }
 
+    /// XOptionalSigners and XKeys::to_account_metas_with_signers()
+    pub fn write_optional_signers(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
+        if !self.has_optional_signers() {
+            return;
+        }
+        let keys_ident = self.keys_ident();
+        let optional_signers_ident = self.optional_signers_ident();
+        let accounts_len_ident = self.accounts_len_ident();
+        let signer_fields = accounts.iter().filter(|a| a.optional_signer).map(|a| {
+            let field = a.field_ident();
+            quote! { pub #field: bool }
+        });
+        let metas = accounts
+            .iter()
+            .map(|acc| acc.to_keys_account_meta_with_signers_tokens());
+        let program_id_param = if self.has_optional_accounts() {
+            quote! { program_id: Pubkey, }
+        } else {
+            quote! {}
+        };
+        tokens.extend(quote! {
+            /// Whether each of the optional signer accounts signs, they are non-signers by default
+            #[derive(Copy, Clone, Debug, Default, PartialEq)]
+            pub struct #optional_signers_ident {
+                #(#signer_fields),*
+            }
+
+            impl #keys_ident {
+                /// Like the `[AccountMeta]` conversion, but with the optional signer accounts
+                /// set as signers according to `optional_signers`
+                pub fn to_account_metas_with_signers(&self, #program_id_param optional_signers: #optional_signers_ident) -> [AccountMeta; #accounts_len_ident] {
+                    let keys = self;
+                    [
+                        #(#metas),*
+                    ]
+                }
+            }
+        });
+    }
+
     /// From <&XKeys> for Vec<AccountMeta>
     pub fn write_from_keys_for_meta_vec(&self, tokens: &mut TokenStream) {
         if !self.has_accounts() {
@@ -217,11 +307,12 @@ impl NamedInstruction {
         }
         let accounts_len_ident = self.accounts_len_ident();
         let keys_ident = self.keys_ident();
-        let from_pubkey_arr_fields = accounts.iter().enumerate().map(|(i, acc)| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+        let from_pubkey_arr_fields = nested_field_inits(accounts, "Keys", |i, acc| {
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
-            quote! {
-                #account_ident: pubkeys[#index_lit]
+            if acc.optional {
+                quote! { Some(pubkeys[#index_lit]).filter(|k| *k != crate::ID) }
+            } else {
+                quote! { pubkeys[#index_lit] }
             }
         });
         tokens.extend(quote! {
@@ -241,15 +332,16 @@ impl NamedInstruction {
         tokens: &mut TokenStream,
         accounts: &[IxAccount],
     ) {
-        if !self.has_accounts() {
+        // absent optional accounts have no AccountInfo
+        if !self.has_accounts() || self.has_optional_accounts() {
             return;
         }
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
         let account_info_clone = accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_path = acc.field_path();
             quote! {
-               accounts.#account_ident.clone()
+               accounts.#account_path.clone()
             }
         });
         tokens.extend(quote! {
@@ -264,12 +356,44 @@ impl NamedInstruction {
     }
 
     /// From <XAccounts> for Vec<AccountInfo>
-    pub fn write_from_accounts_for_account_info_vec(&self, tokens: &mut TokenStream) {
+    pub fn write_from_accounts_for_account_info_vec(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+    ) {
         if !self.has_accounts() {
             return;
         }
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
+        if self.has_optional_accounts() {
+            // absent optional accounts are passed as the program ID,
+            // which does not need an AccountInfo since it is executable
+            let push_account_infos = accounts.iter().map(|acc| {
+                let account_path = acc.field_path();
+                if acc.optional {
+                    quote! {
+                        if let Some(a) = accounts.#account_path {
+                            res.push(a.clone());
+                        }
+                    }
+                } else {
+                    quote! {
+                        res.push(accounts.#account_path.clone());
+                    }
+                }
+            });
+            tokens.extend(quote! {
+                impl<'info> From<#accounts_ident<'_, 'info>> for Vec<AccountInfo<'info>> {
+                    fn from(accounts: #accounts_ident<'_, 'info>) -> Self {
+                        let mut res = Vec::with_capacity(#accounts_len_ident);
+                        #(#push_account_infos)*
+                        res
+                    }
+                }
+            });
+            return;
+        }
         tokens.extend(quote! {
             impl<'info> From<#accounts_ident<'_, 'info>> for Vec<AccountInfo<'info>> {
                 fn from(accounts: #accounts_ident<'_, 'info>) -> Self {
@@ -291,11 +415,12 @@ impl NamedInstruction {
         }
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
-        let from_account_info_fields = accounts.iter().enumerate().map(|(i, acc)| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+        let from_account_info_fields = nested_field_inits(accounts, "Accounts", |i, acc| {
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
-            quote! {
-               #account_ident: &arr[#index_lit]
+            if acc.optional {
+                quote! { Some(&arr[#index_lit]).filter(|a| *a.key != crate::ID) }
+            } else {
+                quote! { &arr[#index_lit] }
             }
         });
         tokens.extend(quote! {
@@ -309,16 +434,20 @@ impl NamedInstruction {
         });
     }
 
-    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream) {
+    pub fn write_ix_args_struct(&self, tokens: &mut TokenStream, analysis: &DeriveAnalysis) {
         let args = if !self.has_ix_args() {
             return;
         } else {
             self.args.as_ref().unwrap()
         };
         let ix_args_ident = self.ix_args_ident();
-        let args_fields = args.iter().map(|a| quote! { pub #a });
+        let args_fields = args.iter().map(|a| a.to_pub_field_tokens());
+        let docs = docs_tokens(self.docs.as_deref());
+        analysis.check_coption_defaults(&ix_args_ident.to_string(), args.iter().map(|a| &a.r#type));
+        let derive = analysis.of_args(args).with_pod(false).derive_tokens(true);
         tokens.extend(quote! {
-            #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+            #docs
+            #derive
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
             pub struct #ix_args_ident {
                 #(#args_fields),*
@@ -328,16 +457,10 @@ impl NamedInstruction {
 
     pub fn write_discm(&self, tokens: &mut TokenStream) {
         let discm_ident = self.discm_ident();
-        // pre-image: "global:{instruction_fn_name}"
-        // TODO: handle non-global instructions (state methods - idk if thats deprecated)
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("global:{}", self.name.to_snake_case()).as_bytes()).as_slice()
-                [..8],
-        )
-        .unwrap();
-        let discm_value_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
+        let discm_len = self.discm_len_lit();
+        let discm_value_tokens: TokenStream = format!("{:?}", self.discm()).parse().unwrap();
         tokens.extend(quote! {
-            pub const #discm_ident: [u8; 8] = #discm_value_tokens;
+            pub const #discm_ident: [u8; #discm_len] = #discm_value_tokens;
 
         })
     }
@@ -375,14 +498,15 @@ impl NamedInstruction {
     pub fn write_ix_data_impl(&self, tokens: &mut TokenStream) {
         let discm_ident = self.discm_ident();
         let ix_data_ident = self.ix_data_ident();
+        let discm_len = self.discm_len_lit();
         let mut deserialize_body = quote! {
             let mut reader = buf;
-            let mut maybe_discm = [0u8; 8];
+            let mut maybe_discm = [0u8; #discm_len];
             reader.read_exact(&mut maybe_discm)?;
             if maybe_discm != #discm_ident {
                 return Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #discm_ident, maybe_discm)
+                    std::io::Error::other(
+                        format!("discm does not match. Expected: {:?}. Received: {:?}", #discm_ident, maybe_discm)
                     )
                 );
             }
@@ -447,7 +571,16 @@ impl NamedInstruction {
             fn_args.extend(quote! { args, });
         }
 
-        let (mut fn_body, accounts_expr) = if self.has_accounts() {
+        let (mut fn_body, accounts_expr) = if self.has_optional_accounts() {
+            (
+                quote! {
+                    let metas = keys.to_account_metas(program_id);
+                },
+                quote! {
+                    Vec::from(metas)
+                },
+            )
+        } else if self.has_accounts() {
             (
                 quote! {
                     let metas: [AccountMeta; #accounts_len_ident] = keys.into();
@@ -475,7 +608,9 @@ impl NamedInstruction {
             quote! { #ix_data_ident.try_to_vec()? }
         };
 
+        let docs = docs_tokens(self.docs.as_deref());
         tokens.extend(quote! {
+            #docs
             pub fn #ix_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> std::io::Result<Instruction> {
                 #fn_body
                 Ok(Instruction {
@@ -485,6 +620,7 @@ impl NamedInstruction {
                 })
             }
 
+            #docs
             pub fn #ix_fn_ident(#fn_params) -> std::io::Result<Instruction> {
                 #ix_with_program_id_fn_ident(crate::ID, #fn_args)
             }
@@ -544,7 +680,12 @@ impl NamedInstruction {
         let fn_params = self.invoke_fn_params_prefix();
         let fn_args = self.invoke_fn_args_prefix();
         let call_assign = self.ix_call_assign();
-        let invoke = if self.has_accounts() {
+        let invoke = if self.has_optional_accounts() {
+            quote! {
+                let account_info: Vec<AccountInfo> = accounts.into();
+                invoke(&ix, &account_info)
+            }
+        } else if self.has_accounts() {
             quote! {
                 invoke_instruction(&ix, accounts)
             }
@@ -578,7 +719,12 @@ impl NamedInstruction {
         let mut fn_args = self.invoke_fn_args_prefix();
         fn_args.extend(quote! { seeds, });
         let call_assign = self.ix_call_assign();
-        let invoke = if self.has_accounts() {
+        let invoke = if self.has_optional_accounts() {
+            quote! {
+                let account_info: Vec<AccountInfo> = accounts.into();
+                invoke_signed(&ix, &account_info, seeds)
+            }
+        } else if self.has_accounts() {
             quote! {
                 invoke_instruction_signed(&ix, accounts, seeds)
             }
@@ -654,15 +800,19 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
 
         let mut verify_fn_body = quote! {};
+        // absent optional accounts are not checked
+        let has_optional_privileged = accounts.iter().any(|a| a.optional && a.is_privileged());
+        let flatten = if has_optional_privileged {
+            quote! { .into_iter().flatten() }
+        } else {
+            quote! {}
+        };
 
         let mut writables = accounts
             .iter()
             .filter_map(|a| {
                 if a.writable {
-                    let name = a.field_ident();
-                    Some(quote! {
-                        accounts.#name
-                    })
+                    Some(a.to_privileged_account_info_tokens(has_optional_privileged))
                 } else {
                     None
                 }
@@ -676,7 +826,7 @@ impl NamedInstruction {
                 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
                     for should_be_writable in [
                         #(#writables),*
-                    ] {
+                    ]#flatten {
                         if !should_be_writable.is_writable {
                             return Err((should_be_writable, ProgramError::InvalidAccountData));
                         }
@@ -693,10 +843,7 @@ impl NamedInstruction {
             .iter()
             .filter_map(|a| {
                 if a.signer {
-                    let name = a.field_ident();
-                    Some(quote! {
-                        accounts.#name
-                    })
+                    Some(a.to_privileged_account_info_tokens(has_optional_privileged))
                 } else {
                     None
                 }
@@ -710,7 +857,7 @@ impl NamedInstruction {
                 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
                     for should_be_signer in [
                         #(#signers),*
-                    ] {
+                    ]#flatten {
                         if !should_be_signer.is_signer {
                             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
                         }
@@ -734,12 +881,15 @@ impl NamedInstruction {
     }
 }
 
-impl ToTokens for NamedInstruction {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let accounts = self
-            .accounts
-            .as_ref()
-            .map_or(Vec::new(), |v| to_ix_accounts(v));
+impl NamedInstruction {
+    /// `accounts` are the flattened accounts, see [`to_ix_accounts`] and
+    /// [`AccountGroups::to_nested_ix_accounts`]
+    pub fn write_with_accounts(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+        analysis: &DeriveAnalysis,
+    ) {
         let n_accounts = accounts.len();
 
         let UniqueByReportDupsResult { duplicates, .. } =
@@ -755,18 +905,19 @@ impl ToTokens for NamedInstruction {
         }
 
         self.write_accounts_len(tokens, n_accounts);
-        self.write_accounts_struct(tokens, &accounts);
-        self.write_keys_struct(tokens, &accounts);
-        self.write_from_accounts_for_keys(tokens, &accounts);
-        self.write_from_keys_for_meta_arr(tokens, &accounts);
+        self.write_accounts_struct(tokens, accounts);
+        self.write_keys_struct(tokens, accounts);
+        self.write_from_accounts_for_keys(tokens, accounts);
+        self.write_from_keys_for_meta_arr(tokens, accounts);
         self.write_from_keys_for_meta_vec(tokens);
-        self.write_from_pubkey_arr_for_keys(tokens, &accounts);
-        self.write_from_accounts_for_account_info_arr(tokens, &accounts);
-        self.write_from_accounts_for_account_info_vec(tokens);
-        self.write_from_account_info_arr_for_accounts(tokens, &accounts);
+        self.write_optional_signers(tokens, accounts);
+        self.write_from_pubkey_arr_for_keys(tokens, accounts);
+        self.write_from_accounts_for_account_info_arr(tokens, accounts);
+        self.write_from_accounts_for_account_info_vec(tokens, accounts);
+        self.write_from_account_info_arr_for_accounts(tokens, accounts);
 
         self.write_discm(tokens);
-        self.write_ix_args_struct(tokens);
+        self.write_ix_args_struct(tokens, analysis);
         self.write_ix_data_struct(tokens);
         self.write_from_ix_args_for_ix_data(tokens);
         self.write_ix_data_impl(tokens);
@@ -775,11 +926,52 @@ impl ToTokens for NamedInstruction {
         self.write_invoke_fn(tokens);
         self.write_invoke_signed_fn(tokens);
 
-        self.write_verify_account_keys_fn(tokens, &accounts);
-        self.write_verify_account_privileges_fns(tokens, &accounts);
+        self.write_verify_account_keys_fn(tokens, accounts);
+        self.write_verify_account_privileges_fns(tokens, accounts);
     }
 }
 
+/// Shank instruction discriminants are either a single byte or
+/// `{ "type": "u8", "value": 0 }`, anchor discriminators are byte arrays
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDiscriminator {
+    Bytes(Vec<u8>),
+    Byte(u8),
+    Typed { r#type: String, value: u64 },
+}
+
+fn deserialize_discriminator<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<u8>>, D::Error> {
+    let discm = match RawDiscriminator::deserialize(deserializer)? {
+        RawDiscriminator::Bytes(v) => v,
+        RawDiscriminator::Byte(b) => vec![b],
+        RawDiscriminator::Typed { r#type, value } => {
+            let len = match r#type.as_str() {
+                "u8" => 1,
+                "u16" => 2,
+                "u32" => 4,
+                "u64" => 8,
+                _ => {
+                    return Err(de::Error::custom(format!(
+                        "unsupported discriminant type {}",
+                        r#type
+                    )))
+                }
+            };
+            if len < 8 && value >> (len * 8) != 0 {
+                return Err(de::Error::custom(format!(
+                    "discriminant {} does not fit in {}",
+                    value, r#type
+                )));
+            }
+            value.to_le_bytes()[..len].to_vec()
+        }
+    };
+    Ok(Some(discm))
+}
+
 #[derive(Deserialize)]
 pub struct InnerAccountStruct {
     pub name: String,
@@ -788,18 +980,17 @@ pub struct InnerAccountStruct {
 
 impl InnerAccountStruct {
     pub fn has_privileged_accounts(&self) -> bool {
-        self.accounts
-            .iter()
-            .map(|a| a.has_privileged_accounts())
-            .any(|b| b)
+        self.accounts.iter().any(|a| a.has_privileged_accounts())
     }
 }
 
+/// Composite account groups are tried first since they also deserialize as
+/// [`IxAccount`]s, whose fields other than `name` are all optional
 #[derive(Deserialize)]
 #[serde(untagged)]
 pub enum IxAccountEntry {
-    Account(IxAccount),
     Struct(Box<InnerAccountStruct>),
+    Account(IxAccount),
 }
 
 impl IxAccountEntry {
@@ -819,6 +1010,23 @@ pub struct IxAccount {
     pub writable: bool,
     #[serde(default, alias = "isSigner")]
     pub signer: bool,
+    /// Absent optional accounts are conventionally passed as the program ID
+    #[serde(default, alias = "isOptional")]
+    pub optional: bool,
+    /// Shank `isOptionalSigner` and codama `isSigner: "either"` accounts, that are non-signers
+    /// unless set as signers with `*Keys::to_account_metas_with_signers()`
+    #[serde(default, alias = "isOptionalSigner")]
+    pub optional_signer: bool,
+    pub docs: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_pda")]
+    pub pda: Option<Pda>,
+    /// Fixed address of e.g. programs and sysvars
+    pub address: Option<String>,
+    /// Accounts whose data has this account's key as a field of the same name (`has_one`)
+    pub relations: Option<Vec<String>>,
+    /// Set for accounts of composite account groups if generating nested account structs
+    #[serde(skip)]
+    pub nested: Option<Box<NestedAccountPath>>,
 }
 
 impl IxAccount {
@@ -826,14 +1034,64 @@ impl IxAccount {
         format_ident!("{}", self.name.to_snake_case())
     }
 
+    /// Name of the account's field in its innermost account struct
+    pub fn leaf_field_ident(&self) -> Ident {
+        match &self.nested {
+            Some(path) => format_ident!("{}", path.field),
+            None => self.field_ident(),
+        }
+    }
+
+    /// `x` or, if nested, `group.x`
+    pub fn field_path(&self) -> TokenStream {
+        let leaf = self.leaf_field_ident();
+        match &self.nested {
+            Some(path) => {
+                let groups = path.groups.iter().map(|g| format_ident!("{}", g.field));
+                quote! { #(#groups.)*#leaf }
+            }
+            None => leaf.into_token_stream(),
+        }
+    }
+
     pub fn is_privileged(&self) -> bool {
         self.writable || self.signer
     }
 
+    /// Absent optional accounts are passed as a readonly `program_id`
     pub fn to_keys_account_meta_tokens(&self) -> TokenStream {
-        let is_writable_arg = LitBool::new(self.writable, Span::call_site());
         let is_signer_arg = LitBool::new(self.signer, Span::call_site());
-        let name = self.field_ident();
+        self.account_meta_tokens(is_signer_arg.into_token_stream())
+    }
+
+    /// [`Self::to_keys_account_meta_tokens`] with optional signers' `is_signer` taken from `optional_signers`
+    pub fn to_keys_account_meta_with_signers_tokens(&self) -> TokenStream {
+        if !self.optional_signer {
+            return self.to_keys_account_meta_tokens();
+        }
+        let field = self.field_ident();
+        self.account_meta_tokens(quote! { optional_signers.#field })
+    }
+
+    fn account_meta_tokens(&self, is_signer_arg: TokenStream) -> TokenStream {
+        let is_writable_arg = LitBool::new(self.writable, Span::call_site());
+        let name = self.field_path();
+        if self.optional {
+            return quote! {
+                match keys.#name {
+                    Some(pubkey) => AccountMeta {
+                        pubkey,
+                        is_signer: #is_signer_arg,
+                        is_writable: #is_writable_arg,
+                    },
+                    None => AccountMeta {
+                        pubkey: program_id,
+                        is_signer: false,
+                        is_writable: false,
+                    },
+                }
+            };
+        }
         quote! {
             AccountMeta {
                 pubkey: keys.#name,
@@ -843,12 +1101,31 @@ impl IxAccount {
         }
     }
 
+    /// Absent optional accounts are compared as the program ID
     pub fn to_verify_account_keys_tuple(&self) -> TokenStream {
-        let name = self.field_ident();
+        let name = self.field_path();
+        if self.optional {
+            return quote! {
+                (
+                    accounts.#name.map_or(crate::ID, |a| *a.key),
+                    keys.#name.unwrap_or(crate::ID)
+                )
+            };
+        }
         quote! {
             (*accounts.#name.key, keys.#name)
         }
     }
+
+    /// `accounts.x` or, if the privileges loop is over `Option`s, `Some(accounts.x)`
+    pub fn to_privileged_account_info_tokens(&self, as_option: bool) -> TokenStream {
+        let name = self.field_path();
+        if as_option && !self.optional {
+            quote! { Some(accounts.#name) }
+        } else {
+            quote! { accounts.#name }
+        }
+    }
 }
 
 pub fn to_ix_accounts(accounts: &[IxAccountEntry]) -> Vec<IxAccount> {
@@ -865,3 +1142,82 @@ pub fn to_ix_accounts(accounts: &[IxAccountEntry]) -> Vec<IxAccount> {
         vec
     })
 }
+
+/// Top-level fields of an account struct: accounts, or the groups containing nested accounts
+enum AccountStructField<'a> {
+    Account(&'a IxAccount),
+    Group(&'a AccountGroupField),
+}
+
+fn account_struct_fields(accounts: &[IxAccount]) -> Vec<AccountStructField<'_>> {
+    let mut res: Vec<AccountStructField> = Vec::new();
+    for acc in accounts {
+        match acc.nested.as_ref().and_then(|p| p.groups.first()) {
+            Some(group) => {
+                if !matches!(res.last(), Some(AccountStructField::Group(g)) if *g == group) {
+                    res.push(AccountStructField::Group(group));
+                }
+            }
+            None => res.push(AccountStructField::Account(acc)),
+        }
+    }
+    res
+}
+
+/// Fields of `*Accounts` structs
+pub fn accounts_struct_fields(accounts: &[IxAccount]) -> Vec<TokenStream> {
+    account_struct_fields(accounts)
+        .into_iter()
+        .map(|field| match field {
+            AccountStructField::Account(acc) => {
+                let account_name = acc.leaf_field_ident();
+                let docs = docs_tokens(acc.docs.as_deref());
+                let ty = if acc.optional {
+                    quote! { Option<&'me AccountInfo<'info>> }
+                } else {
+                    quote! { &'me AccountInfo<'info> }
+                };
+                quote! {
+                    #docs
+                    pub #account_name: #ty
+                }
+            }
+            AccountStructField::Group(group) => {
+                let field = format_ident!("{}", group.field);
+                let ty = format_ident!("{}Accounts", group.struct_name);
+                quote! {
+                    pub #field: #ty<'me, 'info>
+                }
+            }
+        })
+        .collect()
+}
+
+/// Fields of `*Keys` structs
+pub fn keys_struct_fields(accounts: &[IxAccount]) -> Vec<TokenStream> {
+    account_struct_fields(accounts)
+        .into_iter()
+        .map(|field| match field {
+            AccountStructField::Account(acc) => {
+                let account_ident = acc.leaf_field_ident();
+                let docs = docs_tokens(acc.docs.as_deref());
+                let ty = if acc.optional {
+                    quote! { Option<Pubkey> }
+                } else {
+                    quote! { Pubkey }
+                };
+                quote! {
+                    #docs
+                    pub #account_ident: #ty
+                }
+            }
+            AccountStructField::Group(group) => {
+                let field = format_ident!("{}", group.field);
+                let ty = format_ident!("{}Keys", group.struct_name);
+                quote! {
+                    pub #field: #ty
+                }
+            }
+        })
+        .collect()
+}
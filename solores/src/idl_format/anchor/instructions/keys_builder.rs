@@ -0,0 +1,504 @@
+use heck::{ToPascalCase, ToSnakeCase};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+
+use crate::idl_format::anchor::{
+    pdas::{NamedPda, NamedPdaSeed, Pda, PdaProgram, PdaSeed, SeedType},
+    typedefs::{NamedType, TypedefFieldType, TypedefType},
+};
+
+use super::{nested_field_inits, IxAccount, NamedInstruction};
+
+/// How a `*KeysBuilder` fills in an account that was not set
+enum Resolution {
+    /// Must be set, else `MissingAccountError`
+    Required,
+    /// Left absent if not set
+    Optional,
+    Address(String),
+    Pda {
+        pda: NamedPda,
+        /// (param name, expression) of the PDA's seed and program params
+        param_exprs: Vec<(String, TokenStream)>,
+        /// Accounts the seeds depend on
+        deps: Vec<String>,
+        uses_args: bool,
+    },
+}
+
+impl NamedInstruction {
+    pub fn keys_builder_ident(&self) -> Ident {
+        format_ident!("{}KeysBuilder", self.name.to_pascal_case())
+    }
+
+    /// `*KeysBuilder` that resolves unset fixed address and PDA accounts on `build()`.
+    /// `relations` are resolved from the related account's data with `with_*_relations()`.
+    /// `program_accounts` are the names of the program's account types, see [`Self::relations_fns`].
+    /// Not written if no account can be resolved. Returns whether it was written.
+    pub fn write_keys_builder(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+        named_types: &[NamedType],
+        program_accounts: &[&str],
+    ) -> bool {
+        if !self.has_accounts() {
+            return false;
+        }
+        let keys_ident = self.keys_ident();
+        let builder_ident = self.keys_builder_ident();
+        let account_idents: Vec<Ident> = accounts.iter().map(|a| a.field_ident()).collect();
+
+        let resolutions: Vec<Resolution> = accounts
+            .iter()
+            .map(|a| self.account_resolution(a, accounts, named_types))
+            .collect();
+        let setters = account_idents.iter().map(|ident| {
+            quote! {
+                pub fn #ident(mut self, #ident: Pubkey) -> Self {
+                    self.#ident = Some(#ident);
+                    self
+                }
+            }
+        });
+        let relations_fns = self.relations_fns(accounts, named_types, program_accounts);
+        let any_resolvable = resolutions
+            .iter()
+            .any(|r| matches!(r, Resolution::Address(_) | Resolution::Pda { .. }));
+        if !any_resolvable && relations_fns.is_empty() {
+            return false;
+        }
+        let keys_fields =
+            nested_field_inits(accounts, "Keys", |_, a| a.field_ident().into_token_stream());
+        let (build_stmts, uses_args) = ordered_build_stmts(accounts, resolutions);
+        let build_params = if uses_args {
+            let ix_args_ident = self.ix_args_ident();
+            quote! { self, args: &#ix_args_ident }
+        } else {
+            quote! { self }
+        };
+
+        let doc = format!(
+            " Builds [`{}`], resolving fixed address and PDA accounts that were not set",
+            keys_ident
+        );
+        tokens.extend(quote! {
+            #[doc = #doc]
+            #[derive(Clone, Copy, Debug, Default, PartialEq)]
+            pub struct #builder_ident {
+                #(pub #account_idents: Option<Pubkey>),*
+            }
+
+            impl #builder_ident {
+                #(#setters)*
+
+                #(#relations_fns)*
+
+                pub fn build(#build_params) -> Result<#keys_ident, MissingAccountError> {
+                    #(#build_stmts)*
+                    Ok(#keys_ident {
+                        #(#keys_fields),*
+                    })
+                }
+            }
+        });
+        true
+    }
+
+    fn account_resolution(
+        &self,
+        account: &IxAccount,
+        accounts: &[IxAccount],
+        named_types: &[NamedType],
+    ) -> Resolution {
+        if account.optional {
+            return Resolution::Optional;
+        }
+        if let Some(address) = account.address.as_deref().filter(|a| is_pubkey(a)) {
+            return Resolution::Address(address.to_owned());
+        }
+        if let Some(res) = account
+            .pda
+            .as_ref()
+            .and_then(|pda| self.pda_resolution(account, pda, accounts, named_types))
+        {
+            return res;
+        }
+        Resolution::Required
+    }
+
+    /// None if the PDA depends on account data or on accounts not in this instruction
+    fn pda_resolution(
+        &self,
+        account: &IxAccount,
+        pda: &Pda,
+        accounts: &[IxAccount],
+        named_types: &[NamedType],
+    ) -> Option<Resolution> {
+        let args = self.args.as_deref().unwrap_or_default();
+        let named_pda = pda.resolve(&account.name, args, named_types).ok()?;
+        let mut param_exprs = Vec::new();
+        let mut deps = Vec::new();
+        let mut uses_args = false;
+        let account_expr = |path: &str, deps: &mut Vec<String>| {
+            if path.contains('.') {
+                return None;
+            }
+            let dep = accounts
+                .iter()
+                .find(|a| a.name.to_snake_case() == path.to_snake_case())?;
+            // optional accounts are bound as Option<Pubkey>
+            if dep.name == account.name || dep.optional {
+                return None;
+            }
+            deps.push(dep.name.clone());
+            Some(dep.field_ident().into_token_stream())
+        };
+        for (seed, named_seed) in pda.seeds.iter().zip(&named_pda.seeds) {
+            let (name, ty) = match named_seed {
+                NamedPdaSeed::Param { name, ty } => (name, ty),
+                NamedPdaSeed::Const(_) => continue,
+            };
+            let expr = match seed {
+                PdaSeed::Arg { path } => {
+                    uses_args = true;
+                    args_expr(path, ty)
+                }
+                PdaSeed::Account { path, .. } => account_expr(path, &mut deps)?,
+                PdaSeed::Const { .. } => continue,
+            };
+            param_exprs.push((name.clone(), expr));
+        }
+        if let PdaProgram::Param(name) = &named_pda.program {
+            let expr = match pda.program.as_ref()? {
+                PdaSeed::Arg { path } => {
+                    uses_args = true;
+                    args_expr(path, &SeedType::Pubkey)
+                }
+                PdaSeed::Account { path, .. } => account_expr(path, &mut deps)?,
+                PdaSeed::Const { .. } => return None,
+            };
+            param_exprs.push((name.clone(), expr));
+        }
+        Some(Resolution::Pda {
+            pda: named_pda,
+            param_exprs,
+            deps,
+            uses_args,
+        })
+    }
+
+    /// `with_*_relations()` for each account that other accounts have a `relations` (`has_one`) to.
+    ///
+    /// IDLs don't link instruction accounts to account types, so the data type is assumed to be
+    /// the program account named like the instruction account, e.g. `pool_state` holds a `PoolState`.
+    /// Not written if there is no such program account.
+    fn relations_fns(
+        &self,
+        accounts: &[IxAccount],
+        named_types: &[NamedType],
+        program_accounts: &[&str],
+    ) -> Vec<TokenStream> {
+        let mut sources: Vec<&IxAccount> = Vec::new();
+        for a in accounts {
+            for r in a.relations.iter().flatten() {
+                let source = accounts
+                    .iter()
+                    .find(|s| s.name.to_snake_case() == r.to_snake_case());
+                if let Some(source) = source {
+                    if !sources.iter().any(|s| s.name == source.name) {
+                        sources.push(source);
+                    }
+                }
+            }
+        }
+        sources
+            .into_iter()
+            .filter_map(|source| {
+                let type_name = source.name.to_pascal_case();
+                if !program_accounts.iter().any(|a| a.to_pascal_case() == type_name) {
+                    return None;
+                }
+                let fields = match named_types.iter().find(|t| t.name == type_name) {
+                    Some(NamedType {
+                        r#type: TypedefType::r#struct(s),
+                        ..
+                    }) => s.named_fields(),
+                    _ => return None,
+                };
+                let source_ident = source.field_ident();
+                let assigns: Vec<TokenStream> = accounts
+                    .iter()
+                    .filter(|a| {
+                        a.relations
+                            .iter()
+                            .flatten()
+                            .any(|r| r.to_snake_case() == source.name.to_snake_case())
+                    })
+                    .filter(|a| {
+                        fields.iter().any(|f| {
+                            f.name.to_snake_case() == a.name.to_snake_case()
+                                && matches!(&f.r#type, TypedefFieldType::PrimitiveOrPubkey(t) if t == "pubkey" || t == "publicKey")
+                        })
+                    })
+                    .map(|a| {
+                        let ident = a.field_ident();
+                        quote! {
+                            self.#ident.get_or_insert(#source_ident.#ident);
+                        }
+                    })
+                    .collect();
+                if assigns.is_empty() {
+                    return None;
+                }
+                let fn_ident = format_ident!("with_{}_relations", source_ident);
+                let type_ident = format_ident!("{}", type_name);
+                let doc = format!(
+                    " Sets the accounts that `{}` has a relation to from its data, assuming it is a [`crate::{}`] as it is named like one",
+                    source.name, type_name
+                );
+                Some(quote! {
+                    #[doc = #doc]
+                    pub fn #fn_ident(mut self, #source_ident: &crate::#type_ident) -> Self {
+                        #(#assigns)*
+                        self
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+fn is_pubkey(s: &str) -> bool {
+    bs58::decode(s).into_vec().is_ok_and(|b| b.len() == 32)
+}
+
+/// `args.path.to.field`, borrowed if the seed type is a slice
+fn args_expr(path: &str, ty: &SeedType) -> TokenStream {
+    let fields = path
+        .split('.')
+        .map(|f| format_ident!("{}", f.to_snake_case()));
+    match ty {
+        SeedType::Bytes | SeedType::ByteArray(_) => quote! { &args.#(#fields).* },
+        _ => quote! { args.#(#fields).* },
+    }
+}
+
+/// Non-PDA accounts first, then PDAs once the accounts their seeds depend on are resolved.
+/// PDAs with cyclic dependencies are required.
+/// Also returns whether any resolved PDA's seeds use the instruction args.
+fn ordered_build_stmts(
+    accounts: &[IxAccount],
+    resolutions: Vec<Resolution>,
+) -> (Vec<TokenStream>, bool) {
+    let mut stmts = Vec::new();
+    let mut any_uses_args = false;
+    let mut resolved: Vec<&str> = Vec::new();
+    let mut pdas = Vec::new();
+    for (account, res) in accounts.iter().zip(resolutions) {
+        let ident = account.field_ident();
+        let name = &account.name;
+        let stmt = match res {
+            Resolution::Required => quote! {
+                let #ident = self.#ident.ok_or(MissingAccountError(#name))?;
+            },
+            Resolution::Optional => quote! {
+                let #ident = self.#ident;
+            },
+            Resolution::Address(address) => quote! {
+                let #ident = self.#ident.unwrap_or(solana_program::pubkey!(#address));
+            },
+            pda @ Resolution::Pda { .. } => {
+                pdas.push((account, pda));
+                continue;
+            }
+        };
+        stmts.push(stmt);
+        resolved.push(name);
+    }
+    while !pdas.is_empty() {
+        let ready = pdas.iter().position(|(_, res)| match res {
+            Resolution::Pda { deps, .. } => deps.iter().all(|d| resolved.contains(&d.as_str())),
+            _ => true,
+        });
+        let (account, res, required) = match ready {
+            Some(i) => {
+                let (account, res) = pdas.remove(i);
+                (account, res, false)
+            }
+            None => {
+                let (account, res) = pdas.remove(0);
+                (account, res, true)
+            }
+        };
+        let ident = account.field_ident();
+        let name = &account.name;
+        let stmt = match res {
+            Resolution::Pda {
+                pda,
+                param_exprs,
+                uses_args,
+                ..
+            } if !required => {
+                any_uses_args |= uses_args;
+                let param_expr = |name: &str| {
+                    param_exprs
+                        .iter()
+                        .find(|(n, _)| n == name)
+                        .map(|(_, e)| e.clone())
+                        .unwrap()
+                };
+                let seeds = pda.seeds_tokens(param_expr);
+                let program = pda.program_tokens(|name| {
+                    let e = param_expr(name);
+                    quote! { &#e }
+                });
+                quote! {
+                    let #ident = self.#ident.unwrap_or_else(|| {
+                        let seeds: &[&[u8]] = &[#(#seeds),*];
+                        Pubkey::find_program_address(seeds, #program).0
+                    });
+                }
+            }
+            _ => quote! {
+                let #ident = self.#ident.ok_or(MissingAccountError(#name))?;
+            },
+        };
+        stmts.push(stmt);
+        resolved.push(name);
+    }
+    (stmts, any_uses_args)
+}
+
+/// Generated once per instructions module
+pub fn missing_account_error_tokens() -> TokenStream {
+    quote! {
+        /// An account that a `*KeysBuilder` could not resolve and was not set
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct MissingAccountError(pub &'static str);
+
+        impl std::fmt::Display for MissingAccountError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "missing account {}", self.0)
+            }
+        }
+
+        impl std::error::Error for MissingAccountError {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idl_format::anchor::instructions::to_ix_accounts;
+
+    fn keys_builder_tokens(
+        ix_json: &str,
+        named_types_json: &str,
+        program_accounts: &[&str],
+    ) -> String {
+        let ix: NamedInstruction = serde_json::from_str(ix_json).unwrap();
+        let named_types: Vec<NamedType> = serde_json::from_str(named_types_json).unwrap();
+        let accounts = to_ix_accounts(ix.accounts.as_deref().unwrap());
+        let mut tokens = TokenStream::new();
+        ix.write_keys_builder(&mut tokens, &accounts, &named_types, program_accounts);
+        tokens.to_string()
+    }
+
+    #[test]
+    fn test_keys_builder_resolves_pda_after_its_seed_accounts() {
+        let generated = keys_builder_tokens(
+            r#"{
+                "name": "create",
+                "accounts": [
+                    {
+                        "name": "config",
+                        "pda": { "seeds": [{ "kind": "arg", "path": "index" }, { "kind": "account", "path": "owner" }] }
+                    },
+                    { "name": "owner", "signer": true },
+                    { "name": "system_program", "address": "11111111111111111111111111111111" }
+                ],
+                "args": [{ "name": "index", "type": "u16" }]
+            }"#,
+            "[]",
+            &[],
+        );
+        assert!(generated.contains("pub fn build (self , args : & CreateIxArgs)"));
+        let owner_pos = generated.find("let owner = self . owner . ok_or").unwrap();
+        let config_pos = generated
+            .find("let config = self . config . unwrap_or_else")
+            .unwrap();
+        assert!(owner_pos < config_pos);
+        assert!(generated.contains("& args . index . to_le_bytes () , owner . as_ref ()"));
+        assert!(generated.contains(
+            "self . system_program . unwrap_or (solana_program :: pubkey ! (\"11111111111111111111111111111111\"))"
+        ));
+    }
+
+    #[test]
+    fn test_keys_builder_pda_with_optional_seed_account_is_required() {
+        let generated = keys_builder_tokens(
+            r#"{
+                "name": "create",
+                "accounts": [
+                    {
+                        "name": "config",
+                        "pda": { "seeds": [{ "kind": "account", "path": "referrer" }] }
+                    },
+                    { "name": "referrer", "optional": true },
+                    { "name": "system_program", "address": "11111111111111111111111111111111" }
+                ]
+            }"#,
+            "[]",
+            &[],
+        );
+        assert!(generated
+            .contains("let config = self . config . ok_or (MissingAccountError (\"config\")) ?"));
+        assert!(generated.contains("let referrer = self . referrer ;"));
+    }
+
+    #[test]
+    fn test_keys_builder_not_written_if_nothing_resolvable() {
+        let generated = keys_builder_tokens(
+            r#"{
+                "name": "transfer",
+                "accounts": [
+                    { "name": "from", "writable": true, "signer": true },
+                    { "name": "to", "writable": true, "optional": true }
+                ]
+            }"#,
+            "[]",
+            &[],
+        );
+        assert!(generated.is_empty());
+    }
+
+    const RELATIONS_IX: &str = r#"{
+        "name": "withdraw",
+        "accounts": [
+            { "name": "pool_state", "writable": true },
+            { "name": "amm_config", "relations": ["pool_state"] }
+        ]
+    }"#;
+
+    const RELATIONS_TYPES: &str = r#"[{
+        "name": "PoolState",
+        "type": { "kind": "struct", "fields": [{ "name": "amm_config", "type": "pubkey" }] }
+    }]"#;
+
+    #[test]
+    fn test_keys_builder_relations() {
+        let generated = keys_builder_tokens(RELATIONS_IX, RELATIONS_TYPES, &["PoolState"]);
+        assert!(generated.contains("pub fn build (self)"));
+        assert!(generated.contains(
+            "pub fn with_pool_state_relations (mut self , pool_state : & crate :: PoolState) -> Self { self . amm_config . get_or_insert (pool_state . amm_config) ;"
+        ));
+    }
+
+    #[test]
+    fn test_keys_builder_relations_need_program_account() {
+        // PoolState is only a typedef, not an account of the program
+        let generated = keys_builder_tokens(RELATIONS_IX, RELATIONS_TYPES, &[]);
+        assert!(!generated.contains("with_pool_state_relations"));
+    }
+}
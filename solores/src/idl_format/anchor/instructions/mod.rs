@@ -1,15 +1,29 @@
 use heck::ToPascalCase;
-use proc_macro2::TokenStream;
+use itertools::Itertools;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
+use syn::LitInt;
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, type_map::TypeMap};
 
+use super::typedefs::{DeriveAnalysis, NamedType};
+
+mod account_groups;
 mod instruction;
+mod keys_builder;
+pub use account_groups::*;
 pub use instruction::*;
+pub use keys_builder::*;
 
 pub struct IxCodegenModule<'a> {
     pub program_name: &'a str,
     pub instructions: &'a [NamedInstruction],
+    pub named_types: &'a [NamedType],
+    /// Names of the program's account types, whose data `with_*_relations()` read
+    pub program_accounts: Vec<&'a str>,
+    pub type_map: &'a TypeMap,
+    /// Generate composite account groups as nested `*Keys`/`*Accounts` structs
+    pub nested_accounts: bool,
 }
 
 impl IdlCodegenModule for IxCodegenModule<'_> {
@@ -19,21 +33,13 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
 
     fn gen_head(&self) -> TokenStream {
         let mut res = quote! {};
-        let has_args = self
-            .instructions
-            .iter()
-            .map(|ix| ix.has_ix_args())
-            .any(|b| b);
+        let has_args = self.instructions.iter().any(|ix| ix.has_ix_args());
         if has_args {
             res.extend(quote! {
                 use borsh::{BorshDeserialize, BorshSerialize};
             });
         }
-        let has_accounts = self
-            .instructions
-            .iter()
-            .map(|ix| ix.has_accounts())
-            .any(|b| b);
+        let has_accounts = self.instructions.iter().any(|ix| ix.has_accounts());
 
         let mut solana_program_imports = if has_accounts {
             quote! {
@@ -54,8 +60,7 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
         let has_privileged_accounts = self
             .instructions
             .iter()
-            .map(|ix| ix.has_privileged_accounts())
-            .any(|b| b);
+            .any(|ix| ix.has_privileged_accounts());
         if has_privileged_accounts {
             solana_program_imports.extend(quote! {
                 program_error::ProgramError,
@@ -69,8 +74,7 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
         let has_defined_type = self
             .instructions
             .iter()
-            .map(|ix| ix.args_has_defined_type())
-            .any(|b| b);
+            .any(|ix| ix.args_has_defined_type());
         if has_defined_type {
             res.extend(quote! {
                 use crate::*;
@@ -83,8 +87,71 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
         let program_ix_enum_variants = self.instructions.iter().map(enum_variant);
         let serialize_variant_match_arms =
             self.instructions.iter().map(serialize_variant_match_arm);
-        let deserialize_variant_match_arms =
-            self.instructions.iter().map(deserialize_variant_match_arm);
+        // an empty discm would match any data
+        let dispatched: Vec<&NamedInstruction> = self
+            .instructions
+            .iter()
+            .filter(|ix| {
+                let is_empty = ix.discm().is_empty();
+                if is_empty {
+                    log::warn!(
+                        "Instruction {} has no discriminator, {} does not deserialize it",
+                        ix.name,
+                        program_ix_enum_ident
+                    );
+                }
+                !is_empty
+            })
+            .collect();
+        let discm_lens: Vec<usize> = dispatched
+            .iter()
+            .map(|ix| ix.discm().len())
+            .unique()
+            .collect();
+        // Custom anchor discriminators may have different lengths,
+        // in which case we cant match on a fixed-size array
+        let deserialize_body = if let [discm_len] = discm_lens.as_slice() {
+            let discm_len = LitInt::new(&discm_len.to_string(), Span::call_site());
+            let deserialize_variant_match_arms = dispatched
+                .iter()
+                .map(|ix| deserialize_variant_match_arm(ix));
+            quote! {
+                let mut reader = buf;
+                let mut maybe_discm = [0u8; #discm_len];
+                reader.read_exact(&mut maybe_discm)?;
+                match maybe_discm {
+                    #(#deserialize_variant_match_arms),*,
+                    _ => Err(
+                        std::io::Error::other(
+                            format!("discm {:?} not found", maybe_discm)
+                        )
+                    ),
+                }
+            }
+        } else {
+            let max_discm_len = LitInt::new(
+                &discm_lens
+                    .iter()
+                    .max()
+                    .copied()
+                    .unwrap_or_default()
+                    .to_string(),
+                Span::call_site(),
+            );
+            // longest first in case a discm is a prefix of another
+            let deserialize_variant_if_arms = dispatched
+                .iter()
+                .sorted_by_key(|ix| std::cmp::Reverse(ix.discm().len()))
+                .map(|ix| deserialize_variant_if_arm(ix));
+            quote! {
+                #(#deserialize_variant_if_arms)*
+                Err(
+                    std::io::Error::other(
+                        format!("discm {:?} not found", &buf[..buf.len().min(#max_discm_len)])
+                    )
+                )
+            }
+        };
 
         res.extend(quote! {
             #[derive(Clone, Debug, PartialEq)]
@@ -94,17 +161,7 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
 
             impl #program_ix_enum_ident {
                 pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-                    let mut reader = buf;
-                    let mut maybe_discm = [0u8; 8];
-                    reader.read_exact(&mut maybe_discm)?;
-                    match maybe_discm {
-                        #(#deserialize_variant_match_arms),*,
-                        _ => Err(
-                            std::io::Error::new(
-                                std::io::ErrorKind::Other, format!("discm {:?} not found", maybe_discm)
-                            )
-                        ),
-                    }
+                    #deserialize_body
                 }
 
                 pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
@@ -145,10 +202,30 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
     }
 
     fn gen_body(&self) -> TokenStream {
-        self.instructions
-            .iter()
-            .map(|e| e.into_token_stream())
-            .collect()
+        let groups = self
+            .nested_accounts
+            .then(|| AccountGroups::new(self.instructions));
+        let mut res = groups.to_token_stream();
+        let analysis = DeriveAnalysis::new(self.named_types, &[], self.type_map);
+        let mut has_keys_builders = false;
+        for ix in self.instructions {
+            let entries = ix.accounts.as_deref().unwrap_or_default();
+            let accounts = match &groups {
+                Some(groups) => groups.to_nested_ix_accounts(entries),
+                None => to_ix_accounts(entries),
+            };
+            ix.write_with_accounts(&mut res, &accounts, &analysis);
+            has_keys_builders |= ix.write_keys_builder(
+                &mut res,
+                &accounts,
+                self.named_types,
+                &self.program_accounts,
+            );
+        }
+        if has_keys_builders {
+            res.extend(missing_account_error_tokens());
+        }
+        res
     }
 }
 
@@ -202,3 +279,151 @@ pub fn deserialize_variant_match_arm(ix: &NamedInstruction) -> TokenStream {
         #discm_ident => Ok(#variant_expr)
     }
 }
+
+pub fn deserialize_variant_if_arm(ix: &NamedInstruction) -> TokenStream {
+    let variant_ident = format_ident!("{}", ix.name.to_pascal_case());
+    let discm_ident = ix.discm_ident();
+    if ix.has_ix_args() {
+        let ix_args_ident = ix.ix_args_ident();
+        quote! {
+            if let Some(mut reader) = buf.strip_prefix(&#discm_ident) {
+                return Ok(Self::#variant_ident(#ix_args_ident::deserialize(&mut reader)?));
+            }
+        }
+    } else {
+        quote! {
+            if buf.starts_with(&#discm_ident) {
+                return Ok(Self::#variant_ident);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program_ix_tokens(instructions_json: &str) -> String {
+        let instructions: Vec<NamedInstruction> = serde_json::from_str(instructions_json).unwrap();
+        IxCodegenModule {
+            program_name: "test",
+            instructions: &instructions,
+            named_types: &[],
+            program_accounts: Vec::new(),
+            type_map: &TypeMap::default(),
+            nested_accounts: false,
+        }
+        .gen_head()
+        .to_string()
+    }
+
+    #[test]
+    fn test_same_len_discms_match_on_array() {
+        let generated = program_ix_tokens(
+            r#"[
+                { "name": "a", "discriminator": [1] },
+                { "name": "b", "discriminator": [2] }
+            ]"#,
+        );
+        assert!(generated.contains("let mut maybe_discm = [0u8 ; 1]"));
+        assert!(generated.contains("match maybe_discm"));
+    }
+
+    #[test]
+    fn test_mixed_len_discms_match_on_prefix() {
+        let generated = program_ix_tokens(
+            r#"[
+                { "name": "a", "discriminator": [1] },
+                { "name": "b" }
+            ]"#,
+        );
+        assert!(!generated.contains("match maybe_discm"));
+        // longest discm checked first
+        let b_pos = generated.find("buf . starts_with (& B_IX_DISCM)").unwrap();
+        let a_pos = generated.find("buf . starts_with (& A_IX_DISCM)").unwrap();
+        assert!(b_pos < a_pos);
+    }
+
+    #[test]
+    fn test_optional_accounts() {
+        let instructions: Vec<NamedInstruction> = serde_json::from_str(
+            r#"[
+                {
+                    "name": "a",
+                    "discriminator": [1],
+                    "accounts": [
+                        { "name": "payer", "writable": true, "signer": true },
+                        { "name": "delegate", "signer": true, "optional": true }
+                    ]
+                }
+            ]"#,
+        )
+        .unwrap();
+        let generated = IxCodegenModule {
+            program_name: "test",
+            instructions: &instructions,
+            named_types: &[],
+            program_accounts: Vec::new(),
+            type_map: &TypeMap::default(),
+            nested_accounts: false,
+        }
+        .gen_body()
+        .to_string();
+        assert!(generated.contains("pub delegate : Option < Pubkey >"));
+        assert!(generated.contains("pub delegate : Option < & 'me AccountInfo < 'info >>"));
+        assert!(generated.contains("None => AccountMeta { pubkey : program_id"));
+        assert!(generated.contains("let metas = keys . to_account_metas (program_id)"));
+        // absent optional accounts have no AccountInfo to put in an array
+        assert!(!generated.contains("for [AccountInfo < 'info > ; A_IX_ACCOUNTS_LEN]"));
+        assert!(generated.contains(
+            "[Some (accounts . payer) , accounts . delegate] . into_iter () . flatten ()"
+        ));
+    }
+
+    #[test]
+    fn test_optional_signers() {
+        let instructions: Vec<NamedInstruction> = serde_json::from_str(
+            r#"[
+                {
+                    "name": "a",
+                    "discriminant": 1,
+                    "accounts": [
+                        { "name": "payer", "isMut": true, "isSigner": true },
+                        { "name": "delegate", "isMut": false, "isSigner": false, "isOptionalSigner": true }
+                    ]
+                }
+            ]"#,
+        )
+        .unwrap();
+        let generated = IxCodegenModule {
+            program_name: "test",
+            instructions: &instructions,
+            named_types: &[],
+            program_accounts: Vec::new(),
+            type_map: &TypeMap::default(),
+            nested_accounts: false,
+        }
+        .gen_body()
+        .to_string();
+        assert!(generated.contains("pub struct AOptionalSigners { pub delegate : bool }"));
+        assert!(generated.contains(
+            "pub fn to_account_metas_with_signers (& self , optional_signers : AOptionalSigners)"
+        ));
+        assert!(generated
+            .contains("pubkey : keys . delegate , is_signer : optional_signers . delegate"));
+        // not a required signer
+        assert!(generated.contains("pubkey : keys . delegate , is_signer : false"));
+    }
+
+    #[test]
+    fn test_empty_discms_not_dispatched() {
+        let generated = program_ix_tokens(
+            r#"[
+                { "name": "a", "discriminator": [] },
+                { "name": "b", "discriminator": [1] }
+            ]"#,
+        );
+        assert!(generated.contains("B_IX_DISCM => Ok (Self :: B)"));
+        assert!(!generated.contains("A_IX_DISCM => Ok (Self :: A)"));
+    }
+}
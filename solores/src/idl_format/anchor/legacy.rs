@@ -0,0 +1,284 @@
+use serde::Deserialize;
+
+use crate::idl_format::shank::SHANK_ORIGIN;
+
+use super::{
+    accounts::NamedAccount,
+    constants::Constant,
+    errors::ErrorEnumVariant,
+    events::Event,
+    instructions::NamedInstruction,
+    typedefs::{DefinedFields, NamedType, TypedefField, TypedefStruct, TypedefType},
+    AnchorIdl, Metadata,
+};
+
+/// Pre-0.30 anchor IDL.
+///
+/// Program name and version are at the top level and the program address,
+/// if present, is in `metadata.address`.
+/// Instructions and types share the same deserialization as [`AnchorIdl`]
+/// (`isMut`/`isSigner`, `publicKey`, `{"defined": "Name"}` are all aliased there),
+/// so this converts into an [`AnchorIdl`] for codegen.
+#[derive(Deserialize)]
+pub struct LegacyAnchorIdl {
+    pub name: String,
+    pub version: String,
+    pub metadata: Option<LegacyMetadata>,
+    /// Account layouts are defined inline instead of in `types`
+    pub accounts: Option<Vec<NamedType>>,
+    pub types: Option<Vec<NamedType>>,
+    pub instructions: Option<Vec<NamedInstruction>>,
+    pub errors: Option<Vec<ErrorEnumVariant>>,
+    /// Event layouts are defined inline in `fields` instead of in `types`
+    pub events: Option<Vec<LegacyEvent>>,
+    pub constants: Option<Vec<Constant>>,
+}
+
+#[derive(Deserialize)]
+pub struct LegacyEvent {
+    pub name: String,
+    // NB: fields also have an `index` field that's ignored for now since we dk what it does:
+    // https://github.com/coral-xyz/anchor/blob/8f30f00ec363b7e82aa0b3c7041e912919b33cf5/lang/attribute/event/src/lib.rs#L62C1-L64
+    pub fields: Vec<TypedefField>,
+}
+
+impl LegacyEvent {
+    /// The event's inline layout as a struct typedef
+    fn into_named_type(self) -> NamedType {
+        NamedType {
+            name: self.name,
+            generics: Vec::new(),
+            r#type: TypedefType::r#struct(TypedefStruct {
+                fields: Some(DefinedFields::Struct(self.fields)),
+            }),
+            docs: None,
+            serialization: None,
+            repr: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct LegacyMetadata {
+    pub address: Option<String>,
+    pub origin: Option<String>,
+}
+
+impl LegacyAnchorIdl {
+    /// Shank IDLs share the legacy format but must not be treated as anchor IDLs
+    /// since their discriminants are not sighashes
+    pub fn is_shank(&self) -> bool {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.origin.as_deref())
+            .is_some_and(|o| o == SHANK_ORIGIN)
+    }
+}
+
+impl From<LegacyAnchorIdl> for AnchorIdl {
+    fn from(
+        LegacyAnchorIdl {
+            name,
+            version,
+            metadata,
+            accounts,
+            types,
+            instructions,
+            errors,
+            events,
+            constants,
+        }: LegacyAnchorIdl,
+    ) -> Self {
+        let mut inline_types = Vec::new();
+        let accounts = accounts.map(|account_types| {
+            let accounts = account_types
+                .iter()
+                .map(|t| NamedAccount {
+                    name: t.name.clone(),
+                    discriminator: None,
+                })
+                .collect();
+            inline_types.extend(account_types);
+            accounts
+        });
+        let events = events.map(|legacy_events| {
+            legacy_events
+                .into_iter()
+                .map(|e| {
+                    let event = Event {
+                        name: e.name.clone(),
+                        discriminator: None,
+                    };
+                    inline_types.push(e.into_named_type());
+                    event
+                })
+                .collect()
+        });
+        let types = match (types, inline_types.is_empty()) {
+            (types, true) => types,
+            (types, false) => {
+                let types = types.unwrap_or_default();
+                // an account or event may also be listed in types, keep a single layout for it
+                inline_types.retain(|t| {
+                    let is_dup = types.iter().any(|e| e.name == t.name);
+                    if is_dup {
+                        log::warn!("{} is also in types, skipping its inline layout", t.name);
+                    }
+                    !is_dup
+                });
+                inline_types.extend(types);
+                Some(inline_types)
+            }
+        };
+        Self {
+            address: metadata.and_then(|m| m.address),
+            metadata: Metadata {
+                name,
+                version,
+                spec: None,
+                description: None,
+            },
+            accounts,
+            types,
+            instructions,
+            errors,
+            events,
+            constants,
+            pdas: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idl_format::IdlFormat;
+
+    #[test]
+    fn test_legacy_idl_to_anchor_idl() {
+        let legacy: LegacyAnchorIdl = serde_json::from_str(
+            r#"{
+                "version": "0.1.0",
+                "name": "unstake",
+                "instructions": [
+                    {
+                        "name": "initProtocolFee",
+                        "accounts": [
+                            { "name": "payer", "isMut": true, "isSigner": true },
+                            { "name": "protocolFeeAccount", "isMut": true, "isSigner": false }
+                        ],
+                        "args": []
+                    }
+                ],
+                "metadata": { "address": "unpXTU2Ndrc7WWNyEhQWe4udTzSibLPi25SXv2xbCHQ" }
+            }"#,
+        )
+        .unwrap();
+        let idl = AnchorIdl::from(legacy);
+        assert_eq!(idl.program_name(), "unstake");
+        assert_eq!(idl.program_version(), "0.1.0");
+        assert_eq!(
+            idl.program_address(),
+            Some("unpXTU2Ndrc7WWNyEhQWe4udTzSibLPi25SXv2xbCHQ")
+        );
+        assert!(idl.instructions.unwrap()[0].has_privileged_accounts());
+    }
+
+    #[test]
+    fn test_legacy_idl_no_metadata() {
+        let legacy: LegacyAnchorIdl =
+            serde_json::from_str(r#"{ "version": "0.0.0", "name": "anchor_ix_blank" }"#).unwrap();
+        let idl = AnchorIdl::from(legacy);
+        assert_eq!(idl.program_name(), "anchor_ix_blank");
+        assert!(idl.program_address().is_none());
+    }
+
+    #[test]
+    fn test_legacy_accounts_moved_to_types() {
+        let legacy: LegacyAnchorIdl = serde_json::from_str(
+            r#"{
+                "version": "0.1.0",
+                "name": "unstake",
+                "accounts": [
+                    {
+                        "name": "Fee",
+                        "type": { "kind": "struct", "fields": [{ "name": "fee", "type": "u64" }] }
+                    }
+                ],
+                "types": [
+                    {
+                        "name": "Rational",
+                        "type": { "kind": "struct", "fields": [{ "name": "num", "type": "u64" }] }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let idl = AnchorIdl::from(legacy);
+        let accounts = idl.accounts.as_ref().unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].resolve_type(idl.named_types()).name, "Fee");
+        assert_eq!(idl.named_types().len(), 2);
+    }
+
+    #[test]
+    fn test_legacy_account_also_in_types() {
+        let legacy: LegacyAnchorIdl = serde_json::from_str(
+            r#"{
+                "version": "0.1.0",
+                "name": "unstake",
+                "accounts": [
+                    {
+                        "name": "Fee",
+                        "type": { "kind": "struct", "fields": [{ "name": "fee", "type": "u64" }] }
+                    }
+                ],
+                "types": [
+                    {
+                        "name": "Fee",
+                        "type": { "kind": "struct", "fields": [{ "name": "fee", "type": "u64" }] }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let idl = AnchorIdl::from(legacy);
+        assert_eq!(idl.accounts.as_ref().unwrap().len(), 1);
+        assert_eq!(idl.named_types().len(), 1);
+    }
+
+    #[test]
+    fn test_legacy_events_moved_to_types() {
+        let legacy: LegacyAnchorIdl = serde_json::from_str(
+            r#"{
+                "version": "0.1.0",
+                "name": "drift",
+                "events": [
+                    {
+                        "name": "NewUserRecord",
+                        "fields": [{ "name": "ts", "type": "i64", "index": false }]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let idl = AnchorIdl::from(legacy);
+        let events = idl.events.as_ref().unwrap();
+        assert_eq!(events.len(), 1);
+        let layout = events[0].resolve_type(idl.named_types());
+        let TypedefType::r#struct(s) = &layout.r#type else {
+            panic!("expected struct layout");
+        };
+        assert_eq!(s.named_fields().len(), 1);
+    }
+
+    #[test]
+    fn test_new_idl_is_not_legacy() {
+        let new_idl = r#"{
+            "address": "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C",
+            "metadata": { "name": "raydium_cp_swap", "version": "0.2.0", "spec": "0.1.0" }
+        }"#;
+        assert!(serde_json::from_str::<LegacyAnchorIdl>(new_idl).is_err());
+        assert!(serde_json::from_str::<AnchorIdl>(new_idl).is_ok());
+    }
+}
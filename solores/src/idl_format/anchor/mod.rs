@@ -1,44 +1,75 @@
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use toml::{map::Map, Value};
 
+use crate::type_map::TypeMap;
 use crate::write_cargotoml::{
-    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
-    NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_BIG_ARRAY_CRATE, SERDE_BYTES_CRATE, SERDE_CRATE,
-    SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
+    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BASE64_CRATE, BORSH_CRATE,
+    BYTEMUCK_CRATE, NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_BIG_ARRAY_CRATE, SERDE_BYTES_CRATE,
+    SERDE_CRATE, SOLANA_PROGRAM_CRATE, SOLANA_RPC_CLIENT_API_CRATE, THISERROR_CRATE,
 };
 
 use super::{IdlCodegenModule, IdlFormat};
 
 use self::{
     accounts::{AccountsCodegenModule, NamedAccount},
+    builtins::{used_builtins, BuiltinsCodegenModule},
+    constants::{Constant, ConstantsCodegenModule},
     errors::{ErrorEnumVariant, ErrorsCodegenModule},
+    events::{Event, EventsCodegenModule},
     instructions::{IxCodegenModule, NamedInstruction},
+    pdas::{named_pdas, NamedPda, PdasCodegenModule},
     typedefs::{NamedType, TypedefsCodegenModule},
 };
 
 pub mod accounts;
+pub mod builtins;
+pub mod constants;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod legacy;
+pub mod pdas;
 pub mod typedefs;
 
 #[derive(Deserialize)]
 pub struct AnchorIdl {
-    pub address: String,
+    /// Always present in anchor >= 0.30 IDLs,
+    /// optional `metadata.address` in legacy IDLs
+    pub address: Option<String>,
     pub metadata: Metadata,
     pub accounts: Option<Vec<NamedAccount>>,
     pub types: Option<Vec<NamedType>>,
     pub instructions: Option<Vec<NamedInstruction>>,
     pub errors: Option<Vec<ErrorEnumVariant>>,
-    // pub events: Option<Vec<Event>>,
+    pub events: Option<Vec<Event>>,
+    pub constants: Option<Vec<Constant>>,
+    /// PDAs declared outside of instruction accounts, e.g. codama `pdas`
+    #[serde(skip)]
+    pub pdas: Vec<NamedPda>,
 }
 
 #[derive(Deserialize)]
 pub struct Metadata {
     pub name: String,
     pub version: String,
-    pub spec: String,
-    pub description: String,
+    pub spec: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Returns the IDL's explicit `discriminator` if present,
+/// else the first 8 bytes of sha256(`preimage`)
+pub fn discm_or_sighash(discriminator: Option<&[u8]>, preimage: &str) -> Vec<u8> {
+    match discriminator {
+        Some(d) => d.to_vec(),
+        None => Sha256::digest(preimage.as_bytes())[..8].to_vec(),
+    }
+}
+
+impl AnchorIdl {
+    pub fn named_types(&self) -> &[NamedType] {
+        self.types.as_deref().unwrap_or_default()
+    }
 }
 
 impl IdlFormat for AnchorIdl {
@@ -51,7 +82,7 @@ impl IdlFormat for AnchorIdl {
     }
 
     fn program_address(&self) -> Option<&str> {
-        Some(&self.address)
+        self.address.as_deref()
     }
 
     /// Anchor IDLs dont seem to have an identifier,
@@ -61,35 +92,83 @@ impl IdlFormat for AnchorIdl {
         true
     }
 
-    fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+        type_map: &'me TypeMap,
+    ) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
         let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
         if let Some(v) = &self.accounts {
             res.push(Box::new(AccountsCodegenModule {
                 cli_args: args,
+                type_map,
+                program_name: self.program_name(),
                 named_accounts: v,
+                named_types: self.named_types(),
             }));
         }
-        if let Some(v) = &self.r#types {
+        // mapped types are re-exported from typedefs
+        if self.r#types.is_some() || !type_map.types.is_empty() {
             res.push(Box::new(TypedefsCodegenModule {
                 cli_args: args,
-                named_types: v,
+                type_map,
+                named_types: self.named_types(),
+                all_named_types: self.named_types(),
             }));
         }
+        let builtins = used_builtins(
+            self.named_types(),
+            self.instructions
+                .iter()
+                .flatten()
+                .flat_map(|ix| ix.args.iter().flatten()),
+        );
+        if !builtins.is_empty() {
+            res.push(Box::new(BuiltinsCodegenModule { builtins }));
+        }
         if let Some(v) = &self.instructions {
             res.push(Box::new(IxCodegenModule {
                 program_name: self.program_name(),
                 instructions: v,
+                named_types: self.named_types(),
+                program_accounts: self
+                    .accounts
+                    .iter()
+                    .flatten()
+                    .map(|a| a.name.as_str())
+                    .collect(),
+                type_map,
+                nested_accounts: args.nested_accounts,
             }));
         }
+        let pdas = named_pdas(
+            &self.pdas,
+            self.instructions.as_deref().unwrap_or_default(),
+            self.named_types(),
+        );
+        if !pdas.is_empty() {
+            res.push(Box::new(PdasCodegenModule { pdas }));
+        }
         if let Some(v) = &self.errors {
             res.push(Box::new(ErrorsCodegenModule {
                 program_name: self.program_name(),
                 variants: v,
             }));
         }
-        // if let Some(v) = &self.events {
-        //     res.push(Box::new(EventsCodegenModule(v)));
-        // }
+        if let Some(v) = &self.constants {
+            if !v.is_empty() {
+                res.push(Box::new(ConstantsCodegenModule { constants: v }));
+            }
+        }
+        if let Some(v) = &self.events {
+            if !v.is_empty() {
+                res.push(Box::new(EventsCodegenModule {
+                    program_name: self.program_name(),
+                    events: v,
+                    named_types: self.named_types(),
+                }));
+            }
+        }
         res
     }
 
@@ -120,6 +199,19 @@ impl IdlFormat for AnchorIdl {
             SERDE_BIG_ARRAY_CRATE.into(),
             OptionalDependencyValue(DependencyValue(&args.serde_big_array_vers)).into(),
         );
+        // for the accounts' rpc_filter_*()s
+        if self.accounts.as_ref().is_some_and(|v| !v.is_empty()) {
+            map.insert(
+                SOLANA_RPC_CLIENT_API_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.solana_rpc_client_api_vers)).into(),
+            );
+        }
+        if self.events.as_ref().is_some_and(|v| !v.is_empty()) {
+            map.insert(
+                BASE64_CRATE.into(),
+                DependencyValue(&args.base64_vers).into(),
+            );
+        }
         if self.errors.is_some() {
             map.insert(
                 THISERROR_CRATE.into(),
@@ -137,3 +229,17 @@ impl IdlFormat for AnchorIdl {
         map
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discm_or_sighash() {
+        assert_eq!(
+            discm_or_sighash(None, "account:AmmConfig"),
+            [218, 244, 33, 104, 203, 203, 43, 111]
+        );
+        assert_eq!(discm_or_sighash(Some(&[1]), "account:AmmConfig"), [1]);
+    }
+}
@@ -0,0 +1,74 @@
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::idl_format::IdlCodegenModule;
+
+use super::{
+    instructions::{to_ix_accounts, NamedInstruction},
+    typedefs::NamedType,
+};
+
+mod pda;
+pub use pda::*;
+
+pub struct PdasCodegenModule {
+    pub pdas: Vec<NamedPda>,
+}
+
+impl IdlCodegenModule for PdasCodegenModule {
+    fn name(&self) -> &str {
+        "pdas"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        quote! {
+            use solana_program::pubkey::{Pubkey, PubkeyError};
+        }
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        self.pdas.iter().map(|p| p.into_token_stream()).collect()
+    }
+}
+
+/// `declared` PDAs followed by the distinct PDAs of instruction accounts.
+///
+/// PDAs are named after their account. Accounts of the same name but with different seeds
+/// in different instructions are prefixed with the instruction name.
+pub fn named_pdas(
+    declared: &[NamedPda],
+    instructions: &[NamedInstruction],
+    named_types: &[NamedType],
+) -> Vec<NamedPda> {
+    let mut res = declared.to_vec();
+    for ix in instructions {
+        let args = ix.args.as_deref().unwrap_or_default();
+        let accounts = to_ix_accounts(ix.accounts.as_deref().unwrap_or_default());
+        for acc in accounts {
+            let pda = match &acc.pda {
+                Some(p) => p,
+                None => continue,
+            };
+            let mut pda = match pda.resolve(&acc.name, args, named_types) {
+                Ok(p) => p,
+                Err(e) => {
+                    log::warn!(
+                        "Skipping pda of account {} of instruction {}: {}",
+                        acc.name,
+                        ix.name,
+                        e
+                    );
+                    continue;
+                }
+            };
+            if res.iter().any(|p| p.name == pda.name && p != &pda) {
+                pda.name = format!("{}_{}", ix.name.to_snake_case(), pda.name);
+            }
+            if !res.contains(&pda) {
+                res.push(pda);
+            }
+        }
+    }
+    res
+}
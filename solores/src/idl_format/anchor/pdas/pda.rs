@@ -0,0 +1,433 @@
+use heck::ToSnakeCase;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use syn::{LitByteStr, LitInt};
+
+use crate::idl_format::anchor::typedefs::{
+    NamedType, TypedefField, TypedefFieldArray, TypedefFieldType, TypedefType,
+};
+
+/// `pda` of an anchor >= 0.30 instruction account
+#[derive(Clone, Deserialize, PartialEq)]
+pub struct Pda {
+    pub seeds: Vec<PdaSeed>,
+    /// Program the PDA is derived from if not this program
+    pub program: Option<PdaSeed>,
+}
+
+#[derive(Clone, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PdaSeed {
+    Const {
+        #[serde(deserialize_with = "deserialize_const_seed")]
+        value: Vec<u8>,
+    },
+    /// `path` is the instruction arg, or a field of it e.g. `params.index`
+    Arg { path: String },
+    /// `path` is the instruction account, or a field of its data e.g. `pool_state.amm_config`,
+    /// in which case `account` is the account's type
+    Account {
+        path: String,
+        account: Option<String>,
+    },
+}
+
+/// Legacy IDLs' const seeds may be strings instead of bytes
+fn deserialize_const_seed<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ConstSeed {
+        Bytes(Vec<u8>),
+        Str(String),
+    }
+    Ok(match ConstSeed::deserialize(deserializer)? {
+        ConstSeed::Bytes(b) => b,
+        ConstSeed::Str(s) => s.into_bytes(),
+    })
+}
+
+/// Unsupported `pda`s are skipped instead of failing the whole IDL
+pub fn deserialize_pda<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Pda>, D::Error> {
+    let value = Option::<Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|v| match Pda::deserialize(v) {
+        Ok(pda) => Some(pda),
+        Err(e) => {
+            log::warn!("Skipping unsupported pda: {}", e);
+            None
+        }
+    }))
+}
+
+/// Seed parameter types that have a canonical byte representation
+#[derive(Clone, Debug, PartialEq)]
+pub enum SeedType {
+    Pubkey,
+    /// Integer primitive, seeded as its little-endian bytes
+    Int(String),
+    Bool,
+    Str,
+    Bytes,
+    ByteArray(u32),
+}
+
+const INT_TYPES: [&str; 10] = [
+    "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+];
+
+impl SeedType {
+    pub fn from_field_type(ty: &TypedefFieldType) -> Option<Self> {
+        let is_u8 =
+            |t: &TypedefFieldType| matches!(t, TypedefFieldType::PrimitiveOrPubkey(s) if s == "u8");
+        match ty {
+            TypedefFieldType::PrimitiveOrPubkey(s) => match s.as_str() {
+                "pubkey" | "publicKey" => Some(Self::Pubkey),
+                "bool" => Some(Self::Bool),
+                "string" => Some(Self::Str),
+                "bytes" => Some(Self::Bytes),
+                s if INT_TYPES.contains(&s) => Some(Self::Int(s.to_owned())),
+                _ => None,
+            },
+            TypedefFieldType::vec(v) if is_u8(v) => Some(Self::Bytes),
+            TypedefFieldType::array(TypedefFieldArray(v, len)) if is_u8(v) => {
+                len.value().map(Self::ByteArray)
+            }
+            _ => None,
+        }
+    }
+
+    fn param_type_tokens(&self) -> TokenStream {
+        match self {
+            Self::Pubkey => quote! { &Pubkey },
+            Self::Int(s) => {
+                let ty = format_ident!("{}", s);
+                quote! { #ty }
+            }
+            Self::Bool => quote! { bool },
+            Self::Str => quote! { &str },
+            Self::Bytes => quote! { &[u8] },
+            Self::ByteArray(len) => {
+                let len = LitInt::new(&len.to_string(), Span::call_site());
+                quote! { &[u8; #len] }
+            }
+        }
+    }
+
+    fn seed_tokens(&self, param: &TokenStream) -> TokenStream {
+        match self {
+            Self::Pubkey => quote! { #param.as_ref() },
+            Self::Int(_) => quote! { &#param.to_le_bytes() },
+            Self::Bool => quote! { &[#param as u8] },
+            Self::Str => quote! { #param.as_bytes() },
+            Self::Bytes | Self::ByteArray(_) => quote! { #param },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NamedPdaSeed {
+    Const(Vec<u8>),
+    Param { name: String, ty: SeedType },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PdaProgram {
+    /// The generated crate's `ID`
+    This,
+    Const([u8; 32]),
+    Param(String),
+}
+
+/// A PDA with its seeds' types resolved, generated as `find_*_pda()` and `create_*_pda_with_bump()`
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedPda {
+    pub name: String,
+    pub seeds: Vec<NamedPdaSeed>,
+    pub program: PdaProgram,
+}
+
+fn param_name(path: &str) -> String {
+    path.replace('.', "_").to_snake_case()
+}
+
+impl Pda {
+    /// Resolves the types of `arg` seeds from the instruction's `args`
+    /// and of `account` seeds that are fields of account data from `named_types`
+    pub fn resolve(
+        &self,
+        name: &str,
+        args: &[TypedefField],
+        named_types: &[NamedType],
+    ) -> Result<NamedPda, String> {
+        let seeds = self
+            .seeds
+            .iter()
+            .map(|s| s.resolve(args, named_types))
+            .collect::<Result<_, _>>()?;
+        let program = match &self.program {
+            None => PdaProgram::This,
+            Some(PdaSeed::Const { value }) => PdaProgram::Const(
+                value
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| format!("program {:?} is not a pubkey", value))?,
+            ),
+            Some(PdaSeed::Arg { path } | PdaSeed::Account { path, .. }) => {
+                PdaProgram::Param(param_name(path))
+            }
+        };
+        Ok(NamedPda {
+            name: name.to_snake_case(),
+            seeds,
+            program,
+        })
+    }
+}
+
+impl PdaSeed {
+    fn resolve(
+        &self,
+        args: &[TypedefField],
+        named_types: &[NamedType],
+    ) -> Result<NamedPdaSeed, String> {
+        let (path, ty) = match self {
+            Self::Const { value } => return Ok(NamedPdaSeed::Const(value.clone())),
+            Self::Arg { path } => {
+                let (arg_name, rest) = split_path(path);
+                let arg = args
+                    .iter()
+                    .find(|a| a.name.to_snake_case() == arg_name.to_snake_case())
+                    .ok_or_else(|| format!("arg {} not found", arg_name))?;
+                (path, resolve_path(&arg.r#type, rest, named_types)?)
+            }
+            Self::Account { path, account } => match split_path(path) {
+                (_, None) => (path, SeedType::Pubkey),
+                (_, Some(rest)) => {
+                    let account = account
+                        .as_deref()
+                        .ok_or_else(|| format!("type of account {} unknown", path))?;
+                    (path, resolve_type_field(account, rest, named_types)?)
+                }
+            },
+        };
+        Ok(NamedPdaSeed::Param {
+            name: param_name(path),
+            ty,
+        })
+    }
+}
+
+fn split_path(path: &str) -> (&str, Option<&str>) {
+    match path.split_once('.') {
+        Some((first, rest)) => (first, Some(rest)),
+        None => (path, None),
+    }
+}
+
+fn resolve_path(
+    ty: &TypedefFieldType,
+    path: Option<&str>,
+    named_types: &[NamedType],
+) -> Result<SeedType, String> {
+    match (path, ty) {
+        (None, ty) => SeedType::from_field_type(ty).ok_or_else(|| "unsupported seed type".into()),
+        (Some(path), TypedefFieldType::defined(type_name)) => {
+            resolve_type_field(type_name.name(), path, named_types)
+        }
+        (Some(path), _) => Err(format!("{} is not a field of a defined type", path)),
+    }
+}
+
+fn resolve_type_field(
+    type_name: &str,
+    path: &str,
+    named_types: &[NamedType],
+) -> Result<SeedType, String> {
+    let (field_name, rest) = split_path(path);
+    let field = named_types
+        .iter()
+        .find(|t| t.name == type_name)
+        .and_then(|t| match &t.r#type {
+            TypedefType::r#struct(s) => s
+                .named_fields()
+                .iter()
+                .find(|f| f.name.to_snake_case() == field_name.to_snake_case()),
+            TypedefType::r#enum(_) | TypedefType::r#type { .. } => None,
+        })
+        .ok_or_else(|| format!("field {} of {} not found", field_name, type_name))?;
+    resolve_path(&field.r#type, rest, named_types)
+}
+
+impl NamedPda {
+    pub fn find_fn_ident(&self) -> syn::Ident {
+        format_ident!("find_{}_pda", self.name)
+    }
+
+    pub fn create_with_bump_fn_ident(&self) -> syn::Ident {
+        format_ident!("create_{}_pda_with_bump", self.name)
+    }
+
+    /// Distinct params in order of first appearance
+    fn params(&self) -> Vec<(String, SeedType)> {
+        let mut params: Vec<(String, SeedType)> = Vec::new();
+        let seed_params = self.seeds.iter().filter_map(|s| match s {
+            NamedPdaSeed::Param { name, ty } => Some((name.clone(), ty.clone())),
+            NamedPdaSeed::Const(_) => None,
+        });
+        let program_param = match &self.program {
+            PdaProgram::Param(name) => Some((name.clone(), SeedType::Pubkey)),
+            _ => None,
+        };
+        for (name, ty) in seed_params.chain(program_param) {
+            if !params.iter().any(|(n, _)| n == &name) {
+                params.push((name, ty));
+            }
+        }
+        params
+    }
+}
+
+impl NamedPda {
+    /// Elements of the `&[&[u8]]` seeds array, with each param replaced by `param_expr(name)`
+    pub fn seeds_tokens(&self, param_expr: impl Fn(&str) -> TokenStream) -> Vec<TokenStream> {
+        self.seeds
+            .iter()
+            .map(|s| match s {
+                NamedPdaSeed::Const(bytes) => const_seed_tokens(bytes),
+                NamedPdaSeed::Param { name, ty } => ty.seed_tokens(&param_expr(name)),
+            })
+            .collect()
+    }
+
+    /// `&Pubkey` program ID expression, `param_expr(name)` must be a `&Pubkey` if the program is a param
+    pub fn program_tokens(&self, param_expr: impl Fn(&str) -> TokenStream) -> TokenStream {
+        match &self.program {
+            PdaProgram::This => quote! { &crate::ID },
+            PdaProgram::Const(bytes) => {
+                let bytes: TokenStream = format!("{:?}", bytes).parse().unwrap();
+                quote! { &Pubkey::new_from_array(#bytes) }
+            }
+            PdaProgram::Param(name) => param_expr(name),
+        }
+    }
+}
+
+impl ToTokens for NamedPda {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let find_fn_ident = self.find_fn_ident();
+        let create_with_bump_fn_ident = self.create_with_bump_fn_ident();
+        let params = self.params().into_iter().map(|(name, ty)| {
+            let name = format_ident!("{}", name);
+            let ty = ty.param_type_tokens();
+            quote! { #name: #ty }
+        });
+        let params: Vec<TokenStream> = params.collect();
+        let param_ident = |name: &str| format_ident!("{}", name).into_token_stream();
+        let seeds = self.seeds_tokens(param_ident);
+        let program = self.program_tokens(param_ident);
+        tokens.extend(quote! {
+            pub fn #find_fn_ident(#(#params),*) -> (Pubkey, u8) {
+                let seeds: &[&[u8]] = &[#(#seeds),*];
+                Pubkey::find_program_address(seeds, #program)
+            }
+
+            pub fn #create_with_bump_fn_ident(#(#params,)* bump: u8) -> Result<Pubkey, PubkeyError> {
+                let seeds: &[&[u8]] = &[#(#seeds,)* &[bump]];
+                Pubkey::create_program_address(seeds, #program)
+            }
+        });
+    }
+}
+
+/// Printable seeds like `b"pool_vault"` are emitted as byte string literals
+fn const_seed_tokens(bytes: &[u8]) -> TokenStream {
+    let is_printable =
+        !bytes.is_empty() && bytes.iter().all(|b| b.is_ascii_graphic() || *b == b' ');
+    if is_printable {
+        let lit = LitByteStr::new(bytes, Span::call_site());
+        quote! { #lit }
+    } else {
+        let bytes: TokenStream = format!("{:?}", bytes).parse().unwrap();
+        quote! { &#bytes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_pda_seeds() {
+        let pda: Pda = serde_json::from_str(
+            r#"{
+                "seeds": [
+                    { "kind": "const", "value": [97, 109, 109] },
+                    { "kind": "arg", "path": "index" },
+                    { "kind": "account", "path": "pool_state" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let args: Vec<TypedefField> =
+            serde_json::from_str(r#"[{ "name": "index", "type": "u16" }]"#).unwrap();
+        let named = pda.resolve("ammConfig", &args, &[]).unwrap();
+        assert_eq!(named.name, "amm_config");
+        assert_eq!(
+            named.seeds,
+            [
+                NamedPdaSeed::Const(b"amm".to_vec()),
+                NamedPdaSeed::Param {
+                    name: "index".into(),
+                    ty: SeedType::Int("u16".into())
+                },
+                NamedPdaSeed::Param {
+                    name: "pool_state".into(),
+                    ty: SeedType::Pubkey
+                },
+            ]
+        );
+        assert_eq!(named.program, PdaProgram::This);
+        assert!(pda.resolve("ammConfig", &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_resolve_account_field_seed() {
+        let pda: Pda = serde_json::from_str(
+            r#"{
+                "seeds": [{ "kind": "account", "path": "pool.config.mint", "account": "Pool" }],
+                "program": { "kind": "account", "path": "token_program" }
+            }"#,
+        )
+        .unwrap();
+        let named_types: Vec<NamedType> = serde_json::from_str(
+            r#"[
+                { "name": "Pool", "type": { "kind": "struct", "fields": [{ "name": "config", "type": { "defined": "Config" } }] } },
+                { "name": "Config", "type": { "kind": "struct", "fields": [{ "name": "mint", "type": "pubkey" }] } }
+            ]"#,
+        )
+        .unwrap();
+        let named = pda.resolve("vault", &[], &named_types).unwrap();
+        assert_eq!(
+            named.params(),
+            [
+                ("pool_config_mint".to_owned(), SeedType::Pubkey),
+                ("token_program".to_owned(), SeedType::Pubkey)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_legacy_string_const_seed() {
+        let seed: PdaSeed =
+            serde_json::from_str(r#"{ "kind": "const", "type": "string", "value": "vault" }"#)
+                .unwrap();
+        assert!(
+            seed == PdaSeed::Const {
+                value: b"vault".to_vec()
+            }
+        );
+    }
+}
@@ -0,0 +1,455 @@
+use std::collections::{BTreeSet, HashMap};
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::type_map::TypeMap;
+
+use super::{
+    DefinedFields, GenericArg, Layout, LayoutAnalysis, LenPrefix, NamedType, TypedefField,
+    TypedefFieldArray, TypedefFieldSizePrefixed, TypedefFieldType, TypedefType,
+};
+
+/// Array lengths bytemuck impls `Pod` for without its `min_const_generics` feature
+const POD_ARRAY_LENS: [u32; 42] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 48, 64, 96, 128, 256, 512, 1024, 2048, 4096,
+];
+
+/// std only impls `Default` for arrays of up to 32 elements
+const MAX_DEFAULT_ARRAY_LEN: u32 = 32;
+
+const INT_TYPES: [&str; 10] = [
+    "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+];
+
+/// Traits that are derived only if all fields impl them,
+/// on top of the always-derived `Clone`, `Debug`, `PartialEq` and borsh traits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Derives {
+    pub copy: bool,
+    pub eq: bool,
+    pub hash: bool,
+    pub default: bool,
+    pub pod: bool,
+    pub partial_ord: bool,
+}
+
+impl Derives {
+    pub const ALL: Self = Self {
+        copy: true,
+        eq: true,
+        hash: true,
+        default: true,
+        pod: true,
+        partial_ord: true,
+    };
+
+    pub const NONE: Self = Self {
+        copy: false,
+        eq: false,
+        hash: false,
+        default: false,
+        pod: false,
+        partial_ord: false,
+    };
+
+    pub fn and(self, other: Self) -> Self {
+        Self {
+            copy: self.copy && other.copy,
+            eq: self.eq && other.eq,
+            hash: self.hash && other.hash,
+            default: self.default && other.default,
+            pod: self.pod && other.pod,
+            partial_ord: self.partial_ord && other.partial_ord,
+        }
+    }
+
+    pub fn with_pod(self, pod: bool) -> Self {
+        Self { pod, ..self }
+    }
+
+    fn with_default(self, default: bool) -> Self {
+        Self { default, ..self }
+    }
+
+    /// Collections that are never `Copy` or `Pod` but always `Default`
+    fn collection(self) -> Self {
+        Self {
+            copy: false,
+            pod: false,
+            default: true,
+            ..self
+        }
+    }
+
+    /// `#[derive(...)]`, with `Pod` and `Zeroable` if `pod`.
+    /// `BorshSerialize` is omitted if `!borsh_serialize`, e.g. for manual impls.
+    pub fn derive_tokens(&self, borsh_serialize: bool) -> TokenStream {
+        let borsh = if borsh_serialize {
+            quote! { BorshDeserialize, BorshSerialize, }
+        } else {
+            quote! { BorshDeserialize, }
+        };
+        self.tokens(borsh)
+    }
+
+    /// `#[derive(...)]` without borsh traits, `Pod` or `Zeroable`,
+    /// for newtypes with manual (de)serialization like account wrappers
+    pub fn wrapper_derive_tokens(&self) -> TokenStream {
+        self.with_pod(false).tokens(quote! {})
+    }
+
+    fn tokens(&self, borsh: TokenStream) -> TokenStream {
+        let mut res = quote! { Clone, };
+        if self.copy {
+            res.extend(quote! { Copy, });
+        }
+        res.extend(quote! { Debug, });
+        if self.default {
+            res.extend(quote! { Default, });
+        }
+        res.extend(borsh);
+        res.extend(quote! { PartialEq, });
+        if self.eq {
+            res.extend(quote! { Eq, });
+        }
+        if self.hash {
+            res.extend(quote! { Hash, });
+        }
+        if self.partial_ord {
+            res.extend(quote! { PartialOrd, });
+        }
+        if self.pod {
+            res.extend(quote! { Pod, Zeroable, });
+        }
+        quote! { #[derive(#res)] }
+    }
+}
+
+/// Which [`Derives`] each named type gets, computed transitively through `defined` references.
+///
+/// `pod` is only set for zero-copy types whose fields are all `Pod`
+/// and that have no padding, since other types never derive it.
+pub struct DeriveAnalysis {
+    types: HashMap<String, Derives>,
+    mapped_types: BTreeSet<String>,
+    layouts: LayoutAnalysis,
+}
+
+impl DeriveAnalysis {
+    /// `zero_copy` are the `-z` type names
+    pub fn new(named_types: &[NamedType], zero_copy: &[String], type_map: &TypeMap) -> Self {
+        // start from everything and remove what isn't satisfied until nothing changes,
+        // so that recursive types keep the derives their other fields allow
+        let mut res = Self {
+            types: named_types
+                .iter()
+                .map(|t| (t.name.clone(), Derives::ALL))
+                .collect(),
+            mapped_types: type_map.types.keys().cloned().collect(),
+            layouts: LayoutAnalysis::new(named_types, zero_copy, type_map),
+        };
+        loop {
+            let mut changed = false;
+            for t in named_types {
+                let derives = res.compute_named_type(t, zero_copy);
+                if res.types.insert(t.name.clone(), derives) != Some(derives) {
+                    changed = true;
+                }
+            }
+            if !changed {
+                return res;
+            }
+        }
+    }
+
+    fn compute_named_type(&self, t: &NamedType, zero_copy: &[String]) -> Derives {
+        let mut res = match &t.r#type {
+            TypedefType::r#struct(s) => self.of_fields(s.fields.as_ref()),
+            TypedefType::r#enum(e) => {
+                // #[default] can only be put on unit variants
+                let first_is_unit = e.variants.first().is_some_and(|v| v.fields.is_none());
+                e.variants
+                    .iter()
+                    .fold(Derives::ALL, |acc, v| {
+                        acc.and(self.of_fields(v.fields.as_ref()))
+                    })
+                    .with_default(first_is_unit)
+                    .with_pod(false)
+            }
+            TypedefType::r#type { alias } => return self.of_field_type(alias),
+        };
+        let is_unsafe_bytemuck = t.is_unsafe_bytemuck();
+        res.pod = is_unsafe_bytemuck
+            || (t.is_zero_copy(zero_copy)
+                && res.pod
+                && self.layouts.layout(&t.name).is_ok_and(|l| l.padding == 0));
+        if is_unsafe_bytemuck {
+            res.copy = true;
+        }
+        res
+    }
+
+    /// See [`LayoutAnalysis::layout`]
+    pub fn layout(&self, name: &str) -> Result<Layout, String> {
+        self.layouts.layout(name)
+    }
+
+    /// [`Derives::NONE`] for types not in the IDL or mapped to external types
+    pub fn of_type(&self, name: &str) -> Derives {
+        if self.mapped_types.contains(name) {
+            return Derives::NONE;
+        }
+        self.types.get(name).copied().unwrap_or(Derives::NONE)
+    }
+
+    pub fn of_fields(&self, fields: Option<&DefinedFields>) -> Derives {
+        fields.map_or(Derives::ALL, |f| {
+            f.types()
+                .into_iter()
+                .fold(Derives::ALL, |acc, ty| acc.and(self.of_field_type(ty)))
+        })
+    }
+
+    pub fn of_args(&self, args: &[TypedefField]) -> Derives {
+        args.iter().fold(Derives::ALL, |acc, a| {
+            acc.and(self.of_field_type(&a.r#type))
+        })
+    }
+
+    pub fn of_field_type(&self, ty: &TypedefFieldType) -> Derives {
+        match ty {
+            TypedefFieldType::PrimitiveOrPubkey(s) => primitive_derives(s),
+            TypedefFieldType::defined(d) => {
+                d.generics()
+                    .iter()
+                    .fold(self.of_type(d.name()), |acc, g| match g {
+                        GenericArg::Type { r#type } => acc.and(self.of_field_type(r#type)),
+                        GenericArg::Const { .. } => acc,
+                    })
+            }
+            // derives are bounded on the generic param
+            TypedefFieldType::generic(_) => Derives::ALL.with_pod(false),
+            TypedefFieldType::array(TypedefFieldArray(elem, len)) => {
+                let len = len.value();
+                self.of_field_type(elem).and(Derives {
+                    default: len.is_some_and(|n| n <= MAX_DEFAULT_ARRAY_LEN),
+                    pod: len.is_some_and(|n| POD_ARRAY_LENS.contains(&n)),
+                    ..Derives::ALL
+                })
+            }
+            TypedefFieldType::option(t) => self.of_field_type(t).with_pod(false).with_default(true),
+            // derive(Default) is only kept if the value can be serialized for `None`
+            TypedefFieldType::coption(t) => Derives {
+                partial_ord: false,
+                ..self.of_field_type(t).with_pod(false)
+            },
+            TypedefFieldType::vec(t) | TypedefFieldType::bTreeSet(t) => {
+                self.of_field_type(t).collection()
+            }
+            TypedefFieldType::hashSet(t) => Derives {
+                hash: false,
+                partial_ord: false,
+                ..self.of_field_type(t).collection()
+            },
+            TypedefFieldType::bTreeMap(m) => self
+                .of_field_type(&m.0)
+                .and(self.of_field_type(&m.1))
+                .collection(),
+            TypedefFieldType::hashMap(m) => Derives {
+                hash: false,
+                partial_ord: false,
+                ..self
+                    .of_field_type(&m.0)
+                    .and(self.of_field_type(&m.1))
+                    .collection()
+            },
+            TypedefFieldType::tuple(t) => t
+                .iter()
+                .fold(Derives::ALL, |acc, wrap| {
+                    acc.and(self.of_field_type(&wrap.0))
+                })
+                .with_pod(false),
+            TypedefFieldType::sizePrefixed(p) => self.of_size_prefixed(p),
+        }
+    }
+
+    /// Errors if any of `types`, fields of `owner`, has a `COption<T>` whose `T` isn't `Default`.
+    /// `COption` serializes `T::default()` for `None`,
+    /// so it only impls `BorshSerialize` for `T: Default`.
+    pub fn check_coption_defaults<'a>(
+        &self,
+        owner: &str,
+        types: impl IntoIterator<Item = &'a TypedefFieldType>,
+    ) {
+        if let Some(inner) = types
+            .into_iter()
+            .find_map(|ty| self.non_default_coption_inner(ty))
+        {
+            let inner = inner.to_token_stream();
+            log::error!(
+                "{}: COption<{}> cannot be serialized, {} does not impl Default",
+                owner,
+                inner,
+                inner
+            );
+            panic!();
+        }
+    }
+
+    fn non_default_coption_inner<'a>(
+        &self,
+        ty: &'a TypedefFieldType,
+    ) -> Option<&'a TypedefFieldType> {
+        match ty {
+            TypedefFieldType::coption(t) => {
+                // mapped types are assumed to impl Default
+                let is_mapped = matches!(t.as_ref(), TypedefFieldType::defined(d) if self.mapped_types.contains(d.name()));
+                if !is_mapped && !self.of_field_type(t).default {
+                    Some(t)
+                } else {
+                    self.non_default_coption_inner(t)
+                }
+            }
+            TypedefFieldType::array(TypedefFieldArray(t, _))
+            | TypedefFieldType::option(t)
+            | TypedefFieldType::vec(t)
+            | TypedefFieldType::hashSet(t)
+            | TypedefFieldType::bTreeSet(t) => self.non_default_coption_inner(t),
+            TypedefFieldType::hashMap(m) | TypedefFieldType::bTreeMap(m) => self
+                .non_default_coption_inner(&m.0)
+                .or_else(|| self.non_default_coption_inner(&m.1)),
+            TypedefFieldType::tuple(t) => t
+                .iter()
+                .find_map(|wrap| self.non_default_coption_inner(&wrap.0)),
+            TypedefFieldType::defined(d) => d.generics().iter().find_map(|g| match g {
+                GenericArg::Type { r#type } => self.non_default_coption_inner(r#type),
+                GenericArg::Const { .. } => None,
+            }),
+            TypedefFieldType::sizePrefixed(p) => self.non_default_coption_inner(&p.r#type),
+            TypedefFieldType::PrimitiveOrPubkey(_) | TypedefFieldType::generic(_) => None,
+        }
+    }
+
+    fn of_size_prefixed(&self, p: &TypedefFieldSizePrefixed) -> Derives {
+        let inner = self.of_field_type(&p.r#type);
+        if p.prefix == LenPrefix::U32 {
+            return inner;
+        }
+        match p.r#type.as_ref() {
+            TypedefFieldType::vec(elem) => Derives {
+                partial_ord: false,
+                ..self.of_field_type(elem).collection()
+            },
+            // U*PrefixString and U*PrefixVec<u8>
+            _ => Derives {
+                partial_ord: matches!(p.r#type.as_ref(), TypedefFieldType::PrimitiveOrPubkey(s) if s == "string"),
+                ..inner.collection()
+            },
+        }
+    }
+}
+
+fn primitive_derives(s: &str) -> Derives {
+    match s {
+        "bool" => Derives::ALL.with_pod(false),
+        "f32" | "f64" => Derives {
+            eq: false,
+            hash: false,
+            ..Derives::ALL
+        },
+        "string" | "bytes" => Derives::ALL.collection(),
+        "u256" | "i256" => Derives {
+            partial_ord: false,
+            ..Derives::ALL
+        },
+        "pubkey" | "publicKey" => Derives::ALL,
+        s if INT_TYPES.contains(&s) => Derives::ALL,
+        _ => Derives::NONE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transitive_derives() {
+        let types: Vec<NamedType> = serde_json::from_str(
+            r#"[
+                { "name": "Leaf", "type": { "kind": "struct", "fields": [{ "name": "a", "type": "u64" }] } },
+                { "name": "Floats", "type": { "kind": "struct", "fields": [{ "name": "f", "type": "f64" }] } },
+                {
+                    "name": "Outer",
+                    "type": {
+                        "kind": "struct",
+                        "fields": [
+                            { "name": "leaf", "type": { "defined": "Leaf" } },
+                            { "name": "names", "type": { "vec": "string" } }
+                        ]
+                    }
+                },
+                {
+                    "name": "Tree",
+                    "type": {
+                        "kind": "enum",
+                        "variants": [
+                            { "name": "Empty" },
+                            { "name": "Node", "fields": [{ "vec": { "defined": "Tree" } }, { "defined": "Floats" }] }
+                        ]
+                    }
+                },
+                {
+                    "name": "BadPod",
+                    "serialization": "bytemuck",
+                    "type": { "kind": "struct", "fields": [{ "name": "b", "type": "bool" }] }
+                }
+            ]"#,
+        )
+        .unwrap();
+        let analysis = DeriveAnalysis::new(&types, &["Leaf".into()], &TypeMap::default());
+        assert_eq!(analysis.of_type("Leaf"), Derives::ALL);
+        assert_eq!(
+            analysis.of_type("Outer"),
+            Derives {
+                copy: false,
+                pod: false,
+                ..Derives::ALL
+            }
+        );
+        assert_eq!(
+            analysis.of_type("Tree"),
+            Derives {
+                copy: false,
+                eq: false,
+                hash: false,
+                default: true,
+                pod: false,
+                partial_ord: true,
+            }
+        );
+        assert!(!analysis.of_type("BadPod").pod);
+        assert_eq!(analysis.of_type("Unknown"), Derives::NONE);
+    }
+
+    #[test]
+    fn test_coption_default() {
+        let types: Vec<NamedType> = serde_json::from_str(
+            r#"[
+                { "name": "Sig", "type": { "kind": "struct", "fields": [{ "name": "s", "type": { "array": ["u8", 64] } }] } },
+                { "name": "Opt", "type": { "kind": "struct", "fields": [{ "name": "o", "type": { "coption": "u64" } }] } }
+            ]"#,
+        )
+        .unwrap();
+        let analysis = DeriveAnalysis::new(&types, &[], &TypeMap::default());
+        assert!(analysis.of_type("Opt").default);
+        let sig: TypedefFieldType =
+            serde_json::from_str(r#"{ "coption": { "defined": "Sig" } }"#).unwrap();
+        assert!(!analysis.of_field_type(&sig).default);
+        assert!(analysis.non_default_coption_inner(&sig).is_some());
+        let vec_u64: TypedefFieldType =
+            serde_json::from_str(r#"{ "vec": { "coption": "u64" } }"#).unwrap();
+        assert!(analysis.non_default_coption_inner(&vec_u64).is_none());
+    }
+}
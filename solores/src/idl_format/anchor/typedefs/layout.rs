@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+
+use crate::type_map::TypeMap;
+
+use super::{
+    DefinedFields, NamedType, TypedefField, TypedefFieldArray, TypedefFieldType, TypedefType,
+};
+
+/// u128 is 8-aligned on SBF but 16-aligned on x86_64 and aarch64 since rust 1.77,
+/// so layouts are computed for both and only agree if there is no padding
+const U128_ALIGNS: [u64; 2] = [8, 16];
+
+/// The in-memory layout of a zero-copy type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Layout {
+    pub size: u64,
+    pub align: u64,
+    /// Padding bytes, including those of nested types.
+    /// The max over the u128 alignments, so `size` is the same on all targets if 0.
+    pub padding: u64,
+}
+
+impl Layout {
+    const fn scalar(size: u64) -> Self {
+        Self {
+            size,
+            align: size,
+            padding: 0,
+        }
+    }
+
+    const fn bytes(size: u64) -> Self {
+        Self {
+            size,
+            align: 1,
+            padding: 0,
+        }
+    }
+
+    /// `repr(C)` or, if `packed`, `repr(packed)` struct of `fields`
+    fn of_struct(fields: &[Self], packed: bool) -> Self {
+        let mut offset = 0u64;
+        let mut align = 1u64;
+        let mut padding = 0;
+        for field in fields {
+            let field_align = if packed { 1 } else { field.align };
+            let field_offset = offset.next_multiple_of(field_align);
+            padding += field_offset - offset + field.padding;
+            offset = field_offset + field.size;
+            align = align.max(field_align);
+        }
+        let size = offset.next_multiple_of(align);
+        Self {
+            size,
+            align,
+            padding: padding + size - offset,
+        }
+    }
+}
+
+/// [`Layout`]s of the IDL's zero-copy (`-z`, `bytemuck` and `bytemuckunsafe`) types,
+/// or why they don't have one
+pub struct LayoutAnalysis {
+    layouts: HashMap<String, Result<Layout, String>>,
+}
+
+impl LayoutAnalysis {
+    /// `zero_copy` are the `-z` type names
+    pub fn new(named_types: &[NamedType], zero_copy: &[String], type_map: &TypeMap) -> Self {
+        let per_align: Vec<_> = U128_ALIGNS
+            .iter()
+            .map(|&u128_align| {
+                let mut calc = LayoutCalc {
+                    named_types,
+                    zero_copy,
+                    type_map,
+                    u128_align,
+                    layouts: HashMap::new(),
+                };
+                named_types
+                    .iter()
+                    .map(|t| (t.name.clone(), calc.named_type(&t.name)))
+                    .collect::<HashMap<_, _>>()
+            })
+            .collect();
+        let (host, others) = per_align.split_last().unwrap();
+        let layouts = host
+            .iter()
+            .map(|(name, layout)| {
+                let layout = others.iter().fold(layout.clone(), |acc, other| {
+                    let (acc, other) = (acc?, other[name].clone()?);
+                    Ok(Layout {
+                        padding: acc.padding.max(other.padding),
+                        ..acc
+                    })
+                });
+                (name.clone(), layout)
+            })
+            .collect();
+        Self { layouts }
+    }
+
+    /// `Err` with the reason if `name` is not a zero-copy type with a known layout
+    pub fn layout(&self, name: &str) -> Result<Layout, String> {
+        self.layouts
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| Err(format!("{} is not in the IDL", name)))
+    }
+}
+
+struct LayoutCalc<'a> {
+    named_types: &'a [NamedType],
+    zero_copy: &'a [String],
+    type_map: &'a TypeMap,
+    u128_align: u64,
+    layouts: HashMap<String, Result<Layout, String>>,
+}
+
+impl LayoutCalc<'_> {
+    fn named_type(&mut self, name: &str) -> Result<Layout, String> {
+        if let Some(res) = self.layouts.get(name) {
+            return res.clone();
+        }
+        // placeholder so that recursive types terminate
+        self.layouts
+            .insert(name.to_owned(), Err(format!("{} contains itself", name)));
+        let res = self.compute_named_type(name);
+        self.layouts.insert(name.to_owned(), res.clone());
+        res
+    }
+
+    fn compute_named_type(&mut self, name: &str) -> Result<Layout, String> {
+        if let Some(path) = self.type_map.path(name) {
+            return Err(format!(
+                "{} is mapped to {}, whose layout is unknown",
+                name, path
+            ));
+        }
+        let named_types = self.named_types;
+        let t = named_types
+            .iter()
+            .find(|t| t.name == name)
+            .ok_or_else(|| format!("{} is not in the IDL", name))?;
+        if !t.is_zero_copy(self.zero_copy) && !t.is_unsafe_bytemuck() {
+            return Err(format!("{} is not a zero-copy type", name));
+        }
+        if !t.generics.is_empty() {
+            return Err(format!("{} is generic", name));
+        }
+        let fields = match &t.r#type {
+            TypedefType::r#struct(s) => s.fields.as_ref(),
+            TypedefType::r#enum(_) => return Err(format!("{} is an enum, which is not Pod", name)),
+            TypedefType::r#type { alias } => return self.field_type(alias),
+        };
+        if let Some(repr) = t
+            .repr
+            .as_ref()
+            .filter(|r| r.kind != "c" && r.kind != "transparent")
+        {
+            return Err(format!(
+                "{} is repr({}), whose layout is unspecified",
+                name, repr.kind
+            ));
+        }
+        let field_layouts = match fields {
+            None => Vec::new(),
+            Some(DefinedFields::Struct(fields)) => fields
+                .iter()
+                .map(|TypedefField { name, r#type, .. }| {
+                    self.field_type(r#type)
+                        .map_err(|e| format!("field {}: {}", name, e))
+                })
+                .collect::<Result<_, _>>()?,
+            Some(DefinedFields::Tuple(fields)) => fields
+                .iter()
+                .enumerate()
+                .map(|(i, wrap)| {
+                    self.field_type(&wrap.0)
+                        .map_err(|e| format!("field {}: {}", i, e))
+                })
+                .collect::<Result<_, _>>()?,
+        };
+        let packed = t.repr.as_ref().is_some_and(|r| r.packed);
+        Ok(Layout::of_struct(&field_layouts, packed))
+    }
+
+    fn field_type(&mut self, ty: &TypedefFieldType) -> Result<Layout, String> {
+        match ty {
+            TypedefFieldType::PrimitiveOrPubkey(s) => self.primitive(s),
+            TypedefFieldType::defined(d) => {
+                if !d.generics().is_empty() {
+                    return Err(format!("{} has generic args", d.name()));
+                }
+                self.named_type(d.name())
+            }
+            TypedefFieldType::generic(g) => Err(format!("generic param {}", g)),
+            TypedefFieldType::array(TypedefFieldArray(elem, len)) => {
+                let len = u64::from(
+                    len.value()
+                        .ok_or_else(|| "array with a generic length".to_owned())?,
+                );
+                let elem = self.field_type(elem)?;
+                Ok(Layout {
+                    size: elem.size * len,
+                    align: elem.align,
+                    padding: elem.padding * len,
+                })
+            }
+            TypedefFieldType::option(_) => Err("Option is not Pod".to_owned()),
+            TypedefFieldType::coption(_) => Err("COption is not Pod".to_owned()),
+            TypedefFieldType::vec(_) => Err("Vec is not zero-copy".to_owned()),
+            TypedefFieldType::hashMap(_)
+            | TypedefFieldType::bTreeMap(_)
+            | TypedefFieldType::hashSet(_)
+            | TypedefFieldType::bTreeSet(_) => Err("collections are not zero-copy".to_owned()),
+            TypedefFieldType::tuple(_) => Err("tuples have an unspecified layout".to_owned()),
+            TypedefFieldType::sizePrefixed(_) => {
+                Err("size-prefixed strings and vecs are not zero-copy".to_owned())
+            }
+        }
+    }
+
+    fn primitive(&self, s: &str) -> Result<Layout, String> {
+        match s {
+            "u8" | "i8" => Ok(Layout::scalar(1)),
+            "u16" | "i16" => Ok(Layout::scalar(2)),
+            "u32" | "i32" | "f32" => Ok(Layout::scalar(4)),
+            "u64" | "i64" | "f64" => Ok(Layout::scalar(8)),
+            "u128" | "i128" => Ok(Layout {
+                align: self.u128_align,
+                ..Layout::scalar(16)
+            }),
+            "u256" | "i256" | "pubkey" | "publicKey" => Ok(Layout::bytes(32)),
+            "bool" => Err("bool is not Pod".to_owned()),
+            "string" => Err("String is not zero-copy".to_owned()),
+            "bytes" => Err("Vec<u8> is not zero-copy".to_owned()),
+            _ => Err(format!("unknown type {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_copy_layouts() {
+        let types: Vec<NamedType> = serde_json::from_str(
+            r#"[
+                {
+                    "name": "Padded",
+                    "type": { "kind": "struct", "fields": [{ "name": "a", "type": "u8" }, { "name": "b", "type": "u64" }] }
+                },
+                {
+                    "name": "Packed",
+                    "serialization": "bytemuck",
+                    "repr": { "kind": "c", "packed": true },
+                    "type": { "kind": "struct", "fields": [{ "name": "a", "type": "u8" }, { "name": "b", "type": "u64" }] }
+                },
+                {
+                    "name": "Wide",
+                    "serialization": "bytemuck",
+                    "type": { "kind": "struct", "fields": [{ "name": "a", "type": "u64" }, { "name": "b", "type": "u128" }] }
+                },
+                {
+                    "name": "Outer",
+                    "serialization": "bytemuck",
+                    "type": {
+                        "kind": "struct",
+                        "fields": [
+                            { "name": "key", "type": "pubkey" },
+                            { "name": "inner", "type": { "array": [{ "defined": "Packed" }, 2] } }
+                        ]
+                    }
+                },
+                { "name": "Plain", "type": { "kind": "struct", "fields": [{ "name": "a", "type": "u8" }] } },
+                {
+                    "name": "Invalid",
+                    "serialization": "bytemuck",
+                    "type": { "kind": "struct", "fields": [{ "name": "plain", "type": { "defined": "Plain" } }] }
+                }
+            ]"#,
+        )
+        .unwrap();
+        let layouts = LayoutAnalysis::new(&types, &["Padded".into()], &TypeMap::default());
+        assert_eq!(
+            layouts.layout("Padded"),
+            Ok(Layout {
+                size: 16,
+                align: 8,
+                padding: 7
+            })
+        );
+        assert_eq!(
+            layouts.layout("Packed"),
+            Ok(Layout {
+                size: 9,
+                align: 1,
+                padding: 0
+            })
+        );
+        // 16-aligned u128 pads after the u64 on x86_64, but not on SBF
+        assert_eq!(layouts.layout("Wide").unwrap().padding, 8);
+        assert_eq!(
+            layouts.layout("Outer"),
+            Ok(Layout {
+                size: 50,
+                align: 1,
+                padding: 0
+            })
+        );
+        assert_eq!(
+            layouts.layout("Invalid"),
+            Err("field plain: Plain is not a zero-copy type".to_owned())
+        );
+    }
+}
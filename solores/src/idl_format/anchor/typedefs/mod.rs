@@ -1,14 +1,25 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, type_map::TypeMap};
 
+mod derives;
+mod layout;
+mod serialized_len;
 mod typedef;
+pub use derives::*;
+pub use layout::*;
+pub use serialized_len::*;
 pub use typedef::*;
 
 pub struct TypedefsCodegenModule<'a> {
     pub cli_args: &'a crate::Args,
+    pub type_map: &'a TypeMap,
+    /// The types to generate
     pub named_types: &'a [NamedType],
+    /// All of the IDL's types, including `named_types`,
+    /// that the derive, layout and serialized len analyses run over
+    pub all_named_types: &'a [NamedType],
 }
 
 impl IdlCodegenModule for TypedefsCodegenModule<'_> {
@@ -20,16 +31,24 @@ impl IdlCodegenModule for TypedefsCodegenModule<'_> {
         let mut res = quote! {
             use borsh::{BorshDeserialize, BorshSerialize};
         };
-        for a in self.named_types {
-            let use_zero_copy = self.cli_args.zero_copy.iter().any(|e| e == &a.name) ||
-                a.serialization.as_ref().map_or(false, |s| s == "bytemuck");
-            let use_unsafe_bytemuck = a.serialization.as_ref().map_or(false, |s| s == "bytemuckunsafe");
-            if use_zero_copy || use_unsafe_bytemuck {
-                res.extend(quote! {
-                    use bytemuck::{Pod, Zeroable};
-                });
-                break;
-            }
+        let analysis = self.derive_analysis();
+        if self.named_types.iter().any(|t| {
+            matches!(t.r#type, TypedefType::r#struct(_))
+                && self.type_map.path(&t.name).is_none()
+                && analysis.of_type(&t.name).pod
+        }) {
+            res.extend(quote! {
+                use bytemuck::{Pod, Zeroable};
+            });
+        }
+        if self
+            .named_types
+            .iter()
+            .any(|t| !t.r#type.builtins().is_empty())
+        {
+            res.extend(quote! {
+                use crate::*;
+            });
         }
         for t in self.named_types {
             if t.r#type.has_pubkey_field() {
@@ -43,9 +62,66 @@ impl IdlCodegenModule for TypedefsCodegenModule<'_> {
     }
 
     fn gen_body(&self) -> TokenStream {
+        let mut res = self.named_types_tokens();
+        res.extend(self.mapped_types_tokens());
+        res
+    }
+}
+
+impl TypedefsCodegenModule<'_> {
+    fn derive_analysis(&self) -> DeriveAnalysis {
+        DeriveAnalysis::new(
+            self.all_named_types,
+            &self.cli_args.zero_copy,
+            self.type_map,
+        )
+    }
+
+    /// The named types that are not mapped to external types
+    pub fn named_types_tokens(&self) -> TokenStream {
+        let analysis = self.derive_analysis();
+        let lens = SerializedLenAnalysis::new(self.all_named_types, self.type_map);
         self.named_types
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
+            .filter(|e| match self.type_map.path(&e.name) {
+                Some(path) => {
+                    log::info!("Skipping typedef {}: mapped to {}", e.name, path);
+                    false
+                }
+                None => true,
+            })
+            .map(|e| {
+                let mut res = e.to_token_stream(self.cli_args, &analysis);
+                res.extend(lens.named_type_tokens(e));
+                res
+            })
+            .collect()
+    }
+
+    /// Re-exports every mapped type under its IDL name, so that references to it resolve
+    fn mapped_types_tokens(&self) -> TokenStream {
+        self.type_map
+            .types
+            .iter()
+            .map(|(name, path)| {
+                let ident = format_ident!("{}", name);
+                let is_use_path = syn::parse_str::<syn::Path>(path).is_ok_and(|p| {
+                    p.segments.len() > 1 && p.segments.iter().all(|s| s.arguments.is_empty())
+                });
+                if !is_use_path {
+                    let ty: syn::Type = syn::parse_str(path).unwrap_or_else(|e| {
+                        log::error!("Invalid type map path {} for {}: {}", path, name, e);
+                        panic!();
+                    });
+                    return quote! { pub type #ident = #ty; };
+                }
+                let path: syn::Path = syn::parse_str(path).unwrap();
+                if path.segments.last().is_some_and(|s| s.ident == ident) {
+                    quote! { pub use #path; }
+                } else {
+                    quote! { pub use #path as #ident; }
+                }
+            })
             .collect()
     }
 }
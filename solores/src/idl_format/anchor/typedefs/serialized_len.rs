@@ -0,0 +1,572 @@
+use std::collections::HashMap;
+
+use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+
+use crate::{type_map::TypeMap, utils::conditional_pascal_case};
+
+use super::{
+    DefinedFields, EnumVariant, LenPrefix, NamedType, TypedefField, TypedefFieldArray,
+    TypedefFieldSizePrefixed, TypedefFieldType, TypedefType,
+};
+
+/// Borsh-serialized length of a type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SerializedLen {
+    Fixed(usize),
+    /// Variable length, with its min
+    Variable(usize),
+}
+
+impl SerializedLen {
+    pub fn min(&self) -> usize {
+        match self {
+            Self::Fixed(n) | Self::Variable(n) => *n,
+        }
+    }
+
+    fn fixed(&self) -> Option<usize> {
+        match self {
+            Self::Fixed(n) => Some(*n),
+            Self::Variable(_) => None,
+        }
+    }
+
+    /// Concatenation of `self` and `other`
+    fn and(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Fixed(a), Self::Fixed(b)) => Self::Fixed(a + b),
+            (a, b) => Self::Variable(a.min() + b.min()),
+        }
+    }
+
+    /// `n` times `self`
+    fn times(self, n: usize) -> Self {
+        match self {
+            Self::Fixed(len) => Self::Fixed(len * n),
+            Self::Variable(min) => Self::Variable(min * n),
+        }
+    }
+
+    fn concat(lens: impl IntoIterator<Item = Option<Self>>) -> Option<Self> {
+        lens.into_iter()
+            .try_fold(Self::Fixed(0), |acc, len| Some(acc.and(len?)))
+    }
+}
+
+/// `fixed + dynamic[0] + dynamic[1] + ...`, a `usize` expr
+#[derive(Default)]
+struct LenExpr {
+    fixed: usize,
+    dynamic: Vec<TokenStream>,
+}
+
+impl LenExpr {
+    fn fixed(fixed: usize) -> Self {
+        Self {
+            fixed,
+            dynamic: Vec::new(),
+        }
+    }
+
+    fn and(mut self, other: Self) -> Self {
+        self.fixed += other.fixed;
+        self.dynamic.extend(other.dynamic);
+        self
+    }
+
+    fn with_dynamic(mut self, expr: TokenStream) -> Self {
+        self.dynamic.push(expr);
+        self
+    }
+}
+
+impl ToTokens for LenExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let dynamic = &self.dynamic;
+        if dynamic.is_empty() {
+            return Literal::usize_unsuffixed(self.fixed).to_tokens(tokens);
+        }
+        if self.fixed != 0 {
+            Literal::usize_unsuffixed(self.fixed).to_tokens(tokens);
+            tokens.extend(quote! { + });
+        }
+        tokens.extend(quote! { #(#dynamic)+* });
+    }
+}
+
+/// The [`SerializedLen`] of each named type, computed transitively through `defined` references.
+///
+/// Types whose length can't be computed, e.g. generic, mapped or packed variable-length types,
+/// have none.
+pub struct SerializedLenAnalysis<'a> {
+    named_types: &'a [NamedType],
+    lens: HashMap<String, Option<SerializedLen>>,
+}
+
+impl<'a> SerializedLenAnalysis<'a> {
+    pub fn new(named_types: &'a [NamedType], type_map: &TypeMap) -> Self {
+        let mut calc = LenCalc {
+            named_types,
+            type_map,
+            lens: HashMap::new(),
+        };
+        for t in named_types {
+            calc.named_type(&t.name);
+        }
+        Self {
+            named_types,
+            lens: calc.lens,
+        }
+    }
+
+    pub fn of_type(&self, name: &str) -> Option<SerializedLen> {
+        self.lens.get(name).copied().flatten()
+    }
+
+    fn of_field_type(&self, ty: &TypedefFieldType) -> Option<SerializedLen> {
+        field_type_len(ty, &mut |name| self.of_type(name))
+    }
+
+    /// The named fields of struct `t` that are serialized at a fixed offset, with their offsets:
+    /// all fields up to and including the first one that is not fixed-size.
+    pub fn fixed_offset_fields<'t>(&self, t: &'t NamedType) -> Vec<(&'t TypedefField, usize)> {
+        let TypedefType::r#struct(s) = &t.r#type else {
+            return Vec::new();
+        };
+        let mut res = Vec::new();
+        let mut offset = 0;
+        for field in s.named_fields() {
+            res.push((field, offset));
+            match self
+                .of_field_type(&field.r#type)
+                .and_then(|len| len.fixed())
+            {
+                Some(n) => offset += n,
+                None => break,
+            }
+        }
+        res
+    }
+
+    /// Expr of the serialized length of `access`, an expr of type `ty` or a reference to it.
+    /// None if `ty`'s length can't be computed.
+    fn len_expr(&self, ty: &TypedefFieldType, access: TokenStream) -> Option<LenExpr> {
+        let len = self.of_field_type(ty)?;
+        if let Some(n) = len.fixed() {
+            return Some(LenExpr::fixed(n));
+        }
+        let res = match ty {
+            TypedefFieldType::PrimitiveOrPubkey(_) => {
+                LenExpr::fixed(4).with_dynamic(quote! { #access.len() })
+            }
+            TypedefFieldType::defined(d) => {
+                // aliases don't get a serialized_len()
+                let alias = self.named_types.iter().find_map(|t| match &t.r#type {
+                    TypedefType::r#type { alias } if t.name == d.name() => Some(alias),
+                    _ => None,
+                });
+                match alias {
+                    Some(alias) => self.len_expr(alias, access)?,
+                    None => LenExpr::default().with_dynamic(quote! { #access.serialized_len() }),
+                }
+            }
+            TypedefFieldType::array(TypedefFieldArray(elem, _)) => {
+                LenExpr::default().with_dynamic(self.sum_expr(elem, &access)?)
+            }
+            TypedefFieldType::option(t) => {
+                let some = match self.of_field_type(t)?.fixed() {
+                    Some(n) => {
+                        let n = Literal::usize_unsuffixed(n);
+                        quote! { if #access.is_some() { #n } else { 0 } }
+                    }
+                    None => {
+                        let inner = self.len_expr(t, quote! { x })?;
+                        quote! { #access.as_ref().map_or(0, |x| #inner) }
+                    }
+                };
+                LenExpr::fixed(1).with_dynamic(some)
+            }
+            TypedefFieldType::vec(t)
+            | TypedefFieldType::hashSet(t)
+            | TypedefFieldType::bTreeSet(t) => {
+                LenExpr::fixed(4).with_dynamic(self.sum_expr(t, &access)?)
+            }
+            TypedefFieldType::hashMap(m) | TypedefFieldType::bTreeMap(m) => {
+                let entries = match (
+                    self.of_field_type(&m.0)?.fixed(),
+                    self.of_field_type(&m.1)?.fixed(),
+                ) {
+                    (Some(k), Some(v)) => mul_len_expr(&access, k + v),
+                    _ => {
+                        let k = self.len_expr(&m.0, quote! { k })?;
+                        let v = self.len_expr(&m.1, quote! { v })?;
+                        let entry = k.and(v);
+                        quote! { #access.iter().map(|(k, v)| #entry).sum::<usize>() }
+                    }
+                };
+                LenExpr::fixed(4).with_dynamic(entries)
+            }
+            TypedefFieldType::tuple(t) => t
+                .iter()
+                .enumerate()
+                .map(|(i, wrap)| {
+                    let i = syn::Index::from(i);
+                    self.len_expr(&wrap.0, quote! { #access.#i })
+                })
+                .try_fold(LenExpr::default(), |acc, e| Some(acc.and(e?)))?,
+            TypedefFieldType::sizePrefixed(p) => self.size_prefixed_len_expr(p, &access)?,
+            TypedefFieldType::generic(_) | TypedefFieldType::coption(_) => return None,
+        };
+        Some(res)
+    }
+
+    fn size_prefixed_len_expr(
+        &self,
+        p: &TypedefFieldSizePrefixed,
+        access: &TokenStream,
+    ) -> Option<LenExpr> {
+        let prefix = match p.prefix {
+            LenPrefix::ShortU16 => LenExpr::default().with_dynamic(quote! {
+                match #access.len() {
+                    0..=0x7f => 1,
+                    0x80..=0x3fff => 2,
+                    _ => 3,
+                }
+            }),
+            prefix => LenExpr::fixed(prefix_min_len(prefix)),
+        };
+        let contents = match p.r#type.as_ref() {
+            TypedefFieldType::vec(elem) => self.sum_expr(elem, access)?,
+            _ => quote! { #access.len() },
+        };
+        Some(prefix.with_dynamic(contents))
+    }
+
+    /// Total length of the elements of the iterable `access`
+    fn sum_expr(&self, elem: &TypedefFieldType, access: &TokenStream) -> Option<TokenStream> {
+        match self.of_field_type(elem)?.fixed() {
+            Some(n) => Some(mul_len_expr(access, n)),
+            None => {
+                let inner = self.len_expr(elem, quote! { x })?;
+                Some(quote! { #access.iter().map(|x| #inner).sum::<usize>() })
+            }
+        }
+    }
+
+    /// `pub const X_LEN: usize` for fixed-size types,
+    /// `MIN_LEN` and `serialized_len()` for variable-size ones
+    pub fn named_type_tokens(&self, t: &NamedType) -> TokenStream {
+        let name = format_ident!("{}", conditional_pascal_case(&t.name));
+        match self.of_type(&t.name) {
+            None => TokenStream::new(),
+            Some(SerializedLen::Fixed(n)) => {
+                let len_ident = format_ident!("{}_LEN", t.name.to_shouty_snake_case());
+                let n = Literal::usize_unsuffixed(n);
+                quote! {
+                    pub const #len_ident: usize = #n;
+                }
+            }
+            Some(SerializedLen::Variable(min)) => {
+                let body = match &t.r#type {
+                    TypedefType::r#struct(s) => self
+                        .fields_len_expr(s.fields.as_ref(), true)
+                        .map(|e| e.into_token_stream()),
+                    TypedefType::r#enum(e) => self.enum_len_expr(&e.variants),
+                    TypedefType::r#type { .. } => return TokenStream::new(),
+                };
+                let Some(body) = body else {
+                    return TokenStream::new();
+                };
+                let min = Literal::usize_unsuffixed(min);
+                let doc = format!(" Borsh-serialized length of this [`{}`]", name);
+                quote! {
+                    impl #name {
+                        pub const MIN_LEN: usize = #min;
+
+                        #[doc = #doc]
+                        pub fn serialized_len(&self) -> usize {
+                            #body
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sum of the fields' lengths, accessed on `self` if `on_self`, else as bindings
+    fn fields_len_expr(&self, fields: Option<&DefinedFields>, on_self: bool) -> Option<LenExpr> {
+        let exprs = match fields {
+            None => Vec::new(),
+            Some(DefinedFields::Struct(fields)) => fields
+                .iter()
+                .map(|f| {
+                    let ident = format_ident!("{}", f.name.to_snake_case());
+                    let access = if on_self {
+                        quote! { self.#ident }
+                    } else {
+                        ident.into_token_stream()
+                    };
+                    self.len_expr(&f.r#type, access)
+                })
+                .collect::<Option<Vec<_>>>()?,
+            Some(DefinedFields::Tuple(fields)) => fields
+                .iter()
+                .enumerate()
+                .map(|(i, wrap)| {
+                    let access = if on_self {
+                        let i = syn::Index::from(i);
+                        quote! { self.#i }
+                    } else {
+                        format_ident!("f{}", i).into_token_stream()
+                    };
+                    self.len_expr(&wrap.0, access)
+                })
+                .collect::<Option<Vec<_>>>()?,
+        };
+        Some(
+            exprs
+                .into_iter()
+                .fold(LenExpr::default(), |acc, e| acc.and(e)),
+        )
+    }
+
+    fn enum_len_expr(&self, variants: &[EnumVariant]) -> Option<TokenStream> {
+        let arms = variants
+            .iter()
+            .map(|v| {
+                let v_ident = format_ident!("{}", v.name.to_pascal_case());
+                let pattern = self.variant_pattern(v);
+                let len = LenExpr::fixed(1).and(self.fields_len_expr(v.fields.as_ref(), false)?);
+                Some(quote! { Self::#v_ident #pattern => #len })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(quote! {
+            match self {
+                #(#arms),*
+            }
+        })
+    }
+
+    /// Binds only the variable-length fields, so that there are no unused bindings
+    fn variant_pattern(&self, v: &EnumVariant) -> TokenStream {
+        let is_variable = |ty: &TypedefFieldType| {
+            self.of_field_type(ty)
+                .is_some_and(|len| len.fixed().is_none())
+        };
+        match &v.fields {
+            None => TokenStream::new(),
+            Some(DefinedFields::Struct(fields)) => {
+                let idents = fields
+                    .iter()
+                    .filter(|f| is_variable(&f.r#type))
+                    .map(|f| format_ident!("{}", f.name.to_snake_case()));
+                quote! { { #(#idents,)* .. } }
+            }
+            Some(DefinedFields::Tuple(fields)) => {
+                let bindings = fields.iter().enumerate().map(|(i, wrap)| {
+                    if is_variable(&wrap.0) {
+                        format_ident!("f{}", i).into_token_stream()
+                    } else {
+                        quote! { _ }
+                    }
+                });
+                quote! { (#(#bindings),*) }
+            }
+        }
+    }
+}
+
+struct LenCalc<'a> {
+    named_types: &'a [NamedType],
+    type_map: &'a TypeMap,
+    lens: HashMap<String, Option<SerializedLen>>,
+}
+
+impl LenCalc<'_> {
+    fn named_type(&mut self, name: &str) -> Option<SerializedLen> {
+        if let Some(res) = self.lens.get(name) {
+            return *res;
+        }
+        // recursive references must be behind a vec, option or map, whose min doesn't depend on it
+        self.lens
+            .insert(name.to_owned(), Some(SerializedLen::Variable(0)));
+        let res = self.compute_named_type(name);
+        self.lens.insert(name.to_owned(), res);
+        res
+    }
+
+    fn compute_named_type(&mut self, name: &str) -> Option<SerializedLen> {
+        if self.type_map.path(name).is_some() {
+            return None;
+        }
+        let named_types = self.named_types;
+        let t = named_types.iter().find(|t| t.name == name)?;
+        if !t.generics.is_empty() {
+            return None;
+        }
+        let res = match &t.r#type {
+            TypedefType::r#struct(s) => self.of_fields(s.fields.as_ref())?,
+            TypedefType::r#enum(e) => {
+                let variants = e
+                    .variants
+                    .iter()
+                    .map(|v| self.of_fields(v.fields.as_ref()))
+                    .collect::<Option<Vec<_>>>()?;
+                let first = variants.first()?;
+                if variants.iter().all(|v| v.fixed().is_some() && v == first) {
+                    SerializedLen::Fixed(1 + first.min())
+                } else {
+                    SerializedLen::Variable(1 + variants.iter().map(|v| v.min()).min()?)
+                }
+            }
+            TypedefType::r#type { alias } => self.of_field_type(alias)?,
+        };
+        // serialized_len() would take references to the packed fields
+        let is_packed = t.repr.as_ref().is_some_and(|r| r.packed);
+        if is_packed && res.fixed().is_none() {
+            return None;
+        }
+        Some(res)
+    }
+
+    fn of_fields(&mut self, fields: Option<&DefinedFields>) -> Option<SerializedLen> {
+        let types = fields.map_or(Vec::new(), |f| f.types());
+        SerializedLen::concat(types.into_iter().map(|ty| self.of_field_type(ty)))
+    }
+
+    fn of_field_type(&mut self, ty: &TypedefFieldType) -> Option<SerializedLen> {
+        field_type_len(ty, &mut |name| self.named_type(name))
+    }
+}
+
+/// `named_type` returns the length of `defined` types
+fn field_type_len(
+    ty: &TypedefFieldType,
+    named_type: &mut impl FnMut(&str) -> Option<SerializedLen>,
+) -> Option<SerializedLen> {
+    let mut of = |ty: &TypedefFieldType| field_type_len(ty, named_type);
+    match ty {
+        TypedefFieldType::PrimitiveOrPubkey(s) => primitive_len(s),
+        TypedefFieldType::defined(d) => {
+            if !d.generics().is_empty() {
+                return None;
+            }
+            named_type(d.name())
+        }
+        TypedefFieldType::generic(_) => None,
+        TypedefFieldType::array(TypedefFieldArray(elem, len)) => {
+            let len = usize::try_from(len.value()?).ok()?;
+            Some(of(elem)?.times(len))
+        }
+        TypedefFieldType::option(t) => {
+            of(t)?;
+            Some(SerializedLen::Variable(1))
+        }
+        // the value is always serialized, so only fixed-size values are supported
+        TypedefFieldType::coption(t) => Some(SerializedLen::Fixed(4 + of(t)?.fixed()?)),
+        TypedefFieldType::vec(t) | TypedefFieldType::hashSet(t) | TypedefFieldType::bTreeSet(t) => {
+            of(t)?;
+            Some(SerializedLen::Variable(4))
+        }
+        TypedefFieldType::hashMap(m) | TypedefFieldType::bTreeMap(m) => {
+            of(&m.0)?;
+            of(&m.1)?;
+            Some(SerializedLen::Variable(4))
+        }
+        TypedefFieldType::tuple(t) => SerializedLen::concat(t.iter().map(|wrap| of(&wrap.0))),
+        TypedefFieldType::sizePrefixed(p) => {
+            if let TypedefFieldType::vec(elem) = p.r#type.as_ref() {
+                of(elem)?;
+            }
+            Some(SerializedLen::Variable(prefix_min_len(p.prefix)))
+        }
+    }
+}
+
+/// `access.len() * n`
+fn mul_len_expr(access: &TokenStream, n: usize) -> TokenStream {
+    match n {
+        1 => quote! { #access.len() },
+        n => {
+            let n = Literal::usize_unsuffixed(n);
+            quote! { #access.len() * #n }
+        }
+    }
+}
+
+fn prefix_min_len(prefix: LenPrefix) -> usize {
+    match prefix {
+        LenPrefix::U8 | LenPrefix::ShortU16 => 1,
+        LenPrefix::U16 => 2,
+        LenPrefix::U32 => 4,
+        LenPrefix::U64 => 8,
+    }
+}
+
+fn primitive_len(s: &str) -> Option<SerializedLen> {
+    let n = match s {
+        "bool" | "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" | "f32" => 4,
+        "u64" | "i64" | "f64" => 8,
+        "u128" | "i128" => 16,
+        "u256" | "i256" | "pubkey" | "publicKey" => 32,
+        "string" | "bytes" => return Some(SerializedLen::Variable(4)),
+        _ => return None,
+    };
+    Some(SerializedLen::Fixed(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialized_lens() {
+        let types: Vec<NamedType> = serde_json::from_str(
+            r#"[
+                {
+                    "name": "Fixed",
+                    "type": { "kind": "struct", "fields": [{ "name": "a", "type": "u8" }, { "name": "b", "type": { "array": ["pubkey", 2] } }] }
+                },
+                {
+                    "name": "Tree",
+                    "type": {
+                        "kind": "struct",
+                        "fields": [
+                            { "name": "value", "type": { "defined": "Fixed" } },
+                            { "name": "children", "type": { "vec": { "defined": "Tree" } } },
+                            { "name": "parent", "type": { "option": { "defined": "Tree" } } }
+                        ]
+                    }
+                },
+                {
+                    "name": "Tagged",
+                    "type": { "kind": "enum", "variants": [{ "name": "A", "fields": ["u64"] }, { "name": "B", "fields": ["u32", "u32"] }] }
+                },
+                {
+                    "name": "Mixed",
+                    "type": { "kind": "enum", "variants": [{ "name": "A" }, { "name": "B", "fields": ["string"] }] }
+                }
+            ]"#,
+        )
+        .unwrap();
+        let lens = SerializedLenAnalysis::new(&types, &TypeMap::default());
+        assert_eq!(lens.of_type("Fixed"), Some(SerializedLen::Fixed(65)));
+        assert_eq!(lens.of_type("Tree"), Some(SerializedLen::Variable(70)));
+        assert_eq!(lens.of_type("Tagged"), Some(SerializedLen::Fixed(9)));
+        assert_eq!(lens.of_type("Mixed"), Some(SerializedLen::Variable(1)));
+        assert_eq!(lens.of_type("Missing"), None);
+
+        // fields after the first variable-length one have no fixed offset
+        let offsets: Vec<_> = lens
+            .fixed_offset_fields(&types[1])
+            .into_iter()
+            .map(|(f, offset)| (f.name.as_str(), offset))
+            .collect();
+        assert_eq!(offsets, [("value", 0), ("children", 65)]);
+        assert!(lens.fixed_offset_fields(&types[2]).is_empty());
+    }
+}
@@ -1,16 +1,19 @@
 #![allow(non_camel_case_types)]
 
-use std::str::FromStr;
+use std::{collections::BTreeSet, str::FromStr};
 
 use heck::{ToPascalCase, ToSnakeCase};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use serde::{Deserialize, Deserializer};
+use serde::Deserialize;
 use syn::Index;
 use void::Void;
 
+use super::DeriveAnalysis;
+use crate::idl_format::anchor::builtins::Builtin;
 use crate::utils::{
-    conditional_pascal_case, primitive_or_pubkey_to_token, string_or_struct, PUBKEY_TOKEN,
+    conditional_pascal_case, docs_tokens, primitive_or_pubkey_to_token, string_or_struct,
+    PUBKEY_TOKEN,
 };
 
 // Custom struct to handle both string and object formats for "defined"
@@ -18,30 +21,61 @@ use crate::utils::{
 #[serde(untagged)]
 pub enum DefinedType {
     String(String),
-    Object { name: String },
+    Object {
+        name: String,
+        #[serde(default)]
+        generics: Vec<GenericArg>,
+    },
 }
 
 impl DefinedType {
     pub fn name(&self) -> &str {
         match self {
             DefinedType::String(s) => s,
-            DefinedType::Object { name } => name,
+            DefinedType::Object { name, .. } => name,
+        }
+    }
+
+    pub fn generics(&self) -> &[GenericArg] {
+        match self {
+            DefinedType::String(_) => &[],
+            DefinedType::Object { generics, .. } => generics,
         }
     }
 }
 
-// Custom deserializer for the defined field
-fn deserialize_defined<'de, D>(deserializer: D) -> Result<String, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let defined_type = DefinedType::deserialize(deserializer)?;
-    Ok(defined_type.name().to_string())
+impl From<String> for DefinedType {
+    fn from(name: String) -> Self {
+        Self::String(name)
+    }
+}
+
+/// Argument of a generic defined type reference
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum GenericArg {
+    Type {
+        #[serde(deserialize_with = "string_or_struct")]
+        r#type: TypedefFieldType,
+    },
+    Const {
+        value: String,
+    },
+}
+
+/// Generic parameter of a typedef
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum GenericDef {
+    Type { name: String },
+    Const { name: String, r#type: String },
 }
 
 #[derive(Deserialize)]
 pub struct NamedType {
     pub name: String,
+    #[serde(default)]
+    pub generics: Vec<GenericDef>,
     pub r#type: TypedefType,
     pub docs: Option<Vec<String>>,
     pub serialization: Option<String>,
@@ -56,34 +90,105 @@ pub struct Repr {
 }
 
 impl NamedType {
-    pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
+    /// Passed with `-z` or `serialization: "bytemuck"`, derives `Pod` and `Zeroable`
+    pub fn is_zero_copy(&self, zero_copy: &[String]) -> bool {
+        zero_copy.iter().any(|e| e == &self.name)
+            || self.serialization.as_deref() == Some("bytemuck")
+    }
+
+    /// `serialization: "bytemuckunsafe"`, impls `Pod` and `Zeroable` with `unsafe impl`
+    pub fn is_unsafe_bytemuck(&self) -> bool {
+        self.serialization.as_deref() == Some("bytemuckunsafe")
+    }
+
+    /// `<T, const N: usize>`
+    pub fn generic_params_tokens(&self) -> TokenStream {
+        if self.generics.is_empty() {
+            return quote! {};
+        }
+        let params = self.generics.iter().map(|g| match g {
+            GenericDef::Type { name } => format_ident!("{}", name).into_token_stream(),
+            GenericDef::Const { name, r#type } => {
+                let name = format_ident!("{}", name);
+                let ty = format_ident!("{}", r#type);
+                quote! { const #name: #ty }
+            }
+        });
+        quote! { <#(#params),*> }
+    }
+
+    /// `<T, N>`
+    pub fn generic_args_tokens(&self) -> TokenStream {
+        if self.generics.is_empty() {
+            return quote! {};
+        }
+        let args = self.generics.iter().map(|g| match g {
+            GenericDef::Type { name } | GenericDef::Const { name, .. } => format_ident!("{}", name),
+        });
+        quote! { <#(#args),*> }
+    }
+
+    /// [`Self::generic_params_tokens`] with type params bounded by `bound`
+    pub fn bounded_generic_params_tokens(&self, bound: TokenStream) -> TokenStream {
+        if self.generics.is_empty() {
+            return quote! {};
+        }
+        let params = self.generics.iter().map(|g| match g {
+            GenericDef::Type { name } => {
+                let name = format_ident!("{}", name);
+                quote! { #name: #bound }
+            }
+            GenericDef::Const { name, r#type } => {
+                let name = format_ident!("{}", name);
+                let ty = format_ident!("{}", r#type);
+                quote! { const #name: #ty }
+            }
+        });
+        quote! { <#(#params),*> }
+    }
+
+    pub fn to_token_stream(
+        &self,
+        cli_args: &crate::Args,
+        analysis: &DeriveAnalysis,
+    ) -> TokenStream {
         let name = format_ident!("{}", conditional_pascal_case(&self.name));
+        let generic_params = self.generic_params_tokens();
+        let generic_args = self.generic_args_tokens();
+        let docs = docs_tokens(self.docs.as_deref());
+        analysis.check_coption_defaults(&self.name, self.r#type.field_types());
         // rust enums cannot impl Pod due to illegal bitpatterns
         let typedef_struct = match &self.r#type {
             TypedefType::r#struct(typedef_struct) => typedef_struct,
+            TypedefType::r#type { alias } => {
+                return quote! {
+                    #docs
+                    pub type #name #generic_params = #alias;
+                }
+            }
             TypedefType::r#enum(typedef_enum) => {
+                let derives = analysis.of_type(&self.name).with_pod(false);
+                let derive = derives.derive_tokens(true);
+                let variants = typedef_enum.variants.iter().enumerate().map(|(i, v)| {
+                    if i == 0 && derives.default {
+                        quote! { #[default] #v }
+                    } else {
+                        quote! { #v }
+                    }
+                });
                 return quote! {
-                    #[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+                    #docs
+                    #derive
                     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-                    pub enum #name {
-                        #typedef_enum
+                    pub enum #name #generic_params {
+                        #(#variants),*
                     }
-                }
+                };
             }
         };
 
-        // Check if this type should use zero-copy derives from CLI args or IDL serialization field
-        let use_zero_copy = cli_args.zero_copy.iter().any(|e| e == &self.name)
-            || self
-                .serialization
-                .as_ref()
-                .map_or(false, |s| s == "bytemuck");
-        
-        // Check if this type should use unsafe bytemuck
-        let use_unsafe_bytemuck = self
-            .serialization
-            .as_ref()
-            .map_or(false, |s| s == "bytemuckunsafe");
+        let use_zero_copy = self.is_zero_copy(&cli_args.zero_copy);
+        let use_unsafe_bytemuck = self.is_unsafe_bytemuck();
 
         // Generate repr attribute based on CLI args or IDL repr field
         let repr_attr = if let Some(repr) = &self.repr {
@@ -100,38 +205,106 @@ impl NamedType {
             TokenStream::new()
         };
 
-        let derive = if use_zero_copy {
-            quote! {
-                #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Pod, Copy, Zeroable)]
-            }
-        } else if use_unsafe_bytemuck {
-            quote! {
-                #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq, Copy)]
-            }
+        // derive(BorshSerialize) takes references to fields, which is not allowed for
+        // packed structs, so we impl it manually by copying the fields out instead
+        let is_packed = self.repr.as_ref().is_some_and(|r| r.packed);
+
+        let derives = analysis.of_type(&self.name);
+        let size_assert = if use_zero_copy || use_unsafe_bytemuck {
+            self.zero_copy_size_assert(analysis)
         } else {
+            TokenStream::new()
+        };
+        if use_zero_copy && !derives.pod {
+            log::warn!(
+                "{} is not Pod, generating it without Pod and Zeroable",
+                self.name
+            );
+        }
+        // unsafe bytemuck types impl Pod and Zeroable manually below
+        let derive = derives
+            .with_pod(derives.pod && !use_unsafe_bytemuck)
+            .derive_tokens(!is_packed);
+
+        let unsafe_impls = if use_unsafe_bytemuck {
+            let pod_params = self.bounded_generic_params_tokens(quote! { Pod });
+            let zeroable_params = self.bounded_generic_params_tokens(quote! { Zeroable });
             quote! {
-                #[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+                unsafe impl #pod_params Pod for #name #generic_args {}
+                unsafe impl #zeroable_params Zeroable for #name #generic_args {}
             }
+        } else {
+            TokenStream::new()
         };
 
-        let unsafe_impls = if use_unsafe_bytemuck {
+        let packed_borsh_serialize_impl = if is_packed {
+            let (field_idents, destructure): (Vec<_>, _) = match &typedef_struct.fields {
+                Some(DefinedFields::Struct(fields)) => {
+                    let idents: Vec<_> = fields
+                        .iter()
+                        .map(|f| format_ident!("{}", f.name.to_snake_case()))
+                        .collect();
+                    let destructure = quote! { Self { #(#idents),* } };
+                    (idents, destructure)
+                }
+                Some(DefinedFields::Tuple(fields)) => {
+                    let idents: Vec<_> =
+                        (0..fields.len()).map(|i| format_ident!("f{}", i)).collect();
+                    let destructure = quote! { Self(#(#idents),*) };
+                    (idents, destructure)
+                }
+                None => (Vec::new(), quote! { Self }),
+            };
+            let impl_params = self.bounded_generic_params_tokens(quote! { BorshSerialize + Copy });
             quote! {
-                unsafe impl Pod for #name {}
-                unsafe impl Zeroable for #name {}
+                impl #impl_params BorshSerialize for #name #generic_args {
+                    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                        let #destructure = *self;
+                        #(#field_idents.serialize(writer)?;)*
+                        Ok(())
+                    }
+                }
             }
         } else {
             TokenStream::new()
         };
 
         quote! {
+            #docs
             #repr_attr
             #derive
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-            pub struct #name {
-                #typedef_struct
-            }
-            
+            pub struct #name #generic_params #typedef_struct
+
             #unsafe_impls
+            #packed_borsh_serialize_impl
+            #size_assert
+        }
+    }
+
+    /// `const _: () = assert!(size_of::<T>() == N);` for zero-copy types
+    /// whose layout is the same on all targets, warns for the others
+    fn zero_copy_size_assert(&self, analysis: &DeriveAnalysis) -> TokenStream {
+        match analysis.layout(&self.name) {
+            Ok(layout) if layout.padding == 0 => {
+                let name = format_ident!("{}", conditional_pascal_case(&self.name));
+                let size = Literal::u64_unsuffixed(layout.size);
+                quote! {
+                    const _: () = assert!(std::mem::size_of::<#name>() == #size);
+                }
+            }
+            Ok(layout) => {
+                log::warn!(
+                    "{} is not zero-copy: it has {} padding bytes on some targets",
+                    self.name,
+                    layout.padding
+                );
+                TokenStream::new()
+            }
+            Err(reason) => {
+                log::warn!("{} is not zero-copy: {}", self.name, reason);
+                TokenStream::new()
+            }
         }
     }
 }
@@ -141,11 +314,26 @@ impl NamedType {
 pub enum TypedefType {
     r#struct(TypedefStruct),
     r#enum(TypedefEnum),
+    r#type {
+        #[serde(deserialize_with = "string_or_struct")]
+        alias: TypedefFieldType,
+    },
 }
 
 #[derive(Deserialize)]
 pub struct TypedefStruct {
-    pub fields: Vec<TypedefField>,
+    /// None for unit structs
+    pub fields: Option<DefinedFields>,
+}
+
+impl TypedefStruct {
+    /// Empty for tuple and unit structs
+    pub fn named_fields(&self) -> &[TypedefField] {
+        match &self.fields {
+            Some(DefinedFields::Struct(v)) => v,
+            _ => &[],
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -164,27 +352,104 @@ pub enum TypedefFieldType {
     PrimitiveOrPubkey(String),
 
     // rest handled by string_or_struct's struct
-    #[serde(deserialize_with = "deserialize_defined")]
-    defined(String),
+    defined(DefinedType),
     array(TypedefFieldArray),
 
+    /// Generic type param of the enclosing typedef
+    generic(String),
+
     #[serde(deserialize_with = "string_or_struct")]
     option(Box<TypedefFieldType>),
 
     #[serde(deserialize_with = "string_or_struct")]
     vec(Box<TypedefFieldType>),
+
+    tuple(Vec<TypedefFieldTypeWrap>),
+
+    hashMap(TypedefFieldMap),
+    bTreeMap(TypedefFieldMap),
+
+    #[serde(deserialize_with = "string_or_struct")]
+    hashSet(Box<TypedefFieldType>),
+
+    #[serde(deserialize_with = "string_or_struct")]
+    bTreeSet(Box<TypedefFieldType>),
+
+    /// u32-tagged fixed-size option, generated as the `COption` builtin
+    #[serde(deserialize_with = "string_or_struct")]
+    coption(Box<TypedefFieldType>),
+
+    sizePrefixed(TypedefFieldSizePrefixed),
 }
 
+/// `string`, `bytes` or `{ "vec": T }` with a length prefix other than borsh's u32,
+/// generated as one of the prefixed builtins
+#[derive(Deserialize)]
+pub struct TypedefFieldSizePrefixed {
+    #[serde(deserialize_with = "string_or_struct")]
+    pub r#type: Box<TypedefFieldType>,
+    pub prefix: LenPrefix,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub enum LenPrefix {
+    #[serde(rename = "u8")]
+    U8,
+    #[serde(rename = "u16")]
+    U16,
+    #[serde(rename = "u32")]
+    U32,
+    #[serde(rename = "u64")]
+    U64,
+    /// compact-u16 as in solana's `short_vec`
+    #[serde(rename = "shortU16")]
+    ShortU16,
+}
+
+/// `[key, value]`
+#[derive(Deserialize)]
+pub struct TypedefFieldMap(
+    #[serde(deserialize_with = "string_or_struct")] pub Box<TypedefFieldType>,
+    #[serde(deserialize_with = "string_or_struct")] pub Box<TypedefFieldType>,
+);
+
 #[derive(Deserialize)]
 pub struct TypedefFieldArray(
-    #[serde(deserialize_with = "string_or_struct")] Box<TypedefFieldType>,
-    u32, // borsh spec says array sizes are u32
+    #[serde(deserialize_with = "string_or_struct")] pub Box<TypedefFieldType>,
+    pub ArrayLen,
 );
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum ArrayLen {
+    Value(u32), // borsh spec says array sizes are u32
+    /// Const generic param of the enclosing typedef
+    Generic {
+        generic: String,
+    },
+}
+
+impl ArrayLen {
+    pub fn value(&self) -> Option<u32> {
+        match self {
+            Self::Value(n) => Some(*n),
+            Self::Generic { .. } => None,
+        }
+    }
+}
+
+impl From<u32> for ArrayLen {
+    fn from(n: u32) -> Self {
+        Self::Value(n)
+    }
+}
+
 /// serde newtype workaround for use in Vec<TypedefFieldType>:
 /// https://github.com/serde-rs/serde/issues/723#issuecomment-871016087
 #[derive(Deserialize)]
-pub struct TypedefFieldTypeWrap(#[serde(deserialize_with = "string_or_struct")] TypedefFieldType);
+pub struct TypedefFieldTypeWrap(
+    #[serde(deserialize_with = "string_or_struct")] pub TypedefFieldType,
+);
 
 impl FromStr for TypedefFieldType {
     type Err = Void;
@@ -207,14 +472,22 @@ pub struct TypedefEnum {
     pub variants: Vec<EnumVariant>,
 }
 
+/// Named or unnamed fields of a struct or enum variant
 #[derive(Deserialize)]
 #[serde(untagged)]
-pub enum EnumVariantFields {
+pub enum DefinedFields {
     Struct(Vec<TypedefField>),
     Tuple(Vec<TypedefFieldTypeWrap>),
 }
 
-impl EnumVariantFields {
+impl DefinedFields {
+    pub fn types(&self) -> Vec<&TypedefFieldType> {
+        match self {
+            Self::Struct(v) => v.iter().map(|f| &f.r#type).collect(),
+            Self::Tuple(v) => v.iter().map(|wrap| &wrap.0).collect(),
+        }
+    }
+
     pub fn has_pubkey(&self) -> bool {
         match self {
             Self::Struct(v) => v.iter().any(|f| f.r#type.is_or_has_pubkey()),
@@ -233,41 +506,61 @@ impl EnumVariantFields {
 #[derive(Deserialize)]
 pub struct EnumVariant {
     pub name: String,
-    pub fields: Option<EnumVariantFields>,
+    pub fields: Option<DefinedFields>,
+    pub docs: Option<Vec<String>>,
 }
 
+/// `{ pub a: A }`, `(pub A);` or `;`
 impl ToTokens for TypedefStruct {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let typedef_fields = self.fields.iter().map(|f| {
-            // Check if this field needs serde_big_array attribute
-            let serde_attr = if let TypedefFieldType::array(TypedefFieldArray(_, len)) = &f.r#type {
-                if *len > 32 {
-                    quote! { #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))] }
-                } else {
-                    TokenStream::new()
-                }
-            } else {
-                TokenStream::new()
-            };
-
-            let name = format_ident!("{}", f.name.to_snake_case());
-            let ty = &f.r#type;
-            quote! {
-                #serde_attr
-                pub #name: #ty
+        match &self.fields {
+            Some(DefinedFields::Struct(v)) => {
+                let typedef_fields = v.iter().map(|f| f.to_pub_field_tokens());
+                tokens.extend(quote! {
+                    {
+                        #(#typedef_fields),*
+                    }
+                })
             }
-        });
-        tokens.extend(quote! {
-            #(#typedef_fields),*
-        })
+            Some(DefinedFields::Tuple(v)) => {
+                let typedef_fields = v.iter().map(|wrap| {
+                    let serde_attr = wrap.0.serde_attr_tokens();
+                    let ty = &wrap.0;
+                    quote! { #serde_attr pub #ty }
+                });
+                tokens.extend(quote! {
+                    (#(#typedef_fields),*);
+                })
+            }
+            None => tokens.extend(quote! { ; }),
+        }
+    }
+}
+
+impl TypedefField {
+    /// `pub` struct field with its docs and serde attributes
+    pub fn to_pub_field_tokens(&self) -> TokenStream {
+        let serde_attr = self.r#type.serde_attr_tokens();
+        let docs = docs_tokens(self.docs.as_deref());
+        let name = format_ident!("{}", self.name.to_snake_case());
+        let ty = &self.r#type;
+        quote! {
+            #docs
+            #serde_attr
+            pub #name: #ty
+        }
     }
 }
 
 impl ToTokens for TypedefField {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        let serde_attr = self.r#type.serde_attr_tokens();
+        let docs = docs_tokens(self.docs.as_deref());
         let name = format_ident!("{}", self.name.to_snake_case());
         let ty = &self.r#type;
         tokens.extend(quote! {
+            #docs
+            #serde_attr
             #name: #ty
         })
     }
@@ -277,29 +570,120 @@ impl ToTokens for TypedefFieldType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ty: TokenStream = match self {
             Self::PrimitiveOrPubkey(s) => primitive_or_pubkey_to_token(s).parse().unwrap(),
-            Self::defined(s) => s.parse().unwrap(),
+            Self::defined(d) => d.to_token_stream(),
             Self::array(a) => a.to_token_stream(),
+            Self::generic(g) => format_ident!("{}", g).into_token_stream(),
             Self::vec(v) => quote! {
                 Vec<#v>
             },
             Self::option(o) => quote! {
                 Option<#o>
             },
+            Self::tuple(t) => {
+                let tys = t.iter().map(|wrap| &wrap.0);
+                // trailing comma so that 1-tuples are not just parenthesized types
+                quote! {
+                    (#(#tys,)*)
+                }
+            }
+            Self::hashMap(TypedefFieldMap(k, v)) => quote! {
+                std::collections::HashMap<#k, #v>
+            },
+            Self::bTreeMap(TypedefFieldMap(k, v)) => quote! {
+                std::collections::BTreeMap<#k, #v>
+            },
+            Self::hashSet(t) => quote! {
+                std::collections::HashSet<#t>
+            },
+            Self::bTreeSet(t) => quote! {
+                std::collections::BTreeSet<#t>
+            },
+            Self::coption(o) => quote! {
+                COption<#o>
+            },
+            Self::sizePrefixed(p) => p.to_token_stream(),
         };
         tokens.extend(ty);
     }
 }
 
+impl TypedefFieldSizePrefixed {
+    /// None for u32 prefixes, which are just borsh's `String` and `Vec`
+    pub fn builtin(&self) -> Option<Builtin> {
+        if self.prefix == LenPrefix::U32 {
+            return None;
+        }
+        match self.r#type.as_ref() {
+            TypedefFieldType::PrimitiveOrPubkey(s) if s == "string" => {
+                Some(Builtin::PrefixedString(self.prefix))
+            }
+            TypedefFieldType::PrimitiveOrPubkey(s) if s == "bytes" => {
+                Some(Builtin::PrefixedVec(self.prefix))
+            }
+            TypedefFieldType::vec(_) => Some(Builtin::PrefixedVec(self.prefix)),
+            _ => {
+                log::error!("sizePrefixed type must be string, bytes or vec");
+                panic!("sizePrefixed type must be string, bytes or vec");
+            }
+        }
+    }
+}
+
+impl ToTokens for TypedefFieldSizePrefixed {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let inner = &self.r#type;
+        let ident = match self.builtin() {
+            None => return inner.to_tokens(tokens),
+            Some(b) => b.ident(),
+        };
+        match inner.as_ref() {
+            TypedefFieldType::PrimitiveOrPubkey(s) if s == "string" => ident.to_tokens(tokens),
+            TypedefFieldType::vec(elem) => tokens.extend(quote! { #ident<#elem> }),
+            _ => tokens.extend(quote! { #ident<u8> }),
+        }
+    }
+}
+
 impl ToTokens for TypedefFieldArray {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ty = &self.0;
-        let n = Index::from(self.1 as usize);
+        let n = &self.1;
         tokens.extend(quote! {
             [#ty; #n]
         })
     }
 }
 
+impl ToTokens for ArrayLen {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Value(n) => Index::from(*n as usize).to_tokens(tokens),
+            Self::Generic { generic } => format_ident!("{}", generic).to_tokens(tokens),
+        }
+    }
+}
+
+impl ToTokens for DefinedType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name: TokenStream = self.name().parse().unwrap();
+        let generics = self.generics();
+        if generics.is_empty() {
+            tokens.extend(name);
+        } else {
+            tokens.extend(quote! { #name<#(#generics),*> });
+        }
+    }
+}
+
+impl ToTokens for GenericArg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Type { r#type } => r#type.to_tokens(tokens),
+            Self::Const { value } => tokens.extend(value.parse::<TokenStream>().unwrap()),
+        }
+    }
+}
+
 impl ToTokens for TypedefEnum {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let variants = &self.variants;
@@ -316,20 +700,26 @@ impl ToTokens for EnumVariant {
             .fields
             .as_ref()
             .map_or(quote! {}, |fields| match fields {
-                EnumVariantFields::Struct(v) => {
+                DefinedFields::Struct(v) => {
                     let typedef_fields = v.iter();
                     quote! {
                         { #(#typedef_fields),* }
                     }
                 }
-                EnumVariantFields::Tuple(v) => {
-                    let unnamed_fields = v.iter().map(|wrap| &wrap.0);
+                DefinedFields::Tuple(v) => {
+                    let unnamed_fields = v.iter().map(|wrap| {
+                        let serde_attr = wrap.0.serde_attr_tokens();
+                        let ty = &wrap.0;
+                        quote! { #serde_attr #ty }
+                    });
                     quote! {
                         ( #(#unnamed_fields),* )
                     }
                 }
             });
+        let docs = docs_tokens(self.docs.as_deref());
         tokens.extend(quote! {
+            #docs
             #v #maybe_inner_fields
         });
     }
@@ -339,36 +729,137 @@ impl TypedefType {
     pub fn has_pubkey_field(&self) -> bool {
         match self {
             Self::r#enum(e) => e.variants.iter().any(|e| e.has_pubkey()),
-            Self::r#struct(s) => s.fields.iter().any(|f| f.r#type.is_or_has_pubkey()),
+            Self::r#struct(s) => s.fields.as_ref().is_some_and(|f| f.has_pubkey()),
+            Self::r#type { alias } => alias.is_or_has_pubkey(),
         }
     }
 
     pub fn has_defined_field(&self) -> bool {
         match self {
             Self::r#enum(e) => e.variants.iter().any(|e| e.has_defined()),
-            Self::r#struct(s) => s.fields.iter().any(|f| f.r#type.is_or_has_defined()),
+            Self::r#struct(s) => s.fields.as_ref().is_some_and(|f| f.has_defined()),
+            Self::r#type { alias } => alias.is_or_has_defined(),
+        }
+    }
+
+    /// Types of the struct's fields, the enum's variant fields or the alias
+    pub fn field_types(&self) -> Vec<&TypedefFieldType> {
+        match self {
+            Self::r#struct(s) => s.fields.as_ref().map_or_else(Vec::new, |f| f.types()),
+            Self::r#enum(e) => e
+                .variants
+                .iter()
+                .filter_map(|v| v.fields.as_ref())
+                .flat_map(|f| f.types())
+                .collect(),
+            Self::r#type { alias } => vec![alias],
         }
     }
+
+    pub fn builtins(&self) -> BTreeSet<Builtin> {
+        let mut res = BTreeSet::new();
+        for ty in self.field_types() {
+            ty.collect_builtins(&mut res);
+        }
+        res
+    }
 }
 
 impl TypedefFieldType {
+    /// serde only impls arrays of up to 32 elements with literal lengths,
+    /// use serde_big_array for the rest
+    /// and use serde_bytes for `bytes`
+    pub fn serde_attr_tokens(&self) -> TokenStream {
+        match self {
+            Self::array(TypedefFieldArray(_, len)) if len.value().is_none_or(|n| n > 32) => {
+                quote! { #[cfg_attr(feature = "serde", serde(with = "serde_big_array::BigArray"))] }
+            }
+            _ if self.is_bytes() => {
+                quote! { #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))] }
+            }
+            Self::option(o) if o.is_bytes() => {
+                quote! { #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))] }
+            }
+            _ => TokenStream::new(),
+        }
+    }
+
+    /// `bytes` or u32 size-prefixed `bytes`, generated as `Vec<u8>`
+    pub fn is_bytes(&self) -> bool {
+        match self {
+            Self::PrimitiveOrPubkey(s) => s == "bytes",
+            Self::sizePrefixed(p) => p.prefix == LenPrefix::U32 && p.r#type.is_bytes(),
+            _ => false,
+        }
+    }
+
     pub fn is_or_has_pubkey(&self) -> bool {
         match self {
             Self::PrimitiveOrPubkey(s) => primitive_or_pubkey_to_token(s) == PUBKEY_TOKEN,
             Self::array(a) => a.0.is_or_has_pubkey(),
             Self::option(o) => o.is_or_has_pubkey(),
             Self::vec(v) => v.is_or_has_pubkey(),
-            Self::defined(_) => false,
+            Self::defined(d) => d.generics().iter().any(|g| match g {
+                GenericArg::Type { r#type } => r#type.is_or_has_pubkey(),
+                GenericArg::Const { .. } => false,
+            }),
+            Self::generic(_) => false,
+            Self::tuple(t) => t.iter().any(|wrap| wrap.0.is_or_has_pubkey()),
+            Self::hashMap(m) | Self::bTreeMap(m) => {
+                m.0.is_or_has_pubkey() || m.1.is_or_has_pubkey()
+            }
+            Self::hashSet(t) | Self::bTreeSet(t) | Self::coption(t) => t.is_or_has_pubkey(),
+            Self::sizePrefixed(p) => p.r#type.is_or_has_pubkey(),
         }
     }
 
+    /// Whether the type refers to items generated elsewhere in the crate,
+    /// i.e. defined types, including the `typedefs` re-exports of mapped types, or builtins
     pub fn is_or_has_defined(&self) -> bool {
         match self {
-            Self::PrimitiveOrPubkey(_) => false,
+            Self::PrimitiveOrPubkey(s) => Builtin::from_primitive(s).is_some(),
             Self::array(a) => a.0.is_or_has_defined(),
             Self::option(o) => o.is_or_has_defined(),
             Self::vec(v) => v.is_or_has_defined(),
             Self::defined(_) => true,
+            Self::generic(_) => false,
+            Self::tuple(t) => t.iter().any(|wrap| wrap.0.is_or_has_defined()),
+            Self::hashMap(m) | Self::bTreeMap(m) => {
+                m.0.is_or_has_defined() || m.1.is_or_has_defined()
+            }
+            Self::hashSet(t) | Self::bTreeSet(t) => t.is_or_has_defined(),
+            Self::coption(_) => true,
+            Self::sizePrefixed(p) => p.builtin().is_some() || p.r#type.is_or_has_defined(),
+        }
+    }
+
+    /// Adds the builtins this type refers to to `res`
+    pub fn collect_builtins(&self, res: &mut BTreeSet<Builtin>) {
+        match self {
+            Self::PrimitiveOrPubkey(s) => res.extend(Builtin::from_primitive(s)),
+            Self::array(a) => a.0.collect_builtins(res),
+            Self::option(t) | Self::vec(t) | Self::hashSet(t) | Self::bTreeSet(t) => {
+                t.collect_builtins(res)
+            }
+            Self::coption(t) => {
+                res.insert(Builtin::COption);
+                t.collect_builtins(res);
+            }
+            Self::hashMap(m) | Self::bTreeMap(m) => {
+                m.0.collect_builtins(res);
+                m.1.collect_builtins(res);
+            }
+            Self::tuple(t) => t.iter().for_each(|wrap| wrap.0.collect_builtins(res)),
+            Self::defined(d) => d.generics().iter().for_each(|g| {
+                if let GenericArg::Type { r#type } = g {
+                    r#type.collect_builtins(res);
+                }
+            }),
+            Self::sizePrefixed(p) => {
+                res.extend(p.builtin());
+                p.r#type.collect_builtins(res);
+            }
+            Self::generic(_) => (),
         }
     }
 }
@@ -388,3 +879,133 @@ impl EnumVariant {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_generic_field_types() {
+        let field: TypedefField = serde_json::from_str(
+            r#"{
+                "name": "a",
+                "type": {
+                    "defined": {
+                        "name": "Wrapper",
+                        "generics": [
+                            { "kind": "type", "type": { "array": [{ "generic": "T" }, { "generic": "N" }] } },
+                            { "kind": "const", "value": "10" }
+                        ]
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            field.r#type.to_token_stream().to_string(),
+            "Wrapper < [T ; N] , 10 >"
+        );
+    }
+
+    #[test]
+    fn test_collection_and_builtin_field_types() {
+        let ty: TypedefType = serde_json::from_str(
+            r#"{
+                "kind": "struct",
+                "fields": [
+                    { "name": "a", "type": { "bTreeMap": ["u8", { "hashSet": "pubkey" }] } },
+                    { "name": "b", "type": { "coption": "u256" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let tys: Vec<String> = ty
+            .field_types()
+            .iter()
+            .map(|t| t.to_token_stream().to_string())
+            .collect();
+        assert_eq!(
+            tys,
+            [
+                "std :: collections :: BTreeMap < u8 , std :: collections :: HashSet < Pubkey > >",
+                "COption < U256 >"
+            ]
+        );
+        assert!(ty.has_pubkey_field());
+        assert!(ty.has_defined_field());
+        assert_eq!(
+            ty.builtins().into_iter().collect::<Vec<_>>(),
+            [Builtin::COption, Builtin::U256]
+        );
+    }
+
+    #[test]
+    fn test_bytes_and_size_prefixed_field_types() {
+        let fields: Vec<TypedefField> = serde_json::from_str(
+            r#"[
+                { "name": "a", "type": "bytes" },
+                { "name": "b", "type": { "sizePrefixed": { "type": "string", "prefix": "u8" } } },
+                { "name": "c", "type": { "sizePrefixed": { "type": { "vec": "u16" }, "prefix": "shortU16" } } },
+                { "name": "d", "type": { "sizePrefixed": { "type": "bytes", "prefix": "u32" } } }
+            ]"#,
+        )
+        .unwrap();
+        let tys: Vec<String> = fields
+            .iter()
+            .map(|f| f.r#type.to_token_stream().to_string())
+            .collect();
+        assert_eq!(
+            tys,
+            [
+                "Vec < u8 >",
+                "U8PrefixString",
+                "ShortVec < u16 >",
+                "Vec < u8 >"
+            ]
+        );
+        assert!(fields[0]
+            .to_pub_field_tokens()
+            .to_string()
+            .contains("serde (with = \"serde_bytes\")"));
+        assert!(fields[3].r#type.is_bytes());
+        let mut builtins = BTreeSet::new();
+        fields
+            .iter()
+            .for_each(|f| f.r#type.collect_builtins(&mut builtins));
+        assert_eq!(
+            builtins.into_iter().collect::<Vec<_>>(),
+            [
+                Builtin::PrefixedVec(LenPrefix::ShortU16),
+                Builtin::PrefixedString(LenPrefix::U8)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alias_and_tuple_struct() {
+        let types: Vec<NamedType> = serde_json::from_str(
+            r#"[
+                { "name": "Key", "type": { "kind": "type", "alias": { "tuple": ["pubkey"] } } },
+                { "name": "Pair", "type": { "kind": "struct", "fields": ["u8", "bool"] } }
+            ]"#,
+        )
+        .unwrap();
+        assert!(types[0].r#type.has_pubkey_field());
+        assert_eq!(
+            types[0]
+                .to_token_stream(
+                    &crate::Args::parse_from(["solores", "idl.json"]),
+                    &DeriveAnalysis::new(&types, &[], &crate::type_map::TypeMap::default())
+                )
+                .to_string(),
+            "pub type Key = (Pubkey ,) ;"
+        );
+        let TypedefType::r#struct(pair) = &types[1].r#type else {
+            panic!("expected struct");
+        };
+        assert!(pair.named_fields().is_empty());
+        assert_eq!(pair.to_token_stream().to_string(), "(pub u8 , pub bool) ;");
+    }
+}
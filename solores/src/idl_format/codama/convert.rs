@@ -0,0 +1,636 @@
+//! Conversion of Codama nodes into the anchor IDL structs used for codegen
+
+use base64::Engine;
+use heck::{ToPascalCase, ToSnakeCase};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::idl_format::anchor::{
+    accounts::NamedAccount,
+    errors::ErrorEnumVariant,
+    instructions::{IxAccount, IxAccountEntry, NamedInstruction},
+    pdas::{NamedPda, NamedPdaSeed, PdaProgram, SeedType},
+    typedefs::{
+        DefinedFields, EnumVariant, LenPrefix, NamedType, TypedefEnum, TypedefField,
+        TypedefFieldArray, TypedefFieldMap, TypedefFieldSizePrefixed, TypedefFieldType,
+        TypedefFieldTypeWrap, TypedefStruct, TypedefType,
+    },
+    AnchorIdl, Metadata,
+};
+
+use super::nodes::*;
+
+pub type ConvertResult<T> = Result<T, String>;
+
+impl TryFrom<RootNode> for AnchorIdl {
+    type Error = String;
+
+    fn try_from(RootNode { program, .. }: RootNode) -> ConvertResult<Self> {
+        let mut types = Vec::new();
+        let mut accounts = Vec::new();
+        for a in &program.accounts {
+            let (account_type, account) = a.to_named_type_and_account()?;
+            types.push(account_type);
+            accounts.extend(account);
+        }
+        for t in &program.defined_types {
+            types.push(t.to_named_type()?);
+        }
+        let instructions = program
+            .instructions
+            .iter()
+            .map(|ix| ix.to_named_instruction())
+            .collect::<ConvertResult<Vec<_>>>()?;
+        let errors = program
+            .errors
+            .iter()
+            .map(|e| ErrorEnumVariant {
+                code: e.code,
+                name: e.name.clone(),
+                msg: Some(e.message.clone()),
+            })
+            .collect();
+        let pdas = program
+            .pdas
+            .iter()
+            .filter_map(|p| match p.to_named_pda() {
+                Ok(p) => Some(p),
+                Err(e) => {
+                    log::warn!("Skipping pda {}: {}", p.name, e);
+                    None
+                }
+            })
+            .collect();
+        Ok(Self {
+            address: Some(program.public_key),
+            metadata: Metadata {
+                name: program.name.to_snake_case(),
+                version: program.version,
+                spec: None,
+                description: program.docs.map(|d| d.join("\n")),
+            },
+            accounts: Some(accounts),
+            types: Some(types),
+            instructions: Some(instructions),
+            errors: Some(errors),
+            events: None,
+            constants: None,
+            pdas,
+        })
+    }
+}
+
+fn type_name(name: &str) -> String {
+    name.to_pascal_case()
+}
+
+impl AccountNode {
+    /// Codama includes the discriminator in the account's data struct.
+    /// It's stripped out of the generated struct and into the `*Account` newtype's discm,
+    /// accounts without a discriminator at offset 0 are only generated as typedefs.
+    pub fn to_named_type_and_account(&self) -> ConvertResult<(NamedType, Option<NamedAccount>)> {
+        let ctx = |e: String| format!("account {}: {}", self.name, e);
+        let name = type_name(&self.name);
+        let mut discm_field = None;
+        let mut discm = None;
+        for d in &self.discriminators {
+            match d {
+                DiscriminatorNode::FieldDiscriminatorNode { name, offset: 0 } => {
+                    let field = self
+                        .data
+                        .fields
+                        .iter()
+                        .find(|f| &f.name == name)
+                        .ok_or_else(|| ctx(format!("discriminator field {} not found", name)))?;
+                    discm = Some(field_default_bytes(field).map_err(ctx)?);
+                    discm_field = Some(name);
+                    break;
+                }
+                DiscriminatorNode::ConstantDiscriminatorNode {
+                    constant,
+                    offset: 0,
+                } => {
+                    discm = Some(value_bytes(&constant.r#type, &constant.value).map_err(ctx)?);
+                    break;
+                }
+                _ => (),
+            }
+        }
+        let fields = self
+            .data
+            .fields
+            .iter()
+            .filter(|f| Some(&f.name) != discm_field)
+            .map(|f| f.to_typedef_field())
+            .collect::<ConvertResult<Vec<_>>>()
+            .map_err(ctx)?;
+        let named_type = NamedType {
+            name: name.clone(),
+            generics: Vec::new(),
+            r#type: TypedefType::r#struct(TypedefStruct {
+                fields: Some(DefinedFields::Struct(fields)),
+            }),
+            docs: self.docs.clone(),
+            serialization: None,
+            repr: None,
+        };
+        let account = discm.map(|d| NamedAccount {
+            name,
+            discriminator: Some(d),
+        });
+        Ok((named_type, account))
+    }
+}
+
+impl DefinedTypeNode {
+    pub fn to_named_type(&self) -> ConvertResult<NamedType> {
+        let ctx = |e: String| format!("type {}: {}", self.name, e);
+        let r#type = match &self.r#type {
+            TypeNode::StructTypeNode(s) => {
+                TypedefType::r#struct(s.to_typedef_struct().map_err(ctx)?)
+            }
+            TypeNode::EnumTypeNode { variants, size } => {
+                expect_number(size, "u8").map_err(ctx)?;
+                let variants = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| v.to_enum_variant(i))
+                    .collect::<ConvertResult<Vec<_>>>()
+                    .map_err(ctx)?;
+                TypedefType::r#enum(TypedefEnum { variants })
+            }
+            TypeNode::TupleTypeNode(t) => TypedefType::r#struct(TypedefStruct {
+                fields: Some(DefinedFields::Tuple(t.to_field_types().map_err(ctx)?)),
+            }),
+            ty => TypedefType::r#type {
+                alias: ty.to_field_type().map_err(ctx)?,
+            },
+        };
+        Ok(NamedType {
+            name: type_name(&self.name),
+            generics: Vec::new(),
+            r#type,
+            docs: self.docs.clone(),
+            serialization: None,
+            repr: None,
+        })
+    }
+}
+
+impl StructTypeNode {
+    pub fn to_typedef_struct(&self) -> ConvertResult<TypedefStruct> {
+        Ok(TypedefStruct {
+            fields: Some(DefinedFields::Struct(
+                self.fields
+                    .iter()
+                    .map(|f| f.to_typedef_field())
+                    .collect::<ConvertResult<_>>()?,
+            )),
+        })
+    }
+}
+
+impl TupleTypeNode {
+    pub fn to_field_types(&self) -> ConvertResult<Vec<TypedefFieldTypeWrap>> {
+        self.items
+            .iter()
+            .map(|t| t.to_field_type().map(TypedefFieldTypeWrap))
+            .collect()
+    }
+}
+
+impl StructFieldTypeNode {
+    pub fn to_typedef_field(&self) -> ConvertResult<TypedefField> {
+        Ok(TypedefField {
+            name: self.name.clone(),
+            r#type: self
+                .r#type
+                .to_field_type()
+                .map_err(|e| format!("field {}: {}", self.name, e))?,
+            docs: self.docs.clone(),
+        })
+    }
+}
+
+impl EnumVariantTypeNode {
+    pub fn to_enum_variant(&self, index: usize) -> ConvertResult<EnumVariant> {
+        let (name, discriminator) = match self {
+            Self::EnumEmptyVariantTypeNode {
+                name,
+                discriminator,
+            }
+            | Self::EnumStructVariantTypeNode {
+                name,
+                discriminator,
+                ..
+            }
+            | Self::EnumTupleVariantTypeNode {
+                name,
+                discriminator,
+                ..
+            } => (name, discriminator),
+        };
+        // borsh enum variants are serialized as their index
+        if discriminator.is_some_and(|d| d != index as u64) {
+            return Err(format!(
+                "variant {} has explicit discriminator {:?}, only sequential variants are supported",
+                name, discriminator
+            ));
+        }
+        let fields = match self {
+            Self::EnumEmptyVariantTypeNode { .. } => None,
+            Self::EnumStructVariantTypeNode { r#struct, .. } => {
+                r#struct.to_typedef_struct()?.fields
+            }
+            Self::EnumTupleVariantTypeNode { tuple, .. } => {
+                Some(DefinedFields::Tuple(tuple.to_field_types()?))
+            }
+        };
+        Ok(EnumVariant {
+            name: name.clone(),
+            fields,
+            docs: None,
+        })
+    }
+}
+
+const NUMBER_FORMATS: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "f32", "f64",
+];
+
+/// Errs if `n` is not the little-endian number type `format`
+fn expect_number(n: &NumberTypeNode, format: &str) -> ConvertResult<()> {
+    if n.format != format || n.endian != "le" {
+        return Err(format!(
+            "expected {} (le) but got {} ({})",
+            format, n.format, n.endian
+        ));
+    }
+    Ok(())
+}
+
+/// `ty` with a `sizePrefixed` wrapper if `prefix` is not borsh's u32
+fn size_prefixed(ty: TypedefFieldType, prefix: &NumberTypeNode) -> ConvertResult<TypedefFieldType> {
+    let len_prefix = match (prefix.format.as_str(), prefix.endian.as_str()) {
+        ("u32", "le") => return Ok(ty),
+        ("u8", "le") => LenPrefix::U8,
+        ("u16", "le") => LenPrefix::U16,
+        ("u64", "le") => LenPrefix::U64,
+        ("shortU16", "le") => LenPrefix::ShortU16,
+        _ => {
+            return Err(format!(
+                "unsupported size prefix {} ({})",
+                prefix.format, prefix.endian
+            ))
+        }
+    };
+    Ok(TypedefFieldType::sizePrefixed(TypedefFieldSizePrefixed {
+        r#type: Box::new(ty),
+        prefix: len_prefix,
+    }))
+}
+
+/// borsh maps and sets are u32 length-prefixed
+fn expect_prefixed_u32(count: &CountNode, kind: &str) -> ConvertResult<()> {
+    match count {
+        CountNode::PrefixedCountNode { prefix } => expect_number(prefix, "u32"),
+        _ => Err(format!("only u32-prefixed {} are supported", kind)),
+    }
+}
+
+impl NumberTypeNode {
+    pub fn to_field_type(&self) -> ConvertResult<TypedefFieldType> {
+        if !NUMBER_FORMATS.contains(&self.format.as_str()) || self.endian != "le" {
+            return Err(format!(
+                "unsupported number type {} ({})",
+                self.format, self.endian
+            ));
+        }
+        Ok(TypedefFieldType::PrimitiveOrPubkey(self.format.clone()))
+    }
+
+    /// Byte width of unsigned integer formats
+    fn uint_len(&self) -> ConvertResult<usize> {
+        match self.format.as_str() {
+            "u8" => Ok(1),
+            "u16" => Ok(2),
+            "u32" => Ok(4),
+            "u64" => Ok(8),
+            _ => Err(format!("unsupported discriminator type {}", self.format)),
+        }
+    }
+}
+
+impl TypeNode {
+    pub fn to_field_type(&self) -> ConvertResult<TypedefFieldType> {
+        let primitive = |s: &str| Ok(TypedefFieldType::PrimitiveOrPubkey(s.to_owned()));
+        match self {
+            Self::NumberTypeNode(n)
+            | Self::AmountTypeNode { number: n }
+            | Self::SolAmountTypeNode { number: n }
+            | Self::DateTimeTypeNode { number: n } => n.to_field_type(),
+            Self::BooleanTypeNode { size } => {
+                expect_number(size, "u8")?;
+                primitive("bool")
+            }
+            Self::PublicKeyTypeNode => primitive("publicKey"),
+            Self::SizePrefixTypeNode { r#type, prefix } => {
+                let ty = match r#type.as_ref() {
+                    Self::StringTypeNode { .. } => primitive("string"),
+                    Self::BytesTypeNode => primitive("bytes"),
+                    _ => Err("only strings and bytes can be size-prefixed".into()),
+                }?;
+                size_prefixed(ty, prefix)
+            }
+            Self::FixedSizeTypeNode { size, r#type } => match r#type.as_ref() {
+                Self::StringTypeNode { .. } | Self::BytesTypeNode => {
+                    Ok(TypedefFieldType::array(TypedefFieldArray(
+                        Box::new(TypedefFieldType::PrimitiveOrPubkey("u8".into())),
+                        (*size).into(),
+                    )))
+                }
+                _ => Err("only strings and bytes can be fixed-size".into()),
+            },
+            Self::ArrayTypeNode { item, count } => {
+                let item = Box::new(item.to_field_type()?);
+                match count {
+                    CountNode::PrefixedCountNode { prefix } => {
+                        size_prefixed(TypedefFieldType::vec(item), prefix)
+                    }
+                    CountNode::FixedCountNode { value } => Ok(TypedefFieldType::array(
+                        TypedefFieldArray(item, (*value).into()),
+                    )),
+                    CountNode::RemainderCountNode => {
+                        Err("remainder-counted arrays are not supported".into())
+                    }
+                }
+            }
+            Self::MapTypeNode { key, value, count } => {
+                expect_prefixed_u32(count, "maps")?;
+                Ok(TypedefFieldType::bTreeMap(TypedefFieldMap(
+                    Box::new(key.to_field_type()?),
+                    Box::new(value.to_field_type()?),
+                )))
+            }
+            Self::SetTypeNode { item, count } => {
+                expect_prefixed_u32(count, "sets")?;
+                Ok(TypedefFieldType::bTreeSet(Box::new(item.to_field_type()?)))
+            }
+            Self::OptionTypeNode {
+                item,
+                prefix,
+                fixed,
+            } => {
+                let item = Box::new(item.to_field_type()?);
+                // SPL's COption
+                if *fixed {
+                    expect_number(prefix, "u32")?;
+                    return Ok(TypedefFieldType::coption(item));
+                }
+                expect_number(prefix, "u8")?;
+                Ok(TypedefFieldType::option(item))
+            }
+            Self::DefinedTypeLinkNode { name } => {
+                Ok(TypedefFieldType::defined(type_name(name).into()))
+            }
+            Self::StringTypeNode { .. } => {
+                Err("strings must be size-prefixed or fixed-size".into())
+            }
+            Self::BytesTypeNode => Err("bytes must be size-prefixed or fixed-size".into()),
+            Self::TupleTypeNode(t) => Ok(TypedefFieldType::tuple(t.to_field_types()?)),
+            Self::StructTypeNode(_) | Self::EnumTypeNode { .. } => {
+                Err("inline structs and enums are not supported, use a defined type".into())
+            }
+        }
+    }
+}
+
+impl InstructionNode {
+    /// The argument referenced by the instruction's field discriminator is stripped out of the
+    /// generated args and into the discm.
+    /// Instructions without a discriminator at offset 0 get an empty discm
+    /// and are left out of `{Program}ProgramIx::deserialize()`.
+    pub fn to_named_instruction(&self) -> ConvertResult<NamedInstruction> {
+        let ctx = |e: String| format!("instruction {}: {}", self.name, e);
+        let mut discm_arg = None;
+        let mut discm = Vec::new();
+        for d in &self.discriminators {
+            match d {
+                DiscriminatorNode::FieldDiscriminatorNode { name, offset: 0 } => {
+                    let arg = self
+                        .arguments
+                        .iter()
+                        .find(|a| &a.name == name)
+                        .ok_or_else(|| ctx(format!("discriminator argument {} not found", name)))?;
+                    let value = arg.default_value.as_ref().ok_or_else(|| {
+                        ctx(format!("discriminator argument {} has no value", name))
+                    })?;
+                    discm = value_bytes(&arg.r#type, value).map_err(ctx)?;
+                    discm_arg = Some(name);
+                    break;
+                }
+                DiscriminatorNode::ConstantDiscriminatorNode {
+                    constant,
+                    offset: 0,
+                } => {
+                    discm = value_bytes(&constant.r#type, &constant.value).map_err(ctx)?;
+                    break;
+                }
+                _ => (),
+            }
+        }
+        let accounts = self
+            .accounts
+            .iter()
+            .map(|a| {
+                IxAccountEntry::Account(IxAccount {
+                    name: a.name.clone(),
+                    writable: a.is_writable,
+                    signer: matches!(a.is_signer, IsSigner::Bool(true)),
+                    optional: a.is_optional,
+                    optional_signer: matches!(a.is_signer, IsSigner::Either(_)),
+                    docs: a.docs.clone(),
+                    pda: None,
+                    address: a.default_address(),
+                    relations: None,
+                    nested: None,
+                })
+            })
+            .collect();
+        let args = self
+            .arguments
+            .iter()
+            .filter(|a| Some(&a.name) != discm_arg)
+            .map(|a| {
+                Ok(TypedefField {
+                    name: a.name.clone(),
+                    r#type: a
+                        .r#type
+                        .to_field_type()
+                        .map_err(|e| format!("argument {}: {}", a.name, e))?,
+                    docs: a.docs.clone(),
+                })
+            })
+            .collect::<ConvertResult<Vec<_>>>()
+            .map_err(ctx)?;
+        Ok(NamedInstruction {
+            name: self.name.clone(),
+            discriminator: Some(discm),
+            accounts: Some(accounts),
+            args: Some(args),
+            docs: self.docs.clone(),
+        })
+    }
+}
+
+impl InstructionAccountNode {
+    /// `publicKeyValueNode` default values are fixed addresses
+    fn default_address(&self) -> Option<String> {
+        let v = self.default_value.as_ref()?;
+        if v.get("kind")?.as_str()? != "publicKeyValueNode" {
+            return None;
+        }
+        Some(v.get("publicKey")?.as_str()?.to_owned())
+    }
+}
+
+impl PdaNode {
+    pub fn to_named_pda(&self) -> ConvertResult<NamedPda> {
+        let seeds = self
+            .seeds
+            .iter()
+            .map(|s| match s {
+                PdaSeedNode::ConstantPdaSeedNode { r#type, value } => {
+                    value_bytes(r#type, value).map(NamedPdaSeed::Const)
+                }
+                PdaSeedNode::VariablePdaSeedNode { name, r#type } => Ok(NamedPdaSeed::Param {
+                    name: name.to_snake_case(),
+                    ty: r#type.to_seed_type()?,
+                }),
+            })
+            .collect::<ConvertResult<_>>()?;
+        let program = match &self.program_id {
+            None => PdaProgram::This,
+            Some(id) => PdaProgram::Const(
+                decode_bytes(id, "base58")?
+                    .try_into()
+                    .map_err(|_| format!("invalid program id {}", id))?,
+            ),
+        };
+        Ok(NamedPda {
+            name: self.name.to_snake_case(),
+            seeds,
+            program,
+        })
+    }
+}
+
+impl TypeNode {
+    /// Variable PDA seeds are not size-prefixed
+    fn to_seed_type(&self) -> ConvertResult<SeedType> {
+        match self {
+            Self::PublicKeyTypeNode => Ok(SeedType::Pubkey),
+            Self::NumberTypeNode(n) if !n.format.starts_with('f') && n.endian == "le" => {
+                Ok(SeedType::Int(n.format.clone()))
+            }
+            Self::BooleanTypeNode { .. } => Ok(SeedType::Bool),
+            Self::StringTypeNode { .. } => Ok(SeedType::Str),
+            Self::BytesTypeNode => Ok(SeedType::Bytes),
+            Self::FixedSizeTypeNode { size, r#type }
+                if matches!(r#type.as_ref(), Self::BytesTypeNode) =>
+            {
+                Ok(SeedType::ByteArray(*size))
+            }
+            _ => Err("unsupported variable seed type".into()),
+        }
+    }
+}
+
+fn field_default_bytes(field: &StructFieldTypeNode) -> ConvertResult<Vec<u8>> {
+    let value = field
+        .default_value
+        .as_ref()
+        .ok_or_else(|| format!("discriminator field {} has no value", field.name))?;
+    value_bytes(&field.r#type, value)
+}
+
+/// Serializes a discriminator value node of type `ty`
+fn value_bytes(ty: &TypeNode, value: &Value) -> ConvertResult<Vec<u8>> {
+    let value = ValueNode::deserialize(value).map_err(|e| format!("{}", e))?;
+    match value {
+        ValueNode::BytesValueNode { data, encoding } => decode_bytes(&data, &encoding),
+        ValueNode::StringValueNode { string } => Ok(string.into_bytes()),
+        ValueNode::NumberValueNode { number } => {
+            let n = match ty {
+                TypeNode::NumberTypeNode(n) => n,
+                _ => return Err("number value for non-number type".into()),
+            };
+            expect_number(n, &n.format)?;
+            let len = n.uint_len()?;
+            if len < 8 && number >> (len * 8) != 0 {
+                return Err(format!("{} does not fit in {}", number, n.format));
+            }
+            Ok(number.to_le_bytes()[..len].to_vec())
+        }
+    }
+}
+
+fn decode_bytes(data: &str, encoding: &str) -> ConvertResult<Vec<u8>> {
+    match encoding {
+        "base16" => (0..data.len())
+            .step_by(2)
+            .map(|i| {
+                data.get(i..i + 2)
+                    .and_then(|b| u8::from_str_radix(b, 16).ok())
+                    .ok_or_else(|| format!("invalid base16 {}", data))
+            })
+            .collect(),
+        "base58" => bs58::decode(data)
+            .into_vec()
+            .map_err(|e| format!("invalid base58 {}: {}", data, e)),
+        "base64" => base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .map_err(|e| format!("invalid base64 {}: {}", data, e)),
+        "utf8" => Ok(data.as_bytes().to_vec()),
+        _ => Err(format!("unsupported bytes encoding {}", encoding)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_bytes() {
+        assert_eq!(decode_bytes("00ff10", "base16").unwrap(), [0, 255, 16]);
+        assert!(decode_bytes("0", "base16").is_err());
+        assert_eq!(decode_bytes("2g", "base58").unwrap(), [97]);
+        assert_eq!(decode_bytes("YQ==", "base64").unwrap(), [97]);
+        assert_eq!(decode_bytes("a", "utf8").unwrap(), [97]);
+    }
+
+    #[test]
+    fn test_either_signer_is_optional_signer() {
+        let node: InstructionNode = serde_json::from_str(
+            r#"{
+                "name": "increment",
+                "accounts": [
+                    { "name": "authority", "isWritable": false, "isSigner": true },
+                    { "name": "payer", "isWritable": true, "isSigner": "either" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let ix = node.to_named_instruction().unwrap();
+        let flags: Vec<(bool, bool)> = ix
+            .accounts
+            .unwrap()
+            .iter()
+            .map(|a| match a {
+                IxAccountEntry::Account(a) => (a.signer, a.optional_signer),
+                IxAccountEntry::Struct(_) => panic!("unexpected nested accounts"),
+            })
+            .collect();
+        assert_eq!(flags, [(true, false), (false, true)]);
+    }
+}
@@ -0,0 +1,146 @@
+//! Codama (formerly Kinobi) `rootNode` JSON.
+//!
+//! The program node is converted into an [`AnchorIdl`] so that the anchor
+//! accounts, typedefs, instructions and errors codegen is reused:
+//! - account and instruction discriminators are taken from their `discriminators` nodes
+//!   instead of sha256 sighashes
+//! - u32 size-prefixed strings and bytes, u32 size-prefixed and fixed-count arrays,
+//!   u8-prefixed options and fixed-size strings and bytes map onto their borsh equivalents
+//! - `pdas` nodes are generated as `find_*_pda()` and `create_*_pda_with_bump()` functions
+//!   in the `pdas` module, PDAs with unsupported seed types are skipped with a warning
+
+use serde::Deserialize;
+use toml::{map::Map, Value};
+
+use super::{anchor::AnchorIdl, IdlCodegenModule, IdlFormat};
+use crate::type_map::TypeMap;
+
+mod convert;
+pub mod nodes;
+
+use nodes::RootNode;
+
+pub const ROOT_NODE_KIND: &str = "rootNode";
+
+#[derive(Deserialize)]
+#[serde(try_from = "RootNode")]
+pub struct CodamaIdl {
+    pub kind: String,
+    pub idl: AnchorIdl,
+}
+
+impl TryFrom<RootNode> for CodamaIdl {
+    type Error = String;
+
+    fn try_from(root: RootNode) -> Result<Self, Self::Error> {
+        Ok(Self {
+            kind: root.kind.clone(),
+            idl: AnchorIdl::try_from(root)?,
+        })
+    }
+}
+
+impl IdlFormat for CodamaIdl {
+    fn program_name(&self) -> &str {
+        self.idl.program_name()
+    }
+
+    fn program_version(&self) -> &str {
+        self.idl.program_version()
+    }
+
+    fn program_address(&self) -> Option<&str> {
+        self.idl.program_address()
+    }
+
+    fn is_correct_idl_format(&self) -> bool {
+        self.kind == ROOT_NODE_KIND
+    }
+
+    fn dependencies(&self, args: &crate::Args) -> Map<String, Value> {
+        self.idl.dependencies(args)
+    }
+
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+        type_map: &'me TypeMap,
+    ) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
+        self.idl.modules(args, type_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codama_discriminator_arg_stripped() {
+        let idl: CodamaIdl = serde_json::from_str(
+            r#"{
+                "kind": "rootNode",
+                "standard": "codama",
+                "version": "1.0.0",
+                "program": {
+                    "kind": "programNode",
+                    "name": "myProgram",
+                    "publicKey": "11111111111111111111111111111111",
+                    "version": "0.1.0",
+                    "instructions": [
+                        {
+                            "kind": "instructionNode",
+                            "name": "transfer",
+                            "accounts": [],
+                            "arguments": [
+                                {
+                                    "kind": "instructionArgumentNode",
+                                    "name": "discriminator",
+                                    "type": { "kind": "numberTypeNode", "format": "u32", "endian": "le" },
+                                    "defaultValue": { "kind": "numberValueNode", "number": 2 },
+                                    "defaultValueStrategy": "omitted"
+                                },
+                                {
+                                    "kind": "instructionArgumentNode",
+                                    "name": "lamports",
+                                    "type": { "kind": "numberTypeNode", "format": "u64", "endian": "le" }
+                                }
+                            ],
+                            "discriminators": [
+                                { "kind": "fieldDiscriminatorNode", "name": "discriminator", "offset": 0 }
+                            ]
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(idl.is_correct_idl_format());
+        assert_eq!(idl.program_name(), "my_program");
+        let ix = &idl.idl.instructions.as_ref().unwrap()[0];
+        assert_eq!(ix.discm(), [2, 0, 0, 0]);
+        assert_eq!(ix.args.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_codama_unsupported_type_errs() {
+        let res = serde_json::from_str::<CodamaIdl>(
+            r#"{
+                "kind": "rootNode",
+                "program": {
+                    "kind": "programNode",
+                    "name": "myProgram",
+                    "publicKey": "11111111111111111111111111111111",
+                    "version": "0.1.0",
+                    "definedTypes": [
+                        {
+                            "kind": "definedTypeNode",
+                            "name": "name",
+                            "type": { "kind": "stringTypeNode", "encoding": "utf8" }
+                        }
+                    ]
+                }
+            }"#,
+        );
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,272 @@
+//! Subset of the Codama node tree that maps onto borsh codegen.
+//!
+//! Node kinds that have no equivalent yet are left out so that
+//! deserialization fails with serde's `unknown variant` error naming the node kind.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootNode {
+    pub kind: String,
+    pub standard: Option<String>,
+    pub version: Option<String>,
+    pub program: ProgramNode,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgramNode {
+    pub name: String,
+    pub public_key: String,
+    pub version: String,
+    pub origin: Option<String>,
+    pub docs: Option<Vec<String>>,
+    #[serde(default)]
+    pub accounts: Vec<AccountNode>,
+    #[serde(default)]
+    pub instructions: Vec<InstructionNode>,
+    #[serde(default)]
+    pub defined_types: Vec<DefinedTypeNode>,
+    #[serde(default)]
+    pub pdas: Vec<PdaNode>,
+    #[serde(default)]
+    pub errors: Vec<ErrorNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountNode {
+    pub name: String,
+    pub docs: Option<Vec<String>>,
+    pub data: StructTypeNode,
+    pub size: Option<u64>,
+    #[serde(default)]
+    pub discriminators: Vec<DiscriminatorNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionNode {
+    pub name: String,
+    pub docs: Option<Vec<String>>,
+    #[serde(default)]
+    pub accounts: Vec<InstructionAccountNode>,
+    #[serde(default)]
+    pub arguments: Vec<InstructionArgumentNode>,
+    #[serde(default)]
+    pub discriminators: Vec<DiscriminatorNode>,
+    pub optional_account_strategy: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionAccountNode {
+    pub name: String,
+    pub docs: Option<Vec<String>>,
+    pub is_writable: bool,
+    pub is_signer: IsSigner,
+    #[serde(default)]
+    pub is_optional: bool,
+    pub default_value: Option<Value>,
+}
+
+/// `isSigner` is either a bool or `"either"`
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum IsSigner {
+    Bool(bool),
+    Either(String),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionArgumentNode {
+    pub name: String,
+    pub docs: Option<Vec<String>>,
+    pub r#type: TypeNode,
+    pub default_value: Option<Value>,
+    pub default_value_strategy: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefinedTypeNode {
+    pub name: String,
+    pub docs: Option<Vec<String>>,
+    pub r#type: TypeNode,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorNode {
+    pub name: String,
+    pub code: u32,
+    pub message: String,
+    pub docs: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdaNode {
+    pub name: String,
+    pub docs: Option<Vec<String>>,
+    pub program_id: Option<String>,
+    pub seeds: Vec<PdaSeedNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PdaSeedNode {
+    ConstantPdaSeedNode { r#type: TypeNode, value: Value },
+    VariablePdaSeedNode { name: String, r#type: TypeNode },
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DiscriminatorNode {
+    FieldDiscriminatorNode {
+        name: String,
+        offset: u64,
+    },
+    ConstantDiscriminatorNode {
+        constant: ConstantValueNode,
+        offset: u64,
+    },
+    SizeDiscriminatorNode {
+        size: u64,
+    },
+}
+
+#[derive(Deserialize)]
+pub struct ConstantValueNode {
+    pub r#type: TypeNode,
+    pub value: Value,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TypeNode {
+    NumberTypeNode(NumberTypeNode),
+    BooleanTypeNode {
+        size: NumberTypeNode,
+    },
+    PublicKeyTypeNode,
+    StringTypeNode {
+        encoding: String,
+    },
+    BytesTypeNode,
+    SizePrefixTypeNode {
+        r#type: Box<TypeNode>,
+        prefix: NumberTypeNode,
+    },
+    FixedSizeTypeNode {
+        size: u32,
+        r#type: Box<TypeNode>,
+    },
+    ArrayTypeNode {
+        item: Box<TypeNode>,
+        count: CountNode,
+    },
+    OptionTypeNode {
+        item: Box<TypeNode>,
+        prefix: NumberTypeNode,
+        #[serde(default)]
+        fixed: bool,
+    },
+    MapTypeNode {
+        key: Box<TypeNode>,
+        value: Box<TypeNode>,
+        count: CountNode,
+    },
+    SetTypeNode {
+        item: Box<TypeNode>,
+        count: CountNode,
+    },
+    DefinedTypeLinkNode {
+        name: String,
+    },
+    StructTypeNode(StructTypeNode),
+    TupleTypeNode(TupleTypeNode),
+    EnumTypeNode {
+        variants: Vec<EnumVariantTypeNode>,
+        size: NumberTypeNode,
+    },
+    AmountTypeNode {
+        number: NumberTypeNode,
+    },
+    SolAmountTypeNode {
+        number: NumberTypeNode,
+    },
+    DateTimeTypeNode {
+        number: NumberTypeNode,
+    },
+}
+
+#[derive(Deserialize)]
+pub struct NumberTypeNode {
+    pub format: String,
+    #[serde(default = "default_endian")]
+    pub endian: String,
+}
+
+fn default_endian() -> String {
+    "le".to_owned()
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum CountNode {
+    PrefixedCountNode { prefix: NumberTypeNode },
+    FixedCountNode { value: u32 },
+    RemainderCountNode,
+}
+
+#[derive(Deserialize)]
+pub struct StructTypeNode {
+    pub fields: Vec<StructFieldTypeNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructFieldTypeNode {
+    pub name: String,
+    pub docs: Option<Vec<String>>,
+    pub r#type: TypeNode,
+    pub default_value: Option<Value>,
+    pub default_value_strategy: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct TupleTypeNode {
+    pub items: Vec<TypeNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum EnumVariantTypeNode {
+    EnumEmptyVariantTypeNode {
+        name: String,
+        discriminator: Option<u64>,
+    },
+    EnumStructVariantTypeNode {
+        name: String,
+        discriminator: Option<u64>,
+        r#struct: StructTypeNode,
+    },
+    EnumTupleVariantTypeNode {
+        name: String,
+        discriminator: Option<u64>,
+        tuple: TupleTypeNode,
+    },
+}
+
+/// Value nodes used for discriminators
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ValueNode {
+    BytesValueNode { data: String, encoding: String },
+    NumberValueNode { number: u64 },
+    StringValueNode { string: String },
+}
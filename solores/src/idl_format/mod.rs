@@ -3,7 +3,11 @@
 use proc_macro2::TokenStream;
 use toml::{map::Map, Value};
 
+use crate::type_map::TypeMap;
+
 pub mod anchor;
+pub mod codama;
+pub mod shank;
 
 pub trait IdlCodegenModule {
     /// The module file's name e.g. "errors"
@@ -28,5 +32,9 @@ pub trait IdlFormat {
 
     fn dependencies(&self, args: &crate::Args) -> Map<String, Value>;
 
-    fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>>;
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+        type_map: &'me TypeMap,
+    ) -> Vec<Box<dyn IdlCodegenModule + 'me>>;
 }
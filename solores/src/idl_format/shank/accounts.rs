@@ -0,0 +1,55 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    idl_format::{
+        anchor::typedefs::{NamedType, TypedefsCodegenModule},
+        IdlCodegenModule,
+    },
+    type_map::TypeMap,
+};
+
+/// Shank accounts have no discriminants,
+/// so the account structs are generated the same way as typedefs
+pub struct AccountsCodegenModule<'a> {
+    pub cli_args: &'a crate::Args,
+    pub type_map: &'a TypeMap,
+    pub named_accounts: &'a [NamedType],
+    /// Accounts and types, see [`super::ShankIdl::named_types`]
+    pub named_types: &'a [NamedType],
+}
+
+impl AccountsCodegenModule<'_> {
+    fn as_typedefs(&self) -> TypedefsCodegenModule<'_> {
+        TypedefsCodegenModule {
+            cli_args: self.cli_args,
+            type_map: self.type_map,
+            named_types: self.named_accounts,
+            all_named_types: self.named_types,
+        }
+    }
+}
+
+impl IdlCodegenModule for AccountsCodegenModule<'_> {
+    fn name(&self) -> &str {
+        "accounts"
+    }
+
+    fn gen_head(&self) -> TokenStream {
+        let mut res = self.as_typedefs().gen_head();
+        if self
+            .named_accounts
+            .iter()
+            .any(|a| a.r#type.has_defined_field())
+        {
+            res.extend(quote! {
+                use crate::*;
+            });
+        }
+        res
+    }
+
+    fn gen_body(&self) -> TokenStream {
+        self.as_typedefs().named_types_tokens()
+    }
+}
@@ -0,0 +1,313 @@
+//! Shank IDLs are a dialect of the legacy anchor IDL format,
+//! so the anchor typedefs, instructions and errors codegen is reused.
+//!
+//! Differences from anchor:
+//! - instruction discriminants are a single byte or `{ "type": "u8", "value": 0 }` instead of sha256 sighashes
+//! - accounts have no discriminants
+//! - accounts can be `isOptional`. `isOptionalSigner` accounts are non-signers unless set as signers
+//!   with `*Keys::to_account_metas_with_signers()`
+
+use serde::Deserialize;
+use toml::{map::Map, Value};
+
+use crate::type_map::TypeMap;
+use crate::write_cargotoml::{
+    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
+    NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_BIG_ARRAY_CRATE, SERDE_BYTES_CRATE, SERDE_CRATE,
+    SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
+};
+
+use super::{
+    anchor::{
+        builtins::{used_builtins, BuiltinsCodegenModule},
+        errors::{ErrorEnumVariant, ErrorsCodegenModule},
+        instructions::{IxCodegenModule, NamedInstruction},
+        typedefs::{NamedType, TypedefsCodegenModule},
+    },
+    IdlCodegenModule, IdlFormat,
+};
+
+mod accounts;
+
+use accounts::AccountsCodegenModule;
+
+pub const SHANK_ORIGIN: &str = "shank";
+
+#[derive(Deserialize)]
+#[serde(from = "ShankIdlJson")]
+pub struct ShankIdl {
+    pub name: String,
+    pub version: String,
+    pub metadata: ShankMetadata,
+    /// `accounts` followed by `types`,
+    /// so that the typedef analyses of either see the other's types
+    pub named_types: Vec<NamedType>,
+    accounts_len: Option<usize>,
+    has_types: bool,
+    pub instructions: Option<Vec<NamedInstruction>>,
+    pub errors: Option<Vec<ErrorEnumVariant>>,
+}
+
+#[derive(Deserialize)]
+struct ShankIdlJson {
+    name: String,
+    version: String,
+    metadata: ShankMetadata,
+    accounts: Option<Vec<NamedType>>,
+    types: Option<Vec<NamedType>>,
+    instructions: Option<Vec<NamedInstruction>>,
+    errors: Option<Vec<ErrorEnumVariant>>,
+}
+
+impl From<ShankIdlJson> for ShankIdl {
+    fn from(json: ShankIdlJson) -> Self {
+        let accounts_len = json.accounts.as_ref().map(Vec::len);
+        let has_types = json.types.is_some();
+        Self {
+            name: json.name,
+            version: json.version,
+            metadata: json.metadata,
+            named_types: json
+                .accounts
+                .into_iter()
+                .chain(json.types)
+                .flatten()
+                .collect(),
+            accounts_len,
+            has_types,
+            instructions: json.instructions,
+            errors: json.errors,
+        }
+    }
+}
+
+impl ShankIdl {
+    pub fn accounts(&self) -> Option<&[NamedType]> {
+        self.accounts_len.map(|n| &self.named_types[..n])
+    }
+
+    pub fn types(&self) -> Option<&[NamedType]> {
+        self.has_types
+            .then(|| &self.named_types[self.accounts_len.unwrap_or_default()..])
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ShankMetadata {
+    pub origin: String,
+    pub address: Option<String>,
+}
+
+impl IdlFormat for ShankIdl {
+    fn program_name(&self) -> &str {
+        &self.name
+    }
+
+    fn program_version(&self) -> &str {
+        &self.version
+    }
+
+    fn program_address(&self) -> Option<&str> {
+        self.metadata.address.as_deref()
+    }
+
+    fn is_correct_idl_format(&self) -> bool {
+        self.metadata.origin == SHANK_ORIGIN
+    }
+
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+        type_map: &'me TypeMap,
+    ) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
+        let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
+        if let Some(v) = self.accounts() {
+            res.push(Box::new(AccountsCodegenModule {
+                cli_args: args,
+                type_map,
+                named_accounts: v,
+                named_types: &self.named_types,
+            }));
+        }
+        // mapped types are re-exported from typedefs
+        if self.has_types || !type_map.types.is_empty() {
+            res.push(Box::new(TypedefsCodegenModule {
+                cli_args: args,
+                type_map,
+                named_types: self.types().unwrap_or_default(),
+                all_named_types: &self.named_types,
+            }));
+        }
+        let builtins = used_builtins(
+            &self.named_types,
+            self.instructions
+                .iter()
+                .flatten()
+                .flat_map(|ix| ix.args.iter().flatten()),
+        );
+        if !builtins.is_empty() {
+            res.push(Box::new(BuiltinsCodegenModule { builtins }));
+        }
+        if let Some(v) = &self.instructions {
+            res.push(Box::new(IxCodegenModule {
+                program_name: self.program_name(),
+                instructions: v,
+                named_types: &self.named_types,
+                program_accounts: self
+                    .accounts()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|a| a.name.as_str())
+                    .collect(),
+                type_map,
+                nested_accounts: args.nested_accounts,
+            }));
+        }
+        if let Some(v) = &self.errors {
+            res.push(Box::new(ErrorsCodegenModule {
+                program_name: self.program_name(),
+                variants: v,
+            }));
+        }
+        res
+    }
+
+    fn dependencies(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        map.insert(BORSH_CRATE.into(), DependencyValue(&args.borsh_vers).into());
+        map.insert(
+            BYTEMUCK_CRATE.into(),
+            FeaturesDependencyValue {
+                dependency: DependencyValue(&args.bytemuck_vers),
+                features: vec!["derive".into()],
+            }
+            .into(),
+        );
+        map.insert(
+            SOLANA_PROGRAM_CRATE.into(),
+            DependencyValue(&args.solana_program_vers).into(),
+        );
+        map.insert(
+            SERDE_CRATE.into(),
+            OptionalDependencyValue(DependencyValue(&args.serde_vers)).into(),
+        );
+        map.insert(
+            SERDE_BYTES_CRATE.into(),
+            OptionalDependencyValue(DependencyValue(&args.serde_bytes_vers)).into(),
+        );
+        map.insert(
+            SERDE_BIG_ARRAY_CRATE.into(),
+            OptionalDependencyValue(DependencyValue(&args.serde_big_array_vers)).into(),
+        );
+        if self.errors.is_some() {
+            map.insert(
+                THISERROR_CRATE.into(),
+                DependencyValue(&args.thiserror_vers).into(),
+            );
+            map.insert(
+                NUM_DERIVE_CRATE.into(),
+                DependencyValue(&args.num_derive_vers).into(),
+            );
+            map.insert(
+                NUM_TRAITS_CRATE.into(),
+                DependencyValue(&args.num_traits_vers).into(),
+            );
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    const SHANK_IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "shank_program",
+        "instructions": [
+            {
+                "name": "Init",
+                "accounts": [
+                    { "name": "payer", "isMut": true, "isSigner": true },
+                    { "name": "delegate", "isMut": false, "isSigner": false, "isOptional": true }
+                ],
+                "args": [],
+                "discriminant": { "type": "u8", "value": 0 }
+            },
+            {
+                "name": "Close",
+                "accounts": [],
+                "args": [],
+                "discriminant": 1
+            }
+        ],
+        "metadata": { "origin": "shank", "address": "11111111111111111111111111111111" }
+    }"#;
+
+    #[test]
+    fn test_shank_idl_discriminants() {
+        let idl: ShankIdl = serde_json::from_str(SHANK_IDL).unwrap();
+        assert!(idl.is_correct_idl_format());
+        let ixs = idl.instructions.unwrap();
+        assert_eq!(ixs[0].discm(), [0]);
+        assert_eq!(ixs[1].discm(), [1]);
+    }
+
+    #[test]
+    fn test_shank_discriminant_type_width() {
+        let ix: NamedInstruction = serde_json::from_str(
+            r#"{ "name": "Init", "discriminant": { "type": "u16", "value": 258 } }"#,
+        )
+        .unwrap();
+        assert_eq!(ix.discm(), [2, 1]);
+        assert!(serde_json::from_str::<NamedInstruction>(
+            r#"{ "name": "Init", "discriminant": { "type": "u8", "value": 256 } }"#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_legacy_anchor_idl_is_not_shank() {
+        let idl: ShankIdl = serde_json::from_str(
+            r#"{ "version": "0.1.0", "name": "unstake", "metadata": { "origin": "anchor" } }"#,
+        )
+        .unwrap();
+        assert!(!idl.is_correct_idl_format());
+    }
+
+    #[test]
+    fn test_shank_account_derives_see_types() {
+        let idl: ShankIdl = serde_json::from_str(
+            r#"{
+                "version": "0.1.0",
+                "name": "vault",
+                "accounts": [{
+                    "name": "Vault",
+                    "type": { "kind": "struct", "fields": [{ "name": "key", "type": { "defined": "Key" } }] }
+                }],
+                "types": [{
+                    "name": "Key",
+                    "type": { "kind": "enum", "variants": [{ "name": "Uninitialized" }, { "name": "Vault" }] }
+                }],
+                "metadata": { "origin": "shank" }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(idl.accounts().unwrap().len(), 1);
+        assert_eq!(idl.types().unwrap().len(), 1);
+        let args = crate::Args::parse_from(["solores", "idl.json"]);
+        let generated = AccountsCodegenModule {
+            cli_args: &args,
+            type_map: &TypeMap::default(),
+            named_accounts: idl.accounts().unwrap(),
+            named_types: &idl.named_types,
+        }
+        .gen_body()
+        .to_string();
+        assert!(generated.contains("derive (Clone , Copy , Debug , Default ,"));
+        assert!(generated.contains("PartialEq , Eq , Hash ,"));
+        assert!(generated.contains("pub const VAULT_LEN : usize = 1 ;"));
+    }
+}
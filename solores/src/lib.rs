@@ -3,22 +3,31 @@
 use std::{
     env,
     fs::{self, File, OpenOptions},
+    io::Read,
     path::PathBuf,
 };
 
-use clap::{command, Parser};
+use clap::Parser;
 use idl_format::IdlFormat;
+use serde::Deserialize;
 
-use crate::idl_format::anchor::AnchorIdl;
+use crate::idl_format::{
+    anchor::{legacy::LegacyAnchorIdl, AnchorIdl},
+    codama::CodamaIdl,
+    shank::ShankIdl,
+};
 
 // Just make all mods pub to allow ppl to use the lib
 
 pub mod idl_format;
+pub mod onchain_idl;
+pub mod type_map;
 pub mod utils;
 pub mod write_cargotoml;
 pub mod write_gitignore;
 pub mod write_src;
 
+use type_map::TypeMap;
 use write_cargotoml::write_cargotoml;
 use write_gitignore::write_gitignore;
 use write_src::*;
@@ -30,6 +39,9 @@ const RUST_LOG_ENV_VAR: &str = "RUST_LOG";
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+    #[arg(
+        help = "path to the IDL JSON file, or to a dumped anchor IDL account: `solana account <idl-addr> --output json` output or raw account data"
+    )]
     pub idl_path: PathBuf,
 
     #[arg(
@@ -53,7 +65,7 @@ pub struct Args {
     #[arg(
         long,
         short,
-        help = "typedefs and accounts to derive bytemuck::Pod for. Does not currently check validity of derivation."
+        help = "typedefs and accounts to derive bytemuck::Pod for. Types with non-Pod fields or padding are generated without it, with a warning. Accounts get zero-copy load() fns."
     )]
     pub zero_copy: Vec<String>,
 
@@ -122,6 +134,39 @@ pub struct Args {
     )]
     pub serde_big_array_vers: String,
 
+    #[arg(
+        long,
+        help = "base64 dependency version for generated crate",
+        default_value = "workspace = true"
+    )]
+    pub base64_vers: String,
+
+    #[arg(
+        long,
+        help = "solana-rpc-client-api dependency version for generated crate, optional behind its client feature",
+        default_value = "workspace = true"
+    )]
+    pub solana_rpc_client_api_vers: String,
+
+    #[arg(
+        long,
+        help = "generate composite account groups as nested *Keys and *Accounts structs instead of flattening them",
+        default_value = "false"
+    )]
+    pub nested_accounts: bool,
+
+    #[arg(
+        long,
+        help = "map an IDL defined type to an external type instead of generating it, e.g. I80F48=fixed::types::I80F48. The path's crate is added as a workspace dependency. Accepts multiple options."
+    )]
+    pub type_map: Vec<String>,
+
+    #[arg(
+        long,
+        help = "TOML file of type mappings: a `types` table of Name = \"path::to::Type\" and a `dependencies` table for the generated Cargo.toml"
+    )]
+    pub type_map_file: Option<PathBuf>,
+
     #[arg(long, help = "write gitignore file", default_value = "false")]
     pub write_gitignore: bool,
 
@@ -162,8 +207,9 @@ pub fn main() {
     if args.write_gitignore {
         write_gitignore(&args).unwrap();
     }
-    write_cargotoml(&args, idl.as_ref()).unwrap();
-    write_lib(&args, idl.as_ref()).unwrap();
+    let type_map = TypeMap::from_args(&args);
+    write_cargotoml(&args, idl.as_ref(), &type_map).unwrap();
+    write_lib(&args, idl.as_ref(), &type_map).unwrap();
 
     log::info!(
         "{} crate written to {}",
@@ -172,14 +218,42 @@ pub fn main() {
     );
 }
 
-pub fn load_idl(file: &mut File) -> Box<dyn IdlFormat> {
-    match serde_json::from_reader::<&File, AnchorIdl>(file) {
-        Ok(anchor_idl) => {
-            log::info!("Successfully loaded anchor IDL");
-            Box::new(anchor_idl)
+type LoadIdlFn = fn(&serde_json::Value) -> Result<Box<dyn IdlFormat>, serde_json::Error>;
+
+/// Formats to attempt loading the IDL as, in order.
+/// Shank is tried first since shank IDLs also deserialize as legacy anchor IDLs.
+const IDL_FORMATS: [(&str, LoadIdlFn); 4] = [
+    ("codama", |v| Ok(Box::new(CodamaIdl::deserialize(v)?))),
+    ("shank", |v| Ok(Box::new(ShankIdl::deserialize(v)?))),
+    ("anchor", |v| Ok(Box::new(AnchorIdl::deserialize(v)?))),
+    ("legacy anchor", |v| {
+        let legacy = LegacyAnchorIdl::deserialize(v)?;
+        if legacy.is_shank() {
+            return Err(serde::de::Error::custom("metadata.origin is shank"));
         }
-        Err(e) => {
-            panic!("Could not determine IDL format: {:?}", e);
+        Ok(Box::new(AnchorIdl::from(legacy)))
+    }),
+];
+
+/// Loads the IDL from an IDL JSON file or an anchor IDL account,
+/// see [`onchain_idl::read_idl_json`]
+pub fn load_idl(file: &mut File) -> Box<dyn IdlFormat> {
+    let mut contents = Vec::new();
+    file.read_to_end(&mut contents)
+        .unwrap_or_else(|e| panic!("Could not read IDL file: {:?}", e));
+    let idl_json = onchain_idl::read_idl_json(&contents)
+        .unwrap_or_else(|e| panic!("Could not read IDL JSON: {:?}", e));
+    let mut errs = Vec::new();
+    for (format, load) in IDL_FORMATS {
+        match load(&idl_json) {
+            Ok(idl) if idl.is_correct_idl_format() => {
+                log::info!("Successfully loaded {} IDL", format);
+                return idl;
+            }
+            Ok(_) => errs.push(format!("{}: not a {} IDL", format, format)),
+            Err(e) => errs.push(format!("{}: {:?}", format, e)),
         }
     }
+    log::error!("Could not determine IDL format. {}", errs.join(". "));
+    panic!();
 }
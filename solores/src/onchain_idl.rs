@@ -0,0 +1,156 @@
+//! Anchor IDLs stored on-chain.
+//!
+//! The IDL account's data is:
+//! - 8-byte `IdlAccount` discriminant
+//! - 32-byte authority pubkey
+//! - u32 length of the compressed IDL
+//! - zlib-compressed IDL JSON
+
+use std::io::Read;
+
+use base64::Engine;
+use flate2::read::ZlibDecoder;
+use serde::Deserialize;
+
+/// sha256("account:IdlAccount")[..8]
+pub const IDL_ACCOUNT_DISCM: [u8; 8] = [140, 36, 166, 2, 103, 197, 33, 164];
+
+const AUTHORITY_LEN: usize = 32;
+
+const IDL_ACCOUNT_HEADER_LEN: usize = IDL_ACCOUNT_DISCM.len() + AUTHORITY_LEN + 4;
+
+/// `solana account <addr> --output json` output
+#[derive(Deserialize)]
+struct AccountDump {
+    account: DumpedAccount,
+}
+
+#[derive(Deserialize)]
+struct DumpedAccount {
+    /// [data, encoding]
+    data: (String, String),
+}
+
+/// Reads the IDL JSON from the contents of an IDL file, which can be:
+/// - the IDL JSON itself
+/// - a `solana account <idl-addr> --output json` dump of an anchor IDL account
+/// - the raw data of an anchor IDL account
+pub fn read_idl_json(contents: &[u8]) -> std::io::Result<serde_json::Value> {
+    if contents.starts_with(&IDL_ACCOUNT_DISCM) {
+        log::info!("Reading IDL from raw anchor IDL account data");
+        return decompress_idl_account_data(contents);
+    }
+    let json: serde_json::Value = serde_json::from_slice(contents)?;
+    match AccountDump::deserialize(&json) {
+        Ok(AccountDump {
+            account: DumpedAccount {
+                data: (data, encoding),
+            },
+        }) => {
+            log::info!("Reading IDL from anchor IDL account dump");
+            if encoding != "base64" {
+                return Err(std::io::Error::other(format!(
+                    "unsupported account dump encoding {}",
+                    encoding
+                )));
+            }
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(data)
+                .map_err(std::io::Error::other)?;
+            decompress_idl_account_data(&data)
+        }
+        Err(_) => Ok(json),
+    }
+}
+
+/// Inflates the IDL JSON in an anchor IDL account's data
+pub fn decompress_idl_account_data(data: &[u8]) -> std::io::Result<serde_json::Value> {
+    if !data.starts_with(&IDL_ACCOUNT_DISCM) {
+        return Err(std::io::Error::other(format!(
+            "IDL account discm does not match. Expected: {:?}. Received: {:?}",
+            IDL_ACCOUNT_DISCM,
+            &data[..data.len().min(IDL_ACCOUNT_DISCM.len())]
+        )));
+    }
+    let header = data.get(..IDL_ACCOUNT_HEADER_LEN).ok_or_else(|| {
+        std::io::Error::other(format!("IDL account data too short: {} bytes", data.len()))
+    })?;
+    let len_bytes: [u8; 4] = header[IDL_ACCOUNT_HEADER_LEN - 4..].try_into().unwrap();
+    let len = u32::from_le_bytes(len_bytes) as usize;
+    let compressed = data
+        .get(IDL_ACCOUNT_HEADER_LEN..IDL_ACCOUNT_HEADER_LEN + len)
+        .ok_or_else(|| {
+            std::io::Error::other(format!(
+                "IDL account data length {} exceeds account data",
+                len
+            ))
+        })?;
+    let mut json = Vec::new();
+    ZlibDecoder::new(compressed).read_to_end(&mut json)?;
+    Ok(serde_json::from_slice(&json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::ZlibEncoder, Compression};
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+
+    const IDL: &str = r#"{ "version": "0.0.0", "name": "anchor_ix_blank" }"#;
+
+    fn idl_account_data() -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(IDL.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let mut data = IDL_ACCOUNT_DISCM.to_vec();
+        data.extend([1u8; AUTHORITY_LEN]);
+        data.extend((compressed.len() as u32).to_le_bytes());
+        data.extend(compressed);
+        // accounts are allocated larger than the IDL to allow for upgrades
+        data.extend([0u8; 64]);
+        data
+    }
+
+    #[test]
+    fn test_idl_account_discm() {
+        assert_eq!(
+            IDL_ACCOUNT_DISCM,
+            Sha256::digest(b"account:IdlAccount")[..8]
+        );
+    }
+
+    #[test]
+    fn test_read_raw_idl_account() {
+        let expected: serde_json::Value = serde_json::from_str(IDL).unwrap();
+        assert_eq!(read_idl_json(&idl_account_data()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_read_idl_account_dump() {
+        let dump = serde_json::json!({
+            "pubkey": "11111111111111111111111111111111",
+            "account": {
+                "lamports": 1,
+                "data": [base64::engine::general_purpose::STANDARD.encode(idl_account_data()), "base64"],
+                "owner": "11111111111111111111111111111111",
+                "executable": false,
+                "rentEpoch": 0,
+                "space": 0
+            }
+        });
+        let expected: serde_json::Value = serde_json::from_str(IDL).unwrap();
+        assert_eq!(
+            read_idl_json(dump.to_string().as_bytes()).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_read_plain_idl_json() {
+        let expected: serde_json::Value = serde_json::from_str(IDL).unwrap();
+        assert_eq!(read_idl_json(IDL.as_bytes()).unwrap(), expected);
+    }
+}
@@ -0,0 +1,116 @@
+use std::{collections::BTreeMap, fs};
+
+use serde::Deserialize;
+use toml::{map::Map, Value};
+
+use crate::{
+    write_cargotoml::{DependencyValue, BORSH_CRATE, BYTEMUCK_CRATE, SOLANA_PROGRAM_CRATE},
+    Args,
+};
+
+/// Path roots that are not crates to add as dependencies
+const NON_CRATE_ROOTS: [&str; 6] = ["crate", "self", "super", "std", "core", "alloc"];
+
+/// `defined` type names mapped to external types instead of being generated,
+/// from `--type-map-file` and `--type-map`
+#[derive(Debug, Default, Deserialize)]
+pub struct TypeMap {
+    /// IDL type name -> rust path
+    #[serde(default)]
+    pub types: BTreeMap<String, String>,
+    /// Dependencies of the generated crate for the mapped types,
+    /// in the same format as `Cargo.toml`'s
+    #[serde(default)]
+    pub dependencies: Map<String, Value>,
+}
+
+impl TypeMap {
+    /// `--type-map` entries take precedence over `--type-map-file`'s
+    pub fn from_args(args: &Args) -> Self {
+        let mut res = match &args.type_map_file {
+            Some(path) => {
+                let contents = fs::read_to_string(path)
+                    .unwrap_or_else(|e| panic!("Could not read type map file: {:?}", e));
+                toml::from_str(&contents).unwrap_or_else(|e| {
+                    log::error!("Invalid type map file {}: {}", path.to_string_lossy(), e);
+                    panic!();
+                })
+            }
+            None => Self::default(),
+        };
+        for entry in &args.type_map {
+            let (name, path) = entry.split_once('=').unwrap_or_else(|| {
+                log::error!(
+                    "--type-map {} is not of the form Name=path::to::Type",
+                    entry
+                );
+                panic!();
+            });
+            res.types
+                .insert(name.trim().to_owned(), path.trim().to_owned());
+        }
+        res
+    }
+
+    /// [`Self::dependencies`], plus the root crate of every mapped path not in it,
+    /// defaulting to `workspace = true`
+    pub fn cargo_dependencies(&self) -> Map<String, Value> {
+        let mut res = self.dependencies.clone();
+        let existing = [BORSH_CRATE, BYTEMUCK_CRATE, SOLANA_PROGRAM_CRATE];
+        for path in self.types.values() {
+            let root = match path.trim().trim_start_matches("::").split_once("::") {
+                Some((root, _)) => root.trim(),
+                None => continue,
+            };
+            let is_listed = |name: &str| name.replace('-', "_") == root;
+            if NON_CRATE_ROOTS.contains(&root)
+                || existing.iter().any(|c| is_listed(c))
+                || res.keys().any(|k| is_listed(k))
+            {
+                continue;
+            }
+            res.insert(root.to_owned(), DependencyValue("workspace = true").into());
+        }
+        res
+    }
+
+    /// The path the `defined` type `name` is mapped to, if any
+    pub fn path(&self, name: &str) -> Option<&str> {
+        self.types.get(name).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_cargo_dependencies() {
+        let args = Args::parse_from([
+            "solores",
+            "idl.json",
+            "--type-map",
+            "I80F48=fixed::types::I80F48",
+            "--type-map",
+            "Price=common_types::Price",
+            "--type-map",
+            "Hash=solana_program::hash::Hash",
+            "--type-map",
+            "Wrapped=u128",
+            "--type-map",
+            "Rate=::spl_math::Rate",
+        ]);
+        let mut type_map = TypeMap::from_args(&args);
+        type_map
+            .dependencies
+            .insert("common-types".into(), Value::String("^0.1".into()));
+        let deps = type_map.cargo_dependencies();
+        assert_eq!(
+            deps.keys().collect::<Vec<_>>(),
+            ["common-types", "fixed", "spl_math"]
+        );
+        assert_eq!(deps["fixed"].to_string(), "{ workspace = true }");
+    }
+}
@@ -9,6 +9,8 @@ use std::{
 };
 
 use heck::ToPascalCase;
+use proc_macro2::TokenStream;
+use quote::quote;
 use serde::{
     de::{self, MapAccess, Visitor},
     Deserialize, Deserializer,
@@ -21,16 +23,8 @@ pub fn primitive_or_pubkey_to_token(s: &str) -> String {
     match s {
         "pubkey" | "publicKey" => PUBKEY_TOKEN.to_owned(),
         "string" => s.to_pascal_case(),
-        "bytes" => {
-            #[cfg(feature = "bytes_to_u8")]
-            {
-                "u8".to_owned()
-            }
-            #[cfg(not(feature = "bytes_to_u8"))]
-            {
-                "bytes".to_owned()
-            }
-        }
+        "u256" | "i256" => s.to_uppercase(),
+        "bytes" => "Vec<u8>".to_owned(),
         _ => s.to_owned(),
     }
 }
@@ -115,9 +109,20 @@ where
     UniqueByReportDupsResult { unique, duplicates }
 }
 
+/// `#[doc = ...]` attributes, one per line of an IDL `docs` array
+pub fn docs_tokens(docs: Option<&[String]>) -> TokenStream {
+    let lines = docs
+        .unwrap_or_default()
+        .iter()
+        .map(|line| format!(" {}", line));
+    quote! {
+        #(#[doc = #lines])*
+    }
+}
+
 pub fn conditional_pascal_case(s: &str) -> String {
     // Only apply PascalCase if the string does not start with an uppercase letter.
-    if s.chars().next().map_or(false, |c| c.is_uppercase()) {
+    if s.chars().next().is_some_and(|c| c.is_uppercase()) {
         s.to_string()
     } else {
         s.to_pascal_case()
@@ -129,23 +134,9 @@ mod tests {
     use super::*;
 
     #[test]
-    #[cfg(feature = "bytes_to_u8")]
-    fn test_bytes_to_u8_feature_enabled() {
-        let result = primitive_or_pubkey_to_token("bytes");
-        assert_eq!(result, "u8");
-
-        let result = primitive_or_pubkey_to_token("publicKey");
-        assert_eq!(result, PUBKEY_TOKEN.to_owned());
-
-        let result = primitive_or_pubkey_to_token("string");
-        assert_eq!(result, "String");
-    }
-
-    #[test]
-    #[cfg(not(feature = "bytes_to_u8"))]
-    fn test_bytes_to_u8_feature_disabled() {
+    fn test_primitive_or_pubkey_to_token() {
         let result = primitive_or_pubkey_to_token("bytes");
-        assert_eq!(result, "bytes");
+        assert_eq!(result, "Vec<u8>");
 
         let result = primitive_or_pubkey_to_token("publicKey");
         assert_eq!(result, PUBKEY_TOKEN.to_owned());
@@ -209,4 +200,18 @@ mod tests {
         let expected = "Uppercase";
         assert_eq!(conditional_pascal_case(input), expected);
     }
+
+    #[test]
+    fn test_docs_tokens() {
+        let docs = ["first line".to_owned(), "second line".to_owned()];
+        assert_eq!(
+            docs_tokens(Some(&docs)).to_string(),
+            quote! {
+                #[doc = " first line"]
+                #[doc = " second line"]
+            }
+            .to_string()
+        );
+        assert!(docs_tokens(None).is_empty());
+    }
 }
@@ -3,20 +3,26 @@ use std::io::Write;
 use serde::Serialize;
 use toml::{map::Map, Value};
 
-use crate::{idl_format::IdlFormat, utils::open_file_create_overwrite, Args};
+use crate::{idl_format::IdlFormat, type_map::TypeMap, utils::open_file_create_overwrite, Args};
 
+pub const BASE64_CRATE: &str = "base64";
 pub const BORSH_CRATE: &str = "borsh";
 pub const BYTEMUCK_CRATE: &str = "bytemuck";
 pub const SERDE_CRATE: &str = "serde";
 pub const SERDE_BYTES_CRATE: &str = "serde_bytes";
 pub const SERDE_BIG_ARRAY_CRATE: &str = "serde-big-array";
 pub const SOLANA_PROGRAM_CRATE: &str = "solana-program";
+pub const SOLANA_RPC_CLIENT_API_CRATE: &str = "solana-rpc-client-api";
 pub const THISERROR_CRATE: &str = "thiserror";
 pub const NUM_DERIVE_CRATE: &str = "num-derive";
 pub const NUM_TRAITS_CRATE: &str = "num-traits";
 
-pub fn write_cargotoml(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
-    let cargo_toml = CargoToml::from_args_and_idl(args, idl);
+pub fn write_cargotoml(
+    args: &Args,
+    idl: &dyn IdlFormat,
+    type_map: &TypeMap,
+) -> std::io::Result<()> {
+    let cargo_toml = CargoToml::from_args_and_idl(args, idl, type_map);
     let cargo_toml_str = toml::to_string(&cargo_toml).unwrap();
 
     let path = args.output_dir.join("Cargo.toml");
@@ -33,7 +39,7 @@ pub struct CargoToml<'a> {
 }
 
 impl<'a> CargoToml<'a> {
-    pub fn from_args_and_idl(args: &'a Args, idl: &'a dyn IdlFormat) -> Self {
+    pub fn from_args_and_idl(args: &'a Args, idl: &'a dyn IdlFormat, type_map: &TypeMap) -> Self {
         let mut features = Map::new();
         features.insert(
             "serde".into(),
@@ -44,13 +50,25 @@ impl<'a> CargoToml<'a> {
             ]),
         );
 
+        let mut dependencies = idl.dependencies(args);
+        dependencies.extend(type_map.cargo_dependencies());
+        if dependencies.contains_key(SOLANA_RPC_CLIENT_API_CRATE) {
+            features.insert(
+                "client".into(),
+                Value::Array(vec![Value::String(format!(
+                    "dep:{}",
+                    SOLANA_RPC_CLIENT_API_CRATE
+                ))]),
+            );
+        }
+
         Self {
             package: Package {
                 name: &args.output_crate_name,
                 version: idl.program_version(),
                 edition: &args.cargo_edition,
             },
-            dependencies: idl.dependencies(args),
+            dependencies,
             features,
         }
     }
@@ -2,7 +2,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::{io::Write, path::Path};
 
-use crate::{idl_format::IdlFormat, utils::open_file_create_overwrite, Args};
+use crate::{idl_format::IdlFormat, type_map::TypeMap, utils::open_file_create_overwrite, Args};
 
 const DEFAULT_PROGRAM_ID_STR: &str = "TH1S1SNoTAVAL1DPUBKEYDoNoTUSE11111111111111";
 
@@ -25,7 +25,7 @@ fn is_valid_pubkey(s: &str) -> bool {
     true
 }
 
-pub fn write_lib(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
+pub fn write_lib(args: &Args, idl: &dyn IdlFormat, type_map: &TypeMap) -> std::io::Result<()> {
     let user_provided_id_opt =
         args.program_id
             .as_ref()
@@ -46,7 +46,7 @@ pub fn write_lib(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
         solana_program::declare_id!(#id);
     };
 
-    for module in idl.modules(args) {
+    for module in idl.modules(args, type_map) {
         let module_name = module.name();
         let module_ident = Ident::new(module.name(), Span::call_site());
         contents.extend(quote! {
@@ -55,7 +55,7 @@ pub fn write_lib(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
         });
         let mut module_contents = module.gen_head();
         module_contents.extend(module.gen_body());
-        write_src_file(args, &format!("src/{module_name}.rs"), module_contents)?;
+        write_src_file(args, format!("src/{module_name}.rs"), module_contents)?;
     }
 
     write_src_file(args, "src/lib.rs", contents)
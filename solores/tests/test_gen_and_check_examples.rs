@@ -1,6 +1,6 @@
 #![cfg(feature = "test_gen_examples")]
 
-use test_utils::{check_example, gen_example};
+use test_utils::{check_example, example_dir, gen_example};
 
 const BASE_WORKSPACE_DEPS_ARGS: [&str; 14] = [
     "--solana-program-vers",
@@ -19,7 +19,6 @@ const BASE_WORKSPACE_DEPS_ARGS: [&str; 14] = [
     "workspace=true",
 ];
 
-
 #[test]
 fn test_unstake_it() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "anchor/unstake_it";
@@ -69,6 +68,77 @@ fn test_anchor_ix_blank() -> Result<(), Box<dyn std::error::Error>> {
     check_example(EXAMPLE_PATH, "anchor_ix_blank_interface")
 }
 
+#[test]
+fn test_anchor_generics() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/generics";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_generics_interface")
+}
+
+#[test]
+fn test_anchor_typedef_kinds() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/typedef_kinds";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_typedef_kinds_interface")
+}
+
+#[test]
+fn test_anchor_collections() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/collections";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_collections_interface")
+}
+
+#[test]
+fn test_anchor_zero_copy() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/zero_copy";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_zero_copy_interface")
+}
+
+#[test]
+fn test_anchor_type_map() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/type_map";
+    let type_map_file = example_dir(EXAMPLE_PATH).join("type_map.toml");
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--type-map-file",
+                type_map_file.to_str().unwrap(),
+                "--type-map",
+                "Fraction=u128",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "anchor_type_map_interface")
+}
+
+#[test]
+fn test_anchor_nested_accounts() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/nested_accounts";
+    gen_example(
+        EXAMPLE_PATH,
+        &[BASE_WORKSPACE_DEPS_ARGS.as_ref(), &["--nested-accounts"]].concat(),
+    )?;
+    check_example(EXAMPLE_PATH, "anchor_nested_accounts_interface")
+}
+
+#[test]
+fn test_anchor_constants() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/constants";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_constants_interface")
+}
+
+#[test]
+fn test_raydium_cp_swap() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/raydium-cpmm";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "raydium_cp_swap_interface")
+}
 
 #[test]
 fn test_drift() -> Result<(), Box<dyn std::error::Error>> {
@@ -87,3 +157,16 @@ fn test_drift() -> Result<(), Box<dyn std::error::Error>> {
     check_example(EXAMPLE_PATH, "drift_interface")
 }
 
+#[test]
+fn test_shank_vault() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "shank/vault";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "vault_interface")
+}
+
+#[test]
+fn test_codama_counter() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "codama/counter";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "counter_interface")
+}